@@ -34,6 +34,9 @@ fn dedupe(c: &mut Criterion) {
     );
     let cache = CacheConfig {
         num_events: NonZeroUsize::new(4).unwrap(),
+        ttl_secs: None,
+        ttl_mode: Default::default(),
+        persistence: None,
     };
     for param in &[
         // Measurement where field "message" is ignored. This field is
@@ -44,6 +47,7 @@ fn dedupe(c: &mut Criterion) {
             dedupe_config: DedupeConfig {
                 fields: Some(FieldMatchConfig::IgnoreFields(vec!["message".into()])),
                 cache: cache.clone(),
+                internal_metrics: Default::default(),
             },
         },
         // Modification of previous where field "message" is matched.
@@ -53,6 +57,7 @@ fn dedupe(c: &mut Criterion) {
             dedupe_config: DedupeConfig {
                 fields: Some(FieldMatchConfig::MatchFields(vec!["message".into()])),
                 cache: cache.clone(),
+                internal_metrics: Default::default(),
             },
         },
         // Measurement where ignore fields do not exist in the event.
@@ -68,6 +73,7 @@ fn dedupe(c: &mut Criterion) {
                     "cdeab".into(),
                     "bcdea".into(),
                 ])),
+                internal_metrics: Default::default(),
             },
         },
         // Modification of previous where match fields do not exist in the
@@ -84,6 +90,7 @@ fn dedupe(c: &mut Criterion) {
                     "cdeab".into(),
                     "bcdea".into(),
                 ])),
+                internal_metrics: Default::default(),
             },
         },
     ] {
@@ -44,12 +44,16 @@ fn reduce(c: &mut Criterion) {
             input: fixed_stream.clone(),
             reduce_config: ReduceConfig {
                 expire_after_ms: Duration::from_secs(30),
+                end_every_period_ms: None,
                 flush_period_ms: Duration::from_secs(1),
                 group_by: vec![String::from("message")],
                 merge_strategies: IndexMap::default(),
                 ends_when: None,
                 starts_when: None,
                 max_events: None,
+                max_groups: None,
+                max_memory_bytes: None,
+                persistence: None,
             },
         },
     ] {
@@ -1,3 +1,6 @@
 //! A collection of common utility features used by both encoding and decoding logic.
 
 pub mod length_delimited;
+#[cfg(feature = "schema-registry")]
+pub mod schema_registry;
+pub mod varint_length_delimited;
@@ -0,0 +1,746 @@
+//! Support for the [Confluent Schema Registry][schema_registry] and its [wire format][wire_format].
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+//! [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http::{header, HeaderValue, Method, Request, Uri};
+use hyper::{client::HttpConnector, Body, Client};
+use hyper_openssl::HttpsConnector;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_with::serde_as;
+use tokio::runtime::{Builder, Handle, RuntimeFlavor};
+use tracing::warn;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::tls::{tls_connector_builder, MaybeTlsSettings, TlsConfig};
+
+/// The byte data in the wire format starts with.
+const MAGIC_BYTE: u8 = 0;
+
+/// The length of the magic byte and schema ID data in the wire format starts with.
+pub const SCHEMA_ID_PREFIX_LEN: usize = 5;
+
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// The maximum number of lookups cached by a client, successful or not.
+pub const MAX_CACHED_LOOKUPS: usize = 1000;
+
+/// How long a failed lookup is cached before it is attempted again.
+const FAILED_LOOKUP_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Options for resolving schemas from a [Confluent Schema Registry][schema_registry].
+///
+/// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaRegistryConfig {
+    /// The URL of the Schema Registry.
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    pub url: String,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SchemaRegistryAuth>,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+
+    /// The timeout for requests to the Schema Registry, in seconds.
+    #[serde(default = "default_timeout_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    pub timeout_secs: Duration,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub subject_name_strategy: SubjectNameStrategy,
+
+    /// The topic used to name the subject of the schema when encoding.
+    ///
+    /// Required when `subject_name_strategy` is `topic_name` or `topic_record_name`. Only used
+    /// when encoding.
+    #[configurable(metadata(docs::examples = "logs"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Registers the schema under its subject when encoding, if it is not registered yet.
+    ///
+    /// When disabled, the schema must already be registered under its subject. Only used when
+    /// encoding.
+    #[serde(default)]
+    pub auto_register_schemas: bool,
+}
+
+const fn default_timeout_secs() -> Duration {
+    Duration::from_secs(10)
+}
+
+/// Authentication for the Schema Registry.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+#[configurable(metadata(docs::enum_tag_description = "The authentication strategy to use."))]
+pub enum SchemaRegistryAuth {
+    /// Basic authentication.
+    ///
+    /// The username and password are concatenated and encoded via [base64][base64].
+    ///
+    /// [base64]: https://en.wikipedia.org/wiki/Base64
+    Basic {
+        /// The basic authentication username.
+        #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_USERNAME}"))]
+        #[configurable(metadata(docs::examples = "username"))]
+        user: String,
+
+        /// The basic authentication password.
+        #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_PASSWORD}"))]
+        #[configurable(metadata(docs::examples = "password"))]
+        password: SensitiveString,
+    },
+
+    /// Bearer authentication.
+    ///
+    /// The bearer token value (OAuth2, JWT, etc.) is passed as-is.
+    Bearer {
+        /// The bearer authentication token.
+        token: SensitiveString,
+    },
+}
+
+impl SchemaRegistryAuth {
+    fn header_value(&self) -> Result<HeaderValue, http::header::InvalidHeaderValue> {
+        let value = match self {
+            Self::Basic { user, password } => format!(
+                "Basic {}",
+                BASE64_STANDARD.encode(format!("{user}:{}", password.inner()))
+            ),
+            Self::Bearer { token } => format!("Bearer {}", token.inner()),
+        };
+        let mut value = HeaderValue::from_str(&value)?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+}
+
+/// How the subject a schema is registered under is named when encoding.
+///
+/// See the [Confluent documentation][subject_name_strategy] for more details.
+///
+/// [subject_name_strategy]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#subject-name-strategy
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectNameStrategy {
+    /// The subject is named `<topic>-value`.
+    #[default]
+    TopicName,
+
+    /// The subject is named after the fully-qualified name of the record or message type.
+    RecordName,
+
+    /// The subject is named `<topic>-<fully-qualified record or message type name>`.
+    TopicRecordName,
+}
+
+/// The format of a schema registered in the Schema Registry.
+///
+/// JSON schemas are not supported, as no codec decodes or encodes data with them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SchemaType {
+    /// An Apache Avro schema.
+    #[default]
+    Avro,
+
+    /// A Protobuf schema.
+    Protobuf,
+}
+
+/// A schema registered in the Schema Registry.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredSchema {
+    /// The format of the schema. The Schema Registry omits it for Avro schemas.
+    #[serde(default)]
+    pub schema_type: SchemaType,
+
+    /// The schema definition.
+    pub schema: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaRequest<'a> {
+    schema: &'a str,
+    schema_type: SchemaType,
+}
+
+#[derive(Deserialize)]
+struct SchemaIdResponse {
+    id: u32,
+}
+
+/// What the ID of a schema is looked up by.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum SchemaIdKey {
+    Schema {
+        subject: String,
+        schema_type: SchemaType,
+        schema: String,
+    },
+    Latest(String),
+}
+
+impl fmt::Display for SchemaIdKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Schema { subject, .. } => write!(f, "The ID of the schema under `{subject}`"),
+            Self::Latest(subject) => write!(f, "The ID of the latest schema under `{subject}`"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Lookup<V> {
+    Resolved(V),
+    Failed { error: String, at: Instant },
+}
+
+/// The cached lookups of a client.
+#[derive(Debug)]
+struct Lookups<K, V>(Mutex<HashMap<K, Lookup<V>>>);
+
+impl<K, V> Default for Lookups<K, V> {
+    fn default() -> Self {
+        Self(Mutex::default())
+    }
+}
+
+impl<K, V> Lookups<K, V>
+where
+    K: Clone + Eq + Hash + fmt::Display,
+    V: Clone,
+{
+    /// Returns the cached result of the lookup of `key`, making the lookup and waiting for it if it
+    /// hasn't been made yet or its last attempt failed long enough ago.
+    fn get<F, Fut>(&self, key: K, fetch: F) -> vector_common::Result<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = vector_common::Result<V>> + Send,
+    {
+        match self.0.lock().expect("poisoned lock").get(&key) {
+            Some(Lookup::Resolved(value)) => return Ok(value.clone()),
+            Some(Lookup::Failed { error, at }) if at.elapsed() < FAILED_LOOKUP_RETRY_INTERVAL => {
+                return Err(error.clone().into())
+            }
+            Some(Lookup::Failed { .. }) | None => {}
+        }
+
+        let (lookup, result) = match block_on(fetch())? {
+            Ok(value) => (Lookup::Resolved(value.clone()), Ok(value)),
+            Err(error) => {
+                let error =
+                    format!("{key} could not be resolved from the Schema Registry: {error}");
+                warn!(message = "Schema Registry lookup failed.", %error);
+                let lookup = Lookup::Failed {
+                    error: error.clone(),
+                    at: Instant::now(),
+                };
+                (lookup, Err(error.into()))
+            }
+        };
+
+        let mut lookups = self.0.lock().expect("poisoned lock");
+        if lookups.len() >= MAX_CACHED_LOOKUPS && !lookups.contains_key(&key) {
+            evict_one(&mut lookups);
+        }
+        lookups.insert(key, lookup);
+        result
+    }
+}
+
+/// Waits for the given future from the synchronous code of a codec.
+///
+/// Codecs run on the worker threads of a multi-threaded runtime, which can wait for the future with
+/// `block_in_place`. A current-thread runtime can't make progress while it waits, so the future is
+/// run on its own runtime in a separate thread instead.
+fn block_on<F>(future: F) -> vector_common::Result<F::Output>
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    let runtime = Handle::try_current()
+        .map_err(|_| "Schema Registry lookups must be made from within a Tokio runtime")?;
+    match runtime.runtime_flavor() {
+        RuntimeFlavor::CurrentThread => std::thread::scope(|scope| {
+            scope
+                .spawn(|| -> vector_common::Result<F::Output> {
+                    let runtime = Builder::new_current_thread().enable_all().build()?;
+                    Ok(runtime.block_on(future))
+                })
+                .join()
+                .expect("Schema Registry lookup panicked")
+        }),
+        _ => Ok(tokio::task::block_in_place(|| runtime.block_on(future))),
+    }
+}
+
+/// Makes room for a new lookup, preferring to forget failed lookups.
+fn evict_one<K: Clone + Eq + Hash, V>(lookups: &mut HashMap<K, Lookup<V>>) {
+    let key = lookups
+        .iter()
+        .find(|(_, lookup)| matches!(lookup, Lookup::Failed { .. }))
+        .or_else(|| lookups.iter().next())
+        .map(|(key, _)| key.clone());
+    if let Some(key) = key {
+        lookups.remove(&key);
+    }
+}
+
+type HttpsClient = Client<HttpsConnector<HttpConnector>, Body>;
+
+/// A client for the Schema Registry.
+///
+/// Codecs are called synchronously from the tasks of their components, so the first lookup of
+/// something blocks the calling task until the Schema Registry responds. Successful lookups are
+/// cached for as long as the client lives, as registered schemas and their IDs are immutable, while
+/// failed lookups are only attempted again after 30 seconds. At most [`MAX_CACHED_LOOKUPS`] lookups
+/// are cached.
+#[derive(Clone)]
+pub struct SchemaRegistryClient {
+    config: Arc<SchemaRegistryConfig>,
+    http_client: HttpsClient,
+    schemas: Arc<Lookups<u32, Arc<RegisteredSchema>>>,
+    schema_ids: Arc<Lookups<SchemaIdKey, u32>>,
+}
+
+impl fmt::Debug for SchemaRegistryClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaRegistryClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SchemaRegistryClient {
+    /// Creates a new `SchemaRegistryClient`.
+    pub fn new(config: SchemaRegistryConfig) -> vector_common::Result<Self> {
+        let tls_settings = MaybeTlsSettings::tls_client(config.tls.as_ref())?;
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        let mut https =
+            HttpsConnector::with_connector(http, tls_connector_builder(&tls_settings)?)?;
+        let settings = tls_settings.tls().cloned();
+        https.set_callback(move |c, _uri| match &settings {
+            Some(settings) => settings.apply_connect_configuration(c),
+            None => Ok(()),
+        });
+
+        Ok(Self {
+            config: Arc::new(config),
+            // Lookups may run on a runtime of their own, see `block_on`, which closes its
+            // connections once the lookup is done, so connections aren't kept for later lookups.
+            http_client: Client::builder().pool_max_idle_per_host(0).build(https),
+            schemas: Arc::default(),
+            schema_ids: Arc::default(),
+        })
+    }
+
+    /// Returns the schema registered with the given ID.
+    pub fn schema_by_id(&self, id: u32) -> vector_common::Result<Arc<RegisteredSchema>> {
+        self.schemas.get(id, || {
+            let client = self.clone();
+            async move {
+                let schema = client
+                    .request(Method::GET, &format!("/schemas/ids/{id}"), None)
+                    .await?;
+                Ok(Arc::new(schema))
+            }
+        })
+    }
+
+    /// Returns the name of the subject the schema of the given record or message type is
+    /// registered under, according to the configured subject name strategy.
+    pub fn subject(&self, record_name: &str) -> Result<String, String> {
+        let topic = || {
+            self.config.topic.as_deref().ok_or_else(|| {
+                "`topic` must be set to use a topic based `subject_name_strategy`".to_string()
+            })
+        };
+        Ok(match self.config.subject_name_strategy {
+            SubjectNameStrategy::TopicName => format!("{}-value", topic()?),
+            SubjectNameStrategy::RecordName => record_name.to_string(),
+            SubjectNameStrategy::TopicRecordName => format!("{}-{record_name}", topic()?),
+        })
+    }
+
+    /// Returns the ID of the given schema under the given subject, registering it first if
+    /// `auto_register_schemas` is enabled.
+    pub fn schema_id(
+        &self,
+        subject: &str,
+        schema_type: SchemaType,
+        schema: &str,
+    ) -> vector_common::Result<u32> {
+        let key = SchemaIdKey::Schema {
+            subject: subject.to_string(),
+            schema_type,
+            schema: schema.to_string(),
+        };
+        self.schema_ids.get(key, || {
+            let client = self.clone();
+            let path = if self.config.auto_register_schemas {
+                format!("/subjects/{subject}/versions")
+            } else {
+                format!("/subjects/{subject}")
+            };
+            let body = serde_json::to_vec(&SchemaRequest {
+                schema,
+                schema_type,
+            });
+            async move {
+                let response: SchemaIdResponse =
+                    client.request(Method::POST, &path, Some(body?)).await?;
+                Ok(response.id)
+            }
+        })
+    }
+
+    /// Returns the ID of the latest schema registered under the given subject.
+    pub fn latest_schema_id(&self, subject: &str) -> vector_common::Result<u32> {
+        self.schema_ids
+            .get(SchemaIdKey::Latest(subject.to_string()), || {
+                let client = self.clone();
+                let path = format!("/subjects/{subject}/versions/latest");
+                async move {
+                    let response: SchemaIdResponse =
+                        client.request(Method::GET, &path, None).await?;
+                    Ok(response.id)
+                }
+            })
+    }
+
+    /// Whether schemas are registered when encoding.
+    pub fn auto_register_schemas(&self) -> bool {
+        self.config.auto_register_schemas
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+    ) -> vector_common::Result<T> {
+        let uri: Uri = format!("{}{path}", self.config.url.trim_end_matches('/')).parse()?;
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri.clone())
+            .header(header::ACCEPT, CONTENT_TYPE);
+        if let Some(auth) = &self.config.auth {
+            request = request.header(header::AUTHORIZATION, auth.header_value()?);
+        }
+        let request = match body {
+            Some(body) => request
+                .header(header::CONTENT_TYPE, CONTENT_TYPE)
+                .body(Body::from(body))?,
+            None => request.body(Body::empty())?,
+        };
+
+        let response =
+            tokio::time::timeout(self.config.timeout_secs, self.http_client.request(request))
+                .await
+                .map_err(|_| format!("Request to Schema Registry at {uri} timed out"))??;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if !status.is_success() {
+            return Err(format!(
+                "Schema Registry at {uri} responded with {status}: {}",
+                String::from_utf8_lossy(&body)
+            )
+            .into());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+/// Splits data in the wire format into the ID of the schema it was written with and the data
+/// itself.
+pub fn split_schema_id(mut bytes: Bytes) -> vector_common::Result<(u32, Bytes)> {
+    if bytes.len() < SCHEMA_ID_PREFIX_LEN || bytes[0] != MAGIC_BYTE {
+        return Err("Expected data to be prefixed with a schema ID".into());
+    }
+    bytes.advance(1);
+    let id = bytes.get_u32();
+    Ok((id, bytes))
+}
+
+/// Writes the prefix of data in the wire format, for data written with the given schema.
+pub fn put_schema_id(id: u32, buffer: &mut BytesMut) {
+    buffer.put_u8(MAGIC_BYTE);
+    buffer.put_u32(id);
+}
+
+/// Splits Protobuf data following the schema ID into the indexes of its message type in the
+/// schema and the message itself.
+///
+/// Each index is the position of a message type within the file or message type declaring it,
+/// starting with the top-level message type.
+pub fn split_message_indexes(mut bytes: Bytes) -> vector_common::Result<(Vec<i32>, Bytes)> {
+    let count = decode_zigzag(&mut bytes)?;
+    let indexes = if count == 0 {
+        // The first message type of the schema is encoded as a single zero.
+        vec![0]
+    } else {
+        (0..count)
+            .map(|_| decode_zigzag(&mut bytes))
+            .collect::<Result<_, _>>()?
+    };
+    Ok((indexes, bytes))
+}
+
+/// Writes the indexes of the message type of Protobuf data in its schema.
+pub fn put_message_indexes(indexes: &[i32], buffer: &mut BytesMut) {
+    if indexes == [0] {
+        buffer.put_u8(0);
+        return;
+    }
+    encode_zigzag(indexes.len() as i32, buffer);
+    for index in indexes {
+        encode_zigzag(*index, buffer);
+    }
+}
+
+fn decode_zigzag(bytes: &mut Bytes) -> vector_common::Result<i32> {
+    let value = prost::encoding::decode_varint(bytes)? as u32;
+    Ok(((value >> 1) as i32) ^ -((value & 1) as i32))
+}
+
+fn encode_zigzag(value: i32, buffer: &mut BytesMut) {
+    let value = ((value << 1) ^ (value >> 31)) as u32;
+    prost::encoding::encode_varint(u64::from(value), buffer);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    fn config(url: &str) -> SchemaRegistryConfig {
+        SchemaRegistryConfig {
+            url: url.to_string(),
+            auth: None,
+            tls: None,
+            timeout_secs: default_timeout_secs(),
+            subject_name_strategy: SubjectNameStrategy::TopicName,
+            topic: Some("logs".to_string()),
+            auto_register_schemas: false,
+        }
+    }
+
+    fn client(strategy: SubjectNameStrategy, topic: Option<&str>) -> SchemaRegistryClient {
+        SchemaRegistryClient::new(SchemaRegistryConfig {
+            subject_name_strategy: strategy,
+            topic: topic.map(str::to_string),
+            ..config("http://localhost:8081")
+        })
+        .unwrap()
+    }
+
+    /// Serves the given responses by method and path, returning the URL to reach them at and the
+    /// number of requests served so far. Other requests are answered with a 404.
+    async fn serve(
+        responses: &'static [(&'static str, &'static str)],
+    ) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let served = Arc::clone(&requests);
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                let head_len = loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    if let Some(position) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        break position + 4;
+                    }
+                };
+                let head = String::from_utf8_lossy(&request[..head_len]).to_lowercase();
+                let content_length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |length| length.trim().parse().unwrap());
+                while request.len() < head_len + content_length {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+
+                served.fetch_add(1, Ordering::SeqCst);
+                let request_line = head.lines().next().unwrap().to_string();
+                let (status, body) = responses
+                    .iter()
+                    .find(|(route, _)| request_line.starts_with(&route.to_lowercase()))
+                    .map_or(("404 Not Found", r#"{"error_code":40403}"#), |(_, body)| {
+                        ("200 OK", *body)
+                    });
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn schemas_are_resolved_on_first_lookup() {
+        let (url, requests) = serve(&[("GET /schemas/ids/1 ", r#"{"schema":"\"string\""}"#)]).await;
+        let client = SchemaRegistryClient::new(config(&url)).unwrap();
+
+        let schema = client.schema_by_id(1).unwrap();
+        assert_eq!(schema.schema_type, SchemaType::Avro);
+        assert_eq!(schema.schema, r#""string""#);
+        assert!(client.schema_by_id(1).is_ok());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn failed_lookups_are_cached() {
+        let (url, requests) = serve(&[]).await;
+        let client = SchemaRegistryClient::new(config(&url)).unwrap();
+
+        let error = client.latest_schema_id("logs-value").unwrap_err();
+        assert!(error.to_string().contains("404"), "{error}");
+
+        let error = client.latest_schema_id("logs-value").unwrap_err();
+        assert!(error.to_string().contains("404"), "{error}");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn schemas_are_registered() {
+        let (url, _) = serve(&[("POST /subjects/logs-value/versions ", r#"{"id":7}"#)]).await;
+        let client = SchemaRegistryClient::new(SchemaRegistryConfig {
+            auto_register_schemas: true,
+            ..config(&url)
+        })
+        .unwrap();
+
+        let id = client
+            .schema_id("logs-value", SchemaType::Avro, r#""string""#)
+            .unwrap();
+        assert_eq!(id, 7);
+    }
+
+    #[test]
+    fn lookups_require_a_runtime() {
+        let error = client(SubjectNameStrategy::TopicName, Some("logs"))
+            .schema_by_id(1)
+            .unwrap_err();
+        assert!(error.to_string().contains("Tokio runtime"), "{error}");
+    }
+
+    #[test]
+    fn cache_is_bounded() {
+        let mut lookups = HashMap::new();
+        lookups.insert(1, Lookup::Resolved(1));
+        lookups.insert(
+            2,
+            Lookup::Failed {
+                error: String::new(),
+                at: Instant::now(),
+            },
+        );
+
+        evict_one(&mut lookups);
+        assert!(!lookups.contains_key(&2));
+        evict_one(&mut lookups);
+        assert!(lookups.is_empty());
+    }
+
+    #[test]
+    fn schema_id_round_trip() {
+        let mut buffer = BytesMut::new();
+        put_schema_id(42, &mut buffer);
+        buffer.put_slice(b"data");
+
+        let (id, data) = split_schema_id(buffer.freeze()).unwrap();
+        assert_eq!(id, 42);
+        assert_eq!(data, b"data".as_slice());
+    }
+
+    #[test]
+    fn split_schema_id_requires_prefix() {
+        assert!(split_schema_id(Bytes::from_static(b"\x01\0\0\0\x01data")).is_err());
+        assert!(split_schema_id(Bytes::from_static(b"\0\0")).is_err());
+    }
+
+    #[test]
+    fn message_indexes_round_trip() {
+        for indexes in [vec![0], vec![1], vec![2, 0, 3]] {
+            let mut buffer = BytesMut::new();
+            put_message_indexes(&indexes, &mut buffer);
+            buffer.put_slice(b"message");
+
+            let (decoded, message) = split_message_indexes(buffer.freeze()).unwrap();
+            assert_eq!(decoded, indexes);
+            assert_eq!(message, b"message".as_slice());
+        }
+    }
+
+    #[test]
+    fn first_message_index_is_a_single_zero() {
+        let mut buffer = BytesMut::new();
+        put_message_indexes(&[0], &mut buffer);
+        assert_eq!(buffer.as_ref(), b"\0");
+    }
+
+    #[test]
+    fn subject_name_strategies() {
+        let record = "com.example.Log";
+        assert_eq!(
+            client(SubjectNameStrategy::TopicName, Some("logs"))
+                .subject(record)
+                .unwrap(),
+            "logs-value"
+        );
+        assert_eq!(
+            client(SubjectNameStrategy::RecordName, None)
+                .subject(record)
+                .unwrap(),
+            "com.example.Log"
+        );
+        assert_eq!(
+            client(SubjectNameStrategy::TopicRecordName, Some("logs"))
+                .subject(record)
+                .unwrap(),
+            "logs-com.example.Log"
+        );
+        assert!(client(SubjectNameStrategy::TopicName, None)
+            .subject(record)
+            .is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use std::io;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use derivative::Derivative;
+use tokio_util::codec::{Decoder, Encoder};
+use vector_config::configurable_component;
+
+/// The maximum number of bytes of a varint encoding a 64-bit integer.
+const MAX_VARINT_LENGTH: usize = 10;
+
+/// Options for building a `VarintLengthDelimitedDecoder` or `VarintLengthDelimitedEncoder`.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub struct VarintLengthDelimitedCoderOptions {
+    /// Maximum frame length
+    #[serde(default = "default_max_frame_length")]
+    #[derivative(Default(value = "default_max_frame_length()"))]
+    pub max_frame_length: usize,
+}
+
+const fn default_max_frame_length() -> usize {
+    8 * 1_024 * 1_024
+}
+
+impl VarintLengthDelimitedCoderOptions {
+    pub const fn build_codec(&self) -> VarintLengthDelimitedCodec {
+        VarintLengthDelimitedCodec {
+            max_frame_length: self.max_frame_length,
+        }
+    }
+}
+
+/// A codec for frames prefixed with their length as an unsigned [base 128 varint][varint], as
+/// written by protobuf's `writeDelimitedTo`.
+///
+/// [varint]: https://protobuf.dev/programming-guides/encoding/#varints
+#[derive(Clone, Debug)]
+pub struct VarintLengthDelimitedCodec {
+    max_frame_length: usize,
+}
+
+impl Default for VarintLengthDelimitedCodec {
+    fn default() -> Self {
+        VarintLengthDelimitedCoderOptions::default().build_codec()
+    }
+}
+
+impl VarintLengthDelimitedCodec {
+    fn frame_too_big(&self, length: u64) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Frame of {length} bytes exceeds the maximum frame length of {} bytes",
+                self.max_frame_length
+            ),
+        )
+    }
+}
+
+/// Reads the varint at the start of `src`, returning the decoded value and the number of bytes it
+/// occupies, or `None` if `src` ends before the varint does.
+fn read_varint(src: &[u8]) -> io::Result<Option<(u64, usize)>> {
+    let mut value = 0u64;
+    for (index, byte) in src.iter().take(MAX_VARINT_LENGTH).enumerate() {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, index + 1)));
+        }
+    }
+
+    if src.len() >= MAX_VARINT_LENGTH {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Varint length prefix is longer than {MAX_VARINT_LENGTH} bytes"),
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+fn write_varint(mut value: u64, dst: &mut BytesMut) {
+    while value >= 0x80 {
+        dst.put_u8(value as u8 | 0x80);
+        value >>= 7;
+    }
+    dst.put_u8(value as u8);
+}
+
+impl Decoder for VarintLengthDelimitedCodec {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<BytesMut>> {
+        let Some((length, header_length)) = read_varint(src)? else {
+            return Ok(None);
+        };
+
+        if length > self.max_frame_length as u64 {
+            return Err(self.frame_too_big(length));
+        }
+
+        let frame_end = header_length + length as usize;
+        if src.len() < frame_end {
+            src.reserve(frame_end - src.len());
+            return Ok(None);
+        }
+
+        src.advance(header_length);
+        Ok(Some(src.split_to(length as usize)))
+    }
+}
+
+impl Encoder<Bytes> for VarintLengthDelimitedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, data: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        if data.len() > self.max_frame_length {
+            return Err(self.frame_too_big(data.len() as u64));
+        }
+
+        dst.reserve(MAX_VARINT_LENGTH + data.len());
+        write_varint(data.len() as u64, dst);
+        dst.extend_from_slice(&data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_roundtrip() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            300,
+            16_383,
+            16_384,
+            u64::from(u32::MAX),
+            u64::MAX,
+        ] {
+            let mut buffer = BytesMut::new();
+            write_varint(value, &mut buffer);
+            assert_eq!(
+                read_varint(&buffer).unwrap(),
+                Some((value, buffer.len())),
+                "value {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn varint_encoding() {
+        let mut buffer = BytesMut::new();
+        write_varint(300, &mut buffer);
+        assert_eq!(&buffer[..], b"\xac\x02");
+    }
+
+    #[test]
+    fn varint_incomplete() {
+        assert_eq!(read_varint(b"").unwrap(), None);
+        assert_eq!(read_varint(b"\xac").unwrap(), None);
+    }
+
+    #[test]
+    fn varint_too_long() {
+        assert!(read_varint(&[0xff; MAX_VARINT_LENGTH]).is_err());
+    }
+}
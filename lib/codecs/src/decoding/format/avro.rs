@@ -1,4 +1,8 @@
 use super::Deserializer;
+#[cfg(feature = "schema-registry")]
+use crate::common::schema_registry::{
+    split_schema_id, SchemaRegistryClient, SchemaRegistryConfig, SchemaType, MAX_CACHED_LOOKUPS,
+};
 use crate::encoding::AvroSerializerOptions;
 use bytes::Buf;
 use bytes::Bytes;
@@ -6,6 +10,11 @@ use chrono::Utc;
 use lookup::event_path;
 use serde::{Deserialize, Serialize};
 use smallvec::{smallvec, SmallVec};
+#[cfg(feature = "schema-registry")]
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 use vector_config::configurable_component;
 use vector_core::{
     config::{log_schema, DataType, LogNamespace},
@@ -34,19 +43,25 @@ impl AvroDeserializerConfig {
             avro_options: AvroDeserializerOptions {
                 schema,
                 strip_schema_id_prefix,
+                #[cfg(feature = "schema-registry")]
+                schema_registry: None,
             },
         }
     }
 
     /// Build the `AvroDeserializer` from this configuration.
-    pub fn build(&self) -> AvroDeserializer {
+    pub fn build(&self) -> vector_common::Result<AvroDeserializer> {
         let schema = apache_avro::Schema::parse_str(&self.avro_options.schema)
-            .map_err(|error| format!("Failed building Avro serializer: {}", error))
-            .unwrap();
-        AvroDeserializer {
-            schema,
-            strip_schema_id_prefix: self.avro_options.strip_schema_id_prefix,
-        }
+            .map_err(|error| format!("Failed building Avro serializer: {}", error))?;
+        let deserializer = AvroDeserializer::new(schema, self.avro_options.strip_schema_id_prefix);
+        #[cfg(feature = "schema-registry")]
+        let deserializer = match &self.avro_options.schema_registry {
+            Some(config) => {
+                deserializer.with_schema_registry(SchemaRegistryClient::new(config.clone())?)
+            }
+            None => deserializer,
+        };
+        Ok(deserializer)
     }
 
     /// The data type of events that are accepted by `AvroDeserializer`.
@@ -82,6 +97,8 @@ impl From<&AvroDeserializerOptions> for AvroSerializerOptions {
     fn from(value: &AvroDeserializerOptions) -> Self {
         Self {
             schema: value.schema.clone(),
+            #[cfg(feature = "schema-registry")]
+            schema_registry: None,
         }
     }
 }
@@ -106,6 +123,19 @@ pub struct AvroDeserializerOptions {
     /// For Avro datum encoded in Kafka messages, the bytes are prefixed with the schema ID.  Set this to true to strip the schema ID prefix.
     /// According to [Confluent Kafka's document](https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format).
     pub strip_schema_id_prefix: bool,
+
+    /// Resolves the schema each Avro datum was written with from a Confluent Schema Registry, using
+    /// the schema ID the datum is prefixed with.
+    ///
+    /// The data is then resolved into `schema`, following the Avro schema resolution rules, so data
+    /// written with older or newer versions of the schema can be decoded. Schema references are not
+    /// supported.
+    ///
+    /// Each schema is requested the first time it is needed, and decoding waits for the Schema
+    /// Registry to respond. Schemas that can't be resolved are requested again after 30 seconds.
+    #[cfg(feature = "schema-registry")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Serializer that converts bytes to an `Event` using the Apache Avro format.
@@ -113,6 +143,8 @@ pub struct AvroDeserializerOptions {
 pub struct AvroDeserializer {
     schema: apache_avro::Schema,
     strip_schema_id_prefix: bool,
+    #[cfg(feature = "schema-registry")]
+    writer_schemas: Option<WriterSchemas>,
 }
 
 impl AvroDeserializer {
@@ -121,19 +153,67 @@ impl AvroDeserializer {
         Self {
             schema,
             strip_schema_id_prefix,
+            #[cfg(feature = "schema-registry")]
+            writer_schemas: None,
         }
     }
+
+    /// Resolves the schemas data was written with from the given Schema Registry.
+    #[cfg(feature = "schema-registry")]
+    pub fn with_schema_registry(mut self, client: SchemaRegistryClient) -> Self {
+        self.writer_schemas = Some(WriterSchemas {
+            client,
+            schemas: Arc::default(),
+        });
+        self
+    }
 }
 
-impl Deserializer for AvroDeserializer {
-    fn parse(
-        &self,
-        bytes: Bytes,
-        log_namespace: LogNamespace,
-    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
-        // Avro has a `null` type which indicates no value.
-        if bytes.is_empty() {
-            return Ok(smallvec![]);
+/// The schemas data was written with, resolved by ID from a Schema Registry.
+#[cfg(feature = "schema-registry")]
+#[derive(Debug, Clone)]
+struct WriterSchemas {
+    client: SchemaRegistryClient,
+    schemas: Arc<Mutex<HashMap<u32, Arc<apache_avro::Schema>>>>,
+}
+
+#[cfg(feature = "schema-registry")]
+impl WriterSchemas {
+    fn get(&self, id: u32) -> vector_common::Result<Arc<apache_avro::Schema>> {
+        if let Some(schema) = self.schemas.lock().expect("poisoned lock").get(&id) {
+            return Ok(Arc::clone(schema));
+        }
+
+        let registered = self.client.schema_by_id(id)?;
+        if registered.schema_type != SchemaType::Avro {
+            return Err(format!(
+                "Expected schema {id} to be an Avro schema, found {:?}",
+                registered.schema_type
+            )
+            .into());
+        }
+        let schema = Arc::new(apache_avro::Schema::parse_str(&registered.schema)?);
+        let mut schemas = self.schemas.lock().expect("poisoned lock");
+        if schemas.len() >= MAX_CACHED_LOOKUPS {
+            schemas.clear();
+        }
+        schemas.insert(id, Arc::clone(&schema));
+        Ok(schema)
+    }
+}
+
+impl AvroDeserializer {
+    /// Reads the Avro datum in the given bytes, resolved into the configured schema.
+    fn read_datum(&self, bytes: Bytes) -> vector_common::Result<AvroValue> {
+        #[cfg(feature = "schema-registry")]
+        if let Some(writer_schemas) = &self.writer_schemas {
+            let (id, bytes) = split_schema_id(bytes)?;
+            let writer_schema = writer_schemas.get(id)?;
+            return Ok(apache_avro::from_avro_datum(
+                &writer_schema,
+                &mut bytes.reader(),
+                Some(&self.schema),
+            )?);
         }
 
         let bytes = if self.strip_schema_id_prefix {
@@ -147,9 +227,26 @@ impl Deserializer for AvroDeserializer {
         } else {
             bytes
         };
+        Ok(apache_avro::from_avro_datum(
+            &self.schema,
+            &mut bytes.reader(),
+            None,
+        )?)
+    }
+}
 
-        let value = apache_avro::from_avro_datum(&self.schema, &mut bytes.reader(), None)?;
+impl Deserializer for AvroDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        // Avro has a `null` type which indicates no value.
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
 
+        let value = self.read_datum(bytes)?;
         let apache_avro::types::Value::Record(fields) = value else {
             return Err(vector_common::Error::from("Expected an avro Record"));
         };
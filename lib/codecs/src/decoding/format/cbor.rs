@@ -0,0 +1,223 @@
+use bytes::{Buf, Bytes};
+use chrono::{DateTime, Utc};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{KeyString, Kind};
+
+use super::{msgpack::value_to_event, Deserializer};
+
+/// The CBOR tag of a timestamp encoded as an RFC 3339 string.
+const CBOR_DATETIME_TAG: u64 = 0;
+
+/// The CBOR tag of a timestamp encoded as seconds since the Unix epoch.
+const CBOR_EPOCH_TAG: u64 = 1;
+
+/// Config used to build a `CborDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CborDeserializerConfig;
+
+impl CborDeserializerConfig {
+    /// Build the `CborDeserializer` from this configuration.
+    pub fn build(&self) -> CborDeserializer {
+        CborDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(Kind::any());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition =
+                        definition.try_with_field(timestamp_key, Kind::any(), Some("timestamp"));
+                }
+                definition
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing [CBOR][cbor] data items.
+///
+/// Every data item in the frame is decoded into its own event. Both text and byte strings are
+/// decoded as bytes, and data items tagged as standard or epoch-based date/time are decoded as
+/// timestamps. All other tags are ignored.
+///
+/// [cbor]: https://www.rfc-editor.org/rfc/rfc8949
+#[derive(Debug, Clone, Default)]
+pub struct CborDeserializer;
+
+impl Deserializer for CborDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let mut events = smallvec![];
+        let mut reader = bytes.reader();
+        while reader.get_ref().has_remaining() {
+            let value: ciborium::Value = ciborium::de::from_reader(&mut reader)
+                .map_err(|error| format!("Error parsing CBOR: {error}"))?;
+            events.push(value_to_event(to_value(value)?, log_namespace)?);
+        }
+
+        Ok(events)
+    }
+}
+
+fn to_value(value: ciborium::Value) -> vector_common::Result<Value> {
+    Ok(match value {
+        ciborium::Value::Null => Value::Null,
+        ciborium::Value::Bool(b) => Value::Boolean(b),
+        ciborium::Value::Integer(i) => i64::try_from(i)
+            .map(Value::Integer)
+            // Like JSON, integers that don't fit into an `i64` are decoded as strings.
+            .unwrap_or_else(|_| Value::Bytes(i128::from(i).to_string().into())),
+        ciborium::Value::Float(f) => NotNan::new(f).map(Value::Float).unwrap_or(Value::Null),
+        ciborium::Value::Text(s) => Value::Bytes(s.into()),
+        ciborium::Value::Bytes(bytes) => Value::Bytes(bytes.into()),
+        ciborium::Value::Array(values) => {
+            Value::Array(values.into_iter().map(to_value).collect::<Result<_, _>>()?)
+        }
+        ciborium::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((to_key(key)?, to_value(value)?)))
+                .collect::<vector_common::Result<ObjectMap>>()?,
+        ),
+        ciborium::Value::Tag(CBOR_DATETIME_TAG, value) => match *value {
+            ciborium::Value::Text(s) => {
+                Value::Timestamp(DateTime::parse_from_rfc3339(&s)?.with_timezone(&Utc))
+            }
+            _ => return Err("CBOR date/time must be a text string".into()),
+        },
+        ciborium::Value::Tag(CBOR_EPOCH_TAG, value) => {
+            let timestamp = match *value {
+                ciborium::Value::Integer(seconds) => i64::try_from(seconds)
+                    .ok()
+                    .and_then(|seconds| DateTime::from_timestamp(seconds, 0)),
+                ciborium::Value::Float(seconds) if seconds.is_finite() => DateTime::from_timestamp(
+                    seconds.floor() as i64,
+                    (seconds.fract().abs() * 1e9) as u32,
+                ),
+                _ => return Err("CBOR epoch-based date/time must be a number".into()),
+            };
+            Value::Timestamp(timestamp.ok_or("CBOR epoch-based date/time is out of range")?)
+        }
+        ciborium::Value::Tag(_, value) => to_value(*value)?,
+        _ => return Err("Unsupported CBOR data item".into()),
+    })
+}
+
+fn to_key(key: ciborium::Value) -> vector_common::Result<KeyString> {
+    Ok(match key {
+        ciborium::Value::Text(s) => s.into(),
+        ciborium::Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).into(),
+        ciborium::Value::Integer(i) => i128::from(i).to_string().into(),
+        ciborium::Value::Bool(b) => b.to_string().into(),
+        _ => return Err("Unsupported CBOR map key".into()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn encode(value: &ciborium::Value) -> Bytes {
+        let mut buffer = Vec::new();
+        ciborium::ser::into_writer(value, &mut buffer).unwrap();
+        buffer.into()
+    }
+
+    fn map(entries: Vec<(&str, ciborium::Value)>) -> ciborium::Value {
+        ciborium::Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn deserialize_cbor() {
+        let input = encode(&map(vec![
+            ("message", "hello".into()),
+            ("count", 3.into()),
+            ("ratio", 0.5.into()),
+            ("ok", true.into()),
+            ("nothing", ciborium::Value::Null),
+            ("raw", ciborium::Value::Bytes(vec![0, 159, 146, 150])),
+            ("tags", ciborium::Value::Array(vec!["a".into(), "b".into()])),
+            (
+                "at",
+                ciborium::Value::Tag(CBOR_DATETIME_TAG, Box::new("2023-11-14T22:13:20.5Z".into())),
+            ),
+            (
+                "epoch",
+                ciborium::Value::Tag(CBOR_EPOCH_TAG, Box::new(1_700_000_000.into())),
+            ),
+        ]));
+
+        let events = CborDeserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            *events[0].as_log().value(),
+            Value::from(btreemap! {
+                "message" => "hello",
+                "count" => 3,
+                "ratio" => 0.5,
+                "ok" => true,
+                "nothing" => Value::Null,
+                "raw" => Value::Bytes(Bytes::from_static(&[0, 159, 146, 150])),
+                "tags" => vec!["a", "b"],
+                "at" => Utc.timestamp_opt(1_700_000_000, 500_000_000).unwrap(),
+                "epoch" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_multiple_data_items() {
+        let mut input = encode(&map(vec![("a", 1.into())])).to_vec();
+        input.extend_from_slice(&encode(&map(vec![("a", 2.into())])));
+
+        let events = CborDeserializer
+            .parse(input.into(), LogNamespace::Legacy)
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["a"], 1.into());
+        assert_eq!(events[1].as_log()["a"], 2.into());
+        assert!(events[1].as_log().get_timestamp().is_some());
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let input = encode(&map(vec![("a", "hello".into())]));
+
+        assert!(CborDeserializer
+            .parse(input.slice(..input.len() - 1), LogNamespace::Vector)
+            .is_err());
+        assert!(CborDeserializer
+            .parse(encode(&"hello".into()), LogNamespace::Legacy)
+            .is_err());
+    }
+}
@@ -0,0 +1,489 @@
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use derivative::Derivative;
+use lookup::owned_value_path;
+use ordered_float::NotNan;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind};
+
+use super::{default_lossy, Deserializer};
+
+/// The names of the fields the CEF header is decoded into, in order.
+const CEF_HEADER_FIELDS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// Extension keys from the CEF dictionary that hold integers.
+const CEF_INTEGER_KEYS: &[&str] = &[
+    "cn1",
+    "cn2",
+    "cn3",
+    "cnt",
+    "destinationTranslatedPort",
+    "deviceDirection",
+    "dpid",
+    "dpt",
+    "dvcpid",
+    "fsize",
+    "in",
+    "oldFileSize",
+    "out",
+    "sourceTranslatedPort",
+    "spid",
+    "spt",
+    "type",
+];
+
+/// Extension keys from the CEF dictionary that hold floating point numbers.
+const CEF_FLOAT_KEYS: &[&str] = &[
+    "cfp1", "cfp2", "cfp3", "cfp4", "dlat", "dlong", "slat", "slong",
+];
+
+/// Extension keys from the CEF dictionary that hold timestamps.
+const CEF_TIMESTAMP_KEYS: &[&str] = &[
+    "deviceCustomDate1",
+    "deviceCustomDate2",
+    "end",
+    "fileCreateTime",
+    "fileModificationTime",
+    "oldFileCreateTime",
+    "oldFileModificationTime",
+    "rt",
+    "start",
+];
+
+/// The formats, besides milliseconds since the Unix epoch, that CEF and LEEF timestamps can be in.
+const TIMESTAMP_FORMATS: [&str; 2] = ["%b %d %Y %H:%M:%S%.f", "%b %d %Y %H:%M:%S"];
+
+/// Config used to build a `CefDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CefDeserializerConfig {
+    /// CEF-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub cef: CefDeserializerOptions,
+}
+
+impl CefDeserializerConfig {
+    /// Creates a new `CefDeserializerConfig`.
+    pub const fn new(options: CefDeserializerOptions) -> Self {
+        Self { cef: options }
+    }
+
+    /// Build the `CefDeserializer` from this configuration.
+    pub const fn build(&self) -> CefDeserializer {
+        CefDeserializer {
+            lossy: self.cef.lossy,
+        }
+    }
+
+    /// Return the type of event built by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        security_log_schema_definition(&CEF_HEADER_FIELDS, log_namespace)
+    }
+}
+
+/// CEF-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CefDeserializerOptions {
+    /// Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a
+/// [Common Event Format (CEF)][cef] message.
+///
+/// Anything before the `CEF:` marker, such as a syslog header, is skipped. The header fields are
+/// decoded as strings, while extension keys that the CEF dictionary defines as numbers or
+/// timestamps are decoded as such when their values allow it.
+///
+/// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct CefDeserializer {
+    #[derivative(Default(value = "default_lossy()"))]
+    lossy: bool,
+}
+
+impl CefDeserializer {
+    /// Creates a new `CefDeserializer`.
+    pub const fn new(lossy: bool) -> Self {
+        Self { lossy }
+    }
+}
+
+impl Deserializer for CefDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line = match self.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => std::str::from_utf8(&bytes)?.into(),
+        };
+
+        // Like with JSON, empty frames are common when reading newline delimited input.
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let message =
+            strip_prefix(line, "CEF:").ok_or("Error parsing CEF: missing `CEF:` header")?;
+        let (header, extension) = split_header(message, CEF_HEADER_FIELDS.len())
+            .ok_or("Error parsing CEF: incomplete header")?;
+
+        let mut object = ObjectMap::new();
+        for (field, value) in CEF_HEADER_FIELDS.iter().zip(header) {
+            object.insert((*field).into(), Value::from(value));
+        }
+        for (key, value) in parse_extension(extension)? {
+            let value = typed_value(
+                &key,
+                value,
+                CEF_INTEGER_KEYS,
+                CEF_FLOAT_KEYS,
+                CEF_TIMESTAMP_KEYS,
+            );
+            object.insert(key.into(), value);
+        }
+
+        Ok(smallvec![object_to_event(object, log_namespace)])
+    }
+}
+
+/// Returns the message that starts at `marker`, skipping any prefix such as a syslog header.
+pub(crate) fn strip_prefix<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    line.find(marker).map(|start| &line[start + marker.len()..])
+}
+
+/// Splits `fields` pipe-delimited header fields off the start of `message`, returning them
+/// unescaped together with the remainder of the message.
+pub(crate) fn split_header(message: &str, fields: usize) -> Option<(Vec<String>, &str)> {
+    let mut header = Vec::with_capacity(fields);
+    let mut field = String::new();
+    let mut chars = message.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, c @ ('|' | '\\'))) => field.push(c),
+                Some((_, c)) => {
+                    field.push('\\');
+                    field.push(c);
+                }
+                None => field.push('\\'),
+            },
+            '|' => {
+                header.push(std::mem::take(&mut field));
+                if header.len() == fields {
+                    return Some((header, &message[index + 1..]));
+                }
+            }
+            c => field.push(c),
+        }
+    }
+
+    // The delimiter after the last header field can be left out when nothing follows it.
+    if header.len() + 1 == fields {
+        header.push(field);
+        return Some((header, ""));
+    }
+
+    None
+}
+
+/// Parses the space separated `key=value` pairs of a CEF extension. Values may contain spaces, as
+/// a new pair only starts at the last word before an unescaped `=`.
+fn parse_extension(extension: &str) -> vector_common::Result<Vec<(String, String)>> {
+    let mut keys = Vec::new();
+    let mut previous_end = 0;
+    let mut escaped = false;
+    for (index, c) in extension.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => {
+                let start = extension[previous_end..index]
+                    .rfind(' ')
+                    .map_or(previous_end, |space| previous_end + space + 1);
+                // An unescaped `=` without a word of its own before it is part of the value.
+                if start < index && (keys.is_empty() || start > previous_end) {
+                    keys.push((start, index));
+                    previous_end = index + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if keys.is_empty() {
+        if extension.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        return Err("Error parsing CEF: invalid extension".into());
+    }
+
+    Ok(keys
+        .iter()
+        .enumerate()
+        .map(|(index, &(start, equals))| {
+            let end = keys
+                .get(index + 1)
+                .map_or(extension.len(), |&(next, _)| next);
+            let key = extension[start..equals].trim_start().to_owned();
+            (key, unescape_value(extension[equals + 1..end].trim_end()))
+        })
+        .collect())
+}
+
+/// Unescapes a CEF or LEEF extension value.
+pub(crate) fn unescape_value(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c @ ('=' | '|' | '\\')) => unescaped.push(c),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Converts the value of a well-known key into the type it is defined as, keeping it as a string
+/// if it can't be converted.
+pub(crate) fn typed_value(
+    key: &str,
+    value: String,
+    integer_keys: &[&str],
+    float_keys: &[&str],
+    timestamp_keys: &[&str],
+) -> Value {
+    let typed = if integer_keys.contains(&key) {
+        value.parse::<i64>().ok().map(Value::Integer)
+    } else if float_keys.contains(&key) {
+        value
+            .parse::<f64>()
+            .ok()
+            .and_then(|float| NotNan::new(float).ok())
+            .map(Value::Float)
+    } else if timestamp_keys.contains(&key) {
+        parse_timestamp(&value).map(Value::Timestamp)
+    } else {
+        None
+    };
+
+    typed.unwrap_or_else(|| Value::from(value))
+}
+
+/// Parses a timestamp given as milliseconds since the Unix epoch or in one of the
+/// `TIMESTAMP_FORMATS`, which are taken to be in UTC.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(milliseconds) = value.parse::<i64>() {
+        return DateTime::from_timestamp_millis(milliseconds);
+    }
+
+    TIMESTAMP_FORMATS.iter().find_map(|format| {
+        NaiveDateTime::parse_from_str(value, format)
+            .ok()
+            .map(|timestamp| timestamp.and_utc())
+    })
+}
+
+/// Builds an event out of the decoded fields, inserting a timestamp in the legacy namespace.
+pub(crate) fn object_to_event(object: ObjectMap, log_namespace: LogNamespace) -> Event {
+    let mut log = LogEvent::from(object);
+    if log_namespace == LogNamespace::Legacy {
+        if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+            if !log.contains(timestamp_key) {
+                log.insert(timestamp_key, Utc::now());
+            }
+        }
+    }
+
+    Event::Log(log)
+}
+
+/// The schema of events with the given string header fields, alongside typed extension fields.
+pub(crate) fn security_log_schema_definition(
+    header_fields: &[&str],
+    log_namespace: LogNamespace,
+) -> schema::Definition {
+    let extension_kind = Kind::bytes().or_integer().or_float().or_timestamp();
+    let mut definition = schema::Definition::new_with_default_metadata(
+        Kind::object(Collection::empty().with_unknown(extension_kind.clone())),
+        [log_namespace],
+    );
+
+    for field in header_fields {
+        definition = definition.with_event_field(&owned_value_path!(*field), Kind::bytes(), None);
+    }
+
+    if log_namespace == LogNamespace::Legacy {
+        if let Some(timestamp_key) = log_schema().timestamp_key() {
+            definition = definition.try_with_field(
+                timestamp_key,
+                // The decoder inserts a new `timestamp`-type value into the "timestamp_key"
+                // field, but only if that field doesn't already exist.
+                extension_kind,
+                Some("timestamp"),
+            );
+        }
+    }
+
+    definition
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn parse(input: &str) -> vector_common::Result<LogEvent> {
+        let mut events = CefDeserializer::default()
+            .parse(Bytes::from(input.to_owned()), LogNamespace::Vector)?;
+        Ok(events.pop().expect("one event").into_log())
+    }
+
+    #[test]
+    fn deserialize_cef() {
+        let log = parse(
+            "CEF:0|Security|threatmanager|1.0|100|worm successfully stopped|10|\
+             src=10.0.0.1 dst=2.1.2.2 spt=1232 msg=Detected a threat. No action needed \
+             cfp1=0.5 rt=1700000000000",
+        )
+        .unwrap();
+
+        assert_eq!(
+            *log.value(),
+            Value::from(btreemap! {
+                "cefVersion" => "0",
+                "deviceVendor" => "Security",
+                "deviceProduct" => "threatmanager",
+                "deviceVersion" => "1.0",
+                "deviceEventClassId" => "100",
+                "name" => "worm successfully stopped",
+                "severity" => "10",
+                "src" => "10.0.0.1",
+                "dst" => "2.1.2.2",
+                "spt" => 1232,
+                "msg" => "Detected a threat. No action needed",
+                "cfp1" => 0.5,
+                "rt" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_syslog_prefix() {
+        let log = parse(
+            "<134>Feb 14 19:04:54 firewall01 CEF:0|Vendor|Product|2.1|42|Blocked|5|act=blocked",
+        )
+        .unwrap();
+
+        assert_eq!(log.get("deviceVendor"), Some(&Value::from("Vendor")));
+        assert_eq!(log.get("act"), Some(&Value::from("blocked")));
+    }
+
+    #[test]
+    fn deserialize_escapes() {
+        let log = parse(
+            r"CEF:0|Pipe\|Vendor|Back\\slash|1|2|name|3|msg=a\=b c\\d\nnext request=https://example.com/?a=b",
+        )
+        .unwrap();
+
+        assert_eq!(log.get("deviceVendor"), Some(&Value::from("Pipe|Vendor")));
+        assert_eq!(log.get("deviceProduct"), Some(&Value::from(r"Back\slash")));
+        assert_eq!(log.get("msg"), Some(&Value::from("a=b c\\d\nnext")));
+        assert_eq!(
+            log.get("request"),
+            Some(&Value::from("https://example.com/?a=b"))
+        );
+    }
+
+    #[test]
+    fn deserialize_untyped_values() {
+        let log = parse("CEF:0|V|P|1|2|N|3|spt=http rt=Nov 14 2023 22:13:20").unwrap();
+
+        assert_eq!(log.get("spt"), Some(&Value::from("http")));
+        assert_eq!(
+            log.get("rt"),
+            Some(&Value::from(Utc.timestamp_opt(1_700_000_000, 0).unwrap()))
+        );
+    }
+
+    #[test]
+    fn deserialize_empty_extension() {
+        let log = parse("CEF:1|V|P|1|2|N|3|").unwrap();
+        assert_eq!(log.get("severity"), Some(&Value::from("3")));
+
+        let log = parse("CEF:1|V|P|1|2|N|3").unwrap();
+        assert_eq!(log.get("severity"), Some(&Value::from("3")));
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        assert_eq!(
+            parse("not a CEF message").unwrap_err().to_string(),
+            "Error parsing CEF: missing `CEF:` header"
+        );
+        assert_eq!(
+            parse("CEF:0|V|P|1|2|N").unwrap_err().to_string(),
+            "Error parsing CEF: incomplete header"
+        );
+        assert_eq!(
+            parse("CEF:0|V|P|1|2|N|3|garbage").unwrap_err().to_string(),
+            "Error parsing CEF: invalid extension"
+        );
+    }
+
+    #[test]
+    fn deserialize_inserts_timestamp() {
+        let events = CefDeserializer::default()
+            .parse(Bytes::from("CEF:0|V|P|1|2|N|3|a=b"), LogNamespace::Legacy)
+            .unwrap();
+
+        assert!(events[0].as_log().get_timestamp().is_some());
+    }
+}
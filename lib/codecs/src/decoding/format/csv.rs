@@ -0,0 +1,583 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use derivative::Derivative;
+use lookup::owned_value_path;
+use ordered_float::NotNan;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind};
+
+use super::{default_lossy, Deserializer};
+use crate::decoding::BuildError;
+
+/// Config used to build a `CsvDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CsvDeserializerConfig {
+    /// CSV-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub csv: CsvDeserializerOptions,
+}
+
+impl CsvDeserializerConfig {
+    /// Creates a new `CsvDeserializerConfig`.
+    pub const fn new(options: CsvDeserializerOptions) -> Self {
+        Self { csv: options }
+    }
+
+    /// Build the `CsvDeserializer` from this configuration.
+    pub fn build(&self) -> Result<CsvDeserializer, BuildError> {
+        if self.csv.columns.is_empty() && !self.csv.has_header {
+            return Err(
+                "Either `columns` must be specified or `has_header` must be enabled".into(),
+            );
+        }
+
+        Ok(CsvDeserializer {
+            options: Arc::new(self.csv.clone()),
+            header: Default::default(),
+        })
+    }
+
+    /// Return the type of event built by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let mut definition = schema::Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [log_namespace],
+        );
+
+        if self.csv.columns.is_empty() {
+            // The column names are only known once the header row has been read.
+            definition = definition.unknown_fields(
+                self.csv
+                    .types
+                    .values()
+                    .fold(Kind::bytes(), |kind, column_type| {
+                        kind.union(column_type.kind())
+                    }),
+            );
+        } else {
+            for column in &self.csv.columns {
+                let kind = self
+                    .csv
+                    .types
+                    .get(column)
+                    .copied()
+                    .unwrap_or_default()
+                    .kind();
+                definition =
+                    definition.optional_field(&owned_value_path!(column.as_str()), kind, None);
+            }
+        }
+
+        if log_namespace == LogNamespace::Legacy {
+            if let Some(timestamp_key) = log_schema().timestamp_key() {
+                definition = definition.try_with_field(
+                    timestamp_key,
+                    // The CSV decoder inserts a new `timestamp`-type value into the
+                    // "timestamp_key" field, but only if that field doesn't already exist.
+                    Kind::bytes().or_timestamp(),
+                    Some("timestamp"),
+                );
+            }
+        }
+
+        definition
+    }
+}
+
+/// CSV-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CsvDeserializerOptions {
+    /// The field delimiter to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_delimiter",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_delimiter()"))]
+    pub delimiter: u8,
+
+    /// Enables double quote escapes.
+    ///
+    /// This is enabled by default, but you can disable it. When disabled, quotes in
+    /// field data are expected to be escaped with `escape` instead of doubled.
+    #[serde(
+        default = "default_double_quote",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_double_quote()"))]
+    pub double_quote: bool,
+
+    /// The escape character to use when reading CSV.
+    ///
+    /// In some variants of CSV, quotes are escaped using a special escape character
+    /// like \ (instead of escaping quotes by doubling them).
+    ///
+    /// To use this, `double_quote` needs to be disabled as well; otherwise, this setting is ignored.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_quote",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_quote()"))]
+    pub escape: u8,
+
+    /// The quote character to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_quote",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_quote()"))]
+    pub quote: u8,
+
+    /// Treats the first record read by the decoder as a header row holding the column names.
+    ///
+    /// The header row is not decoded into an event. If `columns` is also specified, the header
+    /// row is skipped and the configured column names are used instead.
+    ///
+    /// Each decoder reads its own header row, so for sources that decode several streams, such as
+    /// one per file or connection, every stream must start with a header row.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub has_header: bool,
+
+    /// The names of the columns, in the order they appear in each record.
+    ///
+    /// Records holding fewer fields than there are columns leave the remaining fields unset, while
+    /// records holding more fields fail to decode.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    #[configurable(metadata(docs::examples = "timestamp", docs::examples = "message"))]
+    pub columns: Vec<String>,
+
+    /// The types of the columns, by column name.
+    ///
+    /// Fields of columns that aren't listed here are decoded as strings. Empty fields of typed
+    /// columns are decoded as null.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    #[configurable(metadata(
+        docs::additional_props_description = "The type of the column.",
+        docs::examples = "example_types()"
+    ))]
+    pub types: HashMap<String, CsvColumnType>,
+
+    /// Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+const fn default_delimiter() -> u8 {
+    b','
+}
+
+const fn default_quote() -> u8 {
+    b'"'
+}
+
+const fn default_double_quote() -> bool {
+    true
+}
+
+fn example_types() -> HashMap<String, CsvColumnType> {
+    HashMap::from([
+        ("status".to_string(), CsvColumnType::Integer),
+        ("timestamp".to_string(), CsvColumnType::Timestamp),
+    ])
+}
+
+/// The type a CSV column is decoded as.
+#[configurable_component]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvColumnType {
+    /// The field is decoded as a string.
+    #[default]
+    String,
+
+    /// The field is decoded as a signed 64-bit integer.
+    Integer,
+
+    /// The field is decoded as a 64-bit floating point number.
+    Float,
+
+    /// The field is decoded as a boolean, from `true` or `false`.
+    Boolean,
+
+    /// The field is decoded as a timestamp, from an [RFC 3339][rfc3339] formatted string.
+    ///
+    /// [rfc3339]: https://www.rfc-editor.org/rfc/rfc3339
+    Timestamp,
+}
+
+impl CsvColumnType {
+    fn kind(self) -> Kind {
+        match self {
+            Self::String => Kind::bytes(),
+            Self::Integer => Kind::integer().or_null(),
+            Self::Float => Kind::float().or_null(),
+            Self::Boolean => Kind::boolean().or_null(),
+            Self::Timestamp => Kind::timestamp().or_null(),
+        }
+    }
+
+    fn parse(self, field: String) -> vector_common::Result<Value> {
+        if self != Self::String && field.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        Ok(match self {
+            Self::String => Value::from(field),
+            Self::Integer => Value::from(field.parse::<i64>()?),
+            Self::Float => Value::Float(NotNan::new(field.parse::<f64>()?)?),
+            Self::Boolean => Value::from(field.parse::<bool>()?),
+            Self::Timestamp => {
+                Value::from(DateTime::parse_from_rfc3339(&field)?.with_timezone(&Utc))
+            }
+        })
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing CSV records.
+///
+/// Every record in the frame is decoded into its own event.
+#[derive(Debug)]
+pub struct CsvDeserializer {
+    options: Arc<CsvDeserializerOptions>,
+    /// Whether the header row has been read yet, and the column names it holds.
+    header: Mutex<Option<Arc<Vec<String>>>>,
+}
+
+impl Clone for CsvDeserializer {
+    fn clone(&self) -> Self {
+        // Clones decode separate streams, so each of them reads its own header row.
+        Self {
+            options: Arc::clone(&self.options),
+            header: Default::default(),
+        }
+    }
+}
+
+impl CsvDeserializer {
+    fn reader<'a>(&self, bytes: &'a [u8]) -> ::csv::Reader<&'a [u8]> {
+        let options = &self.options;
+        ::csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(options.delimiter)
+            .quote(options.quote)
+            .double_quote(options.double_quote)
+            .escape((!options.double_quote).then_some(options.escape))
+            .from_reader(bytes)
+    }
+
+    fn field(&self, bytes: &[u8]) -> vector_common::Result<String> {
+        Ok(match self.options.lossy {
+            true => String::from_utf8_lossy(bytes).into_owned(),
+            false => std::str::from_utf8(bytes)?.to_owned(),
+        })
+    }
+
+    /// Returns the column names to decode the given record with, or `None` if the record is the
+    /// header row.
+    fn columns(
+        &self,
+        record: &::csv::ByteRecord,
+    ) -> vector_common::Result<Option<Arc<Vec<String>>>> {
+        if !self.options.has_header {
+            return Ok(Some(Arc::new(self.options.columns.clone())));
+        }
+
+        let mut header = self.header.lock().expect("poisoned lock");
+        if let Some(columns) = header.as_ref() {
+            return Ok(Some(Arc::clone(columns)));
+        }
+
+        *header = Some(Arc::new(if self.options.columns.is_empty() {
+            record
+                .iter()
+                .map(|field| self.field(field))
+                .collect::<Result<_, _>>()?
+        } else {
+            self.options.columns.clone()
+        }));
+        Ok(None)
+    }
+}
+
+impl Deserializer for CsvDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        // It's common to receive empty frames when reading newline delimited CSV, since it allows
+        // multiple empty newlines. We proceed without a warning here.
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let mut events = SmallVec::new();
+        for record in self.reader(&bytes).byte_records() {
+            let record = record?;
+            let Some(columns) = self.columns(&record)? else {
+                continue;
+            };
+
+            if record.len() > columns.len() {
+                return Err(format!(
+                    "CSV record has {} fields, but only {} columns are known",
+                    record.len(),
+                    columns.len()
+                )
+                .into());
+            }
+
+            let mut object = ObjectMap::new();
+            for (column, field) in columns.iter().zip(record.iter()) {
+                let column_type = self.options.types.get(column).copied().unwrap_or_default();
+                let value = column_type
+                    .parse(self.field(field)?)
+                    .map_err(|error| format!("Invalid value for CSV column `{column}`: {error}"))?;
+                object.insert(column.as_str().into(), value);
+            }
+
+            let mut log = LogEvent::from(object);
+            if log_namespace == LogNamespace::Legacy {
+                if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+                    if !log.contains(timestamp_key) {
+                        log.insert(timestamp_key, Utc::now());
+                    }
+                }
+            }
+            events.push(Event::Log(log));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn deserializer(options: CsvDeserializerOptions) -> CsvDeserializer {
+        CsvDeserializerConfig::new(options).build().unwrap()
+    }
+
+    fn fields(event: &Event) -> Value {
+        let mut log = event.as_log().clone();
+        if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+            log.remove(timestamp_key);
+        }
+        log.value().clone()
+    }
+
+    #[test]
+    fn build_error_without_columns() {
+        let err = CsvDeserializerConfig::default().build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Either `columns` must be specified or `has_header` must be enabled"
+        );
+    }
+
+    #[test]
+    fn deserialize_with_columns() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["host".into(), "message".into(), "extra".into()],
+            ..Default::default()
+        });
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer
+                .parse(Bytes::from("foo,\"hello, world\"\n"), namespace)
+                .unwrap();
+
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                fields(&events[0]),
+                Value::from(btreemap! {
+                    "host" => "foo",
+                    "message" => "hello, world",
+                })
+            );
+            assert_eq!(
+                events[0].as_log().get_timestamp().is_some(),
+                namespace == LogNamespace::Legacy
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_with_header() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            has_header: true,
+            ..Default::default()
+        });
+
+        let header = deserializer
+            .parse(Bytes::from("host,message"), LogNamespace::Vector)
+            .unwrap();
+        assert!(header.is_empty());
+
+        let events = deserializer
+            .parse(Bytes::from("foo,bar\nbaz,qux"), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(
+            events.iter().map(fields).collect::<Vec<_>>(),
+            vec![
+                Value::from(btreemap! { "host" => "foo", "message" => "bar" }),
+                Value::from(btreemap! { "host" => "baz", "message" => "qux" }),
+            ]
+        );
+
+        // Clones decode separate streams, which start with their own header row.
+        let events = deserializer
+            .clone()
+            .parse(Bytes::from("a,b\nfoo,bar"), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(
+            events.iter().map(fields).collect::<Vec<_>>(),
+            vec![Value::from(btreemap! { "a" => "foo", "b" => "bar" })]
+        );
+    }
+
+    #[test]
+    fn deserialize_skips_header_with_columns() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            has_header: true,
+            columns: vec!["a".into(), "b".into()],
+            ..Default::default()
+        });
+
+        let events = deserializer
+            .parse(Bytes::from("x,y\n1,2"), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(
+            events.iter().map(fields).collect::<Vec<_>>(),
+            vec![Value::from(btreemap! { "a" => "1", "b" => "2" })]
+        );
+    }
+
+    #[test]
+    fn deserialize_typed_columns() {
+        let timestamp = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec![
+                "count".into(),
+                "ratio".into(),
+                "ok".into(),
+                "at".into(),
+                "name".into(),
+            ],
+            types: HashMap::from([
+                ("count".into(), CsvColumnType::Integer),
+                ("ratio".into(), CsvColumnType::Float),
+                ("ok".into(), CsvColumnType::Boolean),
+                ("at".into(), CsvColumnType::Timestamp),
+            ]),
+            ..Default::default()
+        });
+
+        let events = deserializer
+            .parse(
+                Bytes::from("3,0.5,true,2024-05-01T12:00:00Z,7\n,,,,"),
+                LogNamespace::Vector,
+            )
+            .unwrap();
+        assert_eq!(
+            events.iter().map(fields).collect::<Vec<_>>(),
+            vec![
+                Value::from(btreemap! {
+                    "count" => 3,
+                    "ratio" => 0.5,
+                    "ok" => true,
+                    "at" => timestamp,
+                    "name" => "7",
+                }),
+                Value::from(btreemap! {
+                    "count" => Value::Null,
+                    "ratio" => Value::Null,
+                    "ok" => Value::Null,
+                    "at" => Value::Null,
+                    "name" => "",
+                }),
+            ]
+        );
+
+        let err = deserializer
+            .parse(Bytes::from("three"), LogNamespace::Vector)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid value for CSV column `count`"));
+    }
+
+    #[test]
+    fn deserialize_custom_delimiter_and_escape() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            delimiter: b';',
+            double_quote: false,
+            escape: b'\\',
+            columns: vec!["a".into(), "b".into()],
+            ..Default::default()
+        });
+
+        let events = deserializer
+            .parse(Bytes::from(r#"1;"say \"hi\"""#), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(
+            fields(&events[0]),
+            Value::from(btreemap! { "a" => "1", "b" => r#"say "hi""# })
+        );
+    }
+
+    #[test]
+    fn deserialize_error_on_extra_fields() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["a".into()],
+            ..Default::default()
+        });
+
+        let err = deserializer
+            .parse(Bytes::from("1,2"), LogNamespace::Vector)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "CSV record has 2 fields, but only 1 columns are known"
+        );
+    }
+}
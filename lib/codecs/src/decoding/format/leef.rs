@@ -0,0 +1,316 @@
+use bytes::Bytes;
+use derivative::Derivative;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace},
+    event::{Event, ObjectMap, Value},
+    schema,
+};
+
+use super::{
+    cef::{
+        object_to_event, security_log_schema_definition, split_header, strip_prefix, typed_value,
+        unescape_value,
+    },
+    default_lossy, Deserializer,
+};
+
+/// The names of the fields the LEEF header is decoded into, in order.
+const LEEF_HEADER_FIELDS: [&str; 5] = [
+    "leefVersion",
+    "vendor",
+    "productName",
+    "productVersion",
+    "eventId",
+];
+
+/// Predefined LEEF attributes that hold integers.
+const LEEF_INTEGER_KEYS: &[&str] = &[
+    "dstBytes",
+    "dstPackets",
+    "dstPort",
+    "dstPostNATPort",
+    "dstPreNATPort",
+    "sev",
+    "srcBytes",
+    "srcPackets",
+    "srcPort",
+    "srcPostNATPort",
+    "srcPreNATPort",
+    "totalBytes",
+    "totalPackets",
+];
+
+/// Predefined LEEF attributes that hold timestamps.
+const LEEF_TIMESTAMP_KEYS: &[&str] = &["devTime"];
+
+/// The attribute delimiter of LEEF 1.0, and of LEEF 2.0 when none is given in the header.
+const DEFAULT_DELIMITER: char = '\t';
+
+/// Config used to build a `LeefDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct LeefDeserializerConfig {
+    /// LEEF-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub leef: LeefDeserializerOptions,
+}
+
+impl LeefDeserializerConfig {
+    /// Creates a new `LeefDeserializerConfig`.
+    pub const fn new(options: LeefDeserializerOptions) -> Self {
+        Self { leef: options }
+    }
+
+    /// Build the `LeefDeserializer` from this configuration.
+    pub const fn build(&self) -> LeefDeserializer {
+        LeefDeserializer {
+            lossy: self.leef.lossy,
+        }
+    }
+
+    /// Return the type of event built by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        security_log_schema_definition(&LEEF_HEADER_FIELDS, log_namespace)
+    }
+}
+
+/// LEEF-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct LeefDeserializerOptions {
+    /// Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a
+/// [Log Event Extended Format (LEEF)][leef] message.
+///
+/// Both LEEF 1.0 and LEEF 2.0, including its custom attribute delimiters, are supported. Anything
+/// before the `LEEF:` marker, such as a syslog header, is skipped. The header fields are decoded as
+/// strings, while predefined attributes holding numbers or timestamps are decoded as such when
+/// their values allow it.
+///
+/// [leef]: https://www.ibm.com/docs/en/dsm?topic=overview-leef-event-components
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct LeefDeserializer {
+    #[derivative(Default(value = "default_lossy()"))]
+    lossy: bool,
+}
+
+impl LeefDeserializer {
+    /// Creates a new `LeefDeserializer`.
+    pub const fn new(lossy: bool) -> Self {
+        Self { lossy }
+    }
+}
+
+impl Deserializer for LeefDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line = match self.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => std::str::from_utf8(&bytes)?.into(),
+        };
+
+        // Like with JSON, empty frames are common when reading newline delimited input. Only line
+        // breaks are trimmed, as the attribute delimiter is usually a tab.
+        let line = line.trim_matches(|c| c == '\n' || c == '\r');
+        if line.trim().is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let message =
+            strip_prefix(line, "LEEF:").ok_or("Error parsing LEEF: missing `LEEF:` header")?;
+        let (header, attributes) = split_header(message, LEEF_HEADER_FIELDS.len())
+            .ok_or("Error parsing LEEF: incomplete header")?;
+
+        // LEEF 2.0 can name the attribute delimiter in an additional header field.
+        let (delimiter, attributes) = match attributes.split_once('|') {
+            Some((delimiter, rest)) if header[0].starts_with('2') => parse_delimiter(delimiter)
+                .map_or((DEFAULT_DELIMITER, attributes), |delimiter| {
+                    (delimiter, rest)
+                }),
+            _ => (DEFAULT_DELIMITER, attributes),
+        };
+
+        let mut object = ObjectMap::new();
+        for (field, value) in LEEF_HEADER_FIELDS.iter().zip(header) {
+            object.insert((*field).into(), Value::from(value));
+        }
+        for (key, value) in parse_attributes(attributes, delimiter)? {
+            let value = typed_value(&key, value, LEEF_INTEGER_KEYS, &[], LEEF_TIMESTAMP_KEYS);
+            object.insert(key.into(), value);
+        }
+
+        Ok(smallvec![object_to_event(object, log_namespace)])
+    }
+}
+
+/// Parses the attribute delimiter of a LEEF 2.0 header, given either as a single character or as
+/// its hexadecimal code point, such as `x09` or `0x09`.
+fn parse_delimiter(delimiter: &str) -> Option<char> {
+    let mut chars = delimiter.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Some(DEFAULT_DELIMITER),
+        (Some(c), None) => Some(c),
+        _ => {
+            let hex = delimiter
+                .strip_prefix("0x")
+                .or_else(|| delimiter.strip_prefix('x'))?;
+            if hex.is_empty() || hex.len() > 4 {
+                return None;
+            }
+            u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+        }
+    }
+}
+
+/// Parses the delimited `key=value` pairs of a LEEF message. Text between two delimiters that
+/// isn't a pair is considered part of the preceding value.
+fn parse_attributes(
+    attributes: &str,
+    delimiter: char,
+) -> vector_common::Result<Vec<(String, String)>> {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for attribute in attributes.split(delimiter) {
+        match attribute.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                pairs.push((key.trim().to_owned(), value.to_owned()));
+            }
+            _ if attribute.trim().is_empty() => {}
+            _ => match pairs.last_mut() {
+                Some((_, value)) => {
+                    value.push(delimiter);
+                    value.push_str(attribute);
+                }
+                None => return Err("Error parsing LEEF: invalid attribute".into()),
+            },
+        }
+    }
+
+    Ok(pairs
+        .into_iter()
+        .map(|(key, value)| (key, unescape_value(value.trim_end())))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::LogEvent;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn parse(input: &str) -> vector_common::Result<LogEvent> {
+        let mut events = LeefDeserializer::default()
+            .parse(Bytes::from(input.to_owned()), LogNamespace::Vector)?;
+        Ok(events.pop().expect("one event").into_log())
+    }
+
+    #[test]
+    fn deserialize_leef_1() {
+        let log = parse(
+            "LEEF:1.0|Microsoft|MSExchange|4.0 SP1|15345|src=192.0.2.0\tdst=172.50.123.1\tsev=5\t\
+             cat=anomaly\tsrcPort=81\tmsg=hello world\tdevTime=1700000000000",
+        )
+        .unwrap();
+
+        assert_eq!(
+            *log.value(),
+            Value::from(btreemap! {
+                "leefVersion" => "1.0",
+                "vendor" => "Microsoft",
+                "productName" => "MSExchange",
+                "productVersion" => "4.0 SP1",
+                "eventId" => "15345",
+                "src" => "192.0.2.0",
+                "dst" => "172.50.123.1",
+                "sev" => 5,
+                "cat" => "anomaly",
+                "srcPort" => 81,
+                "msg" => "hello world",
+                "devTime" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_leef_2_delimiters() {
+        for (delimiter, separator) in [("^", "^"), ("x5E", "^"), ("0x5e", "^"), ("", "\t")] {
+            let log = parse(&format!(
+                "LEEF:2.0|Lancope|StealthWatch|1.0|41|{delimiter}|src=10.0.1.8{separator}dst=10.0.0.5{separator}dstPort=443"
+            ))
+            .unwrap();
+
+            assert_eq!(
+                log.get("src"),
+                Some(&Value::from("10.0.1.8")),
+                "{delimiter}"
+            );
+            assert_eq!(log.get("dstPort"), Some(&Value::from(443)), "{delimiter}");
+        }
+
+        let log = parse("LEEF:2.0|Lancope|StealthWatch|1.0|41|src=10.0.1.8\tdst=10.0.0.5").unwrap();
+        assert_eq!(log.get("dst"), Some(&Value::from("10.0.0.5")));
+    }
+
+    #[test]
+    fn deserialize_syslog_prefix() {
+        let log = parse(
+            "<13>Jan 18 11:07:53 192.168.1.1 LEEF:1.0|QRadar|QRM|1.0|NEW_PORT_DISCOVERED|src=172.5.6.67\tdst=172.50.123.1",
+        )
+        .unwrap();
+
+        assert_eq!(log.get("vendor"), Some(&Value::from("QRadar")));
+        assert_eq!(log.get("dst"), Some(&Value::from("172.50.123.1")));
+    }
+
+    #[test]
+    fn deserialize_escapes() {
+        let log = parse(r"LEEF:1.0|Pipe\|Vendor|P|1|E|url=https://example.com/?a\=b|c").unwrap();
+
+        assert_eq!(log.get("vendor"), Some(&Value::from("Pipe|Vendor")));
+        assert_eq!(
+            log.get("url"),
+            Some(&Value::from("https://example.com/?a=b|c"))
+        );
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        assert_eq!(
+            parse("not a LEEF message").unwrap_err().to_string(),
+            "Error parsing LEEF: missing `LEEF:` header"
+        );
+        assert_eq!(
+            parse("LEEF:1.0|V|P").unwrap_err().to_string(),
+            "Error parsing LEEF: incomplete header"
+        );
+        assert_eq!(
+            parse("LEEF:1.0|V|P|1|E|garbage").unwrap_err().to_string(),
+            "Error parsing LEEF: invalid attribute"
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use bytes::Bytes;
+use chrono::Utc;
+use derivative::Derivative;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind};
+
+use super::{default_lossy, Deserializer};
+
+/// Config used to build a `LogfmtDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct LogfmtDeserializerConfig {
+    /// Logfmt-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub logfmt: LogfmtDeserializerOptions,
+}
+
+impl LogfmtDeserializerConfig {
+    /// Creates a new `LogfmtDeserializerConfig`.
+    pub const fn new(options: LogfmtDeserializerOptions) -> Self {
+        Self { logfmt: options }
+    }
+
+    /// Build the `LogfmtDeserializer` from this configuration.
+    pub const fn build(&self) -> LogfmtDeserializer {
+        LogfmtDeserializer {
+            lossy: self.logfmt.lossy,
+        }
+    }
+
+    /// Return the type of event built by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        // Values are strings, standalone keys are `true`, and repeated keys collect their values
+        // into an array.
+        let value_kind = Kind::bytes().or_boolean();
+        let mut definition = schema::Definition::new_with_default_metadata(
+            Kind::object(
+                Collection::empty().with_unknown(
+                    value_kind
+                        .clone()
+                        .or_array(Collection::empty().with_unknown(value_kind)),
+                ),
+            ),
+            [log_namespace],
+        );
+
+        if log_namespace == LogNamespace::Legacy {
+            if let Some(timestamp_key) = log_schema().timestamp_key() {
+                definition = definition.try_with_field(
+                    timestamp_key,
+                    // The logfmt decoder inserts a new `timestamp`-type value into the
+                    // "timestamp_key" field, but only if that field doesn't already exist.
+                    Kind::bytes()
+                        .or_boolean()
+                        .or_array(Collection::any())
+                        .or_timestamp(),
+                    Some("timestamp"),
+                );
+            }
+        }
+
+        definition
+    }
+}
+
+/// Logfmt-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct LogfmtDeserializerOptions {
+    /// Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a [logfmt][logfmt] message.
+///
+/// Keys without a value are decoded as `true`, and keys that appear more than once have their
+/// values collected into an array. All other values are decoded as strings.
+///
+/// [logfmt]: https://brandur.org/logfmt
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct LogfmtDeserializer {
+    #[derivative(Default(value = "default_lossy()"))]
+    lossy: bool,
+}
+
+impl LogfmtDeserializer {
+    /// Creates a new `LogfmtDeserializer`.
+    pub const fn new(lossy: bool) -> Self {
+        Self { lossy }
+    }
+}
+
+impl Deserializer for LogfmtDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line = match self.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => std::str::from_utf8(&bytes)?.into(),
+        };
+
+        // Like with JSON, empty frames are common when reading newline delimited input.
+        if line.trim().is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let mut log = LogEvent::from(parse_logfmt(&line)?);
+        if log_namespace == LogNamespace::Legacy {
+            if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+                if !log.contains(timestamp_key) {
+                    log.insert(timestamp_key, Utc::now());
+                }
+            }
+        }
+
+        Ok(smallvec![Event::Log(log)])
+    }
+}
+
+fn parse_logfmt(line: &str) -> vector_common::Result<ObjectMap> {
+    let mut object = ObjectMap::new();
+    let mut chars = line.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '=' && *c != '"') {
+            key.push(c);
+        }
+
+        let value = match chars.next() {
+            None if key.is_empty() => break,
+            None => Value::Boolean(true),
+            Some(c) if c.is_whitespace() => Value::Boolean(true),
+            Some('=') if key.is_empty() => return Err("Error parsing logfmt: missing key".into()),
+            Some('=') if chars.next_if_eq(&'"').is_some() => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        None => {
+                            return Err("Error parsing logfmt: unterminated quoted value".into())
+                        }
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => value.push('\n'),
+                            Some('r') => value.push('\r'),
+                            Some('t') => value.push('\t'),
+                            Some(c) => value.push(c),
+                            None => {
+                                return Err("Error parsing logfmt: unterminated quoted value".into())
+                            }
+                        },
+                        Some(c) => value.push(c),
+                    }
+                }
+                Value::from(value)
+            }
+            Some('=') => {
+                let mut value = String::new();
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                    value.push(c);
+                }
+                Value::from(value)
+            }
+            Some(c) => return Err(format!("Error parsing logfmt: unexpected `{c}`").into()),
+        };
+
+        match object.get_mut(key.as_str()) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => {
+                let first = std::mem::replace(existing, Value::Null);
+                *existing = Value::Array(vec![first, value]);
+            }
+            None => {
+                object.insert(key.into(), value);
+            }
+        }
+    }
+
+    Ok(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn parse(input: &str) -> vector_common::Result<Value> {
+        let mut events = LogfmtDeserializer::default()
+            .parse(Bytes::from(input.to_owned()), LogNamespace::Vector)?;
+        Ok(events
+            .pop()
+            .map_or(Value::Null, |event| event.into_log().value().clone()))
+    }
+
+    #[test]
+    fn deserialize_logfmt() {
+        assert_eq!(
+            parse(r#"level=info msg="hello \"world\"" duration=1.5s path=/ empty= debug"#).unwrap(),
+            Value::from(btreemap! {
+                "level" => "info",
+                "msg" => r#"hello "world""#,
+                "duration" => "1.5s",
+                "path" => "/",
+                "empty" => "",
+                "debug" => true,
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_repeated_keys() {
+        assert_eq!(
+            parse("tag=a tag=b tag=c other=d").unwrap(),
+            Value::from(btreemap! {
+                "tag" => vec!["a", "b", "c"],
+                "other" => "d",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_dotted_keys() {
+        // The logfmt serializer flattens nested fields into dotted keys, which are kept as-is.
+        assert_eq!(
+            parse("a.b=1").unwrap(),
+            Value::from(btreemap! { "a.b" => "1" })
+        );
+    }
+
+    #[test]
+    fn deserialize_empty() {
+        assert_eq!(parse("  ").unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        assert_eq!(
+            parse(r#"msg="oops"#).unwrap_err().to_string(),
+            "Error parsing logfmt: unterminated quoted value"
+        );
+        assert_eq!(
+            parse("=value").unwrap_err().to_string(),
+            "Error parsing logfmt: missing key"
+        );
+    }
+
+    #[test]
+    fn deserialize_inserts_timestamp() {
+        let events = LogfmtDeserializer::default()
+            .parse(Bytes::from("a=b"), LogNamespace::Legacy)
+            .unwrap();
+        let log = events[0].as_log();
+        assert!(log
+            .get((
+                lookup::PathPrefix::Event,
+                log_schema().timestamp_key().unwrap()
+            ))
+            .is_some());
+    }
+}
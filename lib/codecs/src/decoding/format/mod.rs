@@ -5,9 +5,15 @@
 
 mod avro;
 mod bytes;
+mod cbor;
+mod cef;
+mod csv;
 mod gelf;
 mod influxdb;
 mod json;
+mod leef;
+mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
 mod protobuf;
@@ -17,10 +23,16 @@ mod vrl;
 
 use ::bytes::Bytes;
 pub use avro::{AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions};
+pub use cbor::{CborDeserializer, CborDeserializerConfig};
+pub use cef::{CefDeserializer, CefDeserializerConfig, CefDeserializerOptions};
+pub use csv::{CsvColumnType, CsvDeserializer, CsvDeserializerConfig, CsvDeserializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
 pub use influxdb::{InfluxdbDeserializer, InfluxdbDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions};
+pub use leef::{LeefDeserializer, LeefDeserializerConfig, LeefDeserializerOptions};
+pub use logfmt::{LogfmtDeserializer, LogfmtDeserializerConfig, LogfmtDeserializerOptions};
+pub use msgpack::{MsgpackDeserializer, MsgpackDeserializerConfig};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{
     NativeJsonDeserializer, NativeJsonDeserializerConfig, NativeJsonDeserializerOptions,
@@ -0,0 +1,280 @@
+use bytes::{Buf, Bytes};
+use chrono::DateTime;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{KeyString, Kind};
+
+use super::Deserializer;
+
+/// The MessagePack extension type reserved for timestamps.
+const MSGPACK_TIMESTAMP_EXT: i8 = -1;
+
+/// Config used to build a `MsgpackDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackDeserializerConfig;
+
+impl MsgpackDeserializerConfig {
+    /// Build the `MsgpackDeserializer` from this configuration.
+    pub fn build(&self) -> MsgpackDeserializer {
+        MsgpackDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(Kind::any());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition =
+                        definition.try_with_field(timestamp_key, Kind::any(), Some("timestamp"));
+                }
+                definition
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing [MessagePack][msgpack] values.
+///
+/// Every value in the frame is decoded into its own event. Both strings and binary data are decoded
+/// as bytes, and the timestamp extension type is decoded as a timestamp.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone, Default)]
+pub struct MsgpackDeserializer;
+
+impl Deserializer for MsgpackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let mut events = smallvec![];
+        let mut reader = bytes.reader();
+        while reader.get_ref().has_remaining() {
+            let value = rmpv::decode::read_value(&mut reader)
+                .map_err(|error| format!("Error parsing MessagePack: {error}"))?;
+            events.push(value_to_event(to_value(value)?, log_namespace)?);
+        }
+
+        Ok(events)
+    }
+}
+
+/// Builds an event out of a decoded value, in the same way as the JSON decoder does.
+pub(crate) fn value_to_event(
+    value: Value,
+    log_namespace: LogNamespace,
+) -> vector_common::Result<Event> {
+    let log = match (log_namespace, value) {
+        (LogNamespace::Vector, value) => LogEvent::from(value),
+        (LogNamespace::Legacy, Value::Object(fields)) => {
+            let mut log = LogEvent::from(fields);
+            if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+                if !log.contains(timestamp_key) {
+                    log.insert(timestamp_key, chrono::Utc::now());
+                }
+            }
+            log
+        }
+        (LogNamespace::Legacy, _) => {
+            return Err("Attempted to convert a non-map value into an Event.".into())
+        }
+    };
+
+    Ok(Event::Log(log))
+}
+
+fn to_value(value: rmpv::Value) -> vector_common::Result<Value> {
+    Ok(match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(b) => Value::Boolean(b),
+        rmpv::Value::Integer(i) => i
+            .as_i64()
+            .map(Value::Integer)
+            // Like JSON, integers that don't fit into an `i64` are decoded as strings.
+            .unwrap_or_else(|| Value::Bytes(i.to_string().into())),
+        rmpv::Value::F32(f) => NotNan::new(f64::from(f))
+            .map(Value::Float)
+            .unwrap_or(Value::Null),
+        rmpv::Value::F64(f) => NotNan::new(f).map(Value::Float).unwrap_or(Value::Null),
+        rmpv::Value::String(s) => Value::Bytes(s.into_bytes().into()),
+        rmpv::Value::Binary(bytes) => Value::Bytes(bytes.into()),
+        rmpv::Value::Array(values) => {
+            Value::Array(values.into_iter().map(to_value).collect::<Result<_, _>>()?)
+        }
+        rmpv::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((to_key(key)?, to_value(value)?)))
+                .collect::<vector_common::Result<ObjectMap>>()?,
+        ),
+        rmpv::Value::Ext(MSGPACK_TIMESTAMP_EXT, data) => Value::Timestamp(to_timestamp(&data)?),
+        rmpv::Value::Ext(code, data) => {
+            let mut fields = ObjectMap::new();
+            fields.insert(
+                KeyString::from("msgpack_extension_code"),
+                Value::Integer(code.into()),
+            );
+            fields.insert(KeyString::from("bytes"), Value::Bytes(data.into()));
+            Value::Object(fields)
+        }
+    })
+}
+
+fn to_key(key: rmpv::Value) -> vector_common::Result<KeyString> {
+    Ok(match key {
+        rmpv::Value::String(s) => String::from_utf8_lossy(s.as_bytes()).into(),
+        rmpv::Value::Binary(bytes) => String::from_utf8_lossy(&bytes).into(),
+        rmpv::Value::Integer(i) => i.to_string().into(),
+        rmpv::Value::Boolean(b) => b.to_string().into(),
+        key => return Err(format!("Unsupported MessagePack map key: {key}").into()),
+    })
+}
+
+/// Decodes the data of the timestamp extension type, in any of its 32, 64 or 96-bit formats.
+fn to_timestamp(data: &[u8]) -> vector_common::Result<DateTime<chrono::Utc>> {
+    let (seconds, nanoseconds) = match *data {
+        [a, b, c, d] => (i64::from(u32::from_be_bytes([a, b, c, d])), 0),
+        [a, b, c, d, e, f, g, h] => {
+            let value = u64::from_be_bytes([a, b, c, d, e, f, g, h]);
+            ((value & 0x3_ffff_ffff) as i64, (value >> 34) as u32)
+        }
+        [a, b, c, d, ref seconds @ ..] if seconds.len() == 8 => (
+            i64::from_be_bytes(seconds.try_into().expect("length checked above")),
+            u32::from_be_bytes([a, b, c, d]),
+        ),
+        _ => {
+            return Err(format!(
+                "Invalid MessagePack timestamp extension of {} bytes",
+                data.len()
+            )
+            .into())
+        }
+    };
+
+    DateTime::from_timestamp(seconds, nanoseconds)
+        .ok_or_else(|| "MessagePack timestamp is out of range".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn encode(value: &rmpv::Value) -> Bytes {
+        let mut buffer = Vec::new();
+        rmpv::encode::write_value(&mut buffer, value).unwrap();
+        buffer.into()
+    }
+
+    #[test]
+    fn deserialize_msgpack() {
+        let input = encode(&rmpv::Value::Map(vec![
+            ("message".into(), "hello".into()),
+            ("count".into(), 3.into()),
+            ("ratio".into(), rmpv::Value::F64(0.5)),
+            ("ok".into(), true.into()),
+            ("nothing".into(), rmpv::Value::Nil),
+            ("raw".into(), rmpv::Value::Binary(vec![0, 159, 146, 150])),
+            (
+                "tags".into(),
+                rmpv::Value::Array(vec!["a".into(), "b".into()]),
+            ),
+            (
+                "at".into(),
+                rmpv::Value::Ext(
+                    MSGPACK_TIMESTAMP_EXT,
+                    1_700_000_000u32.to_be_bytes().to_vec(),
+                ),
+            ),
+        ]));
+
+        let events = MsgpackDeserializer
+            .parse(input, LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            *events[0].as_log().value(),
+            Value::from(btreemap! {
+                "message" => "hello",
+                "count" => 3,
+                "ratio" => 0.5,
+                "ok" => true,
+                "nothing" => Value::Null,
+                "raw" => Value::Bytes(Bytes::from_static(&[0, 159, 146, 150])),
+                "tags" => vec!["a", "b"],
+                "at" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_multiple_values() {
+        let mut input = encode(&rmpv::Value::Map(vec![("a".into(), 1.into())])).to_vec();
+        input.extend_from_slice(&encode(&rmpv::Value::Map(vec![("a".into(), 2.into())])));
+
+        let events = MsgpackDeserializer
+            .parse(input.into(), LogNamespace::Legacy)
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["a"], 1.into());
+        assert_eq!(events[1].as_log()["a"], 2.into());
+        assert!(events[1].as_log().get_timestamp().is_some());
+    }
+
+    #[test]
+    fn deserialize_timestamp_formats() {
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+
+        let timestamp64 = (123_456_789u64 << 34) | 1_700_000_000;
+        assert_eq!(to_timestamp(&timestamp64.to_be_bytes()).unwrap(), timestamp);
+
+        let mut timestamp96 = 123_456_789u32.to_be_bytes().to_vec();
+        timestamp96.extend_from_slice(&1_700_000_000i64.to_be_bytes());
+        assert_eq!(to_timestamp(&timestamp96).unwrap(), timestamp);
+
+        assert!(to_timestamp(&[0; 3]).is_err());
+    }
+
+    #[test]
+    fn deserialize_non_map_legacy_namespace() {
+        let input = encode(&rmpv::Value::from("hello"));
+
+        assert!(MsgpackDeserializer
+            .parse(input.clone(), LogNamespace::Legacy)
+            .is_err());
+
+        let events = MsgpackDeserializer
+            .parse(input, LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(*events[0].as_log().value(), Value::from("hello"));
+    }
+
+    #[test]
+    fn deserialize_truncated() {
+        let input = encode(&rmpv::Value::Map(vec![("a".into(), "hello".into())]));
+
+        assert!(MsgpackDeserializer
+            .parse(input.slice(..input.len() - 1), LogNamespace::Vector)
+            .is_err());
+    }
+}
@@ -15,6 +15,10 @@ use vector_core::{
 use vrl::value::Kind;
 
 use super::Deserializer;
+#[cfg(feature = "schema-registry")]
+use crate::common::schema_registry::{
+    split_message_indexes, split_schema_id, SchemaRegistryClient, SchemaRegistryConfig, SchemaType,
+};
 
 /// Config used to build a `ProtobufDeserializer`.
 #[configurable_component]
@@ -76,18 +80,45 @@ pub struct ProtobufDeserializerOptions {
     /// The name of the message type to use for serializing.
     #[configurable(metadata(docs::examples = "package.Message"))]
     pub message_type: String,
+
+    /// Decodes messages in the Confluent wire format, prefixed with the ID of their schema in a
+    /// Confluent Schema Registry.
+    ///
+    /// The schema ID of each message is resolved from the Schema Registry to check that the message
+    /// was written with a Protobuf schema. The message itself is decoded as `message_type`, so the
+    /// descriptor set must contain a compatible version of the schema.
+    ///
+    /// Each schema is requested the first time it is needed, and decoding waits for the Schema
+    /// Registry to respond. Schemas that can't be resolved are requested again after 30 seconds.
+    #[cfg(feature = "schema-registry")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Deserializer that builds `Event`s from a byte frame containing protobuf.
 #[derive(Debug, Clone)]
 pub struct ProtobufDeserializer {
     message_descriptor: MessageDescriptor,
+    #[cfg(feature = "schema-registry")]
+    schema_registry: Option<SchemaRegistryClient>,
 }
 
 impl ProtobufDeserializer {
     /// Creates a new `ProtobufDeserializer`.
     pub fn new(message_descriptor: MessageDescriptor) -> Self {
-        Self { message_descriptor }
+        Self {
+            message_descriptor,
+            #[cfg(feature = "schema-registry")]
+            schema_registry: None,
+        }
+    }
+
+    /// Decodes messages in the Confluent wire format, resolving their schema IDs from the given
+    /// Schema Registry.
+    #[cfg(feature = "schema-registry")]
+    pub fn with_schema_registry(mut self, client: SchemaRegistryClient) -> Self {
+        self.schema_registry = Some(client);
+        self
     }
 }
 
@@ -97,6 +128,23 @@ impl Deserializer for ProtobufDeserializer {
         bytes: Bytes,
         log_namespace: LogNamespace,
     ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        #[cfg(feature = "schema-registry")]
+        let bytes = match &self.schema_registry {
+            Some(client) => {
+                let (id, bytes) = split_schema_id(bytes)?;
+                let schema = client.schema_by_id(id)?;
+                if schema.schema_type != SchemaType::Protobuf {
+                    return Err(format!(
+                        "Expected schema {id} to be a Protobuf schema, found {:?}",
+                        schema.schema_type
+                    )
+                    .into());
+                }
+                split_message_indexes(bytes)?.1
+            }
+            None => bytes,
+        };
+
         let dynamic_message = DynamicMessage::decode(self.message_descriptor.clone(), bytes)
             .map_err(|error| format!("Error parsing protobuf: {:?}", error))?;
 
@@ -128,7 +176,15 @@ impl TryFrom<&ProtobufDeserializerConfig> for ProtobufDeserializer {
             &config.protobuf.desc_file,
             &config.protobuf.message_type,
         )?;
-        Ok(Self::new(message_descriptor))
+        let deserializer = Self::new(message_descriptor);
+        #[cfg(feature = "schema-registry")]
+        let deserializer = match &config.protobuf.schema_registry {
+            Some(config) => {
+                deserializer.with_schema_registry(SchemaRegistryClient::new(config.clone())?)
+            }
+            None => deserializer,
+        };
+        Ok(deserializer)
     }
 }
 
@@ -0,0 +1,147 @@
+use bytes::{Bytes, BytesMut};
+use tokio_util::codec::Decoder;
+use vector_config::configurable_component;
+
+use crate::common::varint_length_delimited::{
+    VarintLengthDelimitedCodec, VarintLengthDelimitedCoderOptions,
+};
+
+use super::BoxedFramingError;
+
+/// Config used to build a `VarintLengthDelimitedDecoder`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct VarintLengthDelimitedDecoderConfig {
+    /// Options for the varint length delimited decoder.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub varint_length_delimited: VarintLengthDelimitedCoderOptions,
+}
+
+impl VarintLengthDelimitedDecoderConfig {
+    /// Build the `VarintLengthDelimitedDecoder` from this configuration.
+    pub const fn build(&self) -> VarintLengthDelimitedDecoder {
+        VarintLengthDelimitedDecoder::new(&self.varint_length_delimited)
+    }
+}
+
+/// A codec for handling bytes sequences whose length is encoded as a varint in a frame head.
+///
+/// This is the framing used by protobuf's `writeDelimitedTo` and `parseDelimitedFrom`.
+#[derive(Debug, Clone, Default)]
+pub struct VarintLengthDelimitedDecoder(VarintLengthDelimitedCodec);
+
+impl VarintLengthDelimitedDecoder {
+    /// Creates a new `VarintLengthDelimitedDecoder`.
+    pub const fn new(config: &VarintLengthDelimitedCoderOptions) -> Self {
+        Self(config.build_codec())
+    }
+}
+
+impl Decoder for VarintLengthDelimitedDecoder {
+    type Item = Bytes;
+    type Error = BoxedFramingError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.0
+            .decode(src)
+            .map(|bytes| bytes.map(BytesMut::freeze))
+            .map_err(Into::into)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.0
+            .decode_eof(src)
+            .map(|bytes| bytes.map(BytesMut::freeze))
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_frame() {
+        let mut input = BytesMut::from("\x03foo");
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "foo");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_frame_multibyte_length() {
+        let mut input = BytesMut::from(&b"\xac\x02"[..]);
+        input.extend_from_slice(&[b'a'; 300]);
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(
+            decoder.decode(&mut input).unwrap().unwrap(),
+            [b'a'; 300][..]
+        );
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_frame_ignore_unexpected_eof() {
+        let mut input = BytesMut::from("\x03fo");
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_frame_ignore_incomplete_header() {
+        let mut input = BytesMut::from(&b"\xac"[..]);
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_frame_exceeding_max_frame_length() {
+        let mut input = BytesMut::from("\x04fooo");
+        let mut decoder = VarintLengthDelimitedDecoder::new(&VarintLengthDelimitedCoderOptions {
+            max_frame_length: 3,
+        });
+
+        assert!(decoder.decode(&mut input).is_err());
+    }
+
+    #[test]
+    fn decode_frames() {
+        let mut input = BytesMut::from("\x03foo\x00\x03bar");
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "foo");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "bar");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_eof_frame() {
+        let mut input = BytesMut::from("\x03foo");
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode_eof(&mut input).unwrap().unwrap(), "foo");
+        assert_eq!(decoder.decode_eof(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_eof_frame_unexpected_eof() {
+        let mut input = BytesMut::from("\x03fo");
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert!(decoder.decode_eof(&mut input).is_err());
+    }
+
+    #[test]
+    fn decode_eof_frame_incomplete_header() {
+        let mut input = BytesMut::from(&b"\x03foo\xac"[..]);
+        let mut decoder = VarintLengthDelimitedDecoder::default();
+
+        assert_eq!(decoder.decode_eof(&mut input).unwrap().unwrap(), "foo");
+        assert!(decoder.decode_eof(&mut input).is_err());
+    }
+}
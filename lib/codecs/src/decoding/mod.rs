@@ -9,12 +9,16 @@ use crate::decoding::format::{VrlDeserializer, VrlDeserializerConfig};
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
+    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, CborDeserializer,
+    CborDeserializerConfig, CefDeserializer, CefDeserializerConfig, CefDeserializerOptions,
+    CsvColumnType, CsvDeserializer, CsvDeserializerConfig, CsvDeserializerOptions,
+    GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
     InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions,
-    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
-    NativeJsonDeserializerConfig, NativeJsonDeserializerOptions, ProtobufDeserializer,
-    ProtobufDeserializerConfig, ProtobufDeserializerOptions,
+    LeefDeserializer, LeefDeserializerConfig, LeefDeserializerOptions, LogfmtDeserializer,
+    LogfmtDeserializerConfig, LogfmtDeserializerOptions, MsgpackDeserializer,
+    MsgpackDeserializerConfig, NativeDeserializer, NativeDeserializerConfig,
+    NativeJsonDeserializer, NativeJsonDeserializerConfig, NativeJsonDeserializerOptions,
+    ProtobufDeserializer, ProtobufDeserializerConfig, ProtobufDeserializerOptions,
 };
 #[cfg(feature = "syslog")]
 pub use format::{SyslogDeserializer, SyslogDeserializerConfig, SyslogDeserializerOptions};
@@ -24,7 +28,7 @@ pub use framing::{
     ChunkedGelfDecoderConfig, ChunkedGelfDecoderOptions, FramingError, LengthDelimitedDecoder,
     LengthDelimitedDecoderConfig, NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig,
     NewlineDelimitedDecoderOptions, OctetCountingDecoder, OctetCountingDecoderConfig,
-    OctetCountingDecoderOptions,
+    OctetCountingDecoderOptions, VarintLengthDelimitedDecoder, VarintLengthDelimitedDecoderConfig,
 };
 use smallvec::SmallVec;
 use std::fmt::Debug;
@@ -105,6 +109,13 @@ pub enum FramingConfig {
     ///
     /// [chunked_gelf]: https://go2docs.graylog.org/current/getting_in_log_data/gelf.html
     ChunkedGelf(ChunkedGelfDecoderConfig),
+
+    /// Byte frames which are prefixed by an unsigned [varint][varint] indicating the length.
+    ///
+    /// This is the framing used by streams of delimited protobuf messages.
+    ///
+    /// [varint]: https://protobuf.dev/programming-guides/encoding/#varints
+    VarintLengthDelimited(VarintLengthDelimitedDecoderConfig),
 }
 
 impl From<BytesDecoderConfig> for FramingConfig {
@@ -143,6 +154,12 @@ impl From<ChunkedGelfDecoderConfig> for FramingConfig {
     }
 }
 
+impl From<VarintLengthDelimitedDecoderConfig> for FramingConfig {
+    fn from(config: VarintLengthDelimitedDecoderConfig) -> Self {
+        Self::VarintLengthDelimited(config)
+    }
+}
+
 impl FramingConfig {
     /// Build the `Framer` from this configuration.
     pub fn build(&self) -> Framer {
@@ -153,6 +170,9 @@ impl FramingConfig {
             FramingConfig::NewlineDelimited(config) => Framer::NewlineDelimited(config.build()),
             FramingConfig::OctetCounting(config) => Framer::OctetCounting(config.build()),
             FramingConfig::ChunkedGelf(config) => Framer::ChunkedGelf(config.build()),
+            FramingConfig::VarintLengthDelimited(config) => {
+                Framer::VarintLengthDelimited(config.build())
+            }
         }
     }
 }
@@ -174,6 +194,8 @@ pub enum Framer {
     Boxed(BoxedFramer),
     /// Uses a `ChunkedGelfDecoder` for framing.
     ChunkedGelf(ChunkedGelfDecoder),
+    /// Uses a `VarintLengthDelimitedDecoder` for framing.
+    VarintLengthDelimited(VarintLengthDelimitedDecoder),
 }
 
 impl tokio_util::codec::Decoder for Framer {
@@ -189,6 +211,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::OctetCounting(framer) => framer.decode(src),
             Framer::Boxed(framer) => framer.decode(src),
             Framer::ChunkedGelf(framer) => framer.decode(src),
+            Framer::VarintLengthDelimited(framer) => framer.decode(src),
         }
     }
 
@@ -201,6 +224,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::OctetCounting(framer) => framer.decode_eof(src),
             Framer::Boxed(framer) => framer.decode_eof(src),
             Framer::ChunkedGelf(framer) => framer.decode_eof(src),
+            Framer::VarintLengthDelimited(framer) => framer.decode_eof(src),
         }
     }
 }
@@ -220,6 +244,51 @@ pub enum DeserializerConfig {
     /// [json]: https://www.json.org/
     Json(JsonDeserializerConfig),
 
+    /// Decodes the raw bytes as [CSV][csv] records.
+    ///
+    /// Each record is decoded into an event whose fields are named after the configured columns,
+    /// or after the header row of the stream.
+    ///
+    /// [csv]: https://datatracker.ietf.org/doc/html/rfc4180
+    Csv(CsvDeserializerConfig),
+
+    /// Decodes the raw bytes as a [logfmt][logfmt] message.
+    ///
+    /// [logfmt]: https://brandur.org/logfmt
+    Logfmt(LogfmtDeserializerConfig),
+
+    /// Decodes the raw bytes as [MessagePack][msgpack] values.
+    ///
+    /// Both strings and binary data are decoded as bytes, and the timestamp extension type is
+    /// decoded as a timestamp.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    Msgpack,
+
+    /// Decodes the raw bytes as [CBOR][cbor] data items.
+    ///
+    /// Both text and byte strings are decoded as bytes, and data items tagged as standard or
+    /// epoch-based date/time are decoded as timestamps.
+    ///
+    /// [cbor]: https://www.rfc-editor.org/rfc/rfc8949
+    Cbor,
+
+    /// Decodes the raw bytes as a [Common Event Format (CEF)][cef] message.
+    ///
+    /// Any syslog header before the message is skipped, and extension keys that the CEF
+    /// dictionary defines as numbers or timestamps are decoded as such.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+    Cef(CefDeserializerConfig),
+
+    /// Decodes the raw bytes as a [Log Event Extended Format (LEEF)][leef] message.
+    ///
+    /// Any syslog header before the message is skipped, and predefined attributes holding
+    /// numbers or timestamps are decoded as such.
+    ///
+    /// [leef]: https://www.ibm.com/docs/en/dsm?topic=overview-leef-event-components
+    Leef(LeefDeserializerConfig),
+
     /// Decodes the raw bytes as [protobuf][protobuf].
     ///
     /// [protobuf]: https://protobuf.dev/
@@ -300,6 +369,42 @@ impl From<JsonDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<CsvDeserializerConfig> for DeserializerConfig {
+    fn from(config: CsvDeserializerConfig) -> Self {
+        Self::Csv(config)
+    }
+}
+
+impl From<LogfmtDeserializerConfig> for DeserializerConfig {
+    fn from(config: LogfmtDeserializerConfig) -> Self {
+        Self::Logfmt(config)
+    }
+}
+
+impl From<MsgpackDeserializerConfig> for DeserializerConfig {
+    fn from(_: MsgpackDeserializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
+impl From<CborDeserializerConfig> for DeserializerConfig {
+    fn from(_: CborDeserializerConfig) -> Self {
+        Self::Cbor
+    }
+}
+
+impl From<CefDeserializerConfig> for DeserializerConfig {
+    fn from(config: CefDeserializerConfig) -> Self {
+        Self::Cef(config)
+    }
+}
+
+impl From<LeefDeserializerConfig> for DeserializerConfig {
+    fn from(config: LeefDeserializerConfig) -> Self {
+        Self::Leef(config)
+    }
+}
+
 #[cfg(feature = "syslog")]
 impl From<SyslogDeserializerConfig> for DeserializerConfig {
     fn from(config: SyslogDeserializerConfig) -> Self {
@@ -339,10 +444,18 @@ impl DeserializerConfig {
                 AvroDeserializerConfig {
                     avro_options: avro.clone(),
                 }
-                .build(),
+                .build()?,
             )),
             DeserializerConfig::Bytes => Ok(Deserializer::Bytes(BytesDeserializerConfig.build())),
             DeserializerConfig::Json(config) => Ok(Deserializer::Json(config.build())),
+            DeserializerConfig::Csv(config) => Ok(Deserializer::Csv(config.build()?)),
+            DeserializerConfig::Logfmt(config) => Ok(Deserializer::Logfmt(config.build())),
+            DeserializerConfig::Cef(config) => Ok(Deserializer::Cef(config.build())),
+            DeserializerConfig::Leef(config) => Ok(Deserializer::Leef(config.build())),
+            DeserializerConfig::Msgpack => {
+                Ok(Deserializer::Msgpack(MsgpackDeserializerConfig.build()))
+            }
+            DeserializerConfig::Cbor => Ok(Deserializer::Cbor(CborDeserializerConfig.build())),
             DeserializerConfig::Protobuf(config) => Ok(Deserializer::Protobuf(config.build()?)),
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog(config) => Ok(Deserializer::Syslog(config.build())),
@@ -360,9 +473,15 @@ impl DeserializerConfig {
     pub fn default_stream_framing(&self) -> FramingConfig {
         match self {
             DeserializerConfig::Avro { .. } => FramingConfig::Bytes,
-            DeserializerConfig::Native => FramingConfig::LengthDelimited(Default::default()),
+            DeserializerConfig::Native | DeserializerConfig::Msgpack | DeserializerConfig::Cbor => {
+                FramingConfig::LengthDelimited(Default::default())
+            }
             DeserializerConfig::Bytes
             | DeserializerConfig::Json(_)
+            | DeserializerConfig::Csv(_)
+            | DeserializerConfig::Logfmt(_)
+            | DeserializerConfig::Cef(_)
+            | DeserializerConfig::Leef(_)
             | DeserializerConfig::Influxdb(_)
             | DeserializerConfig::NativeJson(_) => {
                 FramingConfig::NewlineDelimited(Default::default())
@@ -394,6 +513,12 @@ impl DeserializerConfig {
             .output_type(),
             DeserializerConfig::Bytes => BytesDeserializerConfig.output_type(),
             DeserializerConfig::Json(config) => config.output_type(),
+            DeserializerConfig::Csv(config) => config.output_type(),
+            DeserializerConfig::Logfmt(config) => config.output_type(),
+            DeserializerConfig::Cef(config) => config.output_type(),
+            DeserializerConfig::Leef(config) => config.output_type(),
+            DeserializerConfig::Msgpack => MsgpackDeserializerConfig.output_type(),
+            DeserializerConfig::Cbor => CborDeserializerConfig.output_type(),
             DeserializerConfig::Protobuf(config) => config.output_type(),
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog(config) => config.output_type(),
@@ -414,6 +539,14 @@ impl DeserializerConfig {
             .schema_definition(log_namespace),
             DeserializerConfig::Bytes => BytesDeserializerConfig.schema_definition(log_namespace),
             DeserializerConfig::Json(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Csv(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Logfmt(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Cef(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Leef(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Msgpack => {
+                MsgpackDeserializerConfig.schema_definition(log_namespace)
+            }
+            DeserializerConfig::Cbor => CborDeserializerConfig.schema_definition(log_namespace),
             DeserializerConfig::Protobuf(config) => config.schema_definition(log_namespace),
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog(config) => config.schema_definition(log_namespace),
@@ -448,12 +581,18 @@ impl DeserializerConfig {
                 "application/octet-stream"
             }
             (DeserializerConfig::Protobuf(_), _) => "application/octet-stream",
+            (DeserializerConfig::Csv(_), _) => "text/csv",
+            (DeserializerConfig::Msgpack, _) => "application/msgpack",
+            (DeserializerConfig::Cbor, _) => "application/cbor",
             (
                 DeserializerConfig::Json(_)
                 | DeserializerConfig::NativeJson(_)
                 | DeserializerConfig::Bytes
                 | DeserializerConfig::Gelf(_)
                 | DeserializerConfig::Influxdb(_)
+                | DeserializerConfig::Logfmt(_)
+                | DeserializerConfig::Cef(_)
+                | DeserializerConfig::Leef(_)
                 | DeserializerConfig::Vrl(_),
                 _,
             ) => "text/plain",
@@ -472,6 +611,18 @@ pub enum Deserializer {
     Bytes(BytesDeserializer),
     /// Uses a `JsonDeserializer` for deserialization.
     Json(JsonDeserializer),
+    /// Uses a `CsvDeserializer` for deserialization.
+    Csv(CsvDeserializer),
+    /// Uses a `LogfmtDeserializer` for deserialization.
+    Logfmt(LogfmtDeserializer),
+    /// Uses a `MsgpackDeserializer` for deserialization.
+    Msgpack(MsgpackDeserializer),
+    /// Uses a `CborDeserializer` for deserialization.
+    Cbor(CborDeserializer),
+    /// Uses a `CefDeserializer` for deserialization.
+    Cef(CefDeserializer),
+    /// Uses a `LeefDeserializer` for deserialization.
+    Leef(LeefDeserializer),
     /// Uses a `ProtobufDeserializer` for deserialization.
     Protobuf(ProtobufDeserializer),
     #[cfg(feature = "syslog")]
@@ -501,6 +652,12 @@ impl format::Deserializer for Deserializer {
             Deserializer::Avro(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Bytes(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Json(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Csv(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Logfmt(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Msgpack(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Cbor(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Cef(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Leef(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Protobuf(deserializer) => deserializer.parse(bytes, log_namespace),
             #[cfg(feature = "syslog")]
             Deserializer::Syslog(deserializer) => deserializer.parse(bytes, log_namespace),
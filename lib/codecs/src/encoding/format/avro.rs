@@ -1,9 +1,15 @@
+#[cfg(feature = "schema-registry")]
+use crate::common::schema_registry::{
+    put_schema_id, SchemaRegistryClient, SchemaRegistryConfig, SchemaType,
+};
 use crate::encoding::BuildError;
 use bytes::{BufMut, BytesMut};
+use lookup::{owned_value_path, OwnedTargetPath};
 use serde::{Deserialize, Serialize};
 use tokio_util::codec::Encoder;
 use vector_config::configurable_component;
 use vector_core::{config::DataType, event::Event, schema};
+use vrl::value::{kind::Collection, Kind};
 
 /// Config used to build a `AvroSerializer`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,7 +22,11 @@ impl AvroSerializerConfig {
     /// Creates a new `AvroSerializerConfig`.
     pub const fn new(schema: String) -> Self {
         Self {
-            avro: AvroSerializerOptions { schema },
+            avro: AvroSerializerOptions {
+                schema,
+                #[cfg(feature = "schema-registry")]
+                schema_registry: None,
+            },
         }
     }
 
@@ -24,7 +34,16 @@ impl AvroSerializerConfig {
     pub fn build(&self) -> Result<AvroSerializer, BuildError> {
         let schema = apache_avro::Schema::parse_str(&self.avro.schema)
             .map_err(|error| format!("Failed building Avro serializer: {}", error))?;
-        Ok(AvroSerializer { schema })
+        let serializer = AvroSerializer::new(schema);
+        #[cfg(feature = "schema-registry")]
+        let serializer = match &self.avro.schema_registry {
+            Some(config) => serializer.with_schema_registry(
+                SchemaRegistryClient::new(config.clone())?,
+                self.avro.schema.clone(),
+            )?,
+            None => serializer,
+        };
+        Ok(serializer)
     }
 
     /// The data type of events that are accepted by `AvroSerializer`.
@@ -33,9 +52,59 @@ impl AvroSerializerConfig {
     }
 
     /// The schema required by the serializer.
+    ///
+    /// Each field of the Avro record must be present in the event, unless it has a default value.
     pub fn schema_requirement(&self) -> schema::Requirement {
-        // TODO: Convert the Avro schema to a vector schema requirement.
-        schema::Requirement::empty()
+        // Invalid schemas are reported when building the serializer.
+        let Ok(apache_avro::Schema::Record(record)) =
+            apache_avro::Schema::parse_str(&self.avro.schema)
+        else {
+            return schema::Requirement::empty();
+        };
+
+        record
+            .fields
+            .iter()
+            .fold(schema::Requirement::empty(), |requirement, field| {
+                let path = OwnedTargetPath::event(owned_value_path!(field.name.as_str()));
+                let kind = kind_for_schema(&field.schema);
+                if field.default.is_some() {
+                    requirement.optional_field(path, kind)
+                } else {
+                    requirement.required_field(path, kind)
+                }
+            })
+    }
+}
+
+/// The kind of values that resolve to the given Avro schema once serialized.
+fn kind_for_schema(schema: &apache_avro::Schema) -> Kind {
+    use apache_avro::Schema;
+
+    match schema {
+        Schema::Null => Kind::null(),
+        Schema::Boolean => Kind::boolean(),
+        Schema::Int
+        | Schema::Long
+        | Schema::Date
+        | Schema::TimeMillis
+        | Schema::TimeMicros
+        | Schema::TimestampMillis
+        | Schema::TimestampMicros => Kind::integer(),
+        Schema::Float | Schema::Double => Kind::integer().or_float(),
+        // Timestamps are serialized as RFC 3339 strings.
+        Schema::Bytes | Schema::String | Schema::Enum(_) | Schema::Fixed(_) | Schema::Uuid => {
+            Kind::bytes().or_timestamp()
+        }
+        Schema::Array(_) => Kind::array(Collection::any()),
+        Schema::Map(_) | Schema::Record(_) => Kind::object(Collection::any()),
+        Schema::Union(union) => union
+            .variants()
+            .iter()
+            .map(kind_for_schema)
+            .reduce(Kind::union)
+            .unwrap_or_else(Kind::never),
+        _ => Kind::any(),
     }
 }
 
@@ -49,18 +118,67 @@ pub struct AvroSerializerOptions {
     ))]
     #[configurable(metadata(docs::human_name = "Schema JSON"))]
     pub schema: String,
+
+    /// Prefixes each Avro datum with the ID of `schema` in a Confluent Schema Registry, following
+    /// the Confluent wire format.
+    ///
+    /// The ID is looked up, or registered if `auto_register_schemas` is enabled, under the subject
+    /// named by `subject_name_strategy` when the first event is encoded, which waits for the Schema
+    /// Registry to respond. Failed lookups are attempted again after 30 seconds.
+    #[cfg(feature = "schema-registry")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Serializer that converts an `Event` to bytes using the Apache Avro format.
 #[derive(Debug, Clone)]
 pub struct AvroSerializer {
     schema: apache_avro::Schema,
+    #[cfg(feature = "schema-registry")]
+    registered_schema: Option<RegisteredSchema>,
+}
+
+/// The schema as registered in a Schema Registry.
+#[cfg(feature = "schema-registry")]
+#[derive(Debug, Clone)]
+struct RegisteredSchema {
+    client: SchemaRegistryClient,
+    subject: String,
+    schema: String,
+    /// The ID of the schema, once resolved.
+    id: Option<u32>,
 }
 
 impl AvroSerializer {
     /// Creates a new `AvroSerializer`.
     pub const fn new(schema: apache_avro::Schema) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            #[cfg(feature = "schema-registry")]
+            registered_schema: None,
+        }
+    }
+
+    /// Prefixes each datum with the ID of the schema in the given Schema Registry.
+    ///
+    /// The given schema definition must be the one this serializer was created with.
+    #[cfg(feature = "schema-registry")]
+    pub fn with_schema_registry(
+        mut self,
+        client: SchemaRegistryClient,
+        schema: String,
+    ) -> Result<Self, BuildError> {
+        let apache_avro::Schema::Record(record) = &self.schema else {
+            return Err("Avro schemas registered in a Schema Registry must be records".into());
+        };
+        let subject = client.subject(&record.name.fullname(None))?;
+        self.registered_schema = Some(RegisteredSchema {
+            client,
+            subject,
+            schema,
+            id: None,
+        });
+        Ok(self)
     }
 }
 
@@ -72,6 +190,18 @@ impl Encoder<Event> for AvroSerializer {
         let value = apache_avro::to_value(log)?;
         let value = value.resolve(&self.schema)?;
         let bytes = apache_avro::to_avro_datum(&self.schema, value)?;
+        #[cfg(feature = "schema-registry")]
+        if let Some(registered) = &mut self.registered_schema {
+            let id = match registered.id {
+                Some(id) => id,
+                None => *registered.id.insert(registered.client.schema_id(
+                    &registered.subject,
+                    SchemaType::Avro,
+                    &registered.schema,
+                )?),
+            };
+            put_schema_id(id, buffer);
+        }
         buffer.put_slice(&bytes);
         Ok(())
     }
@@ -112,4 +242,41 @@ mod tests {
 
         assert_eq!(bytes.freeze(), b"\0\x06bar".as_slice());
     }
+
+    #[test]
+    fn schema_requirement() {
+        let avro_schema = indoc! {r#"
+            {
+                "type": "record",
+                "name": "Log",
+                "fields": [
+                    { "name": "message", "type": "string" },
+                    { "name": "count", "type": ["null", "long"] },
+                    { "name": "tags", "type": { "type": "map", "values": "string" }, "default": {} }
+                ]
+            }
+        "#}
+        .to_owned();
+        let requirement = AvroSerializerConfig::new(avro_schema).schema_requirement();
+        let definition = schema::Definition::empty_legacy_namespace().with_event_field(
+            &owned_value_path!("message"),
+            Kind::integer(),
+            None,
+        );
+
+        let errors = requirement.validate_fields(&definition).unwrap_err();
+        assert_eq!(
+            errors.errors(),
+            [
+                schema::ValidationError::FieldMissing {
+                    path: OwnedTargetPath::event(owned_value_path!("count")),
+                },
+                schema::ValidationError::FieldKind {
+                    path: OwnedTargetPath::event(owned_value_path!("message")),
+                    want: Kind::bytes().or_timestamp(),
+                    got: Kind::integer(),
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,135 @@
+use bytes::{BufMut, BytesMut};
+use chrono::SecondsFormat;
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use vector_core::{
+    config::DataType,
+    event::{Event, Value},
+    schema,
+};
+
+/// The CBOR tag of a timestamp encoded as an RFC 3339 string.
+const CBOR_DATETIME_TAG: u64 = 0;
+
+/// Config used to build a `CborSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CborSerializerConfig;
+
+impl CborSerializerConfig {
+    /// Creates a new `CborSerializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `CborSerializer` from this configuration.
+    pub const fn build(&self) -> CborSerializer {
+        CborSerializer
+    }
+
+    /// The data type of events that are accepted by `CborSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::all_bits()
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [CBOR][cbor] format.
+///
+/// Bytes that are valid UTF-8 are encoded as text strings and all other bytes as byte strings,
+/// while timestamps are encoded as RFC 3339 strings tagged as standard date/time. Metrics are
+/// encoded in the same shape as with the JSON serializer.
+///
+/// [cbor]: https://www.rfc-editor.org/rfc/rfc8949
+#[derive(Debug, Clone)]
+pub struct CborSerializer;
+
+impl Encoder<Event> for CborSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let value = match event {
+            Event::Log(log) => to_cbor(log.value()),
+            Event::Metric(metric) => to_cbor(&Value::from(serde_json::to_value(&metric)?)),
+            Event::Trace(trace) => to_cbor(trace.value()),
+        };
+
+        ciborium::ser::into_writer(&value, buffer.writer())
+            .map_err(|error| format!("Error encoding CBOR: {error}").into())
+    }
+}
+
+fn to_cbor(value: &Value) -> ciborium::Value {
+    match value {
+        Value::Null => ciborium::Value::Null,
+        Value::Boolean(b) => ciborium::Value::Bool(*b),
+        Value::Integer(i) => ciborium::Value::from(*i),
+        Value::Float(f) => ciborium::Value::Float(f.into_inner()),
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(s) => ciborium::Value::Text(s.to_owned()),
+            Err(_) => ciborium::Value::Bytes(bytes.to_vec()),
+        },
+        Value::Regex(regex) => ciborium::Value::Text(regex.as_str().to_owned()),
+        Value::Timestamp(timestamp) => ciborium::Value::Tag(
+            CBOR_DATETIME_TAG,
+            Box::new(ciborium::Value::Text(
+                timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )),
+        ),
+        Value::Array(values) => ciborium::Value::Array(values.iter().map(to_cbor).collect()),
+        Value::Object(fields) => ciborium::Value::Map(
+            fields
+                .iter()
+                .map(|(key, value)| (ciborium::Value::Text(key.to_string()), to_cbor(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::LogEvent;
+    use vrl::btreemap;
+
+    use super::*;
+
+    #[test]
+    fn serialize_cbor_log() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => "hello",
+            "count" => 3,
+            "raw" => Value::Bytes(Bytes::from_static(&[0, 159, 146, 150])),
+            "tags" => vec!["a", "b"],
+            "at" => Utc.timestamp_opt(1_700_000_000, 500_000_000).unwrap(),
+        }));
+
+        let mut buffer = BytesMut::new();
+        CborSerializer.encode(event, &mut buffer).unwrap();
+        let value: ciborium::Value = ciborium::de::from_reader(&buffer[..]).unwrap();
+
+        assert_eq!(
+            value,
+            ciborium::Value::Map(vec![
+                (
+                    "at".into(),
+                    ciborium::Value::Tag(
+                        CBOR_DATETIME_TAG,
+                        Box::new("2023-11-14T22:13:20.500Z".into())
+                    )
+                ),
+                ("count".into(), 3.into()),
+                ("message".into(), "hello".into()),
+                ("raw".into(), ciborium::Value::Bytes(vec![0, 159, 146, 150])),
+                (
+                    "tags".into(),
+                    ciborium::Value::Array(vec!["a".into(), "b".into()])
+                ),
+            ])
+        );
+    }
+}
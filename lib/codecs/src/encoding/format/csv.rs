@@ -10,6 +10,7 @@ use vector_core::{
     event::{Event, Value},
     schema,
 };
+use vrl::value::Kind;
 
 /// The user configuration to choose the metric tag strategy.
 #[configurable_component]
@@ -65,9 +66,20 @@ impl CsvSerializerConfig {
 
     /// The schema required by the serializer.
     pub fn schema_requirement(&self) -> schema::Requirement {
-        // While technically we support `Value` variants that can't be losslessly serialized to
-        // CSV, we don't want to enforce that limitation to users yet.
-        schema::Requirement::empty()
+        // Missing fields are written as empty strings, so we only require that each field can
+        // exist and hold a value that isn't encoded as an empty string.
+        let kind = Kind::bytes()
+            .or_integer()
+            .or_float()
+            .or_boolean()
+            .or_timestamp()
+            .or_null();
+        self.csv
+            .fields
+            .iter()
+            .fold(schema::Requirement::empty(), |requirement, field| {
+                requirement.required_field(field.0.clone(), kind.clone())
+            })
     }
 }
 
@@ -338,6 +350,23 @@ mod tests {
         assert_eq!(err.to_string(), "At least one CSV field must be specified");
     }
 
+    #[test]
+    fn schema_requirement_rejects_unencodable_fields() {
+        let opts = CsvSerializerOptions {
+            fields: vec!["foo".into(), "bar".into(), "missing".into()],
+            ..Default::default()
+        };
+        let requirement = CsvSerializerConfig::new(opts).schema_requirement();
+        let definition = schema::Definition::empty_legacy_namespace()
+            .with_event_field(&lookup::owned_value_path!("foo"), Kind::bytes(), None)
+            .with_event_field(&lookup::owned_value_path!("bar"), Kind::any_object(), None);
+
+        let errors = requirement.validate_fields(&definition).unwrap_err();
+        assert_eq!(errors.errors().len(), 2);
+        assert!(errors.is_field_kind());
+        assert!(errors.is_field_missing());
+    }
+
     #[test]
     fn serialize_fields() {
         let event = Event::Log(LogEvent::from(btreemap! {
@@ -4,29 +4,44 @@
 #![deny(missing_docs)]
 
 mod avro;
+mod cbor;
 mod cef;
 mod common;
 mod csv;
 mod gelf;
 mod json;
 mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
+#[cfg(feature = "opentelemetry")]
+mod otlp;
+#[cfg(feature = "parquet")]
+mod parquet;
 mod protobuf;
 mod raw_message;
 mod text;
 
 use std::fmt::Debug;
 
-pub use self::csv::{CsvSerializer, CsvSerializerConfig};
+pub use self::csv::{CsvSerializer, CsvSerializerConfig, CsvSerializerOptions};
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
-pub use cef::{CefSerializer, CefSerializerConfig};
+pub use cbor::{CborSerializer, CborSerializerConfig};
+pub use cef::{CefSerializer, CefSerializerConfig, CefSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig, JsonSerializerOptions};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
+pub use msgpack::{MsgpackSerializer, MsgpackSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
+#[cfg(feature = "opentelemetry")]
+pub use otlp::{OtlpFormat, OtlpSerializer, OtlpSerializerConfig, OtlpSerializerOptions};
+#[cfg(feature = "parquet")]
+pub use parquet::{
+    ParquetColumnType, ParquetCompression, ParquetField, ParquetSerializer,
+    ParquetSerializerConfig, ParquetSerializerOptions,
+};
 pub use protobuf::{ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions};
 pub use raw_message::{RawMessageSerializer, RawMessageSerializerConfig};
 pub use text::{TextSerializer, TextSerializerConfig};
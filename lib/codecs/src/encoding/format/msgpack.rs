@@ -0,0 +1,184 @@
+use bytes::{BufMut, BytesMut};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use vector_core::{
+    config::DataType,
+    event::{Event, Value},
+    schema,
+};
+
+/// The MessagePack extension type reserved for timestamps.
+const MSGPACK_TIMESTAMP_EXT: i8 = -1;
+
+/// Config used to build a `MsgpackSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackSerializerConfig;
+
+impl MsgpackSerializerConfig {
+    /// Creates a new `MsgpackSerializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `MsgpackSerializer` from this configuration.
+    pub const fn build(&self) -> MsgpackSerializer {
+        MsgpackSerializer
+    }
+
+    /// The data type of events that are accepted by `MsgpackSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::all_bits()
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [MessagePack][msgpack] format.
+///
+/// Bytes that are valid UTF-8 are encoded as strings and all other bytes as binary data, while
+/// timestamps are encoded with the timestamp extension type. Metrics are encoded in the same shape
+/// as with the JSON serializer.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone)]
+pub struct MsgpackSerializer;
+
+impl Encoder<Event> for MsgpackSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let value = match event {
+            Event::Log(log) => to_msgpack(log.value()),
+            Event::Metric(metric) => to_msgpack(&Value::from(serde_json::to_value(&metric)?)),
+            Event::Trace(trace) => to_msgpack(trace.value()),
+        };
+
+        rmpv::encode::write_value(&mut buffer.writer(), &value)?;
+        Ok(())
+    }
+}
+
+fn to_msgpack(value: &Value) -> rmpv::Value {
+    match value {
+        Value::Null => rmpv::Value::Nil,
+        Value::Boolean(b) => rmpv::Value::Boolean(*b),
+        Value::Integer(i) => rmpv::Value::from(*i),
+        Value::Float(f) => rmpv::Value::F64(f.into_inner()),
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(s) => rmpv::Value::from(s),
+            Err(_) => rmpv::Value::Binary(bytes.to_vec()),
+        },
+        Value::Regex(regex) => rmpv::Value::from(regex.as_str()),
+        Value::Timestamp(timestamp) => {
+            rmpv::Value::Ext(MSGPACK_TIMESTAMP_EXT, timestamp_ext(timestamp))
+        }
+        Value::Array(values) => rmpv::Value::Array(values.iter().map(to_msgpack).collect()),
+        Value::Object(fields) => rmpv::Value::Map(
+            fields
+                .iter()
+                .map(|(key, value)| (rmpv::Value::from(key.as_str()), to_msgpack(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Encodes the data of the timestamp extension type, using the smallest format that fits.
+fn timestamp_ext(timestamp: &DateTime<Utc>) -> Vec<u8> {
+    let seconds = timestamp.timestamp();
+    let nanoseconds = timestamp.timestamp_subsec_nanos();
+
+    if seconds >> 34 == 0 {
+        let value = (u64::from(nanoseconds) << 34) | seconds as u64;
+        if value >> 32 == 0 {
+            (value as u32).to_be_bytes().to_vec()
+        } else {
+            value.to_be_bytes().to_vec()
+        }
+    } else {
+        let mut data = nanoseconds.to_be_bytes().to_vec();
+        data.extend_from_slice(&seconds.to_be_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::TimeZone;
+    use vector_core::event::{LogEvent, Metric, MetricKind, MetricValue};
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn serialize(event: Event) -> rmpv::Value {
+        let mut buffer = BytesMut::new();
+        MsgpackSerializer.encode(event, &mut buffer).unwrap();
+        rmpv::decode::read_value(&mut &buffer[..]).unwrap()
+    }
+
+    #[test]
+    fn serialize_msgpack_log() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => "hello",
+            "count" => 3,
+            "raw" => Value::Bytes(Bytes::from_static(&[0, 159, 146, 150])),
+            "tags" => vec!["a", "b"],
+            "at" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        }));
+
+        assert_eq!(
+            serialize(event),
+            rmpv::Value::Map(vec![
+                (
+                    "at".into(),
+                    rmpv::Value::Ext(
+                        MSGPACK_TIMESTAMP_EXT,
+                        1_700_000_000u32.to_be_bytes().to_vec()
+                    )
+                ),
+                ("count".into(), 3.into()),
+                ("message".into(), "hello".into()),
+                ("raw".into(), rmpv::Value::Binary(vec![0, 159, 146, 150])),
+                (
+                    "tags".into(),
+                    rmpv::Value::Array(vec!["a".into(), "b".into()])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn serialize_msgpack_metric() {
+        let event = Event::Metric(Metric::new(
+            "foos",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 100.0 },
+        ));
+
+        let rmpv::Value::Map(fields) = serialize(event) else {
+            panic!("metric should be encoded as a map");
+        };
+        assert!(fields.contains(&("name".into(), "foos".into())));
+    }
+
+    #[test]
+    fn timestamp_formats() {
+        let timestamp32 = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        assert_eq!(timestamp_ext(&timestamp32).len(), 4);
+
+        let timestamp64 = Utc.timestamp_opt(1_700_000_000, 1).unwrap();
+        assert_eq!(
+            timestamp_ext(&timestamp64),
+            ((1u64 << 34) | 1_700_000_000).to_be_bytes()
+        );
+
+        let timestamp96 = Utc.timestamp_opt(-1, 5).unwrap();
+        let mut expected = 5u32.to_be_bytes().to_vec();
+        expected.extend_from_slice(&(-1i64).to_be_bytes());
+        assert_eq!(timestamp_ext(&timestamp96), expected);
+    }
+}
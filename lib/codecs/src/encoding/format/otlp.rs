@@ -0,0 +1,449 @@
+use std::sync::OnceLock;
+
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use bytes::{BufMut, BytesMut};
+use opentelemetry_proto::{
+    common::to_hex,
+    proto::{
+        collector::{
+            logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            trace::v1::ExportTraceServiceRequest,
+        },
+        FILE_DESCRIPTOR_SET,
+    },
+};
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, SerializeOptions};
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+const LOGS_REQUEST: &str = "opentelemetry.proto.collector.logs.v1.ExportLogsServiceRequest";
+const METRICS_REQUEST: &str =
+    "opentelemetry.proto.collector.metrics.v1.ExportMetricsServiceRequest";
+const TRACE_REQUEST: &str = "opentelemetry.proto.collector.trace.v1.ExportTraceServiceRequest";
+
+/// The OTLP/JSON fields holding identifiers, which are encoded as hex strings rather than base64.
+const ID_FIELDS: [&str; 3] = ["traceId", "spanId", "parentSpanId"];
+
+/// Config used to build an `OtlpSerializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct OtlpSerializerConfig {
+    /// OTLP-specific encoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub otlp: OtlpSerializerOptions,
+}
+
+/// OTLP-specific encoding options.
+#[configurable_component]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OtlpSerializerOptions {
+    /// The format the export requests are encoded in.
+    #[serde(default)]
+    pub format: OtlpFormat,
+}
+
+/// The format OTLP export requests are encoded in.
+#[configurable_component]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OtlpFormat {
+    /// Encodes the export requests as binary Protobuf messages.
+    #[default]
+    Protobuf,
+
+    /// Encodes the export requests as [OTLP/JSON][otlp_json].
+    ///
+    /// [otlp_json]: https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding
+    Json,
+}
+
+impl OtlpSerializerConfig {
+    /// Creates a new `OtlpSerializerConfig`.
+    pub const fn new(options: OtlpSerializerOptions) -> Self {
+        Self { otlp: options }
+    }
+
+    /// Build the `OtlpSerializer` from this configuration.
+    pub const fn build(&self) -> OtlpSerializer {
+        OtlpSerializer::new(self.otlp.format)
+    }
+
+    /// The data type of events that are accepted by `OtlpSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::all_bits()
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [OpenTelemetry protocol][otlp].
+///
+/// Each event is encoded as its own export request, unless a whole batch of events is encoded as a
+/// single request with [`OtlpSerializer::encode_batch`]. Logs and traces are rebuilt from the fields
+/// added by the `opentelemetry` source, while the tags of metrics are split into resource, scope,
+/// and data point attributes based on their `resource.` and `scope.` prefixes.
+///
+/// [otlp]: https://opentelemetry.io/docs/specs/otlp/
+#[derive(Debug, Clone)]
+pub struct OtlpSerializer {
+    format: OtlpFormat,
+}
+
+impl OtlpSerializer {
+    /// Creates a new `OtlpSerializer`.
+    pub const fn new(format: OtlpFormat) -> Self {
+        Self { format }
+    }
+
+    /// Whether the serializer produces binary output.
+    pub const fn is_binary(&self) -> bool {
+        matches!(self.format, OtlpFormat::Protobuf)
+    }
+
+    /// Get the HTTP content type of the export requests.
+    pub const fn content_type(&self) -> &'static str {
+        match self.format {
+            OtlpFormat::Protobuf => "application/x-protobuf",
+            OtlpFormat::Json => "application/json",
+        }
+    }
+
+    /// Encodes a batch of events as a single export request.
+    ///
+    /// Concatenated OTLP/JSON requests are not a valid request, so batches sent as a single message
+    /// are merged into one request instead. As a request only holds a single signal, all events of
+    /// the batch must be of the same type.
+    pub fn encode_batch(
+        &self,
+        events: Vec<Event>,
+        buffer: &mut BytesMut,
+    ) -> Result<(), vector_common::Error> {
+        let mut resource_logs = Vec::new();
+        let mut resource_metrics = Vec::new();
+        let mut resource_spans = Vec::new();
+        for event in events {
+            match event {
+                Event::Log(log) => resource_logs.push(log.into()),
+                Event::Metric(metric) => resource_metrics.push(metric.try_into()?),
+                Event::Trace(trace) => resource_spans.push(trace.into()),
+            }
+        }
+
+        match (
+            resource_logs.is_empty(),
+            resource_metrics.is_empty(),
+            resource_spans.is_empty(),
+        ) {
+            (true, true, true) => Ok(()),
+            (false, true, true) => self.write(
+                ExportLogsServiceRequest { resource_logs },
+                LOGS_REQUEST,
+                buffer,
+            ),
+            (true, false, true) => self.write(
+                ExportMetricsServiceRequest { resource_metrics },
+                METRICS_REQUEST,
+                buffer,
+            ),
+            (true, true, false) => self.write(
+                ExportTraceServiceRequest { resource_spans },
+                TRACE_REQUEST,
+                buffer,
+            ),
+            _ => Err("A single OTLP request can't hold events of different types".into()),
+        }
+    }
+
+    fn write(
+        &self,
+        request: impl Message,
+        message_name: &str,
+        buffer: &mut BytesMut,
+    ) -> Result<(), vector_common::Error> {
+        match self.format {
+            OtlpFormat::Protobuf => request.encode(buffer)?,
+            OtlpFormat::Json => {
+                let descriptor = descriptor_pool()
+                    .get_message_by_name(message_name)
+                    .ok_or_else(|| format!("Message type `{message_name}` not found"))?;
+                let message =
+                    DynamicMessage::decode(descriptor, request.encode_to_vec().as_slice())?;
+                let options = SerializeOptions::new().use_enum_numbers(true);
+                let mut value =
+                    message.serialize_with_options(serde_json::value::Serializer, &options)?;
+                hex_encode_ids(&mut value);
+                serde_json::to_writer(buffer.writer(), &value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Encoder<Event> for OtlpSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        match event {
+            Event::Log(log) => self.write(
+                ExportLogsServiceRequest {
+                    resource_logs: vec![log.into()],
+                },
+                LOGS_REQUEST,
+                buffer,
+            ),
+            Event::Metric(metric) => self.write(
+                ExportMetricsServiceRequest {
+                    resource_metrics: vec![metric.try_into()?],
+                },
+                METRICS_REQUEST,
+                buffer,
+            ),
+            Event::Trace(trace) => self.write(
+                ExportTraceServiceRequest {
+                    resource_spans: vec![trace.into()],
+                },
+                TRACE_REQUEST,
+                buffer,
+            ),
+        }
+    }
+}
+
+fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(FILE_DESCRIPTOR_SET)
+            .expect("should not fail to decode the OpenTelemetry file descriptor set")
+    })
+}
+
+/// Re-encodes the base64 identifiers produced by the canonical Protobuf JSON mapping as hex
+/// strings, as required by OTLP/JSON.
+fn hex_encode_ids(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                match value {
+                    serde_json::Value::String(id) if ID_FIELDS.contains(&key.as_str()) => {
+                        if let Ok(bytes) = BASE64_STANDARD.decode(id.as_bytes()) {
+                            *id = to_hex(&bytes);
+                        }
+                    }
+                    value => hex_encode_ids(value),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(hex_encode_ids),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use opentelemetry_proto::proto::{
+        common::v1::any_value::Value as PBValue, logs::v1::SeverityNumber,
+        metrics::v1::metric::Data,
+    };
+    use vector_core::event::{LogEvent, Metric, MetricKind, MetricValue, TraceEvent};
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn encode(format: OtlpFormat, event: Event) -> BytesMut {
+        let mut buffer = BytesMut::new();
+        OtlpSerializer::new(format)
+            .encode(event, &mut buffer)
+            .unwrap();
+        buffer
+    }
+
+    fn opentelemetry_log() -> LogEvent {
+        LogEvent::from(btreemap! {
+            "message" => "hello world",
+            "timestamp" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            "source_type" => "opentelemetry",
+            "resources" => btreemap! { "service.name" => "checkout" },
+            "attributes" => btreemap! { "http.status_code" => 500 },
+            "scope" => btreemap! { "name" => "checkout-lib", "version" => "1.2.0" },
+            "trace_id" => "4bf92f3577b34da6a3ce929d0e0e4736",
+            "span_id" => "00f067aa0ba902b7",
+            "severity_text" => "ERROR",
+            "severity_number" => 17,
+            "dropped_attributes_count" => 0,
+            "host" => "web-01",
+        })
+    }
+
+    #[test]
+    fn encode_log_protobuf() {
+        let buffer = encode(OtlpFormat::Protobuf, opentelemetry_log().into());
+        let request = ExportLogsServiceRequest::decode(buffer.freeze()).unwrap();
+
+        let resource_logs = &request.resource_logs[0];
+        let resource = resource_logs.resource.as_ref().unwrap();
+        assert_eq!(resource.attributes[0].key, "service.name");
+
+        let scope_logs = &resource_logs.scope_logs[0];
+        let scope = scope_logs.scope.as_ref().unwrap();
+        assert_eq!(scope.name, "checkout-lib");
+        assert_eq!(scope.version, "1.2.0");
+
+        let record = &scope_logs.log_records[0];
+        assert_eq!(
+            record.body.as_ref().unwrap().value,
+            Some(PBValue::StringValue("hello world".into()))
+        );
+        assert_eq!(record.time_unix_nano, 1_700_000_000_000_000_000);
+        assert_eq!(record.severity_text, "ERROR");
+        assert_eq!(record.severity_number, SeverityNumber::Error as i32);
+        assert_eq!(to_hex(&record.trace_id), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(to_hex(&record.span_id), "00f067aa0ba902b7");
+
+        let attributes = record
+            .attributes
+            .iter()
+            .map(|kv| (kv.key.as_str(), kv.value.clone().unwrap().value.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            [
+                ("host", PBValue::StringValue("web-01".into())),
+                ("http.status_code", PBValue::IntValue(500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_log_json() {
+        let buffer = encode(OtlpFormat::Json, opentelemetry_log().into());
+        let value: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+
+        let record = &value["resourceLogs"][0]["scopeLogs"][0]["logRecords"][0];
+        assert_eq!(record["body"]["stringValue"], "hello world");
+        assert_eq!(record["timeUnixNano"], "1700000000000000000");
+        assert_eq!(record["severityNumber"], 17);
+        assert_eq!(record["traceId"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(record["spanId"], "00f067aa0ba902b7");
+        assert_eq!(
+            value["resourceLogs"][0]["resource"]["attributes"][0]["key"],
+            "service.name"
+        );
+    }
+
+    #[test]
+    fn encode_trace() {
+        let trace = TraceEvent::from(btreemap! {
+            "trace_id" => "4bf92f3577b34da6a3ce929d0e0e4736",
+            "span_id" => "00f067aa0ba902b7",
+            "parent_span_id" => "",
+            "name" => "GET /checkout",
+            "kind" => 2,
+            "start_time_unix_nano" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            "end_time_unix_nano" => Utc.timestamp_opt(1_700_000_001, 0).unwrap(),
+            "status" => btreemap! { "message" => "", "code" => 1 },
+            "resources" => btreemap! { "service.name" => "checkout" },
+        });
+
+        let buffer = encode(OtlpFormat::Json, trace.clone().into());
+        let value: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        let span = &value["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["traceId"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(span["kind"], 2);
+        assert!(span.get("parentSpanId").is_none());
+
+        let buffer = encode(OtlpFormat::Protobuf, trace.into());
+        let request = ExportTraceServiceRequest::decode(buffer.freeze()).unwrap();
+        let span = &request.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "GET /checkout");
+        assert_eq!(span.end_time_unix_nano, 1_700_000_001_000_000_000);
+        assert_eq!(span.status.as_ref().unwrap().code, 1);
+    }
+
+    #[test]
+    fn encode_metric() {
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 3.0 },
+        )
+        .with_tags(Some(
+            [
+                ("resource.service.name".to_owned(), "checkout".to_owned()),
+                ("scope.name".to_owned(), "checkout-lib".to_owned()),
+                ("method".to_owned(), "GET".to_owned()),
+            ]
+            .into_iter()
+            .collect(),
+        ))
+        .with_timestamp(Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap()));
+
+        let buffer = encode(OtlpFormat::Protobuf, metric.into());
+        let request = ExportMetricsServiceRequest::decode(buffer.freeze()).unwrap();
+
+        let resource_metrics = &request.resource_metrics[0];
+        let resource = resource_metrics.resource.as_ref().unwrap();
+        assert_eq!(resource.attributes[0].key, "service.name");
+
+        let scope_metrics = &resource_metrics.scope_metrics[0];
+        assert_eq!(scope_metrics.scope.as_ref().unwrap().name, "checkout-lib");
+
+        let metric = &scope_metrics.metrics[0];
+        assert_eq!(metric.name, "requests");
+        let Some(Data::Sum(sum)) = &metric.data else {
+            panic!("expected a sum");
+        };
+        assert!(sum.is_monotonic);
+        assert_eq!(sum.data_points[0].attributes[0].key, "method");
+        assert_eq!(sum.data_points[0].time_unix_nano, 1_700_000_000_000_000_000);
+    }
+
+    #[test]
+    fn encode_batch_as_single_request() {
+        let mut buffer = BytesMut::new();
+        OtlpSerializer::new(OtlpFormat::Json)
+            .encode_batch(
+                vec![opentelemetry_log().into(), opentelemetry_log().into()],
+                &mut buffer,
+            )
+            .unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        assert_eq!(value["resourceLogs"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn encode_batch_of_mixed_events() {
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 3.0 },
+        );
+
+        let mut buffer = BytesMut::new();
+        assert!(OtlpSerializer::new(OtlpFormat::Json)
+            .encode_batch(vec![opentelemetry_log().into(), metric.into()], &mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn encode_unsupported_metric() {
+        let metric = Metric::new(
+            "users",
+            MetricKind::Incremental,
+            MetricValue::Set {
+                values: ["alice".to_owned()].into_iter().collect(),
+            },
+        );
+
+        let mut buffer = BytesMut::new();
+        assert!(OtlpSerializer::new(OtlpFormat::Protobuf)
+            .encode(metric.into(), &mut buffer)
+            .is_err());
+    }
+}
@@ -0,0 +1,823 @@
+use std::{collections::HashSet, sync::Arc};
+
+use bytes::Bytes;
+use chrono::SecondsFormat;
+use lookup::lookup_v2::ConfigTargetPath;
+use parquet::{
+    basic::{
+        Compression as ParquetCodec, ConvertedType, GzipLevel, Repetition, Type as PhysicalType,
+        ZstdLevel,
+    },
+    column::writer::ColumnWriterImpl,
+    data_type::{
+        BoolType, ByteArray, ByteArrayType, DataType as ParquetDataType, DoubleType, Int64Type,
+    },
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedColumnWriter, SerializedFileWriter},
+    },
+    schema::types::{ColumnPath, Type, TypePtr},
+};
+use vector_config_macros::configurable_component;
+use vector_core::{
+    config::DataType,
+    event::{Event, LogEvent, Value},
+    schema,
+};
+use vrl::value::Kind;
+
+use crate::encoding::BuildError;
+
+/// The name of the root group of the written Parquet schema.
+const SCHEMA_NAME: &str = "vector";
+
+/// Config used to build a `ParquetSerializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct ParquetSerializerConfig {
+    /// Options for the Parquet serializer.
+    #[serde(default)]
+    pub parquet: ParquetSerializerOptions,
+}
+
+impl ParquetSerializerConfig {
+    /// Creates a new `ParquetSerializerConfig`.
+    pub const fn new(parquet: ParquetSerializerOptions) -> Self {
+        Self { parquet }
+    }
+
+    /// Build the `ParquetSerializer` from this configuration.
+    pub fn build(&self) -> Result<ParquetSerializer, BuildError> {
+        if self.parquet.row_group_size == 0 {
+            return Err("`row_group_size` must be greater than zero".into());
+        }
+
+        let columns = self
+            .parquet
+            .schema
+            .as_ref()
+            .map(|fields| {
+                if fields.is_empty() {
+                    return Err::<_, BuildError>(
+                        "At least one Parquet schema field must be specified".into(),
+                    );
+                }
+
+                let mut names = HashSet::new();
+                fields
+                    .iter()
+                    .map(|field| {
+                        if !names.insert(field.name.as_str()) {
+                            return Err(format!(
+                                "Parquet schema field `{}` is specified more than once",
+                                field.name
+                            )
+                            .into());
+                        }
+                        Ok(Column {
+                            name: field.name.clone(),
+                            source: field
+                                .path
+                                .clone()
+                                .map_or_else(|| Source::Key(field.name.clone()), Source::Path),
+                            column_type: field.column_type,
+                            compression: field.compression,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        Ok(ParquetSerializer {
+            columns: columns.map(Arc::new),
+            row_group_size: self.parquet.row_group_size,
+            compression: self.parquet.compression,
+        })
+    }
+
+    /// The data type of events that are accepted by `ParquetSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Options for the Parquet serializer.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct ParquetSerializerOptions {
+    /// The columns of the written Parquet files, in order.
+    ///
+    /// If not set, the columns are derived from the schema definitions of the events in each
+    /// batch: every top-level field known to the definition of any of them becomes a column, typed
+    /// according to the kind of values the definitions allow for it. Deriving the columns requires
+    /// [schema support][schema] to be enabled.
+    ///
+    /// [schema]: https://vector.dev/docs/reference/configuration/global-options/#schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Vec<ParquetField>>,
+
+    /// The maximum number of rows written to a single row group.
+    ///
+    /// Batches holding more events than this are split into multiple row groups within the same
+    /// file.
+    #[serde(default = "default_row_group_size")]
+    #[configurable(metadata(docs::type_unit = "rows"))]
+    pub row_group_size: usize,
+
+    /// The compression applied to the columns of the written files.
+    ///
+    /// This can be overridden for individual columns in `schema`.
+    #[serde(default)]
+    pub compression: ParquetCompression,
+}
+
+impl Default for ParquetSerializerOptions {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            row_group_size: default_row_group_size(),
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+const fn default_row_group_size() -> usize {
+    1024 * 1024
+}
+
+/// A column of the written Parquet files.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct ParquetField {
+    /// The name of the column.
+    #[configurable(metadata(docs::examples = "message"))]
+    pub name: String,
+
+    /// The path of the event field the column is populated from.
+    ///
+    /// If not set, the top-level field with the same name as the column is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::examples = ".kubernetes.pod_name"))]
+    pub path: Option<ConfigTargetPath>,
+
+    /// The type of the column.
+    ///
+    /// Missing fields and null values are written as nulls. Values that do not match the type of
+    /// their column cause the whole batch to fail to encode.
+    #[serde(rename = "type")]
+    pub column_type: ParquetColumnType,
+
+    /// The compression applied to this column, overriding the default compression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<ParquetCompression>,
+}
+
+/// The type of a Parquet column.
+#[configurable_component]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetColumnType {
+    /// UTF-8 encoded strings.
+    ///
+    /// Integer, float, boolean, and timestamp values are converted to their string
+    /// representation.
+    String,
+
+    /// Signed 64-bit integers.
+    Int64,
+
+    /// Double precision floating point numbers.
+    ///
+    /// Integer values are converted to floating point numbers.
+    Double,
+
+    /// Booleans.
+    Boolean,
+
+    /// Timestamps in UTC, with microsecond precision.
+    Timestamp,
+
+    /// JSON encoded values of any type.
+    Json,
+}
+
+impl ParquetColumnType {
+    /// Picks the column type best suited to hold values of the given kind.
+    fn from_kind(kind: &Kind) -> Self {
+        let compound = kind.contains_array() || kind.contains_object() || kind.contains_regex();
+        let scalars = [
+            (kind.contains_bytes(), Self::String),
+            (kind.contains_integer(), Self::Int64),
+            (kind.contains_float(), Self::Double),
+            (kind.contains_boolean(), Self::Boolean),
+            (kind.contains_timestamp(), Self::Timestamp),
+        ];
+        let mut allowed = scalars
+            .iter()
+            .filter_map(|(contained, column_type)| contained.then_some(*column_type));
+
+        match (compound, allowed.next(), allowed.next(), allowed.next()) {
+            (false, Some(column_type), None, _) => column_type,
+            (false, Some(Self::Int64), Some(Self::Double), None) => Self::Double,
+            _ => Self::Json,
+        }
+    }
+
+    const fn physical_type(self) -> PhysicalType {
+        match self {
+            Self::String | Self::Json => PhysicalType::BYTE_ARRAY,
+            Self::Int64 | Self::Timestamp => PhysicalType::INT64,
+            Self::Double => PhysicalType::DOUBLE,
+            Self::Boolean => PhysicalType::BOOLEAN,
+        }
+    }
+
+    const fn converted_type(self) -> ConvertedType {
+        match self {
+            Self::String => ConvertedType::UTF8,
+            Self::Json => ConvertedType::JSON,
+            Self::Timestamp => ConvertedType::TIMESTAMP_MICROS,
+            Self::Int64 | Self::Double | Self::Boolean => ConvertedType::NONE,
+        }
+    }
+}
+
+/// The compression applied to Parquet columns.
+#[configurable_component]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetCompression {
+    /// No compression.
+    None,
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// [snappy]: https://github.com/google/snappy/blob/main/docs/README.md
+    #[default]
+    Snappy,
+
+    /// [Gzip][gzip] compression.
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// [LZ4][lz4] compression, without framing.
+    ///
+    /// [lz4]: https://lz4.org/
+    Lz4,
+}
+
+impl From<ParquetCompression> for ParquetCodec {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::None => Self::UNCOMPRESSED,
+            ParquetCompression::Snappy => Self::SNAPPY,
+            ParquetCompression::Gzip => Self::GZIP(GzipLevel::default()),
+            ParquetCompression::Zstd => Self::ZSTD(ZstdLevel::default()),
+            ParquetCompression::Lz4 => Self::LZ4_RAW,
+        }
+    }
+}
+
+/// Where the values of a column are read from.
+#[derive(Debug, Clone)]
+enum Source {
+    /// A top-level field of the event.
+    Key(String),
+    /// An arbitrary path within the event.
+    Path(ConfigTargetPath),
+}
+
+#[derive(Debug, Clone)]
+struct Column {
+    name: String,
+    source: Source,
+    column_type: ParquetColumnType,
+    compression: Option<ParquetCompression>,
+}
+
+impl Column {
+    fn value<'a>(&self, log: &'a LogEvent) -> Option<&'a Value> {
+        match &self.source {
+            Source::Key(key) => log
+                .value()
+                .as_object()
+                .and_then(|map| map.get(key.as_str())),
+            Source::Path(path) => log.get(path),
+        }
+    }
+
+    fn parquet_type(&self) -> Result<TypePtr, vector_common::Error> {
+        Ok(Arc::new(
+            Type::primitive_type_builder(&self.name, self.column_type.physical_type())
+                .with_repetition(Repetition::OPTIONAL)
+                .with_converted_type(self.column_type.converted_type())
+                .build()?,
+        ))
+    }
+}
+
+/// Serializer that converts a batch of `Event`s to a single Apache Parquet file.
+///
+/// Unlike the other serializers, this encodes whole batches of events rather than one event at a
+/// time, as Parquet files are columnar and only complete once their footer is written.
+#[derive(Debug, Clone)]
+pub struct ParquetSerializer {
+    columns: Option<Arc<Vec<Column>>>,
+    row_group_size: usize,
+    compression: ParquetCompression,
+}
+
+impl ParquetSerializer {
+    /// Encodes the given events into a Parquet file.
+    pub fn encode_batch(&self, events: &[Event]) -> Result<Bytes, vector_common::Error> {
+        let logs = events
+            .iter()
+            .map(|event| {
+                event
+                    .maybe_as_log()
+                    .ok_or_else(|| "Only log events can be encoded as Parquet".into())
+            })
+            .collect::<Result<Vec<_>, vector_common::Error>>()?;
+
+        let columns = match &self.columns {
+            Some(columns) => Arc::clone(columns),
+            None => Arc::new(derive_columns(events)?),
+        };
+
+        let schema = Arc::new(
+            Type::group_type_builder(SCHEMA_NAME)
+                .with_fields(
+                    columns
+                        .iter()
+                        .map(Column::parquet_type)
+                        .collect::<Result<_, _>>()?,
+                )
+                .build()?,
+        );
+
+        let properties = columns
+            .iter()
+            .filter_map(|column| column.compression.map(|c| (column, c)))
+            .fold(
+                WriterProperties::builder()
+                    .set_created_by(format!("Vector {}", env!("CARGO_PKG_VERSION")))
+                    .set_max_row_group_size(self.row_group_size)
+                    .set_compression(self.compression.into()),
+                |builder, (column, compression)| {
+                    builder.set_column_compression(
+                        ColumnPath::from(column.name.clone()),
+                        compression.into(),
+                    )
+                },
+            )
+            .build();
+
+        let mut writer = SerializedFileWriter::new(Vec::new(), schema, Arc::new(properties))?;
+        for rows in logs.chunks(self.row_group_size) {
+            let mut row_group = writer.next_row_group()?;
+            for column in columns.iter() {
+                let mut column_writer = row_group
+                    .next_column()?
+                    .ok_or("Parquet writer has fewer columns than the schema")?;
+                write_column(&mut column_writer, column, rows)?;
+                column_writer.close()?;
+            }
+            row_group.close()?;
+        }
+
+        Ok(writer.into_inner()?.into())
+    }
+}
+
+/// Derives the columns from the schema definitions of the given events.
+///
+/// Events of the same batch can come from different sources, so the definitions of all of them
+/// are merged, and every field known to any of them becomes a column.
+fn derive_columns(events: &[Event]) -> Result<Vec<Column>, vector_common::Error> {
+    let mut seen = HashSet::new();
+    let definition = events
+        .iter()
+        .map(|event| event.metadata().schema_definition())
+        .filter(|definition| seen.insert(Arc::as_ptr(definition)))
+        .map(|definition| definition.as_ref().clone())
+        .reduce(schema::Definition::merge)
+        .ok_or("Unable to derive a Parquet schema from an empty batch")?;
+
+    let columns = definition
+        .event_kind()
+        .as_object()
+        .map(|object| {
+            object
+                .known()
+                .iter()
+                .map(|(field, kind)| {
+                    let name = field.to_string();
+                    Column {
+                        source: Source::Key(name.clone()),
+                        name,
+                        column_type: ParquetColumnType::from_kind(kind),
+                        compression: None,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if columns.is_empty() {
+        return Err(concat!(
+            "Unable to derive a Parquet schema: the schema definition of the events has no known ",
+            "fields. Configure the columns with `schema` instead."
+        )
+        .into());
+    }
+
+    Ok(columns)
+}
+
+fn write_column(
+    writer: &mut SerializedColumnWriter<'_>,
+    column: &Column,
+    rows: &[&LogEvent],
+) -> Result<(), vector_common::Error> {
+    match column.column_type {
+        ParquetColumnType::String => write_values::<ByteArrayType>(
+            writer.typed(),
+            convert(column, rows, |value| {
+                string_value(value).map(ByteArray::from)
+            })?,
+        ),
+        ParquetColumnType::Json => write_values::<ByteArrayType>(
+            writer.typed(),
+            rows.iter()
+                .map(|log| match column.value(log) {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(value) => serde_json::to_vec(value).map(|json| Some(json.into())),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        ParquetColumnType::Int64 => {
+            write_values::<Int64Type>(writer.typed(), convert(column, rows, Value::as_integer)?)
+        }
+        ParquetColumnType::Double => write_values::<DoubleType>(
+            writer.typed(),
+            convert(column, rows, |value| match value {
+                Value::Float(float) => Some(float.into_inner()),
+                Value::Integer(int) => Some(*int as f64),
+                _ => None,
+            })?,
+        ),
+        ParquetColumnType::Boolean => {
+            write_values::<BoolType>(writer.typed(), convert(column, rows, Value::as_boolean)?)
+        }
+        ParquetColumnType::Timestamp => write_values::<Int64Type>(
+            writer.typed(),
+            convert(column, rows, |value| {
+                value
+                    .as_timestamp()
+                    .map(|timestamp| timestamp.timestamp_micros())
+            })?,
+        ),
+    }
+}
+
+/// Converts the values of a column with the given function.
+///
+/// Missing fields and nulls are written as nulls, while values the function cannot convert are an
+/// error, so they are never silently dropped.
+fn convert<T>(
+    column: &Column,
+    rows: &[&LogEvent],
+    to_parquet: impl Fn(&Value) -> Option<T>,
+) -> Result<Vec<Option<T>>, vector_common::Error> {
+    rows.iter()
+        .map(|log| match column.value(log) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => to_parquet(value).map(Some).ok_or_else(|| {
+                format!(
+                    "Unable to write {} value to Parquet column `{}` of type {:?}",
+                    value.kind_str(),
+                    column.name,
+                    column.column_type
+                )
+                .into()
+            }),
+        })
+        .collect()
+}
+
+/// Writes the given optional values, encoding their presence in the definition levels.
+fn write_values<T: ParquetDataType>(
+    writer: &mut ColumnWriterImpl<'_, T>,
+    values: impl IntoIterator<Item = Option<T::T>>,
+) -> Result<(), vector_common::Error> {
+    let (definition_levels, values): (Vec<i16>, Vec<Option<T::T>>) = values
+        .into_iter()
+        .map(|value| (i16::from(value.is_some()), value))
+        .unzip();
+    let values = values.into_iter().flatten().collect::<Vec<_>>();
+
+    writer.write_batch(&values, Some(&definition_levels), None)?;
+    Ok(())
+}
+
+fn string_value(value: &Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Bytes(bytes) => Some(bytes.to_vec()),
+        Value::Integer(int) => Some(int.to_string().into_bytes()),
+        Value::Float(float) => Some(float.to_string().into_bytes()),
+        Value::Boolean(bool) => Some(bool.to_string().into_bytes()),
+        Value::Timestamp(timestamp) => Some(
+            timestamp
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                .into_bytes(),
+        ),
+        Value::Regex(regex) => Some(regex.as_str().as_bytes().to_vec()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::Field,
+    };
+    use vector_core::{config::LogNamespace, event::LogEvent};
+    use vrl::{btreemap, value::kind::Collection};
+
+    use super::*;
+
+    fn field(name: &str, column_type: ParquetColumnType) -> ParquetField {
+        ParquetField {
+            name: name.to_string(),
+            path: None,
+            column_type,
+            compression: None,
+        }
+    }
+
+    fn serializer(fields: Option<Vec<ParquetField>>, row_group_size: usize) -> ParquetSerializer {
+        ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: fields,
+            row_group_size,
+            compression: ParquetCompression::Zstd,
+        })
+        .build()
+        .unwrap()
+    }
+
+    fn read_rows(bytes: Bytes) -> (SerializedFileReader<Bytes>, Vec<Vec<(String, Field)>>) {
+        let reader = SerializedFileReader::new(bytes).unwrap();
+        let rows = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().into_columns())
+            .collect();
+        (reader, rows)
+    }
+
+    #[test]
+    fn build_error_on_empty_schema() {
+        let config = ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: Some(Vec::new()),
+            ..Default::default()
+        });
+        let err = config.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "At least one Parquet schema field must be specified"
+        );
+    }
+
+    #[test]
+    fn build_error_on_duplicate_columns() {
+        let config = ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: Some(vec![
+                field("message", ParquetColumnType::String),
+                field("message", ParquetColumnType::Json),
+            ]),
+            ..Default::default()
+        });
+        let err = config.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet schema field `message` is specified more than once"
+        );
+    }
+
+    #[test]
+    fn encodes_configured_columns() {
+        let timestamp = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let events = vec![
+            Event::Log(LogEvent::from(btreemap! {
+                "message" => "hello",
+                "status" => 200,
+                "duration" => 1.5,
+                "ok" => true,
+                "timestamp" => timestamp,
+                "labels" => btreemap! { "app" => "web" },
+            })),
+            Event::Log(LogEvent::from(btreemap! {
+                "message" => 42,
+                "status" => Value::Null,
+                "duration" => 2,
+            })),
+        ];
+        let mut pod = field("pod", ParquetColumnType::String);
+        pod.path = Some("labels.app".into());
+
+        let serializer = serializer(
+            Some(vec![
+                field("message", ParquetColumnType::String),
+                field("status", ParquetColumnType::Int64),
+                field("duration", ParquetColumnType::Double),
+                field("ok", ParquetColumnType::Boolean),
+                field("timestamp", ParquetColumnType::Timestamp),
+                field("labels", ParquetColumnType::Json),
+                pod,
+            ]),
+            1024,
+        );
+        let (_, rows) = read_rows(serializer.encode_batch(&events).unwrap());
+
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    ("message".to_string(), Field::Str("hello".to_string())),
+                    ("status".to_string(), Field::Long(200)),
+                    ("duration".to_string(), Field::Double(1.5)),
+                    ("ok".to_string(), Field::Bool(true)),
+                    (
+                        "timestamp".to_string(),
+                        Field::TimestampMicros(timestamp.timestamp_micros())
+                    ),
+                    (
+                        "labels".to_string(),
+                        Field::Str(r#"{"app":"web"}"#.to_string())
+                    ),
+                    ("pod".to_string(), Field::Str("web".to_string())),
+                ],
+                vec![
+                    ("message".to_string(), Field::Str("42".to_string())),
+                    ("status".to_string(), Field::Null),
+                    ("duration".to_string(), Field::Double(2.0)),
+                    ("ok".to_string(), Field::Null),
+                    ("timestamp".to_string(), Field::Null),
+                    ("labels".to_string(), Field::Null),
+                    ("pod".to_string(), Field::Null),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_values_not_matching_column_type() {
+        let events = vec![
+            Event::Log(LogEvent::from(btreemap! { "status" => 200 })),
+            Event::Log(LogEvent::from(btreemap! { "status" => "not a number" })),
+        ];
+        let serializer = serializer(Some(vec![field("status", ParquetColumnType::Int64)]), 1024);
+
+        let err = serializer.encode_batch(&events).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unable to write string value to Parquet column `status` of type Int64"
+        );
+    }
+
+    #[test]
+    fn splits_batches_into_row_groups() {
+        let events = (0..5)
+            .map(|i| Event::Log(LogEvent::from(btreemap! { "n" => i })))
+            .collect::<Vec<_>>();
+        let serializer = serializer(Some(vec![field("n", ParquetColumnType::Int64)]), 2);
+
+        let (reader, rows) = read_rows(serializer.encode_batch(&events).unwrap());
+
+        assert_eq!(reader.metadata().num_row_groups(), 3);
+        assert_eq!(rows.len(), 5);
+    }
+
+    #[test]
+    fn applies_column_compression() {
+        let events = vec![Event::Log(LogEvent::from(
+            btreemap! { "a" => "x", "b" => "y" },
+        ))];
+        let mut b = field("b", ParquetColumnType::String);
+        b.compression = Some(ParquetCompression::None);
+        let serializer = serializer(Some(vec![field("a", ParquetColumnType::String), b]), 1024);
+
+        let (reader, _) = read_rows(serializer.encode_batch(&events).unwrap());
+        let row_group = reader.metadata().row_group(0);
+
+        assert_eq!(
+            row_group.column(0).compression(),
+            ParquetCodec::ZSTD(ZstdLevel::default())
+        );
+        assert_eq!(
+            row_group.column(1).compression(),
+            ParquetCodec::UNCOMPRESSED
+        );
+    }
+
+    #[test]
+    fn derives_columns_from_schema_definition() {
+        let definition = schema::Definition::new_with_default_metadata(
+            Kind::object(
+                Collection::empty()
+                    .with_known("message", Kind::bytes())
+                    .with_known("count", Kind::integer().or_undefined())
+                    .with_known("ratio", Kind::integer().or_float())
+                    .with_known("tags", Kind::object(Collection::any())),
+            ),
+            [LogNamespace::Legacy],
+        );
+        let mut event = Event::Log(LogEvent::from(btreemap! {
+            "message" => "hello",
+            "ratio" => 3,
+            "tags" => btreemap! { "a" => "b" },
+        }));
+        event
+            .metadata_mut()
+            .set_schema_definition(&Arc::new(definition));
+        let events = vec![event];
+
+        let (reader, rows) = read_rows(serializer(None, 1024).encode_batch(&events).unwrap());
+
+        let schema = reader.metadata().file_metadata().schema_descr();
+        let column_types = (0..schema.num_columns())
+            .map(|i| {
+                let column = schema.column(i);
+                (column.name().to_string(), column.converted_type())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            column_types,
+            vec![
+                ("count".to_string(), ConvertedType::NONE),
+                ("message".to_string(), ConvertedType::UTF8),
+                ("ratio".to_string(), ConvertedType::NONE),
+                ("tags".to_string(), ConvertedType::JSON),
+            ]
+        );
+        assert_eq!(
+            rows,
+            vec![vec![
+                ("count".to_string(), Field::Null),
+                ("message".to_string(), Field::Str("hello".to_string())),
+                ("ratio".to_string(), Field::Double(3.0)),
+                ("tags".to_string(), Field::Str(r#"{"a":"b"}"#.to_string())),
+            ]]
+        );
+    }
+
+    #[test]
+    fn derives_columns_from_every_event_of_the_batch() {
+        let events = [("message", Kind::bytes()), ("count", Kind::integer())]
+            .into_iter()
+            .map(|(field, kind)| {
+                let definition = schema::Definition::new_with_default_metadata(
+                    Kind::object(Collection::empty().with_known(field, kind)),
+                    [LogNamespace::Legacy],
+                );
+                let mut event = Event::Log(LogEvent::from(btreemap! { field => 1 }));
+                event
+                    .metadata_mut()
+                    .set_schema_definition(&Arc::new(definition));
+                event
+            })
+            .collect::<Vec<_>>();
+
+        let (reader, rows) = read_rows(serializer(None, 1024).encode_batch(&events).unwrap());
+
+        let schema = reader.metadata().file_metadata().schema_descr();
+        let columns = (0..schema.num_columns())
+            .map(|i| schema.column(i).name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["count", "message"]);
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn derive_error_without_known_fields() {
+        let events = vec![Event::Log(LogEvent::from("hello"))];
+        let err = serializer(None, 1024).encode_batch(&events).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Unable to derive a Parquet schema"));
+    }
+}
@@ -1,7 +1,14 @@
+#[cfg(feature = "schema-registry")]
+use crate::common::schema_registry::{
+    put_message_indexes, put_schema_id, SchemaRegistryClient, SchemaRegistryConfig,
+};
 use crate::encoding::BuildError;
 use bytes::BytesMut;
-use prost_reflect::{prost::Message as _, MessageDescriptor};
-use std::path::PathBuf;
+use lookup::{owned_value_path, OwnedTargetPath};
+use prost_reflect::{
+    prost::Message as _, Cardinality, FieldDescriptor, Kind as ProtobufKind, MessageDescriptor,
+};
+use std::{path::PathBuf, sync::OnceLock};
 use tokio_util::codec::Encoder;
 use vector_config_macros::configurable_component;
 use vector_core::{
@@ -9,6 +16,7 @@ use vector_core::{
     event::{Event, Value},
     schema,
 };
+use vrl::value::{kind::Collection, Kind};
 
 /// Config used to build a `ProtobufSerializer`.
 #[configurable_component]
@@ -16,16 +24,35 @@ use vector_core::{
 pub struct ProtobufSerializerConfig {
     /// Options for the Protobuf serializer.
     pub protobuf: ProtobufSerializerOptions,
+
+    #[serde(skip)]
+    schema_requirement: OnceLock<schema::Requirement>,
 }
 
 impl ProtobufSerializerConfig {
+    /// Creates a new `ProtobufSerializerConfig`.
+    pub const fn new(options: ProtobufSerializerOptions) -> Self {
+        Self {
+            protobuf: options,
+            schema_requirement: OnceLock::new(),
+        }
+    }
+
     /// Build the `ProtobufSerializer` from this configuration.
     pub fn build(&self) -> Result<ProtobufSerializer, BuildError> {
         let message_descriptor = vrl::protobuf::get_message_descriptor(
             &self.protobuf.desc_file,
             &self.protobuf.message_type,
         )?;
-        Ok(ProtobufSerializer { message_descriptor })
+        let serializer = ProtobufSerializer::new(message_descriptor);
+        #[cfg(feature = "schema-registry")]
+        let serializer = match &self.protobuf.schema_registry {
+            Some(config) => {
+                serializer.with_schema_registry(SchemaRegistryClient::new(config.clone())?)?
+            }
+            None => serializer,
+        };
+        Ok(serializer)
     }
 
     /// The data type of events that are accepted by `ProtobufSerializer`.
@@ -34,10 +61,67 @@ impl ProtobufSerializerConfig {
     }
 
     /// The schema required by the serializer.
+    ///
+    /// Each field of the message type must hold values that can be converted to the field's type,
+    /// and `required` fields must be present in the event. The descriptor is only read the first
+    /// time the requirement is asked for.
     pub fn schema_requirement(&self) -> schema::Requirement {
-        // While technically we support `Value` variants that can't be losslessly serialized to
-        // Protobuf, we don't want to enforce that limitation to users yet.
-        schema::Requirement::empty()
+        self.schema_requirement
+            .get_or_init(|| message_schema_requirement(&self.protobuf))
+            .clone()
+    }
+}
+
+/// Derives the schema requirement of the configured message type from its descriptor.
+fn message_schema_requirement(options: &ProtobufSerializerOptions) -> schema::Requirement {
+    // Invalid descriptors are reported when building the serializer.
+    let Ok(message_descriptor) =
+        vrl::protobuf::get_message_descriptor(&options.desc_file, &options.message_type)
+    else {
+        return schema::Requirement::empty();
+    };
+
+    message_descriptor
+        .fields()
+        .fold(schema::Requirement::empty(), |requirement, field| {
+            let path = OwnedTargetPath::event(owned_value_path!(field.name()));
+            let kind = kind_for_field(&field);
+            if field.cardinality() == Cardinality::Required {
+                requirement.required_field(path, kind)
+            } else {
+                requirement.optional_field(path, kind.or_null())
+            }
+        })
+}
+
+/// The kind of values that can be converted to the type of the given field.
+fn kind_for_field(field: &FieldDescriptor) -> Kind {
+    if field.is_map() {
+        return Kind::object(Collection::any());
+    }
+    if field.is_list() {
+        return Kind::array(Collection::any());
+    }
+
+    match field.kind() {
+        ProtobufKind::Double | ProtobufKind::Float => Kind::integer().or_float(),
+        ProtobufKind::Int32
+        | ProtobufKind::Int64
+        | ProtobufKind::Uint32
+        | ProtobufKind::Uint64
+        | ProtobufKind::Sint32
+        | ProtobufKind::Sint64
+        | ProtobufKind::Fixed32
+        | ProtobufKind::Fixed64
+        | ProtobufKind::Sfixed32
+        | ProtobufKind::Sfixed64 => Kind::integer(),
+        ProtobufKind::Bool => Kind::boolean(),
+        ProtobufKind::String | ProtobufKind::Bytes => Kind::bytes(),
+        ProtobufKind::Enum(_) => Kind::bytes().or_integer(),
+        ProtobufKind::Message(message) if message.full_name() == "google.protobuf.Timestamp" => {
+            Kind::timestamp().or_object(Collection::any())
+        }
+        ProtobufKind::Message(_) => Kind::object(Collection::any()),
     }
 }
 
@@ -56,6 +140,17 @@ pub struct ProtobufSerializerOptions {
     /// The name of the message type to use for serializing.
     #[configurable(metadata(docs::examples = "package.Message"))]
     pub message_type: String,
+
+    /// Encodes messages in the Confluent wire format, prefixed with the ID of their schema in a
+    /// Confluent Schema Registry.
+    ///
+    /// The ID of the latest schema registered under the subject named by `subject_name_strategy` is
+    /// looked up when the first event is encoded, which waits for the Schema Registry to respond.
+    /// Failed lookups are attempted again after 30 seconds. Registering the schema requires its
+    /// `.proto` source, so `auto_register_schemas` is not supported.
+    #[cfg(feature = "schema-registry")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Serializer that converts an `Event` to bytes using the Protobuf format.
@@ -63,12 +158,51 @@ pub struct ProtobufSerializerOptions {
 pub struct ProtobufSerializer {
     /// The protobuf message definition to use for serialization.
     message_descriptor: MessageDescriptor,
+    #[cfg(feature = "schema-registry")]
+    registered_schema: Option<RegisteredSchema>,
+}
+
+/// The schema of the message type as registered in a Schema Registry.
+#[cfg(feature = "schema-registry")]
+#[derive(Debug, Clone)]
+struct RegisteredSchema {
+    client: SchemaRegistryClient,
+    subject: String,
+    /// The indexes of the message type in its schema.
+    message_indexes: Vec<i32>,
+    /// The ID of the schema, once resolved.
+    id: Option<u32>,
 }
 
 impl ProtobufSerializer {
     /// Creates a new `ProtobufSerializer`.
     pub fn new(message_descriptor: MessageDescriptor) -> Self {
-        Self { message_descriptor }
+        Self {
+            message_descriptor,
+            #[cfg(feature = "schema-registry")]
+            registered_schema: None,
+        }
+    }
+
+    /// Prefixes each message with the ID of its schema in the given Schema Registry.
+    #[cfg(feature = "schema-registry")]
+    pub fn with_schema_registry(
+        mut self,
+        client: SchemaRegistryClient,
+    ) -> Result<Self, BuildError> {
+        if client.auto_register_schemas() {
+            return Err(
+                "`auto_register_schemas` is not supported when encoding Protobuf messages".into(),
+            );
+        }
+        let subject = client.subject(self.message_descriptor.full_name())?;
+        self.registered_schema = Some(RegisteredSchema {
+            client,
+            subject,
+            message_indexes: message_indexes(&self.message_descriptor),
+            id: None,
+        });
+        Ok(self)
     }
 
     /// Get a description of the message type used in serialization.
@@ -91,6 +225,101 @@ impl Encoder<Event> for ProtobufSerializer {
                 Value::Object(trace.into_parts().0),
             ),
         }?;
+        #[cfg(feature = "schema-registry")]
+        if let Some(registered) = &mut self.registered_schema {
+            let id = match registered.id {
+                Some(id) => id,
+                None => *registered
+                    .id
+                    .insert(registered.client.latest_schema_id(&registered.subject)?),
+            };
+            put_schema_id(id, buffer);
+            put_message_indexes(&registered.message_indexes, buffer);
+        }
         message.encode(buffer).map_err(Into::into)
     }
 }
+
+/// The indexes of a message type in the file declaring it, as written in the Confluent wire format.
+#[cfg(feature = "schema-registry")]
+fn message_indexes(message_descriptor: &MessageDescriptor) -> Vec<i32> {
+    let mut indexes = Vec::new();
+    let mut current = message_descriptor.clone();
+    loop {
+        let parent = current.parent_message();
+        let index = match &parent {
+            Some(parent) => parent
+                .child_messages()
+                .position(|message| message.full_name() == current.full_name()),
+            None => current
+                .parent_file()
+                .messages()
+                .position(|message| message.full_name() == current.full_name()),
+        };
+        indexes.push(index.unwrap_or_default() as i32);
+        match parent {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    indexes.reverse();
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+
+    use super::*;
+
+    fn message_descriptor(message_type: &str) -> MessageDescriptor {
+        let desc_file = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap())
+            .join("tests/data/protobuf/protos/test_protobuf.desc");
+        vrl::protobuf::get_message_descriptor(&desc_file, message_type).unwrap()
+    }
+
+    #[cfg(feature = "schema-registry")]
+    #[test]
+    fn message_indexes_of_top_level_and_nested_messages() {
+        assert_eq!(
+            message_indexes(&message_descriptor("test_protobuf.Person")),
+            vec![0]
+        );
+        assert_eq!(
+            message_indexes(&message_descriptor("test_protobuf.AddressBook")),
+            vec![1]
+        );
+        assert_eq!(
+            message_indexes(&message_descriptor("test_protobuf.Person.PhoneNumber")),
+            vec![0, 0]
+        );
+    }
+
+    #[test]
+    fn schema_requirement_checks_field_kinds() {
+        let config = ProtobufSerializerConfig::new(ProtobufSerializerOptions {
+            desc_file: PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap())
+                .join("tests/data/protobuf/protos/test_protobuf.desc"),
+            message_type: "test_protobuf.Person".to_string(),
+            #[cfg(feature = "schema-registry")]
+            schema_registry: None,
+        });
+        let definition = schema::Definition::empty_legacy_namespace()
+            .with_event_field(&owned_value_path!("name"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("id"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("phones"), Kind::any_object(), None);
+
+        let errors = config
+            .schema_requirement()
+            .validate_fields(&definition)
+            .unwrap_err();
+        let messages = errors
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(errors.is_field_kind());
+        assert!(!errors.is_field_missing());
+    }
+}
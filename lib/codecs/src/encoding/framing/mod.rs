@@ -7,6 +7,7 @@ mod bytes;
 mod character_delimited;
 mod length_delimited;
 mod newline_delimited;
+mod varint_length_delimited;
 
 use std::fmt::Debug;
 
@@ -17,6 +18,9 @@ use dyn_clone::DynClone;
 pub use length_delimited::{LengthDelimitedEncoder, LengthDelimitedEncoderConfig};
 pub use newline_delimited::{NewlineDelimitedEncoder, NewlineDelimitedEncoderConfig};
 use tokio_util::codec::LinesCodecError;
+pub use varint_length_delimited::{
+    VarintLengthDelimitedEncoder, VarintLengthDelimitedEncoderConfig,
+};
 
 pub use self::bytes::{BytesEncoder, BytesEncoderConfig};
 
@@ -0,0 +1,84 @@
+use bytes::BytesMut;
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+
+use crate::common::varint_length_delimited::{
+    VarintLengthDelimitedCodec, VarintLengthDelimitedCoderOptions,
+};
+
+use super::BoxedFramingError;
+
+/// Config used to build a `VarintLengthDelimitedEncoder`.
+#[configurable_component]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VarintLengthDelimitedEncoderConfig {
+    /// Options for the varint length delimited encoder.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub varint_length_delimited: VarintLengthDelimitedCoderOptions,
+}
+
+impl VarintLengthDelimitedEncoderConfig {
+    /// Build the `VarintLengthDelimitedEncoder` from this configuration.
+    pub const fn build(&self) -> VarintLengthDelimitedEncoder {
+        VarintLengthDelimitedEncoder::new(&self.varint_length_delimited)
+    }
+}
+
+/// An encoder for handling bytes that are delimited by a varint length header.
+///
+/// This is the framing used by protobuf's `writeDelimitedTo` and `parseDelimitedFrom`.
+#[derive(Debug, Clone, Default)]
+pub struct VarintLengthDelimitedEncoder(VarintLengthDelimitedCodec);
+
+impl VarintLengthDelimitedEncoder {
+    /// Creates a new `VarintLengthDelimitedEncoder`.
+    pub const fn new(config: &VarintLengthDelimitedCoderOptions) -> Self {
+        Self(config.build_codec())
+    }
+}
+
+impl Encoder<()> for VarintLengthDelimitedEncoder {
+    type Error = BoxedFramingError;
+
+    fn encode(&mut self, _: (), buffer: &mut BytesMut) -> Result<(), BoxedFramingError> {
+        let bytes = buffer.split().freeze();
+        self.0.encode(bytes, buffer)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let mut codec = VarintLengthDelimitedEncoder::default();
+
+        let mut buffer = BytesMut::from("abc");
+        codec.encode((), &mut buffer).unwrap();
+
+        assert_eq!(&buffer[..], b"\x03abc");
+    }
+
+    #[test]
+    fn encode_multibyte_length() {
+        let mut codec = VarintLengthDelimitedEncoder::default();
+
+        let mut buffer = BytesMut::from(&[b'a'; 300][..]);
+        codec.encode((), &mut buffer).unwrap();
+
+        assert_eq!(&buffer[..2], b"\xac\x02");
+        assert_eq!(buffer.len(), 302);
+    }
+
+    #[test]
+    fn encode_exceeding_max_frame_length() {
+        let mut codec = VarintLengthDelimitedEncoder::new(&VarintLengthDelimitedCoderOptions {
+            max_frame_length: 2,
+        });
+
+        let mut buffer = BytesMut::from("abc");
+        assert!(codec.encode((), &mut buffer).is_err());
+    }
+}
@@ -8,18 +8,27 @@ use std::fmt::Debug;
 
 use bytes::BytesMut;
 pub use format::{
-    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
-    CefSerializerConfig, CsvSerializer, CsvSerializerConfig, GelfSerializer, GelfSerializerConfig,
+    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CborSerializer,
+    CborSerializerConfig, CefSerializer, CefSerializerConfig, CefSerializerOptions, CsvSerializer,
+    CsvSerializerConfig, CsvSerializerOptions, GelfSerializer, GelfSerializerConfig,
     JsonSerializer, JsonSerializerConfig, JsonSerializerOptions, LogfmtSerializer,
-    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-    NativeSerializerConfig, ProtobufSerializer, ProtobufSerializerConfig,
-    ProtobufSerializerOptions, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
-    TextSerializerConfig,
+    LogfmtSerializerConfig, MsgpackSerializer, MsgpackSerializerConfig, NativeJsonSerializer,
+    NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig, ProtobufSerializer,
+    ProtobufSerializerConfig, ProtobufSerializerOptions, RawMessageSerializer,
+    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
+};
+#[cfg(feature = "opentelemetry")]
+pub use format::{OtlpFormat, OtlpSerializer, OtlpSerializerConfig, OtlpSerializerOptions};
+#[cfg(feature = "parquet")]
+pub use format::{
+    ParquetColumnType, ParquetCompression, ParquetField, ParquetSerializer,
+    ParquetSerializerConfig, ParquetSerializerOptions,
 };
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
     CharacterDelimitedEncoderConfig, CharacterDelimitedEncoderOptions, LengthDelimitedEncoder,
     LengthDelimitedEncoderConfig, NewlineDelimitedEncoder, NewlineDelimitedEncoderConfig,
+    VarintLengthDelimitedEncoder, VarintLengthDelimitedEncoderConfig,
 };
 use vector_config::configurable_component;
 use vector_core::{config::DataType, event::Event, schema};
@@ -72,6 +81,13 @@ pub enum FramingConfig {
 
     /// Event data is delimited by a newline (LF) character.
     NewlineDelimited,
+
+    /// Event data is prefixed with its length in bytes, encoded as an unsigned [varint][varint].
+    ///
+    /// This is the framing used by streams of delimited protobuf messages.
+    ///
+    /// [varint]: https://protobuf.dev/programming-guides/encoding/#varints
+    VarintLengthDelimited(VarintLengthDelimitedEncoderConfig),
 }
 
 impl From<BytesEncoderConfig> for FramingConfig {
@@ -98,6 +114,12 @@ impl From<NewlineDelimitedEncoderConfig> for FramingConfig {
     }
 }
 
+impl From<VarintLengthDelimitedEncoderConfig> for FramingConfig {
+    fn from(config: VarintLengthDelimitedEncoderConfig) -> Self {
+        Self::VarintLengthDelimited(config)
+    }
+}
+
 impl FramingConfig {
     /// Build the `Framer` from this configuration.
     pub fn build(&self) -> Framer {
@@ -108,6 +130,9 @@ impl FramingConfig {
             FramingConfig::NewlineDelimited => {
                 Framer::NewlineDelimited(NewlineDelimitedEncoderConfig.build())
             }
+            FramingConfig::VarintLengthDelimited(config) => {
+                Framer::VarintLengthDelimited(config.build())
+            }
         }
     }
 }
@@ -123,6 +148,8 @@ pub enum Framer {
     LengthDelimited(LengthDelimitedEncoder),
     /// Uses a `NewlineDelimitedEncoder` for framing.
     NewlineDelimited(NewlineDelimitedEncoder),
+    /// Uses a `VarintLengthDelimitedEncoder` for framing.
+    VarintLengthDelimited(VarintLengthDelimitedEncoder),
     /// Uses an opaque `Encoder` implementation for framing.
     Boxed(BoxedFramer),
 }
@@ -151,6 +178,12 @@ impl From<NewlineDelimitedEncoder> for Framer {
     }
 }
 
+impl From<VarintLengthDelimitedEncoder> for Framer {
+    fn from(encoder: VarintLengthDelimitedEncoder) -> Self {
+        Self::VarintLengthDelimited(encoder)
+    }
+}
+
 impl From<BoxedFramer> for Framer {
     fn from(encoder: BoxedFramer) -> Self {
         Self::Boxed(encoder)
@@ -166,6 +199,7 @@ impl tokio_util::codec::Encoder<()> for Framer {
             Framer::CharacterDelimited(framer) => framer.encode((), buffer),
             Framer::LengthDelimited(framer) => framer.encode((), buffer),
             Framer::NewlineDelimited(framer) => framer.encode((), buffer),
+            Framer::VarintLengthDelimited(framer) => framer.encode((), buffer),
             Framer::Boxed(framer) => framer.encode((), buffer),
         }
     }
@@ -185,6 +219,14 @@ pub enum SerializerConfig {
         avro: AvroSerializerOptions,
     },
 
+    /// Encodes an event as a [CBOR][cbor] data item.
+    ///
+    /// Bytes that are valid UTF-8 are encoded as text strings, and all other bytes as byte strings.
+    /// Timestamps are encoded as RFC 3339 strings tagged as standard date/time.
+    ///
+    /// [cbor]: https://www.rfc-editor.org/rfc/rfc8949
+    Cbor,
+
     /// Encodes an event as a CEF (Common Event Format) formatted message.
     ///
     Cef(
@@ -226,6 +268,14 @@ pub enum SerializerConfig {
     /// [logfmt]: https://brandur.org/logfmt
     Logfmt,
 
+    /// Encodes an event as a [MessagePack][msgpack] value.
+    ///
+    /// Bytes that are valid UTF-8 are encoded as strings, and all other bytes as binary data.
+    /// Timestamps are encoded with the timestamp extension type.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    Msgpack,
+
     /// Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
     ///
     /// This codec is **[experimental][experimental]**.
@@ -242,6 +292,17 @@ pub enum SerializerConfig {
     /// [experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
     NativeJson,
 
+    /// Encodes an event as an [OpenTelemetry protocol (OTLP)][otlp] export request.
+    ///
+    /// Each event is encoded as its own request, except for batches sent as a single message
+    /// without framing, whose events are all encoded into one request and so must be of the same
+    /// type. Logs and traces are rebuilt from the fields added by the `opentelemetry` source, and
+    /// the `resource.` and `scope.` prefixed tags of metrics become resource and scope attributes.
+    ///
+    /// [otlp]: https://opentelemetry.io/docs/specs/otlp/
+    #[cfg(feature = "opentelemetry")]
+    Otlp(OtlpSerializerConfig),
+
     /// Encodes an event as a [Protobuf][protobuf] message.
     ///
     /// [protobuf]: https://protobuf.dev/
@@ -273,6 +334,12 @@ impl From<AvroSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<CborSerializerConfig> for SerializerConfig {
+    fn from(_: CborSerializerConfig) -> Self {
+        Self::Cbor
+    }
+}
+
 impl From<CefSerializerConfig> for SerializerConfig {
     fn from(config: CefSerializerConfig) -> Self {
         Self::Cef(config)
@@ -303,6 +370,12 @@ impl From<LogfmtSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<MsgpackSerializerConfig> for SerializerConfig {
+    fn from(_: MsgpackSerializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
 impl From<NativeSerializerConfig> for SerializerConfig {
     fn from(_: NativeSerializerConfig) -> Self {
         Self::Native
@@ -315,6 +388,13 @@ impl From<NativeJsonSerializerConfig> for SerializerConfig {
     }
 }
 
+#[cfg(feature = "opentelemetry")]
+impl From<OtlpSerializerConfig> for SerializerConfig {
+    fn from(config: OtlpSerializerConfig) -> Self {
+        Self::Otlp(config)
+    }
+}
+
 impl From<ProtobufSerializerConfig> for SerializerConfig {
     fn from(config: ProtobufSerializerConfig) -> Self {
         Self::Protobuf(config)
@@ -338,17 +418,21 @@ impl SerializerConfig {
     pub fn build(&self) -> Result<Serializer, Box<dyn std::error::Error + Send + Sync + 'static>> {
         match self {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
-                AvroSerializerConfig::new(avro.schema.clone()).build()?,
+                AvroSerializerConfig { avro: avro.clone() }.build()?,
             )),
+            SerializerConfig::Cbor => Ok(Serializer::Cbor(CborSerializerConfig.build())),
             SerializerConfig::Cef(config) => Ok(Serializer::Cef(config.build()?)),
             SerializerConfig::Csv(config) => Ok(Serializer::Csv(config.build()?)),
             SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
             SerializerConfig::Json(config) => Ok(Serializer::Json(config.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
+            SerializerConfig::Msgpack => Ok(Serializer::Msgpack(MsgpackSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
             SerializerConfig::NativeJson => {
                 Ok(Serializer::NativeJson(NativeJsonSerializerConfig.build()))
             }
+            #[cfg(feature = "opentelemetry")]
+            SerializerConfig::Otlp(config) => Ok(Serializer::Otlp(config.build())),
             SerializerConfig::Protobuf(config) => Ok(Serializer::Protobuf(config.build()?)),
             SerializerConfig::RawMessage => {
                 Ok(Serializer::RawMessage(RawMessageSerializerConfig.build()))
@@ -372,6 +456,8 @@ impl SerializerConfig {
             //
             // [1]: https://avro.apache.org/docs/1.11.1/specification/_print/#message-framing
             SerializerConfig::Avro { .. }
+            | SerializerConfig::Cbor
+            | SerializerConfig::Msgpack
             | SerializerConfig::Native
             | SerializerConfig::Protobuf(_) => {
                 FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
@@ -386,6 +472,13 @@ impl SerializerConfig {
             SerializerConfig::Gelf => {
                 FramingConfig::CharacterDelimited(CharacterDelimitedEncoderConfig::new(0))
             }
+            #[cfg(feature = "opentelemetry")]
+            SerializerConfig::Otlp(config) => match config.otlp.format {
+                OtlpFormat::Protobuf => {
+                    FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
+                }
+                OtlpFormat::Json => FramingConfig::NewlineDelimited,
+            },
         }
     }
 
@@ -395,13 +488,17 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).input_type()
             }
+            SerializerConfig::Cbor => CborSerializerConfig.input_type(),
             SerializerConfig::Cef(config) => config.input_type(),
             SerializerConfig::Csv(config) => config.input_type(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::input_type(),
             SerializerConfig::Json(config) => config.input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
+            #[cfg(feature = "opentelemetry")]
+            SerializerConfig::Otlp(config) => config.input_type(),
             SerializerConfig::Protobuf(config) => config.input_type(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.input_type(),
             SerializerConfig::Text(config) => config.input_type(),
@@ -414,13 +511,17 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).schema_requirement()
             }
+            SerializerConfig::Cbor => CborSerializerConfig.schema_requirement(),
             SerializerConfig::Cef(config) => config.schema_requirement(),
             SerializerConfig::Csv(config) => config.schema_requirement(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::schema_requirement(),
             SerializerConfig::Json(config) => config.schema_requirement(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
+            #[cfg(feature = "opentelemetry")]
+            SerializerConfig::Otlp(config) => config.schema_requirement(),
             SerializerConfig::Protobuf(config) => config.schema_requirement(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.schema_requirement(),
             SerializerConfig::Text(config) => config.schema_requirement(),
@@ -433,6 +534,8 @@ impl SerializerConfig {
 pub enum Serializer {
     /// Uses an `AvroSerializer` for serialization.
     Avro(AvroSerializer),
+    /// Uses a `CborSerializer` for serialization.
+    Cbor(CborSerializer),
     /// Uses a `CefSerializer` for serialization.
     Cef(CefSerializer),
     /// Uses a `CsvSerializer` for serialization.
@@ -443,10 +546,15 @@ pub enum Serializer {
     Json(JsonSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
     Logfmt(LogfmtSerializer),
+    /// Uses a `MsgpackSerializer` for serialization.
+    Msgpack(MsgpackSerializer),
     /// Uses a `NativeSerializer` for serialization.
     Native(NativeSerializer),
     /// Uses a `NativeJsonSerializer` for serialization.
     NativeJson(NativeJsonSerializer),
+    /// Uses an `OtlpSerializer` for serialization.
+    #[cfg(feature = "opentelemetry")]
+    Otlp(OtlpSerializer),
     /// Uses a `ProtobufSerializer` for serialization.
     Protobuf(ProtobufSerializer),
     /// Uses a `RawMessageSerializer` for serialization.
@@ -461,13 +569,17 @@ impl Serializer {
         match self {
             Serializer::Json(_) | Serializer::NativeJson(_) | Serializer::Gelf(_) => true,
             Serializer::Avro(_)
+            | Serializer::Cbor(_)
             | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
             | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => false,
+            #[cfg(feature = "opentelemetry")]
+            Serializer::Otlp(_) => false,
         }
     }
 
@@ -483,15 +595,19 @@ impl Serializer {
             Serializer::Json(serializer) => serializer.to_json_value(event),
             Serializer::NativeJson(serializer) => serializer.to_json_value(event),
             Serializer::Avro(_)
+            | Serializer::Cbor(_)
             | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
             | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => {
                 panic!("Serializer does not support JSON")
             }
+            #[cfg(feature = "opentelemetry")]
+            Serializer::Otlp(_) => panic!("Serializer does not support JSON"),
         }
     }
 }
@@ -502,6 +618,12 @@ impl From<AvroSerializer> for Serializer {
     }
 }
 
+impl From<CborSerializer> for Serializer {
+    fn from(serializer: CborSerializer) -> Self {
+        Self::Cbor(serializer)
+    }
+}
+
 impl From<CefSerializer> for Serializer {
     fn from(serializer: CefSerializer) -> Self {
         Self::Cef(serializer)
@@ -532,6 +654,12 @@ impl From<LogfmtSerializer> for Serializer {
     }
 }
 
+impl From<MsgpackSerializer> for Serializer {
+    fn from(serializer: MsgpackSerializer) -> Self {
+        Self::Msgpack(serializer)
+    }
+}
+
 impl From<NativeSerializer> for Serializer {
     fn from(serializer: NativeSerializer) -> Self {
         Self::Native(serializer)
@@ -544,6 +672,13 @@ impl From<NativeJsonSerializer> for Serializer {
     }
 }
 
+#[cfg(feature = "opentelemetry")]
+impl From<OtlpSerializer> for Serializer {
+    fn from(serializer: OtlpSerializer) -> Self {
+        Self::Otlp(serializer)
+    }
+}
+
 impl From<ProtobufSerializer> for Serializer {
     fn from(serializer: ProtobufSerializer) -> Self {
         Self::Protobuf(serializer)
@@ -568,16 +703,95 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
         match self {
             Serializer::Avro(serializer) => serializer.encode(event, buffer),
+            Serializer::Cbor(serializer) => serializer.encode(event, buffer),
             Serializer::Cef(serializer) => serializer.encode(event, buffer),
             Serializer::Csv(serializer) => serializer.encode(event, buffer),
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
+            Serializer::Msgpack(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
+            #[cfg(feature = "opentelemetry")]
+            Serializer::Otlp(serializer) => serializer.encode(event, buffer),
             Serializer::Protobuf(serializer) => serializer.encode(event, buffer),
             Serializer::RawMessage(serializer) => serializer.encode(event, buffer),
             Serializer::Text(serializer) => serializer.encode(event, buffer),
         }
     }
 }
+
+/// Encodes each batch of events as a whole into a single file.
+///
+/// When set, this takes the place of the codec and framing configured in `encoding`, while the
+/// options of `encoding` that select and transform event fields still apply. As columnar formats
+/// compress their data internally, `compression` is not applied to the encoded batches.
+#[cfg(feature = "parquet")]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "codec", rename_all = "snake_case")]
+#[configurable(metadata(
+    docs::enum_tag_description = "The codec to use for encoding batches of events."
+))]
+pub enum BatchSerializerConfig {
+    /// Encodes each batch of events as an [Apache Parquet][apache_parquet] file.
+    ///
+    /// [apache_parquet]: https://parquet.apache.org/
+    Parquet(ParquetSerializerConfig),
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializerConfig {
+    /// Build the `BatchSerializer` from this configuration.
+    pub fn build(&self) -> Result<BatchSerializer, BuildError> {
+        match self {
+            BatchSerializerConfig::Parquet(config) => Ok(BatchSerializer::Parquet(config.build()?)),
+        }
+    }
+
+    /// The data type of events that are accepted by this batch serializer.
+    pub fn input_type(&self) -> DataType {
+        match self {
+            BatchSerializerConfig::Parquet(config) => config.input_type(),
+        }
+    }
+
+    /// The schema required by the batch serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        match self {
+            BatchSerializerConfig::Parquet(config) => config.schema_requirement(),
+        }
+    }
+}
+
+/// Serialize batches of structured events as bytes.
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone)]
+pub enum BatchSerializer {
+    /// Uses a `ParquetSerializer` for serialization.
+    Parquet(ParquetSerializer),
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializer {
+    /// Encode the batch of events.
+    pub fn encode_batch(&self, events: &[Event]) -> Result<bytes::Bytes, vector_common::Error> {
+        match self {
+            BatchSerializer::Parquet(serializer) => serializer.encode_batch(events),
+        }
+    }
+
+    /// Get the HTTP content type of the encoded batches.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// Get the file extension of the encoded batches.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "parquet",
+        }
+    }
+}
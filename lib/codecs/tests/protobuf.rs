@@ -25,18 +25,20 @@ fn build_serializer_pair(
     desc_file: PathBuf,
     message_type: String,
 ) -> (ProtobufSerializer, ProtobufDeserializer) {
-    let serializer = ProtobufSerializerConfig {
-        protobuf: ProtobufSerializerOptions {
-            desc_file: desc_file.clone(),
-            message_type: message_type.clone(),
-        },
-    }
+    let serializer = ProtobufSerializerConfig::new(ProtobufSerializerOptions {
+        desc_file: desc_file.clone(),
+        message_type: message_type.clone(),
+        #[cfg(feature = "schema-registry")]
+        schema_registry: None,
+    })
     .build()
     .unwrap();
     let deserializer = ProtobufDeserializerConfig {
         protobuf: ProtobufDeserializerOptions {
             desc_file,
             message_type,
+            #[cfg(feature = "schema-registry")]
+            schema_registry: None,
         },
     }
     .build()
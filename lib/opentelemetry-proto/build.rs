@@ -1,9 +1,15 @@
-use std::io::Error;
+use std::{io::Error, path::PathBuf};
 
 fn main() -> Result<(), Error> {
+    // The descriptor set is used to encode the messages as OTLP/JSON.
+    let fds_path =
+        PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR environment variable not set"))
+            .join("opentelemetry-fds.bin");
+
     tonic_build::configure()
         .build_client(true)
         .build_server(true)
+        .file_descriptor_set_path(fds_path)
         .compile(
             &[
                 "src/proto/opentelemetry-proto/opentelemetry/proto/common/v1/common.proto",
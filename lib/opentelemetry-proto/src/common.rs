@@ -1,5 +1,8 @@
-use super::proto::common::v1::{any_value::Value as PBValue, KeyValue};
+use super::proto::common::v1::{
+    any_value::Value as PBValue, AnyValue, ArrayValue, KeyValue, KeyValueList,
+};
 use bytes::Bytes;
+use chrono::SecondsFormat;
 use ordered_float::NotNan;
 use vector_core::event::metric::TagValue;
 use vrl::value::{ObjectMap, Value};
@@ -36,6 +39,49 @@ impl From<PBValue> for TagValue {
     }
 }
 
+impl From<Value> for AnyValue {
+    fn from(value: Value) -> Self {
+        let value = match value {
+            Value::Bytes(b) => match String::from_utf8(b.to_vec()) {
+                Ok(s) => Some(PBValue::StringValue(s)),
+                Err(error) => Some(PBValue::BytesValue(error.into_bytes())),
+            },
+            Value::Regex(r) => Some(PBValue::StringValue(r.as_str().to_owned())),
+            Value::Integer(i) => Some(PBValue::IntValue(i)),
+            Value::Float(f) => Some(PBValue::DoubleValue(f.into_inner())),
+            Value::Boolean(b) => Some(PBValue::BoolValue(b)),
+            Value::Timestamp(ts) => Some(PBValue::StringValue(
+                ts.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )),
+            Value::Object(obj) => Some(PBValue::KvlistValue(KeyValueList {
+                values: object_into_kv_list(obj),
+            })),
+            Value::Array(arr) => Some(PBValue::ArrayValue(ArrayValue {
+                values: arr.into_iter().map(Into::into).collect(),
+            })),
+            Value::Null => None,
+        };
+        AnyValue { value }
+    }
+}
+
+pub fn object_into_kv_list(obj: ObjectMap) -> Vec<KeyValue> {
+    obj.into_iter()
+        .map(|(key, value)| KeyValue {
+            key: key.into(),
+            value: Some(value.into()),
+        })
+        .collect()
+}
+
+/// Converts an object of attributes into a list of key/value pairs, ignoring any other value.
+pub fn value_into_kv_list(value: Option<Value>) -> Vec<KeyValue> {
+    match value {
+        Some(Value::Object(obj)) => object_into_kv_list(obj),
+        _ => Vec::new(),
+    }
+}
+
 pub fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     Value::Object(
         arr.into_iter()
@@ -57,3 +103,40 @@ pub fn to_hex(d: &[u8]) -> String {
     }
     hex::encode(d)
 }
+
+/// Decodes an identifier encoded with `to_hex`, returning an empty identifier if it isn't valid hex.
+pub fn from_hex(value: Option<Value>) -> Vec<u8> {
+    match value {
+        Some(Value::Bytes(b)) => hex::decode(b).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn value_into_string(value: Option<Value>) -> String {
+    match value {
+        Some(Value::Null) | None => String::new(),
+        Some(value) => value.to_string_lossy().into_owned(),
+    }
+}
+
+pub fn value_into_u32(value: Option<Value>) -> u32 {
+    match value {
+        Some(Value::Integer(i)) => u32::try_from(i).unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+pub fn value_into_i32(value: Option<Value>) -> i32 {
+    match value {
+        Some(Value::Integer(i)) => i32::try_from(i).unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+/// Converts a timestamp into nanoseconds since the Unix epoch, where zero means it is unknown.
+pub fn value_into_unix_nano(value: Option<Value>) -> u64 {
+    match value {
+        Some(Value::Timestamp(ts)) => ts.timestamp_nanos_opt().unwrap_or_default().max(0) as u64,
+        _ => 0,
+    }
+}
@@ -1,7 +1,10 @@
-use super::common::{kv_list_into_value, to_hex};
+use super::common::{
+    from_hex, kv_list_into_value, object_into_kv_list, to_hex, value_into_kv_list,
+    value_into_string, value_into_u32, value_into_unix_nano,
+};
 use crate::proto::{
     common::v1::{any_value::Value as PBValue, InstrumentationScope},
-    logs::v1::{LogRecord, ResourceLogs, SeverityNumber},
+    logs::v1::{LogRecord, ResourceLogs, ScopeLogs, SeverityNumber},
     resource::v1::Resource,
 };
 use bytes::Bytes;
@@ -12,6 +15,7 @@ use vector_core::{
 };
 use vrl::core::Value;
 use vrl::path;
+use vrl::value::ObjectMap;
 
 const SOURCE_NAME: &str = "opentelemetry";
 pub const RESOURCE_KEY: &str = "resources";
@@ -26,6 +30,7 @@ pub const SEVERITY_NUMBER_KEY: &str = "severity_number";
 pub const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
 pub const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
 pub const FLAGS_KEY: &str = "flags";
+const TIMESTAMP_KEY: &str = "timestamp";
 
 impl ResourceLogs {
     pub fn into_event_iter(self, log_namespace: LogNamespace) -> impl Iterator<Item = Event> {
@@ -236,3 +241,107 @@ impl ResourceLog {
         log.into()
     }
 }
+
+// Rebuilds the OTLP log record from the fields added by the `opentelemetry` source. With the
+// `Legacy` namespace, any other fields of the event are added to the attributes of the record.
+impl From<LogEvent> for ResourceLogs {
+    fn from(mut log: LogEvent) -> Self {
+        let (body, mut fields, timestamp, extra_attributes) = match log.namespace() {
+            LogNamespace::Vector => {
+                let timestamp = log.get_timestamp().cloned();
+                let (value, metadata) = log.into_parts();
+                let fields = match metadata.value().get(path!(SOURCE_NAME)) {
+                    Some(Value::Object(fields)) => fields.clone(),
+                    _ => ObjectMap::new(),
+                };
+                (Some(value), fields, timestamp, ObjectMap::new())
+            }
+            LogNamespace::Legacy => {
+                let body = log_schema()
+                    .message_key_target_path()
+                    .and_then(|path| log.remove(path));
+                let timestamp = log.remove_timestamp();
+                if let Some(path) = log_schema().source_type_key_target_path() {
+                    log.remove(path);
+                }
+                let mut fields = match log.into_parts().0 {
+                    Value::Object(fields) => fields,
+                    _ => ObjectMap::new(),
+                };
+                let mut known_fields = ObjectMap::new();
+                for key in [
+                    RESOURCE_KEY,
+                    ATTRIBUTES_KEY,
+                    SCOPE_KEY,
+                    TRACE_ID_KEY,
+                    SPAN_ID_KEY,
+                    SEVERITY_TEXT_KEY,
+                    SEVERITY_NUMBER_KEY,
+                    OBSERVED_TIMESTAMP_KEY,
+                    DROPPED_ATTRIBUTES_COUNT_KEY,
+                    FLAGS_KEY,
+                ] {
+                    if let Some(value) = fields.remove(key) {
+                        known_fields.insert(key.into(), value);
+                    }
+                }
+                (body, known_fields, timestamp, fields)
+            }
+        };
+
+        let mut attributes = match fields.remove(ATTRIBUTES_KEY) {
+            Some(Value::Object(attributes)) => attributes,
+            _ => ObjectMap::new(),
+        };
+        for (key, value) in extra_attributes {
+            attributes.entry(key).or_insert(value);
+        }
+
+        let scope = match fields.remove(SCOPE_KEY) {
+            Some(Value::Object(mut scope)) => Some(InstrumentationScope {
+                name: value_into_string(scope.remove(NAME_KEY)),
+                version: value_into_string(scope.remove(VERSION_KEY)),
+                attributes: value_into_kv_list(scope.remove(ATTRIBUTES_KEY)),
+                dropped_attributes_count: value_into_u32(
+                    scope.remove(DROPPED_ATTRIBUTES_COUNT_KEY),
+                ),
+            }),
+            _ => None,
+        };
+        let resource = match value_into_kv_list(fields.remove(RESOURCE_KEY)) {
+            attributes if attributes.is_empty() => None,
+            attributes => Some(Resource {
+                attributes,
+                dropped_attributes_count: 0,
+            }),
+        };
+
+        let log_record = LogRecord {
+            time_unix_nano: value_into_unix_nano(fields.remove(TIMESTAMP_KEY).or(timestamp)),
+            observed_time_unix_nano: value_into_unix_nano(fields.remove(OBSERVED_TIMESTAMP_KEY)),
+            severity_number: match fields.remove(SEVERITY_NUMBER_KEY) {
+                Some(Value::Integer(number)) => SeverityNumber::try_from(number as i32)
+                    .unwrap_or(SeverityNumber::Unspecified)
+                    .into(),
+                _ => SeverityNumber::Unspecified.into(),
+            },
+            severity_text: value_into_string(fields.remove(SEVERITY_TEXT_KEY)),
+            body: body.filter(|body| !body.is_null()).map(Into::into),
+            attributes: object_into_kv_list(attributes),
+            dropped_attributes_count: value_into_u32(fields.remove(DROPPED_ATTRIBUTES_COUNT_KEY)),
+            flags: value_into_u32(fields.remove(FLAGS_KEY)),
+            trace_id: from_hex(fields.remove(TRACE_ID_KEY)),
+            span_id: from_hex(fields.remove(SPAN_ID_KEY)),
+        };
+
+        ResourceLogs {
+            resource,
+            scope_logs: vec![ScopeLogs {
+                scope,
+                log_records: vec![log_record],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }
+    }
+}
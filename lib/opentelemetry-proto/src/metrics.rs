@@ -1,9 +1,12 @@
+use std::fmt;
+
 use super::proto::{
-    common::v1::{InstrumentationScope, KeyValue},
+    common::v1::{any_value::Value as PBValue, AnyValue, InstrumentationScope, KeyValue},
     metrics::v1::{
-        metric::Data, number_data_point::Value as NumberDataPointValue, AggregationTemporality,
-        ExponentialHistogram, ExponentialHistogramDataPoint, Gauge, Histogram, HistogramDataPoint,
-        NumberDataPoint, ResourceMetrics, Sum, Summary, SummaryDataPoint,
+        metric::Data, number_data_point::Value as NumberDataPointValue,
+        summary_data_point::ValueAtQuantile, AggregationTemporality, ExponentialHistogram,
+        ExponentialHistogramDataPoint, Gauge, Histogram, HistogramDataPoint, Metric,
+        NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum, Summary, SummaryDataPoint,
     },
     resource::v1::Resource,
 };
@@ -439,3 +442,170 @@ impl ToF64 for Option<NumberDataPointValue> {
         }
     }
 }
+
+/// The error returned when converting a metric whose value has no OTLP equivalent.
+#[derive(Debug)]
+pub struct UnsupportedMetricValue(&'static str);
+
+impl fmt::Display for UnsupportedMetricValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Metrics of type `{}` can't be converted to OTLP.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedMetricValue {}
+
+// Rebuilds the OTLP metric from a metric event. Tags are split into resource attributes, scope and
+// data point attributes the same way `build_metric_tags` joins them.
+impl TryFrom<MetricEvent> for ResourceMetrics {
+    type Error = UnsupportedMetricValue;
+
+    fn try_from(metric: MetricEvent) -> Result<Self, Self::Error> {
+        let name = match metric.namespace() {
+            Some(namespace) => format!("{namespace}.{}", metric.name()),
+            None => metric.name().to_owned(),
+        };
+        let time_unix_nano = metric
+            .timestamp()
+            .unwrap_or_else(Utc::now)
+            .timestamp_nanos_opt()
+            .unwrap_or_default()
+            .max(0) as u64;
+        let aggregation_temporality = if metric.kind() == MetricKind::Incremental {
+            AggregationTemporality::Delta as i32
+        } else {
+            AggregationTemporality::Cumulative as i32
+        };
+        let (resource, scope, attributes) = split_metric_tags(metric.tags());
+
+        let number_data_point = |value: f64| NumberDataPoint {
+            attributes: attributes.clone(),
+            time_unix_nano,
+            value: Some(NumberDataPointValue::AsDouble(value)),
+            ..Default::default()
+        };
+        let data = match metric.value() {
+            MetricValue::Counter { value } => Data::Sum(Sum {
+                data_points: vec![number_data_point(*value)],
+                aggregation_temporality,
+                is_monotonic: true,
+            }),
+            // An incremental gauge holds the change of the value, like a non-monotonic delta sum.
+            MetricValue::Gauge { value } if metric.kind() == MetricKind::Incremental => {
+                Data::Sum(Sum {
+                    data_points: vec![number_data_point(*value)],
+                    aggregation_temporality,
+                    is_monotonic: false,
+                })
+            }
+            MetricValue::Gauge { value } => Data::Gauge(Gauge {
+                data_points: vec![number_data_point(*value)],
+            }),
+            MetricValue::AggregatedHistogram {
+                buckets,
+                count,
+                sum,
+            } => {
+                let mut bucket_counts: Vec<u64> = buckets.iter().map(|b| b.count).collect();
+                let mut explicit_bounds: Vec<f64> = buckets.iter().map(|b| b.upper_limit).collect();
+                // OTLP histograms always end with an unbounded bucket.
+                if explicit_bounds.last() == Some(&f64::INFINITY) {
+                    explicit_bounds.pop();
+                } else {
+                    bucket_counts.push(0);
+                }
+                Data::Histogram(Histogram {
+                    data_points: vec![HistogramDataPoint {
+                        attributes: attributes.clone(),
+                        time_unix_nano,
+                        count: *count,
+                        sum: Some(*sum),
+                        bucket_counts,
+                        explicit_bounds,
+                        ..Default::default()
+                    }],
+                    aggregation_temporality,
+                })
+            }
+            MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            } => Data::Summary(Summary {
+                data_points: vec![SummaryDataPoint {
+                    attributes: attributes.clone(),
+                    time_unix_nano,
+                    count: *count,
+                    sum: *sum,
+                    quantile_values: quantiles
+                        .iter()
+                        .map(|q| ValueAtQuantile {
+                            quantile: q.quantile,
+                            value: q.value,
+                        })
+                        .collect(),
+                    ..Default::default()
+                }],
+            }),
+            value => return Err(UnsupportedMetricValue(value.as_name())),
+        };
+
+        Ok(ResourceMetrics {
+            resource,
+            scope_metrics: vec![ScopeMetrics {
+                scope,
+                metrics: vec![Metric {
+                    name,
+                    description: String::new(),
+                    unit: String::new(),
+                    data: Some(data),
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        })
+    }
+}
+
+pub fn split_metric_tags(
+    tags: Option<&MetricTags>,
+) -> (
+    Option<Resource>,
+    Option<InstrumentationScope>,
+    Vec<KeyValue>,
+) {
+    let mut resource_attributes = Vec::new();
+    let mut scope = InstrumentationScope::default();
+    let mut attributes = Vec::new();
+
+    for (key, value) in tags.into_iter().flat_map(MetricTags::iter_single) {
+        let key_value = |key: &str| KeyValue {
+            key: key.to_owned(),
+            value: Some(AnyValue {
+                value: Some(PBValue::StringValue(value.to_owned())),
+            }),
+        };
+        if let Some(key) = key.strip_prefix("resource.") {
+            resource_attributes.push(key_value(key));
+        } else if key == "scope.name" {
+            scope.name = value.to_owned();
+        } else if key == "scope.version" {
+            scope.version = value.to_owned();
+        } else if let Some(key) = key.strip_prefix("scope.") {
+            scope.attributes.push(key_value(key));
+        } else {
+            attributes.push(key_value(key));
+        }
+    }
+
+    let resource = (!resource_attributes.is_empty()).then(|| Resource {
+        attributes: resource_attributes,
+        dropped_attributes_count: 0,
+    });
+    let scope = (scope != InstrumentationScope::default()).then_some(scope);
+    (resource, scope, attributes)
+}
@@ -1,3 +1,7 @@
+/// The encoded file descriptor set of all the OpenTelemetry protocol definitions.
+pub const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/opentelemetry-fds.bin"));
+
 /// Service stub and clients.
 pub mod collector {
     pub mod trace {
@@ -1,9 +1,12 @@
-use super::common::{kv_list_into_value, to_hex};
+use super::common::{
+    from_hex, kv_list_into_value, to_hex, value_into_i32, value_into_kv_list, value_into_string,
+    value_into_u32, value_into_unix_nano,
+};
 use super::proto::{
     resource::v1::Resource,
     trace::v1::{
         span::{Event as SpanEvent, Link},
-        ResourceSpans, Span, Status as SpanStatus,
+        ResourceSpans, ScopeSpans, Span, Status as SpanStatus,
     },
 };
 use chrono::{DateTime, TimeZone, Utc};
@@ -11,7 +14,7 @@ use std::collections::BTreeMap;
 use vector_core::event::{Event, TraceEvent};
 use vrl::{
     event_path,
-    value::{KeyString, Value},
+    value::{KeyString, ObjectMap, Value},
 };
 
 pub const TRACE_ID_KEY: &str = "trace_id";
@@ -153,3 +156,82 @@ impl From<SpanStatus> for Value {
         Value::Object(obj)
     }
 }
+
+// Rebuilds the OTLP span from the fields added by the `opentelemetry` source.
+impl From<TraceEvent> for ResourceSpans {
+    fn from(trace: TraceEvent) -> Self {
+        let (mut fields, _) = trace.into_parts();
+
+        let resource = match value_into_kv_list(fields.remove(RESOURCE_KEY)) {
+            attributes if attributes.is_empty() => None,
+            attributes => Some(Resource {
+                attributes,
+                dropped_attributes_count: 0,
+            }),
+        };
+
+        let span = Span {
+            trace_id: from_hex(fields.remove(TRACE_ID_KEY)),
+            span_id: from_hex(fields.remove(SPAN_ID_KEY)),
+            trace_state: value_into_string(fields.remove("trace_state")),
+            parent_span_id: from_hex(fields.remove("parent_span_id")),
+            name: value_into_string(fields.remove("name")),
+            kind: value_into_i32(fields.remove("kind")),
+            start_time_unix_nano: value_into_unix_nano(fields.remove("start_time_unix_nano")),
+            end_time_unix_nano: value_into_unix_nano(fields.remove("end_time_unix_nano")),
+            attributes: value_into_kv_list(fields.remove(ATTRIBUTES_KEY)),
+            dropped_attributes_count: value_into_u32(fields.remove(DROPPED_ATTRIBUTES_COUNT_KEY)),
+            events: into_objects(fields.remove("events"))
+                .map(|mut event| SpanEvent {
+                    time_unix_nano: value_into_unix_nano(event.remove("time_unix_nano")),
+                    name: value_into_string(event.remove("name")),
+                    attributes: value_into_kv_list(event.remove(ATTRIBUTES_KEY)),
+                    dropped_attributes_count: value_into_u32(
+                        event.remove(DROPPED_ATTRIBUTES_COUNT_KEY),
+                    ),
+                })
+                .collect(),
+            dropped_events_count: value_into_u32(fields.remove("dropped_events_count")),
+            links: into_objects(fields.remove("links"))
+                .map(|mut link| Link {
+                    trace_id: from_hex(link.remove(TRACE_ID_KEY)),
+                    span_id: from_hex(link.remove(SPAN_ID_KEY)),
+                    trace_state: value_into_string(link.remove("trace_state")),
+                    attributes: value_into_kv_list(link.remove(ATTRIBUTES_KEY)),
+                    dropped_attributes_count: value_into_u32(
+                        link.remove(DROPPED_ATTRIBUTES_COUNT_KEY),
+                    ),
+                })
+                .collect(),
+            dropped_links_count: value_into_u32(fields.remove("dropped_links_count")),
+            status: match fields.remove("status") {
+                Some(Value::Object(mut status)) => Some(SpanStatus {
+                    message: value_into_string(status.remove("message")),
+                    code: value_into_i32(status.remove("code")),
+                }),
+                _ => None,
+            },
+        };
+
+        ResourceSpans {
+            resource,
+            scope_spans: vec![ScopeSpans {
+                scope: None,
+                spans: vec![span],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }
+    }
+}
+
+fn into_objects(value: Option<Value>) -> impl Iterator<Item = ObjectMap> {
+    let values = match value {
+        Some(Value::Array(values)) => values,
+        _ => Vec::new(),
+    };
+    values.into_iter().filter_map(|value| match value {
+        Value::Object(object) => Some(object),
+        _ => None,
+    })
+}
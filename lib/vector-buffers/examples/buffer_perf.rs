@@ -16,6 +16,7 @@ use tokio::{select, sync::oneshot, task, time};
 use tracing::{debug, info, Span};
 use tracing_subscriber::EnvFilter;
 use vector_buffers::{
+    config::DiskBufferCompression,
     encoding::FixedEncodable,
     topology::{
         builder::TopologyBuilder,
@@ -273,6 +274,8 @@ where
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
                 when_full,
+                encryption: None,
+                compression: DiskBufferCompression::None,
             }
         }
         s => panic!(
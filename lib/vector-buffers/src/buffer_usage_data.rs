@@ -11,7 +11,10 @@ use tracing::{Instrument, Span};
 use vector_common::internal_event::emit;
 
 use crate::{
-    internal_events::{BufferCreated, BufferEventsDropped, BufferEventsReceived, BufferEventsSent},
+    internal_events::{
+        BufferCreated, BufferEventsDropped, BufferEventsEvicted, BufferEventsReceived,
+        BufferEventsSent,
+    },
     spawn_named,
 };
 
@@ -140,6 +143,14 @@ impl BufferUsageHandle {
             self.state.dropped.increment(count, byte_size);
         }
     }
+
+    /// Increment the number of evicted events (and their total size) for this buffer component.
+    ///
+    /// This represents the oldest events in the buffer being intentionally dropped to make room for
+    /// newer events.
+    pub fn increment_evicted_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.evicted.increment(count, byte_size);
+    }
 }
 
 #[derive(Debug, Default)]
@@ -149,6 +160,7 @@ struct BufferUsageData {
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
+    evicted: CategoryMetrics,
     max_size: CategoryMetrics,
 }
 
@@ -165,6 +177,7 @@ impl BufferUsageData {
         let sent = self.sent.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
+        let evicted = self.evicted.get();
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -176,6 +189,8 @@ impl BufferUsageData {
             dropped_event_byte_size: dropped.event_byte_size,
             dropped_event_count_intentional: dropped_intentional.event_count,
             dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            evicted_event_count: evicted.event_count,
+            evicted_event_byte_size: evicted.event_byte_size,
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -196,6 +211,8 @@ pub struct BufferUsageSnapshot {
     pub dropped_event_byte_size: u64,
     pub dropped_event_count_intentional: u64,
     pub dropped_event_byte_size_intentional: u64,
+    pub evicted_event_count: u64,
+    pub evicted_event_byte_size: u64,
     pub max_size_bytes: u64,
     pub max_size_events: usize,
 }
@@ -300,6 +317,15 @@ impl BufferUsage {
                             byte_size: dropped_intentional.event_byte_size,
                         });
                     }
+
+                    let evicted = stage.evicted.consume();
+                    if evicted.has_updates() {
+                        emit(BufferEventsEvicted {
+                            idx: stage.idx,
+                            count: evicted.event_count,
+                            byte_size: evicted.event_byte_size,
+                        });
+                    }
                 }
             }
         };
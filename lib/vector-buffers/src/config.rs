@@ -1,14 +1,18 @@
 use std::{
+    error::Error,
     fmt,
     num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     slice,
 };
 
+use base64::{prelude::BASE64_STANDARD, Engine};
 use serde::{de, Deserialize, Deserializer, Serialize};
 use snafu::{ResultExt, Snafu};
 use tracing::Span;
-use vector_common::{config::ComponentKey, finalization::Finalizable};
+use vector_common::{
+    config::ComponentKey, finalization::Finalizable, sensitive_string::SensitiveString,
+};
 use vector_config::configurable_component;
 
 use crate::{
@@ -16,7 +20,12 @@ use crate::{
         builder::{TopologyBuilder, TopologyError},
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{
+        disk_v2::{
+            open_disk_v2_buffer_for_inspection, BufferInspector, RecordCipher, RecordCompression,
+        },
+        DiskV2Buffer, MemoryBuffer,
+    },
     Bufferable, WhenFull,
 };
 
@@ -28,6 +37,21 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display(
+        "disk buffer stage {} must be the last stage in the buffer topology",
+        stage_idx
+    ))]
+    DiskStageNotLast { stage_idx: usize },
+    #[snafu(display("disk buffer encryption key '{}' is not valid base64", key_id))]
+    InvalidEncryptionKeyEncoding { key_id: String },
+    #[snafu(display("invalid disk buffer encryption configuration: {}", reason))]
+    InvalidEncryption { reason: String },
+    #[snafu(display("the configured buffer type has no disk buffer stage"))]
+    NoDiskStage,
+    #[snafu(display("failed to open disk buffer: {}", source))]
+    FailedToOpenDiskBuffer {
+        source: Box<dyn Error + Send + Sync>,
+    },
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +62,14 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+const ALL_FIELDS: [&str; 6] = [
+    "type",
+    "max_events",
+    "max_size",
+    "when_full",
+    "encryption",
+    "compression",
+];
 
 struct BufferTypeVisitor;
 
@@ -51,6 +82,8 @@ impl BufferTypeVisitor {
         let mut max_events: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
         let mut when_full: Option<WhenFull> = None;
+        let mut encryption: Option<DiskBufferEncryption> = None;
+        let mut compression: Option<DiskBufferCompression> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
                 "type" => {
@@ -77,6 +110,18 @@ impl BufferTypeVisitor {
                     }
                     when_full = Some(map.next_value()?);
                 }
+                "encryption" => {
+                    if encryption.is_some() {
+                        return Err(de::Error::duplicate_field("encryption"));
+                    }
+                    encryption = Some(map.next_value()?);
+                }
+                "compression" => {
+                    if compression.is_some() {
+                        return Err(de::Error::duplicate_field("compression"));
+                    }
+                    compression = Some(map.next_value()?);
+                }
                 other => {
                     return Err(de::Error::unknown_field(other, &ALL_FIELDS));
                 }
@@ -92,6 +137,18 @@ impl BufferTypeVisitor {
                         &["type", "max_events", "when_full"],
                     ));
                 }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "encryption",
+                        &["type", "max_events", "when_full"],
+                    ));
+                }
+                if compression.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "compression",
+                        &["type", "max_events", "when_full"],
+                    ));
+                }
                 Ok(BufferType::Memory {
                     max_events: max_events.unwrap_or_else(memory_buffer_default_max_events),
                     when_full,
@@ -101,12 +158,14 @@ impl BufferTypeVisitor {
                 if max_events.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_events",
-                        &["type", "max_size", "when_full"],
+                        &["type", "max_size", "when_full", "encryption", "compression"],
                     ));
                 }
                 Ok(BufferType::DiskV2 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
                     when_full,
+                    encryption,
+                    compression: compression.unwrap_or_default(),
                 })
             }
         }
@@ -175,9 +234,125 @@ impl DiskUsage {
     }
 }
 
+/// An encryption key for disk buffer data files.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DiskBufferEncryptionKey {
+    /// The identifier this key was used with when it was the active key.
+    #[configurable(metadata(docs::examples = "2024-01"))]
+    pub key_id: String,
+
+    /// The key, as 32 bytes of base64-encoded key material.
+    #[configurable(metadata(docs::examples = "SECRET[vault.disk_buffer_key_2024_01]"))]
+    pub key: SensitiveString,
+}
+
+/// Encryption at rest for disk buffer data files.
+///
+/// Records are encrypted with XChaCha20-Poly1305, which also authenticates them, such that records
+/// which were modified on disk are detected and dropped rather than being sent.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DiskBufferEncryption {
+    /// The identifier of the active key.
+    ///
+    /// All new records are encrypted with the active key. The identifier is stored alongside every
+    /// record, and is used to find the correct key when reading records back. Identifiers must be
+    /// unique, and should never be reused for different key material.
+    #[configurable(metadata(docs::examples = "2024-06"))]
+    pub key_id: String,
+
+    /// The active key, as 32 bytes of base64-encoded key material.
+    ///
+    /// The key should be kept in a secret backend, and referenced with `SECRET[<backend>.<name>]`.
+    #[configurable(metadata(docs::examples = "SECRET[vault.disk_buffer_key_2024_06]"))]
+    pub key: SensitiveString,
+
+    /// Keys that were previously active.
+    ///
+    /// Previous keys are never used to encrypt new records, and are only used to read records that
+    /// were written before the active key was rotated. Once all such records have been read, the
+    /// previous key can be removed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_keys: Vec<DiskBufferEncryptionKey>,
+}
+
+impl DiskBufferEncryption {
+    fn build_cipher(&self) -> Result<RecordCipher, BufferBuildError> {
+        let active_key = decode_encryption_key(&self.key_id, &self.key)?;
+        let cipher = RecordCipher::new(&self.key_id, &active_key).map_err(|e| {
+            BufferBuildError::InvalidEncryption {
+                reason: e.to_string(),
+            }
+        })?;
+
+        self.previous_keys
+            .iter()
+            .try_fold(cipher, |cipher, previous| {
+                let previous_key = decode_encryption_key(&previous.key_id, &previous.key)?;
+                cipher
+                    .with_previous_key(&previous.key_id, &previous_key)
+                    .map_err(|e| BufferBuildError::InvalidEncryption {
+                        reason: e.to_string(),
+                    })
+            })
+    }
+}
+
+fn decode_encryption_key(key_id: &str, key: &SensitiveString) -> Result<Vec<u8>, BufferBuildError> {
+    BASE64_STANDARD.decode(key.inner()).map_err(|_| {
+        BufferBuildError::InvalidEncryptionKeyEncoding {
+            key_id: key_id.to_string(),
+        }
+    })
+}
+
+/// Compression algorithm for disk buffer data files.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskBufferCompression {
+    /// Records are not compressed.
+    #[default]
+    None,
+
+    /// Records are compressed with [Zstandard][zstd].
+    ///
+    /// This typically achieves a better compression ratio than LZ4, at the cost of more CPU.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// Records are compressed with [LZ4][lz4].
+    ///
+    /// This is very fast, at the cost of a lower compression ratio than Zstandard.
+    ///
+    /// [lz4]: https://lz4.org/
+    Lz4,
+}
+
+impl DiskBufferCompression {
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl From<DiskBufferCompression> for RecordCompression {
+    fn from(compression: DiskBufferCompression) -> Self {
+        match compression {
+            DiskBufferCompression::None => RecordCompression::None,
+            DiskBufferCompression::Zstd => RecordCompression::Zstd,
+            DiskBufferCompression::Lz4 => RecordCompression::Lz4,
+        }
+    }
+}
+
 /// A specific type of buffer stage.
 #[configurable_component(no_deser)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case", tag = "type")]
 #[configurable(metadata(docs::enum_tag_description = "The type of buffer to use."))]
 pub enum BufferType {
@@ -218,6 +393,28 @@ pub enum BufferType {
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        /// Encrypts data files at rest.
+        ///
+        /// When not set, records are written to data files unencrypted. Records written before
+        /// encryption was enabled can still be read. Encrypted records whose key is no longer
+        /// provided, for example because encryption was disabled, don't prevent the buffer from
+        /// starting, but can't be read and are dropped.
+        #[configurable(derived)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encryption: Option<DiskBufferEncryption>,
+
+        /// Compresses data files.
+        ///
+        /// Records are compressed individually before being written, and encrypted afterwards if
+        /// encryption is enabled. As `max_size` limits the size of the buffer on disk, compressing
+        /// records allows more events to be buffered within the same amount of space.
+        ///
+        /// Changing this setting only affects new records: existing records are always read back
+        /// correctly, regardless of how they were compressed.
+        #[configurable(derived)]
+        #[serde(default, skip_serializing_if = "DiskBufferCompression::is_none")]
+        compression: DiskBufferCompression,
     },
 }
 
@@ -276,19 +473,28 @@ impl BufferType {
     where
         T: Bufferable + Clone + Finalizable,
     {
-        match *self {
+        match self {
             BufferType::Memory {
                 when_full,
                 max_events,
             } => {
-                builder.stage(MemoryBuffer::new(max_events), when_full);
+                builder.stage(MemoryBuffer::new(*max_events), *when_full);
             }
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                encryption,
+                compression,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let encryption = encryption
+                    .as_ref()
+                    .map(DiskBufferEncryption::build_cipher)
+                    .transpose()?;
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, *max_size, encryption, (*compression).into()),
+                    *when_full,
+                );
             }
         };
 
@@ -310,10 +516,9 @@ impl BufferType {
 /// functionality to allow chaining buffers together, you'll see "buffer topology" used in internal
 /// documentation to correctly reflect the internal structure.
 ///
-// TODO: We need to limit chained buffers to only allowing a single copy of each buffer type to be
-// defined, otherwise, for example, two instances of the same disk buffer type in a single chained
-// buffer topology would try to both open the same buffer files on disk, which wouldn't work or
-// would go horribly wrong.
+/// Chained buffers are typically used to overflow from an in-memory buffer to a disk buffer.  A
+/// disk buffer can only be used as the last stage of a buffer topology, which also ensures that
+/// two disk buffer stages never try to open the same buffer files on disk.
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
@@ -357,8 +562,8 @@ impl BufferConfig {
     ///
     /// # Errors
     ///
-    /// If the buffer is configured with anything other than a single stage, an error variant will
-    /// be thrown.
+    /// If a disk buffer stage is configured anywhere but as the last stage, or if the stages are
+    /// otherwise not a valid buffer topology, an error variant will be thrown.
     ///
     /// If a disk buffer stage is configured and the data directory provided is `None`, an error
     /// variant will be thrown.
@@ -372,9 +577,18 @@ impl BufferConfig {
     where
         T: Bufferable + Clone + Finalizable,
     {
+        let stages = self.stages();
+        if let Some(stage_idx) = stages
+            .iter()
+            .take(stages.len().saturating_sub(1))
+            .position(|stage| matches!(stage, BufferType::DiskV2 { .. }))
+        {
+            return Err(BufferBuildError::DiskStageNotLast { stage_idx });
+        }
+
         let mut builder = TopologyBuilder::default();
 
-        for stage in self.stages() {
+        for stage in stages {
             stage.add_to_builder(&mut builder, data_dir.clone(), buffer_id.clone())?;
         }
 
@@ -383,13 +597,58 @@ impl BufferConfig {
             .await
             .context(FailedToBuildTopologySnafu)
     }
+
+    /// Opens the disk buffer stage of this buffer for inspection.
+    ///
+    /// The caller gets back a [`BufferInspector`], which reads the records currently held by the
+    /// disk buffer without modifying it in any way.
+    ///
+    /// # Errors
+    ///
+    /// If there is no disk buffer stage configured, or if the data directory provided is `None`,
+    /// an error variant will be thrown.
+    ///
+    /// If the disk buffer does not exist, or can't be opened, an error variant will be thrown.
+    pub async fn inspect<T>(
+        &self,
+        data_dir: Option<&Path>,
+        buffer_id: &str,
+    ) -> Result<BufferInspector<T>, BufferBuildError>
+    where
+        T: Bufferable,
+    {
+        // A disk buffer stage can only ever be the last stage.
+        let Some(BufferType::DiskV2 { encryption, .. }) = self.stages().last() else {
+            return Err(BufferBuildError::NoDiskStage);
+        };
+
+        let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
+        let encryption = encryption
+            .as_ref()
+            .map(DiskBufferEncryption::build_cipher)
+            .transpose()?;
+
+        open_disk_v2_buffer_for_inspection(data_dir, buffer_id, encryption)
+            .await
+            .context(FailedToOpenDiskBufferSnafu)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use std::num::{NonZeroU64, NonZeroUsize};
+    use std::{
+        num::{NonZeroU64, NonZeroUsize},
+        path::PathBuf,
+    };
 
-    use crate::{BufferConfig, BufferType, WhenFull};
+    use tracing::Span;
+
+    use super::{
+        BufferBuildError, DiskBufferCompression, DiskBufferEncryption, DiskBufferEncryptionKey,
+    };
+    use crate::{
+        test::SizedRecord, topology::builder::TopologyBuilder, BufferConfig, BufferType, WhenFull,
+    };
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -466,6 +725,168 @@ max_events: 42
         );
     }
 
+    #[test]
+    fn parse_memory_overflow_to_disk() {
+        check_multiple_stages(
+            r"
+          - type: memory
+            max_events: 1000
+            when_full: overflow
+          - type: disk
+            max_size: 1073741824
+          ",
+            &[
+                BufferType::Memory {
+                    max_events: NonZeroUsize::new(1000).unwrap(),
+                    when_full: WhenFull::Overflow,
+                },
+                BufferType::DiskV2 {
+                    max_size: NonZeroU64::new(1_073_741_824).unwrap(),
+                    when_full: WhenFull::Block,
+                    encryption: None,
+                    compression: DiskBufferCompression::None,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_disk_with_encryption() {
+        check_single_stage(
+            r#"
+          type: disk
+          max_size: 1073741824
+          encryption:
+            key_id: "2024-06"
+            key: "bmV3"
+            previous_keys:
+              - key_id: "2024-01"
+                key: "b2xk"
+          "#,
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1_073_741_824).unwrap(),
+                when_full: WhenFull::Block,
+                encryption: Some(DiskBufferEncryption {
+                    key_id: String::from("2024-06"),
+                    key: String::from("bmV3").into(),
+                    previous_keys: vec![DiskBufferEncryptionKey {
+                        key_id: String::from("2024-01"),
+                        key: String::from("b2xk").into(),
+                    }],
+                }),
+                compression: DiskBufferCompression::None,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_memory_with_encryption() {
+        let source = r#"
+          type: memory
+          encryption:
+            key_id: "2024-06"
+            key: "bmV3"
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn disk_encryption_keys_are_validated() {
+        let valid_key = "GhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGho=";
+        let build = |key: &str, previous_key: &str| {
+            let stage = BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1_073_741_824).unwrap(),
+                when_full: WhenFull::Block,
+                encryption: Some(DiskBufferEncryption {
+                    key_id: String::from("2024-06"),
+                    key: String::from(key).into(),
+                    previous_keys: vec![DiskBufferEncryptionKey {
+                        key_id: String::from("2024-01"),
+                        key: String::from(previous_key).into(),
+                    }],
+                }),
+                compression: DiskBufferCompression::None,
+            };
+            let mut builder = TopologyBuilder::<SizedRecord>::default();
+            stage.add_to_builder(
+                &mut builder,
+                Some(PathBuf::from("/tmp/dummy/path")),
+                String::from("test"),
+            )
+        };
+
+        assert!(build(valid_key, valid_key).is_ok());
+        assert!(matches!(
+            build(valid_key, "not base64!"),
+            Err(BufferBuildError::InvalidEncryptionKeyEncoding { key_id }) if key_id == "2024-01"
+        ));
+        assert!(matches!(
+            build("c2hvcnQ=", valid_key),
+            Err(BufferBuildError::InvalidEncryption { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_disk_with_compression() {
+        for (name, compression) in [
+            ("none", DiskBufferCompression::None),
+            ("zstd", DiskBufferCompression::Zstd),
+            ("lz4", DiskBufferCompression::Lz4),
+        ] {
+            check_single_stage(
+                &format!(
+                    r"
+          type: disk
+          max_size: 1073741824
+          compression: {name}
+          "
+                ),
+                BufferType::DiskV2 {
+                    max_size: NonZeroU64::new(1_073_741_824).unwrap(),
+                    when_full: WhenFull::Block,
+                    encryption: None,
+                    compression,
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn parse_memory_with_compression() {
+        let source = r"
+          type: memory
+          compression: zstd
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[tokio::test]
+    async fn disk_stage_must_be_last() {
+        let config = BufferConfig::Chained(vec![
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1_073_741_824).unwrap(),
+                when_full: WhenFull::Overflow,
+                encryption: None,
+                compression: DiskBufferCompression::None,
+            },
+            BufferType::Memory {
+                max_events: NonZeroUsize::new(1000).unwrap(),
+                when_full: WhenFull::Block,
+            },
+        ]);
+
+        let result = config
+            .build::<SizedRecord>(None, String::from("test"), Span::none())
+            .await;
+        match result {
+            Err(BufferBuildError::DiskStageNotLast { stage_idx }) => assert_eq!(stage_idx, 0),
+            Err(error) => panic!("unexpected build error: {error}"),
+            Ok(_) => panic!("buffer should not have been built"),
+        }
+    }
+
     #[test]
     fn ensure_field_defaults_for_all_types() {
         check_single_stage(
@@ -519,6 +940,8 @@ max_events: 42
             BufferType::DiskV2 {
                 max_size: NonZeroU64::new(1024).unwrap(),
                 when_full: WhenFull::Block,
+                encryption: None,
+                compression: DiskBufferCompression::None,
             },
         );
     }
@@ -102,6 +102,34 @@ impl InternalEvent for BufferEventsDropped {
     }
 }
 
+pub struct BufferEventsEvicted {
+    pub idx: usize,
+    pub count: u64,
+    pub byte_size: u64,
+}
+
+impl InternalEvent for BufferEventsEvicted {
+    #[allow(clippy::cast_precision_loss)]
+    fn emit(self) {
+        debug!(
+            message = "Events evicted.",
+            count = %self.count,
+            intentional = "true",
+            reason = "drop_oldest",
+            stage = %self.idx,
+        );
+        counter!(
+            "buffer_discarded_events_total", "intentional" => "true",
+        )
+        .increment(self.count);
+        counter!("buffer_evicted_events_total", "stage" => self.idx.to_string())
+            .increment(self.count);
+        gauge!("buffer_events", "stage" => self.idx.to_string()).decrement(self.count as f64);
+        gauge!("buffer_byte_size", "stage" => self.idx.to_string())
+            .decrement(self.byte_size as f64);
+    }
+}
+
 pub struct BufferReadError {
     pub error_code: &'static str,
     pub error: String,
@@ -32,6 +32,7 @@ pub mod test;
 pub mod topology;
 
 pub(crate) mod variants;
+pub use variants::disk_v2::{BufferInspector, InspectedRecord};
 
 use std::fmt::Debug;
 
@@ -59,6 +60,17 @@ pub enum WhenFull {
     /// slowdown in the acceptance/consumption of events.
     DropNewest,
 
+    /// Drops the oldest buffered events to make room for the event.
+    ///
+    /// The evicted events will be intentionally dropped. This mode is typically used when the
+    /// freshness of the data is the highest priority, such as for real-time monitoring, and it is
+    /// preferable to lose the stalest events rather than the most recent ones.
+    ///
+    /// For disk buffers, space is reclaimed one data file at a time, and only data files that the
+    /// reader has not yet started reading can be evicted. When no such data file exists, the event
+    /// is dropped instead.
+    DropOldest,
+
     /// Overflows to the next stage in the buffer topology.
     ///
     /// If the current buffer stage is full, attempt to send this event to the next buffer stage.
@@ -66,21 +78,21 @@ pub enum WhenFull {
     /// buffer topology must use one of the other handling behaviors. This means that next stage may
     /// potentially be able to buffer the event, but it may also block or drop the event.
     ///
-    /// This mode can only be used when two or more buffer stages are configured.
-    #[configurable(metadata(docs::hidden))]
+    /// This mode can only be used when two or more buffer stages are configured, and is typically
+    /// used to overflow from an in-memory buffer to a disk buffer.
     Overflow,
 }
 
 #[cfg(test)]
 impl Arbitrary for WhenFull {
     fn arbitrary(g: &mut Gen) -> Self {
-        // TODO: We explicitly avoid generating "overflow" as a possible value because nothing yet
-        // supports handling it, and will be defaulted to using "block" if they encounter
-        // "overflow".  Thus, there's no reason to emit it here... yet.
-        if bool::arbitrary(g) {
-            WhenFull::Block
-        } else {
-            WhenFull::DropNewest
+        // We explicitly avoid generating "overflow" as a possible value because it is only valid for
+        // a stage followed by another stage, and the buffer variants we generate are single-stage,
+        // where "overflow" would be defaulted to using "block" anyways.
+        match u8::arbitrary(g) % 3 {
+            0 => WhenFull::Block,
+            1 => WhenFull::DropNewest,
+            _ => WhenFull::DropOldest,
         }
     }
 }
@@ -13,7 +13,7 @@ use crate::{
         builder::TopologyBuilder,
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{disk_v2::RecordCompression, DiskV2Buffer, MemoryBuffer},
     Bufferable, WhenFull,
 };
 
@@ -63,7 +63,13 @@ impl Variant {
                 id,
             } => {
                 builder.stage(
-                    DiskV2Buffer::new(id.clone(), data_dir.clone(), *max_size),
+                    DiskV2Buffer::new(
+                        id.clone(),
+                        data_dir.clone(),
+                        *max_size,
+                        None,
+                        RecordCompression::None,
+                    ),
                     *when_full,
                 );
             }
@@ -40,7 +40,7 @@ pub enum TopologyError {
     #[snafu(display("buffer topology cannot be empty"))]
     EmptyTopology,
     #[snafu(display(
-        "stage {} configured with block/drop newest behavior in front of subsequent stage",
+        "stage {} configured with block/drop newest/drop oldest behavior in front of subsequent stage",
         stage_idx
     ))]
     NextStageNotUsed { stage_idx: usize },
@@ -122,9 +122,9 @@ impl<T: Bufferable> TopologyBuilder<T> {
                         return Err(TopologyError::OverflowWhenLast);
                     }
                 }
-                // If there's already an inner stage, then blocking or dropping the newest events
-                // doesn't no sense.  Overflowing is the only valid transition to another stage.
-                WhenFull::Block | WhenFull::DropNewest => {
+                // If there's already an inner stage, then blocking or dropping events doesn't make
+                // sense.  Overflowing is the only valid transition to another stage.
+                WhenFull::Block | WhenFull::DropNewest | WhenFull::DropOldest => {
                     if current_stage.is_some() {
                         return Err(TopologyError::NextStageNotUsed { stage_idx });
                     }
@@ -257,15 +257,16 @@ impl<T: Bufferable> Default for TopologyBuilder<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroUsize;
+    use std::num::{NonZeroU64, NonZeroUsize};
 
     use tracing::Span;
 
     use super::TopologyBuilder;
     use crate::{
+        test::{acknowledge, with_temp_dir, SizedRecord},
         topology::builder::TopologyError,
         topology::test_util::{assert_current_send_capacity, Sample},
-        variants::MemoryBuffer,
+        variants::{disk_v2::RecordCompression, DiskV2Buffer, MemoryBuffer},
         WhenFull,
     };
 
@@ -365,4 +366,53 @@ mod tests {
         let (mut sender, _) = result.unwrap();
         assert_current_send_capacity(&mut sender, Some(1), Some(1));
     }
+
+    #[tokio::test]
+    async fn two_stage_topology_memory_overflow_to_disk() {
+        with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let mut builder = TopologyBuilder::<SizedRecord>::default();
+                builder.stage(
+                    MemoryBuffer::new(NonZeroUsize::new(2).unwrap()),
+                    WhenFull::Overflow,
+                );
+                builder.stage(
+                    DiskV2Buffer::new(
+                        String::from("test"),
+                        data_dir,
+                        NonZeroU64::new(268_435_488).unwrap(),
+                        None,
+                        RecordCompression::None,
+                    ),
+                    WhenFull::Block,
+                );
+                let (mut sender, mut receiver) = builder
+                    .build(String::from("test"), Span::none())
+                    .await
+                    .expect("should not fail to build memory/disk buffer topology");
+
+                // The first two records fit in the in-memory stage, and the rest overflow to the
+                // disk stage.
+                for id in 1..=5 {
+                    sender.send(SizedRecord::new(id), None).await.unwrap();
+                }
+                assert_current_send_capacity(&mut sender, Some(0), None);
+                sender.flush().await.unwrap();
+                drop(sender);
+
+                // Records read from the disk stage must be acknowledged before the disk stage
+                // considers itself empty, and so before the receiver completes.
+                let mut results = Vec::new();
+                while let Some(record) = receiver.next().await {
+                    results.push(record.0);
+                    acknowledge(record).await;
+                }
+                results.sort_unstable();
+                assert_eq!(results, vec![1, 2, 3, 4, 5]);
+            }
+        })
+        .await;
+    }
 }
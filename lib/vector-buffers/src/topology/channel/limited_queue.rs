@@ -163,6 +163,62 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
 
         Ok(())
     }
+
+    /// Sends an item into the channel, evicting the oldest items in the channel until there is
+    /// enough capacity for it.
+    ///
+    /// The evicted items, if any, are returned in the order they were sent.
+    ///
+    /// # Errors
+    ///
+    /// If the receiver has disconnected (does not exist anymore), then `Err(SendError)` be returned
+    /// with the given `item`.
+    pub async fn send_dropping_oldest(&mut self, item: T) -> Result<Vec<T>, SendError<T>> {
+        let permits_required = self.get_required_permits_for_item(&item);
+        let mut evicted = Vec::new();
+        let permits = loop {
+            match self
+                .inner
+                .limiter
+                .clone()
+                .try_acquire_many_owned(permits_required)
+            {
+                Ok(permits) => break permits,
+                Err(TryAcquireError::Closed) => return Err(SendError(item)),
+                Err(TryAcquireError::NoPermits) => {}
+            }
+
+            // Dropping the permits of the oldest item hands its capacity back to the channel, so
+            // that we can try again.
+            if let Some((_permits, oldest)) = self.inner.data.pop() {
+                evicted.push(oldest);
+                continue;
+            }
+
+            // The channel is empty, so the missing permits are held by concurrent senders that
+            // have yet to push their items, and we simply wait for the receiver to make room.
+            let Ok(permits) = self
+                .inner
+                .limiter
+                .clone()
+                .acquire_many_owned(permits_required)
+                .await
+            else {
+                return Err(SendError(item));
+            };
+            break permits;
+        };
+
+        self.inner
+            .data
+            .push((permits, item))
+            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
+        self.inner.read_waker.notify_one();
+
+        trace!(evicted = evicted.len(), "Sent item.");
+
+        Ok(evicted)
+    }
 }
 
 impl<T> Clone for LimitedSender<T> {
@@ -595,4 +651,47 @@ mod tests {
 
         assert_eq!(2, tx.available_capacity());
     }
+
+    #[test]
+    fn send_dropping_oldest_evicts_until_capacity_available() {
+        let (mut tx, mut rx) = limited(5);
+
+        let msgs1 = vec![
+            MultiEventRecord::new(1),
+            MultiEventRecord::new(2),
+            MultiEventRecord::new(2),
+        ];
+        let msg2 = MultiEventRecord::new(3);
+
+        // Fill the channel up to its limit, which should not evict anything.
+        let mut small_sends = spawn(async {
+            for msg in msgs1.clone() {
+                assert!(tx.send_dropping_oldest(msg).await?.is_empty());
+            }
+
+            Ok::<_, SendError<MultiEventRecord>>(())
+        });
+        assert_eq!(Ok(()), assert_ready!(small_sends.poll()));
+        drop(small_sends);
+
+        assert_eq!(0, tx.available_capacity());
+
+        // Our next send needs three slots, so the two oldest items have to be evicted, and the send
+        // should complete immediately.
+        let mut send2 = spawn(tx.send_dropping_oldest(msg2.clone()));
+        assert_eq!(Ok(msgs1[..2].to_vec()), assert_ready!(send2.poll()));
+        drop(send2);
+
+        assert_eq!(0, rx.available_capacity());
+
+        let mut recv1 = spawn(async { rx.next().await });
+        assert_eq!(Some(&msgs1[2]), assert_ready!(recv1.poll()).as_ref());
+        drop(recv1);
+
+        let mut recv2 = spawn(async { rx.next().await });
+        assert_eq!(Some(msg2), assert_ready!(recv2.poll()));
+        drop(recv2);
+
+        assert_eq!(5, rx.available_capacity());
+    }
 }
@@ -1,11 +1,10 @@
 use std::{
-    mem,
+    fmt, mem,
     pin::Pin,
     task::{ready, Context, Poll},
 };
 
-use async_recursion::async_recursion;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use tokio::select;
 use tokio_util::sync::ReusableBoxFuture;
 use vector_common::internal_event::emit;
@@ -72,10 +71,16 @@ where
 /// for querying the overflow buffer as well.  The ordering of events when operating in "overflow"
 /// is undefined, as the receiver will try to manage polling both its own buffer, as well as the
 /// overflow buffer, in order to fairly balance throughput.
+///
+/// The overflow buffer is held as a [`BufferReceiverStream`], so that a read from it which loses
+/// the race against a read from the base buffer is resumed, rather than dropped, the next time the
+/// receiver is polled.  Reads from disk buffers are not cancel-safe, as a partially-read record
+/// would be lost, which is also why a disk buffer can only ever be used as the last stage of a
+/// buffer topology.
 #[derive(Debug)]
 pub struct BufferReceiver<T: Bufferable> {
     base: ReceiverAdapter<T>,
-    overflow: Option<Box<BufferReceiver<T>>>,
+    overflow: Option<Box<BufferReceiverStream<T>>>,
     instrumentation: Option<BufferUsageHandle>,
 }
 
@@ -93,7 +98,7 @@ impl<T: Bufferable> BufferReceiver<T> {
     pub fn with_overflow(base: ReceiverAdapter<T>, overflow: BufferReceiver<T>) -> Self {
         Self {
             base,
-            overflow: Some(Box::new(overflow)),
+            overflow: Some(Box::new(overflow.into_stream())),
             instrumentation: None,
         }
     }
@@ -104,7 +109,7 @@ impl<T: Bufferable> BufferReceiver<T> {
     /// when initially constructing `BufferSender<T>`.
     #[cfg(test)]
     pub fn switch_to_overflow(&mut self, overflow: BufferReceiver<T>) {
-        self.overflow = Some(Box::new(overflow));
+        self.overflow = Some(Box::new(overflow.into_stream()));
     }
 
     /// Configures this receiver to instrument the items passing through it.
@@ -112,7 +117,6 @@ impl<T: Bufferable> BufferReceiver<T> {
         self.instrumentation = Some(handle);
     }
 
-    #[async_recursion]
     pub async fn next(&mut self) -> Option<T> {
         // We want to poll both our base and overflow receivers without waiting for one or the
         // other to entirely drain before checking the other.  This ensures that we're fairly
@@ -122,14 +126,12 @@ impl<T: Bufferable> BufferReceiver<T> {
         // occurred, and is over, and items are flowing through the base receiver.  If we waited to
         // entirely drain the overflow receiver, we might cause another small stall of the pipeline
         // attached to the base receiver.
-        let overflow = self.overflow.as_mut().map(Pin::new);
-
-        let (item, from_base) = match overflow {
+        let (item, from_base) = match self.overflow.as_mut() {
             None => match self.base.next().await {
                 Some(item) => (item, true),
                 None => return None,
             },
-            Some(mut overflow) => {
+            Some(overflow) => {
                 select! {
                     Some(item) = overflow.next() => (item, false),
                     Some(item) = self.base.next() => (item, true),
@@ -177,6 +179,13 @@ impl<T: Bufferable> BufferReceiverStream<T> {
     }
 }
 
+impl<T: Bufferable> fmt::Debug for BufferReceiverStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferReceiverStream")
+            .finish_non_exhaustive()
+    }
+}
+
 impl<T: Bufferable> Stream for BufferReceiverStream<T> {
     type Item = T;
 
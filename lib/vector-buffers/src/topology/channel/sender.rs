@@ -83,6 +83,41 @@ where
         }
     }
 
+    /// Sends an item, dropping the oldest items in the buffer to make room for it if necessary.
+    ///
+    /// If the item was sent, any items evicted from the buffer in order to do so are returned.
+    /// Otherwise, the buffer was not able to evict anything, and the item is handed back.
+    pub(crate) async fn send_dropping_oldest(
+        &mut self,
+        item: T,
+    ) -> crate::Result<Result<Vec<T>, T>> {
+        match self {
+            Self::InMemory(tx) => tx
+                .send_dropping_oldest(item)
+                .await
+                .map(Ok)
+                .map_err(Into::into),
+            Self::DiskV2(writer) => {
+                let mut writer = writer.lock().await;
+
+                writer
+                    .write_record_dropping_oldest(item)
+                    .await
+                    .map(|result| result.map(|_| Vec::new()))
+                    .map_err(|e| {
+                        // TODO: Could some errors be handled and not be unrecoverable? Right now,
+                        // encoding should theoretically be recoverable -- encoded value was too
+                        // big, or error during encoding -- but the traits don't allow for
+                        // recovering the original event value because we have to consume it to do
+                        // the encoding... but that might not always be the case.
+                        error!("Disk buffer writer has encountered an unrecoverable error.");
+
+                        e.into()
+                    })
+            }
+        }
+    }
+
     pub(crate) async fn flush(&mut self) -> crate::Result<()> {
         match self {
             Self::InMemory(_) => Ok(()),
@@ -112,16 +147,19 @@ where
 /// events when the internal channel is full.
 ///
 /// When creating a buffer sender/receiver pair, callers can specify the "when full" behavior of the
-/// sender.  This controls how events are handled when the internal channel is full.  Three modes
+/// sender.  This controls how events are handled when the internal channel is full.  Four modes
 /// are possible:
 /// - block
 /// - drop newest
+/// - drop oldest
 /// - overflow
 ///
 /// In "block" mode, callers are simply forced to wait until the channel has enough capacity to
 /// accept the event.  In "drop newest" mode, any event being sent when the channel is full will be
-/// dropped and proceed no further. In "overflow" mode, events will be sent to another buffer
-/// sender.  Callers can specify the overflow sender to use when constructing their buffers initially.
+/// dropped and proceed no further. In "drop oldest" mode, the oldest events in the channel are
+/// evicted to make room for the event being sent. In "overflow" mode, events will be sent to
+/// another buffer sender.  Callers can specify the overflow sender to use when constructing their
+/// buffers initially.
 ///
 /// TODO: We should eventually rework `BufferSender`/`BufferReceiver` so that they contain a vector
 /// of the fields we already have here, but instead of cascading via calling into `overflow`, we'd
@@ -204,6 +242,7 @@ impl<T: Bufferable> BufferSender<T> {
 
         let mut sent_to_base = true;
         let mut was_dropped = false;
+        let mut evicted = Vec::new();
         match self.when_full {
             WhenFull::Block => self.base.send(item).await?,
             WhenFull::DropNewest => {
@@ -211,6 +250,10 @@ impl<T: Bufferable> BufferSender<T> {
                     was_dropped = true;
                 }
             }
+            WhenFull::DropOldest => match self.base.send_dropping_oldest(item).await? {
+                Ok(evicted_items) => evicted = evicted_items,
+                Err(_) => was_dropped = true,
+            },
             WhenFull::Overflow => {
                 if let Some(item) = self.base.try_send(item).await? {
                     sent_to_base = false;
@@ -248,8 +291,23 @@ impl<T: Bufferable> BufferSender<T> {
                     );
                 }
             }
+
+            if !evicted.is_empty() {
+                let (evicted_count, evicted_size) =
+                    evicted.iter().fold((0, 0), |(count, size), item| {
+                        (count + item.event_count(), size + item.size_of())
+                    });
+                instrumentation.increment_evicted_event_count_and_byte_size(
+                    evicted_count as u64,
+                    evicted_size as u64,
+                );
+            }
         }
 
+        // Evicted items were never handed to the receiver, so dropping them finalizes them with
+        // `EventStatus::Dropped`.
+        drop(evicted);
+
         Ok(())
     }
 
@@ -1,4 +1,5 @@
 use std::{
+    num::NonZeroUsize,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -6,9 +7,11 @@ use std::{
 use tokio::{pin, sync::Barrier, time::sleep};
 
 use crate::{
+    buffer_usage_data::BufferUsageHandle,
     topology::{
+        builder::TopologyBuilder,
         channel::{BufferReceiver, BufferSender},
-        test_util::{assert_current_send_capacity, build_buffer},
+        test_util::{assert_current_send_capacity, build_buffer, Sample},
     },
     Bufferable, WhenFull,
 };
@@ -134,6 +137,28 @@ async fn test_sender_drop_newest() {
     assert_eq!(results, vec![1, 2, 3]);
 }
 
+#[tokio::test]
+async fn test_sender_drop_oldest() {
+    // Get a non-overflow buffer in "drop oldest" mode with a capacity of 3.
+    let (mut tx, rx, _) = build_buffer(3, WhenFull::DropOldest, None).await;
+
+    // We should be able to send three messages through unimpeded.
+    assert_current_send_capacity(&mut tx, Some(3), None);
+    assert_send_ok_with_capacities(&mut tx, 1, Some(2), None).await;
+    assert_send_ok_with_capacities(&mut tx, 2, Some(1), None).await;
+    assert_send_ok_with_capacities(&mut tx, 3, Some(0), None).await;
+
+    // Then, since we're in "drop oldest" mode, we could continue to send without issue or being
+    // blocked, but we would expect the oldest items to be evicted to make room.
+    assert_send_ok_with_capacities(&mut tx, 7, Some(0), None).await;
+    assert_send_ok_with_capacities(&mut tx, 8, Some(0), None).await;
+
+    // Then, when we collect all of the messages from the receiver, we should only get back the
+    // last three of them.
+    let results: Vec<u64> = drain_receiver(tx, rx).await;
+    assert_eq!(results, vec![3, 7, 8]);
+}
+
 #[tokio::test]
 async fn test_sender_overflow_block() {
     // Get an overflow buffer, where the overflow buffer is in blocking mode, and both the base
@@ -214,6 +239,53 @@ async fn test_buffer_metrics_normal() {
     assert_eq!(0, snapshot.dropped_event_count_intentional);
 }
 
+#[tokio::test]
+async fn test_buffer_metrics_overflow() {
+    // Get an overflow buffer where each stage has its own usage handle, with both the base and
+    // overflow buffers having a capacity of 2.
+    let base_handle = BufferUsageHandle::noop();
+    let overflow_handle = BufferUsageHandle::noop();
+    let (overflow_tx, overflow_rx) = TopologyBuilder::standalone_memory_test(
+        NonZeroUsize::new(2).unwrap(),
+        WhenFull::Block,
+        overflow_handle.clone(),
+    )
+    .await;
+    let (mut tx, mut rx) = TopologyBuilder::<Sample>::standalone_memory_test(
+        NonZeroUsize::new(2).unwrap(),
+        WhenFull::Overflow,
+        base_handle.clone(),
+    )
+    .await;
+    tx.switch_to_overflow(overflow_tx);
+    rx.switch_to_overflow(overflow_rx);
+
+    // Send three items through, and make sure that each stage only accounts for the items it
+    // actually buffered.
+    assert_send_ok_with_capacities(&mut tx, 7, Some(1), Some(2)).await;
+    assert_send_ok_with_capacities(&mut tx, 8, Some(0), Some(2)).await;
+    assert_send_ok_with_capacities(&mut tx, 2, Some(0), Some(1)).await;
+
+    let snapshot = base_handle.snapshot();
+    assert_eq!(2, snapshot.received_event_count);
+    assert_eq!(0, snapshot.sent_event_count);
+    let snapshot = overflow_handle.snapshot();
+    assert_eq!(1, snapshot.received_event_count);
+    assert_eq!(0, snapshot.sent_event_count);
+
+    // Then, when we collect all of the messages from the receiver, the metrics should also reflect that.
+    let mut results: Vec<u64> = drain_receiver(tx, rx).await;
+    results.sort_unstable();
+    assert_eq!(results, vec![2, 7, 8]);
+
+    let snapshot = base_handle.snapshot();
+    assert_eq!(2, snapshot.received_event_count);
+    assert_eq!(2, snapshot.sent_event_count);
+    let snapshot = overflow_handle.snapshot();
+    assert_eq!(1, snapshot.received_event_count);
+    assert_eq!(1, snapshot.sent_event_count);
+}
+
 #[tokio::test]
 async fn test_buffer_metrics_drop_newest() {
     // Get a buffer that drops the newest items when full.
@@ -240,3 +312,30 @@ async fn test_buffer_metrics_drop_newest() {
     assert_eq!(2, snapshot.sent_event_count);
     assert_eq!(1, snapshot.dropped_event_count_intentional);
 }
+
+#[tokio::test]
+async fn test_buffer_metrics_drop_oldest() {
+    // Get a buffer that drops the oldest items when full.
+    let (mut tx, rx, handle) = build_buffer(2, WhenFull::DropOldest, None).await;
+
+    // Send three items through, and make sure the buffer usage stats reflect that.
+    assert_current_send_capacity(&mut tx, Some(2), None);
+    assert_send_ok_with_capacities(&mut tx, 7, Some(1), None).await;
+    assert_send_ok_with_capacities(&mut tx, 8, Some(0), None).await;
+    assert_send_ok_with_capacities(&mut tx, 2, Some(0), None).await;
+
+    let snapshot = handle.snapshot();
+    assert_eq!(3, snapshot.received_event_count);
+    assert_eq!(0, snapshot.sent_event_count);
+    assert_eq!(0, snapshot.dropped_event_count_intentional);
+    assert_eq!(1, snapshot.evicted_event_count);
+
+    // Then, when we collect all of the messages from the receiver, the metrics should also reflect that.
+    let results: Vec<u64> = drain_receiver(tx, rx).await;
+    assert_eq!(results, vec![8, 2]);
+
+    let snapshot = handle.snapshot();
+    assert_eq!(3, snapshot.received_event_count);
+    assert_eq!(2, snapshot.sent_event_count);
+    assert_eq!(1, snapshot.evicted_event_count);
+}
@@ -1,5 +1,6 @@
 use std::{
     path::{Path, PathBuf},
+    sync::Arc,
     time::Duration,
 };
 
@@ -7,6 +8,8 @@ use crc32fast::Hasher;
 use snafu::Snafu;
 
 use super::{
+    compression::RecordCompression,
+    encryption::RecordCipher,
     io::{Filesystem, ProductionFilesystem},
     ledger::LEDGER_LEN,
     record::RECORD_HEADER_LEN,
@@ -153,6 +156,19 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Cipher used to encrypt record payloads.
+    ///
+    /// When present, record payloads are sealed before being written to a data file, and opened
+    /// after being read back, such that no record data is ever stored on disk in plaintext.
+    pub(crate) encryption: Option<Arc<RecordCipher>>,
+
+    /// Compression algorithm used for record payloads.
+    ///
+    /// Record payloads are compressed before being encrypted, if encryption is enabled, and the
+    /// algorithm is recorded alongside each record, such that changing it never affects the ability
+    /// to read records that were already written.
+    pub(crate) compression: RecordCompression,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -173,6 +189,8 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) encryption: Option<Arc<RecordCipher>>,
+    pub(crate) compression: Option<RecordCompression>,
     pub(crate) filesystem: FS,
 }
 
@@ -188,6 +206,8 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            encryption: None,
+            compression: None,
             filesystem: ProductionFilesystem,
         }
     }
@@ -268,6 +288,31 @@ where
         self
     }
 
+    /// Sets the cipher used to encrypt record payloads.
+    ///
+    /// When set, record payloads are sealed before being written to a data file, and opened after
+    /// being read back, such that no record data is ever stored on disk in plaintext.  Records that
+    /// were sealed with a key the cipher does not know about cannot be read.
+    ///
+    /// Defaults to no encryption.
+    #[allow(dead_code)]
+    pub fn encryption(mut self, cipher: RecordCipher) -> Self {
+        self.encryption = Some(Arc::new(cipher));
+        self
+    }
+
+    /// Sets the compression algorithm used for record payloads.
+    ///
+    /// Records are compressed individually, and only stored in their compressed form if that is
+    /// actually smaller.  As the maximum buffer size is enforced against the size of records as
+    /// written to disk, enabling compression allows more events to fit in the buffer.
+    ///
+    /// Defaults to no compression.
+    pub fn compression(mut self, compression: RecordCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -287,6 +332,8 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            encryption: self.encryption,
+            compression: self.compression,
             filesystem,
         }
     }
@@ -301,6 +348,8 @@ where
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
         let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let encryption = self.encryption;
+        let compression = self.compression.unwrap_or_default();
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -412,6 +461,8 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            encryption,
+            compression,
             filesystem,
         })
     }
@@ -0,0 +1,168 @@
+//! Compression of record payloads.
+//!
+//! When compression is enabled, the encoded form of each record is compressed before being sealed
+//! (if encryption is enabled) and wrapped in a [`Record`][super::record::Record].  Records are
+//! compressed individually, which keeps them independently readable, and since a record will
+//! typically hold a batch of events, still allows for a meaningful compression ratio.
+//!
+//! The algorithm used for a given record is stored in the two most significant bits of the record
+//! metadata, which are otherwise reserved, rather than being derived from the buffer configuration.
+//! This means records can always be read back regardless of the compression setting in effect at
+//! the time they were written, and that changing the setting never requires draining the buffer.
+//!
+//! If compressing a payload does not actually make it smaller, the payload is stored uncompressed.
+
+use std::io;
+
+/// Bits of the record metadata that are reserved for identifying the compression algorithm.
+pub(super) const COMPRESSION_METADATA_MASK: u32 = 0b11 << COMPRESSION_METADATA_SHIFT;
+
+const COMPRESSION_METADATA_SHIFT: u32 = 30;
+
+// Level 3 is the default level used by the `zstd` CLI, and is a good balance between compression
+// ratio and throughput.
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+/// Compression algorithm applied to record payloads.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RecordCompression {
+    /// Payloads are stored as-is.
+    #[default]
+    None,
+
+    /// Payloads are compressed with Zstandard.
+    Zstd,
+
+    /// Payloads are compressed with LZ4.
+    Lz4,
+}
+
+impl RecordCompression {
+    const fn as_metadata_bits(self) -> u32 {
+        let id = match self {
+            Self::None => 0,
+            Self::Zstd => 1,
+            Self::Lz4 => 2,
+        };
+
+        id << COMPRESSION_METADATA_SHIFT
+    }
+
+    /// Splits the given record metadata into the compression algorithm used for the record, and the
+    /// metadata of the record itself.
+    ///
+    /// If the reserved bits do not correspond to a known compression algorithm, `None` is returned.
+    pub(super) fn from_record_metadata(metadata: u32) -> Option<(Self, u32)> {
+        let compression = match (metadata & COMPRESSION_METADATA_MASK) >> COMPRESSION_METADATA_SHIFT
+        {
+            0 => Self::None,
+            1 => Self::Zstd,
+            2 => Self::Lz4,
+            _ => return None,
+        };
+
+        Some((compression, metadata & !COMPRESSION_METADATA_MASK))
+    }
+
+    /// Compresses the given payload, writing the compressed payload into `dst`.
+    ///
+    /// Any existing contents of `dst` are replaced.  The compression algorithm that was actually
+    /// used is returned, which is [`RecordCompression::None`] if compressing the payload did not
+    /// make it any smaller, in which case `dst` is left empty and the payload should be stored as-is.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurs during compression, an error variant will be returned describing the
+    /// error.
+    pub(super) fn compress(self, payload: &[u8], dst: &mut Vec<u8>) -> io::Result<Self> {
+        dst.clear();
+        match self {
+            Self::None => return Ok(Self::None),
+            Self::Zstd => *dst = zstd::bulk::compress(payload, ZSTD_COMPRESSION_LEVEL)?,
+            Self::Lz4 => *dst = lz4_flex::block::compress_prepend_size(payload),
+        }
+
+        if dst.len() >= payload.len() {
+            dst.clear();
+            return Ok(Self::None);
+        }
+
+        Ok(self)
+    }
+
+    /// Decompresses the given payload.
+    ///
+    /// # Errors
+    ///
+    /// If the payload is not valid for this compression algorithm, an error variant will be
+    /// returned describing the error.
+    pub(super) fn decompress(self, payload: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(payload.to_vec()),
+            Self::Zstd => zstd::stream::decode_all(payload),
+            Self::Lz4 => lz4_flex::block::decompress_size_prepended(payload)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Adds this compression algorithm to the given record metadata.
+    pub(super) const fn tag_record_metadata(self, metadata: u32) -> u32 {
+        metadata | self.as_metadata_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RecordCompression, COMPRESSION_METADATA_MASK};
+
+    #[test]
+    fn roundtrip() {
+        let payload = b"a fairly compressible payload ".repeat(64);
+
+        for compression in [RecordCompression::Zstd, RecordCompression::Lz4] {
+            let mut compressed = Vec::new();
+            let used = compression
+                .compress(&payload, &mut compressed)
+                .expect("compression should not fail");
+            assert_eq!(used, compression);
+            assert!(compressed.len() < payload.len());
+
+            let metadata = used.tag_record_metadata(0b101);
+            assert_eq!(
+                RecordCompression::from_record_metadata(metadata),
+                Some((compression, 0b101))
+            );
+
+            let decompressed = compression
+                .decompress(&compressed)
+                .expect("decompression should not fail");
+            assert_eq!(decompressed, payload);
+        }
+    }
+
+    #[test]
+    fn incompressible_payloads_are_stored_as_is() {
+        let payload = [0x2a, 0x13, 0x37];
+
+        for compression in [RecordCompression::Zstd, RecordCompression::Lz4] {
+            let mut compressed = Vec::new();
+            let used = compression
+                .compress(&payload, &mut compressed)
+                .expect("compression should not fail");
+            assert_eq!(used, RecordCompression::None);
+            assert!(compressed.is_empty());
+        }
+    }
+
+    #[test]
+    fn unknown_compression_bits_are_rejected() {
+        assert_eq!(
+            RecordCompression::from_record_metadata(COMPRESSION_METADATA_MASK | 0b1),
+            None
+        );
+        assert_eq!(
+            RecordCompression::from_record_metadata(0b1),
+            Some((RecordCompression::None, 0b1))
+        );
+    }
+}
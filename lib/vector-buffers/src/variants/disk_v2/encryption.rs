@@ -0,0 +1,456 @@
+//! Authenticated encryption of record payloads.
+//!
+//! When encryption is enabled, the encoded form of each record is sealed with XChaCha20-Poly1305
+//! before being wrapped in a [`Record`][super::record::Record].  The record framing, and its
+//! checksum, are left untouched, so encryption is entirely transparent to the reader/writer logic
+//! that deals with data files, acknowledgements, and so on.
+//!
+//! Sealed payloads are represented by the following pseudo-structure:
+//!
+//! ```text
+//! sealed_payload:
+//!   version:     uint8
+//!   key_id_len:  uint8
+//!   key_id:      uint8[key_id_len]
+//!   event_count: uint64
+//!   nonce:       uint8[24]
+//!   ciphertext:  uint8[..]
+//!   tag:         uint8[16]
+//! ```
+//!
+//! Whether a given record was sealed is stored in a bit of the record metadata which is otherwise
+//! reserved, rather than being derived from the buffer configuration, in the same way as the
+//! compression algorithm.  Records written before encryption was enabled can thus still be read, and
+//! the event count stored in the clear lets the writer and reader find where they left off without
+//! opening any record, so a buffer can always be opened, even when the keys its records were sealed
+//! with are no longer provided.  Only reading such records fails.
+//!
+//! The record ID, record metadata and event count are used as the associated data when sealing a
+//! payload, so a sealed payload cannot be moved to another record without failing authentication.
+//! As the key ID is stored alongside every payload, payloads sealed with a previous key can still be
+//! opened after the active key has been rotated, so long as that previous key is still provided.
+
+use std::{borrow::Cow, collections::HashMap, fmt};
+
+use chacha20poly1305::{
+    aead::{AeadCore, AeadInPlace, KeyInit, OsRng},
+    Tag, XChaCha20Poly1305, XNonce,
+};
+use snafu::Snafu;
+
+use super::record::ArchivedRecord;
+
+/// Length, in bytes, of an encryption key.
+pub const KEY_LEN: usize = 32;
+
+/// Maximum length, in bytes, of a key ID.
+pub const MAX_KEY_ID_LEN: usize = u8::MAX as usize;
+
+/// Bit of the record metadata that is reserved for marking records whose payload was sealed.
+pub(super) const SEALED_METADATA_BIT: u32 = 1 << 29;
+
+const SEALED_PAYLOAD_VERSION: u8 = 1;
+const EVENT_COUNT_LEN: usize = 8;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+
+/// Error that occurred when configuring or using record encryption.
+#[derive(Debug, Snafu)]
+pub enum EncryptionError {
+    /// The key ID was empty, or too long to be stored alongside a record.
+    #[snafu(display("key ID must be between 1 and {} bytes long", MAX_KEY_ID_LEN))]
+    InvalidKeyId,
+
+    /// The key was not the expected length.
+    #[snafu(display(
+        "key '{}' must be {} bytes long, but was {} bytes long",
+        key_id,
+        KEY_LEN,
+        actual
+    ))]
+    InvalidKeyLength { key_id: String, actual: usize },
+
+    /// The same key ID was provided more than once.
+    #[snafu(display("key ID '{}' was provided more than once", key_id))]
+    DuplicateKeyId { key_id: String },
+
+    /// The payload could not be sealed.
+    #[snafu(display("payload could not be sealed"))]
+    Seal,
+
+    /// The payload was truncated, or does not look like a sealed payload at all.
+    #[snafu(display("malformed sealed payload: {}", reason))]
+    Malformed { reason: &'static str },
+
+    /// The payload was sealed, but encryption is not enabled.
+    #[snafu(display("payload was sealed, but encryption is not enabled"))]
+    NotEnabled,
+
+    /// The payload was sealed with a key that was not provided.
+    #[snafu(display("payload was sealed with unknown key '{}'", key_id))]
+    UnknownKey { key_id: String },
+
+    /// The payload did not pass authentication.
+    ///
+    /// This indicates that either the payload was modified after being sealed, or that the key
+    /// with the same ID is not the key that the payload was originally sealed with.
+    #[snafu(display("payload failed authentication"))]
+    Authentication,
+}
+
+/// Seals and opens record payloads.
+///
+/// Payloads are always sealed with the active key, while payloads sealed with any of the provided
+/// keys, active or previous, can be opened.
+pub struct RecordCipher {
+    active_key_id: Vec<u8>,
+    keys: HashMap<Vec<u8>, XChaCha20Poly1305>,
+}
+
+impl RecordCipher {
+    /// Creates a new [`RecordCipher`] with the given active key.
+    ///
+    /// # Errors
+    ///
+    /// If the key ID is empty or longer than [`MAX_KEY_ID_LEN`], or the key is not exactly
+    /// [`KEY_LEN`] bytes long, an error variant will be returned describing the error.
+    pub fn new(active_key_id: &str, active_key: &[u8]) -> Result<Self, EncryptionError> {
+        let mut cipher = Self {
+            active_key_id: active_key_id.as_bytes().to_vec(),
+            keys: HashMap::new(),
+        };
+        cipher.add_key(active_key_id, active_key)?;
+
+        Ok(cipher)
+    }
+
+    /// Adds a previous key.
+    ///
+    /// Previous keys are never used to seal payloads, only to open payloads that were sealed before
+    /// the active key was rotated.
+    ///
+    /// # Errors
+    ///
+    /// If the key ID is invalid or has already been provided, or the key is not exactly [`KEY_LEN`]
+    /// bytes long, an error variant will be returned describing the error.
+    pub fn with_previous_key(mut self, key_id: &str, key: &[u8]) -> Result<Self, EncryptionError> {
+        self.add_key(key_id, key)?;
+        Ok(self)
+    }
+
+    fn add_key(&mut self, key_id: &str, key: &[u8]) -> Result<(), EncryptionError> {
+        if key_id.is_empty() || key_id.len() > MAX_KEY_ID_LEN {
+            return Err(EncryptionError::InvalidKeyId);
+        }
+
+        if self.keys.contains_key(key_id.as_bytes()) {
+            return Err(EncryptionError::DuplicateKeyId {
+                key_id: key_id.to_string(),
+            });
+        }
+
+        let aead = XChaCha20Poly1305::new_from_slice(key).map_err(|_| {
+            EncryptionError::InvalidKeyLength {
+                key_id: key_id.to_string(),
+                actual: key.len(),
+            }
+        })?;
+        self.keys.insert(key_id.as_bytes().to_vec(), aead);
+
+        Ok(())
+    }
+
+    /// Gets the number of bytes that sealing adds to a payload.
+    pub fn overhead(&self) -> usize {
+        2 + self.active_key_id.len() + EVENT_COUNT_LEN + NONCE_LEN + TAG_LEN
+    }
+
+    /// Seals the given payload with the active key, writing the sealed payload into `dst`.
+    ///
+    /// The given metadata must already be marked with [`SEALED_METADATA_BIT`], and any existing
+    /// contents of `dst` are cleared first.
+    ///
+    /// # Errors
+    ///
+    /// If the payload could not be sealed, an error variant will be returned describing the error.
+    pub(super) fn seal(
+        &self,
+        record_id: u64,
+        metadata: u32,
+        event_count: u64,
+        payload: &[u8],
+        dst: &mut Vec<u8>,
+    ) -> Result<(), EncryptionError> {
+        let aead = self
+            .keys
+            .get(&self.active_key_id)
+            .expect("active key must always be present");
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let key_id_len =
+            u8::try_from(self.active_key_id.len()).expect("key ID length is validated on creation");
+
+        dst.clear();
+        dst.reserve(self.overhead() + payload.len());
+        dst.push(SEALED_PAYLOAD_VERSION);
+        dst.push(key_id_len);
+        dst.extend_from_slice(&self.active_key_id);
+        dst.extend_from_slice(&event_count.to_be_bytes());
+        dst.extend_from_slice(&nonce);
+
+        let ciphertext_start = dst.len();
+        dst.extend_from_slice(payload);
+
+        let tag = aead
+            .encrypt_in_place_detached(
+                &nonce,
+                &associated_data(record_id, metadata, event_count),
+                &mut dst[ciphertext_start..],
+            )
+            .map_err(|_| EncryptionError::Seal)?;
+        dst.extend_from_slice(&tag);
+
+        Ok(())
+    }
+
+    /// Opens the given sealed payload.
+    ///
+    /// # Errors
+    ///
+    /// If the sealed payload is malformed, was sealed with a key that was not provided, or fails
+    /// authentication, an error variant will be returned describing the error.
+    pub(super) fn open(
+        &self,
+        record_id: u64,
+        metadata: u32,
+        sealed: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (key_id, event_count, rest) = split_header(sealed)?;
+        if rest.len() < NONCE_LEN + TAG_LEN {
+            return Err(EncryptionError::Malformed {
+                reason: "payload is truncated",
+            });
+        }
+
+        let (nonce, rest) = rest.split_at(NONCE_LEN);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+
+        let aead = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| EncryptionError::UnknownKey {
+                key_id: String::from_utf8_lossy(key_id).into_owned(),
+            })?;
+
+        let mut payload = ciphertext.to_vec();
+        aead.decrypt_in_place_detached(
+            XNonce::from_slice(nonce),
+            &associated_data(record_id, metadata, event_count),
+            &mut payload,
+            Tag::from_slice(tag),
+        )
+        .map_err(|_| EncryptionError::Authentication)?;
+
+        Ok(payload)
+    }
+}
+
+impl fmt::Debug for RecordCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // We purposefully only show the key IDs, and never the keys themselves.
+        let mut key_ids = self
+            .keys
+            .keys()
+            .map(|key_id| String::from_utf8_lossy(key_id))
+            .collect::<Vec<_>>();
+        key_ids.sort();
+
+        f.debug_struct("RecordCipher")
+            .field(
+                "active_key_id",
+                &String::from_utf8_lossy(&self.active_key_id),
+            )
+            .field("key_ids", &key_ids)
+            .finish()
+    }
+}
+
+fn associated_data(record_id: u64, metadata: u32, event_count: u64) -> [u8; 20] {
+    let mut aad = [0; 20];
+    aad[..8].copy_from_slice(&record_id.to_be_bytes());
+    aad[8..12].copy_from_slice(&metadata.to_be_bytes());
+    aad[12..].copy_from_slice(&event_count.to_be_bytes());
+    aad
+}
+
+/// Splits the given sealed payload into the ID of the key it was sealed with, its event count, and
+/// the rest of the payload.
+fn split_header(sealed: &[u8]) -> Result<(&[u8], u64, &[u8]), EncryptionError> {
+    let Some((&version, rest)) = sealed.split_first() else {
+        return Err(EncryptionError::Malformed {
+            reason: "payload is empty",
+        });
+    };
+    if version != SEALED_PAYLOAD_VERSION {
+        return Err(EncryptionError::Malformed {
+            reason: "unsupported version",
+        });
+    }
+
+    let Some((&key_id_len, rest)) = rest.split_first() else {
+        return Err(EncryptionError::Malformed {
+            reason: "missing key ID",
+        });
+    };
+    let key_id_len = usize::from(key_id_len);
+    if rest.len() < key_id_len + EVENT_COUNT_LEN {
+        return Err(EncryptionError::Malformed {
+            reason: "payload is truncated",
+        });
+    }
+
+    let (key_id, rest) = rest.split_at(key_id_len);
+    let (event_count, rest) = rest.split_at(EVENT_COUNT_LEN);
+    let event_count = u64::from_be_bytes(
+        event_count
+            .try_into()
+            .expect("event count should be exactly eight bytes"),
+    );
+
+    Ok((key_id, event_count, rest))
+}
+
+/// Whether or not the record with the given metadata was sealed.
+pub(super) const fn is_sealed(metadata: u32) -> bool {
+    metadata & SEALED_METADATA_BIT != 0
+}
+
+/// Gets the number of events held by the given sealed payload, without opening it.
+///
+/// # Errors
+///
+/// If the sealed payload is malformed, an error variant will be returned describing the error.
+pub(super) fn sealed_event_count(sealed: &[u8]) -> Result<u64, EncryptionError> {
+    split_header(sealed).map(|(_, event_count, _)| event_count)
+}
+
+/// Gets the plaintext payload of the given record, opening it first if it was sealed.
+pub(super) fn open_record_payload<'a>(
+    record: &'a ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
+) -> Result<Cow<'a, [u8]>, EncryptionError> {
+    if !is_sealed(record.metadata()) {
+        return Ok(Cow::Borrowed(record.payload()));
+    }
+
+    cipher
+        .ok_or(EncryptionError::NotEnabled)?
+        .open(record.id(), record.metadata(), record.payload())
+        .map(Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sealed_event_count, EncryptionError, RecordCipher, KEY_LEN};
+
+    const KEY_A: [u8; KEY_LEN] = [0x1a; KEY_LEN];
+    const KEY_B: [u8; KEY_LEN] = [0x2b; KEY_LEN];
+
+    fn seal(cipher: &RecordCipher, record_id: u64, metadata: u32, payload: &[u8]) -> Vec<u8> {
+        let mut sealed = Vec::new();
+        cipher
+            .seal(record_id, metadata, 3, payload, &mut sealed)
+            .expect("sealing should not fail");
+        sealed
+    }
+
+    #[test]
+    fn roundtrip() {
+        let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+        let payload = b"hello world";
+
+        let sealed = seal(&cipher, 42, 7, payload);
+        assert_eq!(sealed.len(), payload.len() + cipher.overhead());
+        assert!(!sealed.windows(payload.len()).any(|w| w == payload));
+        assert_eq!(sealed_event_count(&sealed).unwrap(), 3);
+
+        let opened = cipher
+            .open(42, 7, &sealed)
+            .expect("opening should not fail");
+        assert_eq!(opened, payload);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert!(matches!(
+            RecordCipher::new("", &KEY_A),
+            Err(EncryptionError::InvalidKeyId)
+        ));
+        assert!(matches!(
+            RecordCipher::new("a", &KEY_A[..16]),
+            Err(EncryptionError::InvalidKeyLength { actual: 16, .. })
+        ));
+        assert!(matches!(
+            RecordCipher::new("a", &KEY_A)
+                .unwrap()
+                .with_previous_key("a", &KEY_B),
+            Err(EncryptionError::DuplicateKeyId { .. })
+        ));
+    }
+
+    #[test]
+    fn opens_payloads_sealed_with_previous_key() {
+        let old = RecordCipher::new("a", &KEY_A).unwrap();
+        let sealed = seal(&old, 1, 0, b"before rotation");
+
+        let rotated = RecordCipher::new("b", &KEY_B)
+            .unwrap()
+            .with_previous_key("a", &KEY_A)
+            .unwrap();
+        assert_eq!(rotated.open(1, 0, &sealed).unwrap(), b"before rotation");
+
+        let without_previous = RecordCipher::new("b", &KEY_B).unwrap();
+        assert!(matches!(
+            without_previous.open(1, 0, &sealed),
+            Err(EncryptionError::UnknownKey { key_id }) if key_id == "a"
+        ));
+    }
+
+    #[test]
+    fn rejects_tampered_payloads() {
+        let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+        let sealed = seal(&cipher, 1, 0, b"payload");
+
+        // Flipping a bit in the ciphertext fails authentication.
+        let mut tampered = sealed.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0x01;
+        assert!(matches!(
+            cipher.open(1, 0, &tampered),
+            Err(EncryptionError::Authentication)
+        ));
+
+        // So does changing the event count, which is stored in the clear.
+        let mut tampered = sealed.clone();
+        tampered[3] ^= 0x01;
+        assert!(matches!(
+            cipher.open(1, 0, &tampered),
+            Err(EncryptionError::Authentication)
+        ));
+
+        // Moving the payload to a different record also fails authentication.
+        assert!(matches!(
+            cipher.open(2, 0, &sealed),
+            Err(EncryptionError::Authentication)
+        ));
+        assert!(matches!(
+            cipher.open(1, 1, &sealed),
+            Err(EncryptionError::Authentication)
+        ));
+
+        // Truncated payloads are rejected outright.
+        assert!(matches!(
+            cipher.open(1, 0, &sealed[..10]),
+            Err(EncryptionError::Malformed { .. })
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{io, path::Path};
+
+use super::{
+    backed_archive::BackedArchive,
+    common::{DiskBufferConfig, MAX_FILE_ID},
+    ledger::{LedgerLoadCreateError, LedgerState},
+    reader::{ReaderError, RecordReader},
+    Filesystem, ProductionFilesystem,
+};
+use crate::Bufferable;
+
+/// A record read from a disk buffer by a [`BufferInspector`].
+#[derive(Debug)]
+pub struct InspectedRecord<T> {
+    /// ID of the record.
+    ///
+    /// Record IDs are assigned per event, so this is the ID of the first event in the record.
+    pub id: u64,
+
+    /// Size of the record on disk, in bytes.
+    pub size: usize,
+
+    /// The decoded record.
+    pub item: T,
+}
+
+/// Read-only view over the records in a disk buffer.
+///
+/// The inspector takes a snapshot of the ledger when opened, and then reads every data file from
+/// the one the reader is currently on through to the one the writer is currently on, yielding all
+/// records that have not yet been acknowledged.  Neither the ledger nor any of the data files are
+/// modified, and the buffer lock is not taken, so a buffer can be inspected while it is in use by
+/// a running Vector process.  In that case, records written or acknowledged after the inspector was
+/// opened may or may not be seen, and a record in the middle of being written may show up as a
+/// partial write.
+pub struct BufferInspector<T, FS = ProductionFilesystem>
+where
+    FS: Filesystem,
+{
+    config: DiskBufferConfig<FS>,
+    last_reader_record_id: u64,
+    next_writer_record_id: u64,
+    writer_file_id: u16,
+    next_data_file_id: Option<u16>,
+    reader: Option<RecordReader<FS::File, T>>,
+}
+
+impl<T, FS> BufferInspector<T, FS>
+where
+    T: Bufferable,
+    FS: Filesystem,
+    FS::File: Unpin,
+{
+    /// Opens the disk buffer described by the given configuration for inspection.
+    ///
+    /// # Errors
+    ///
+    /// If the ledger does not exist, can't be read, or is not a valid ledger, an error variant will
+    /// be returned describing the error.
+    pub(crate) async fn open(config: DiskBufferConfig<FS>) -> Result<Self, LedgerLoadCreateError> {
+        let ledger_path = config.data_dir.join("buffer.db");
+        let ledger_mmap = config
+            .filesystem
+            .open_mmap_readable(&ledger_path)
+            .await
+            .map_err(|source| LedgerLoadCreateError::Io { source })?;
+        let ledger = BackedArchive::<_, LedgerState>::from_backing(ledger_mmap).map_err(|e| {
+            LedgerLoadCreateError::FailedToDeserialize {
+                reason: e.into_inner(),
+            }
+        })?;
+
+        // We only need the ledger state as it was when we opened the buffer, so grab what we need
+        // and let go of the ledger.
+        let state = ledger.get_archive_ref();
+        let last_reader_record_id = state.get_last_reader_record_id();
+        let next_writer_record_id = state.get_next_writer_record_id();
+        let reader_file_id = state.get_current_reader_file_id();
+        let writer_file_id = state.get_current_writer_file_id();
+
+        Ok(Self {
+            config,
+            last_reader_record_id,
+            next_writer_record_id,
+            writer_file_id,
+            next_data_file_id: Some(reader_file_id),
+            reader: None,
+        })
+    }
+
+    /// Gets the data directory of the buffer being inspected.
+    pub fn data_dir(&self) -> &Path {
+        &self.config.data_dir
+    }
+
+    /// Gets the number of events in the buffer that have not yet been acknowledged.
+    ///
+    /// This is based solely on the ledger, and so includes events in records that can no longer be
+    /// read, such as records in data files that were corrupted.
+    pub fn pending_events(&self) -> u64 {
+        self.next_writer_record_id
+            .wrapping_sub(self.last_reader_record_id)
+            .saturating_sub(1)
+    }
+
+    /// Reads the next unacknowledged record from the buffer.
+    ///
+    /// If there are no more records to read, `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// If a record can't be read or decoded, or a data file can't be opened, an error variant will
+    /// be returned describing the error.  None of these errors are fatal: calling `next` again will
+    /// continue with the next record that can be read, skipping the rest of the current data file
+    /// if it is corrupted.
+    pub async fn next(&mut self) -> Result<Option<InspectedRecord<T>>, ReaderError<T>> {
+        loop {
+            let Some(reader) = self.reader.as_mut() else {
+                let Some(data_file_id) = self.next_data_file_id.take() else {
+                    return Ok(None);
+                };
+                if data_file_id != self.writer_file_id {
+                    self.next_data_file_id = Some((data_file_id + 1) % MAX_FILE_ID);
+                }
+
+                let data_file_path = self
+                    .config
+                    .data_dir
+                    .join(format!("buffer-data-{data_file_id}.dat"));
+                match self
+                    .config
+                    .filesystem
+                    .open_file_readable(&data_file_path)
+                    .await
+                {
+                    Ok(data_file) => {
+                        self.reader =
+                            Some(RecordReader::new(data_file, self.config.encryption.clone()));
+                    }
+                    // Data files evicted by the writer no longer exist, and neither do data files
+                    // deleted by a running Vector process since we took our snapshot of the ledger.
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                    Err(source) => return Err(ReaderError::Io { source }),
+                }
+                continue;
+            };
+
+            // We always treat data files as finalized, as we have no writer to wait on: whatever
+            // is in the data file right now is all we're going to read.
+            let token = match reader.try_next_record(true).await {
+                Ok(Some(token)) => token,
+                Ok(None) => {
+                    self.reader = None;
+                    continue;
+                }
+                Err(e) => {
+                    self.reader = None;
+                    return Err(e);
+                }
+            };
+
+            let id = token.record_id();
+            let size = token.record_bytes();
+            let result = reader.read_record(token);
+
+            // Records are acknowledged as a whole, so any record starting at or before the last
+            // acknowledged record ID has already been processed, even if its data file is still
+            // around.
+            if id <= self.last_reader_record_id {
+                continue;
+            }
+
+            return result.map(|item| Some(InspectedRecord { id, size, item }));
+        }
+    }
+}
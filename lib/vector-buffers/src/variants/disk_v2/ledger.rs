@@ -1,4 +1,5 @@
 use std::{
+    collections::VecDeque,
     fmt, io, mem,
     path::PathBuf,
     sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering},
@@ -13,7 +14,11 @@ use fslock::LockFile;
 use futures::StreamExt;
 use rkyv::{with::Atomic, Archive, Serialize};
 use snafu::{ResultExt, Snafu};
-use tokio::{fs, io::AsyncWriteExt, sync::Notify};
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{Mutex, MutexGuard, Notify},
+};
 use vector_common::finalizer::OrderedFinalizer;
 
 use super::{
@@ -119,7 +124,7 @@ impl Default for LedgerState {
 }
 
 impl ArchivedLedgerState {
-    fn get_current_writer_file_id(&self) -> u16 {
+    pub(super) fn get_current_writer_file_id(&self) -> u16 {
         self.writer_current_data_file.load(Ordering::Acquire)
     }
 
@@ -141,7 +146,7 @@ impl ArchivedLedgerState {
         previous.wrapping_add(amount)
     }
 
-    fn get_current_reader_file_id(&self) -> u16 {
+    pub(super) fn get_current_reader_file_id(&self) -> u16 {
         self.reader_current_data_file.load(Ordering::Acquire)
     }
 
@@ -225,6 +230,11 @@ where
     pending_acks: AtomicU64,
     // The file ID offset of the reader past the acknowledged reader file ID.
     unacked_reader_file_id_offset: AtomicU16,
+    // File IDs of the data files evicted by the writer that the reader has yet to skip over.
+    //
+    // The lock is also held by the reader while opening a data file, so that a data file can't be
+    // evicted while the reader is opening it.
+    evicted_data_files: Mutex<VecDeque<u16>>,
     // Last flush of all unflushed files: ledger, data file, etc.
     last_flush: AtomicCell<Instant>,
     // Tracks usage data about the buffer.
@@ -434,6 +444,25 @@ where
         );
     }
 
+    /// Returns `true` if the reader has moved past any data files that have not yet been durably
+    /// processed.
+    pub fn has_unacked_data_files(&self) -> bool {
+        self.unacked_reader_file_id_offset.load(Ordering::Acquire) > 0
+    }
+
+    /// Returns `true` if the current reader file ID is behind the current writer file ID.
+    ///
+    /// As file IDs wrap around, this is determined relative to the acknowledged reader file ID.
+    pub fn is_reader_behind_writer(&self) -> bool {
+        let acked_reader_file_id = u32::from(self.state().get_current_reader_file_id());
+        let writer_file_id = u32::from(self.get_current_writer_file_id());
+        let max_file_id = u32::from(MAX_FILE_ID);
+
+        let reader_offset = u32::from(self.unacked_reader_file_id_offset.load(Ordering::Acquire));
+        let writer_offset = (writer_file_id + max_file_id - acked_reader_file_id) % max_file_id;
+        reader_offset < writer_offset
+    }
+
     /// Increments the acknowledged reader file ID.
     ///
     /// As records may be read and stored for a small period of time (batching in a sink, etc), we
@@ -538,6 +567,69 @@ where
         self.usage_handle
             .increment_dropped_event_count_and_byte_size(count, 0, false);
     }
+
+    pub fn track_evicted_events(&self, count: u64) {
+        // The size of evicted events is accounted for when their data file is evicted, as the
+        // writer doesn't know how many events a data file holds, and the reader only finds out
+        // once it reads the records that follow it.
+        self.usage_handle
+            .increment_evicted_event_count_and_byte_size(count, 0);
+    }
+
+    /// Locks the list of data files evicted by the writer that the reader has yet to skip over.
+    ///
+    /// While the lock is held, no further data files can be evicted.
+    pub async fn lock_evicted_data_files(&self) -> MutexGuard<'_, VecDeque<u16>> {
+        self.evicted_data_files.lock().await
+    }
+
+    /// Evicts the oldest data file that the reader has not yet started reading.
+    ///
+    /// Data files that either the reader or the writer currently have open can't be evicted, so
+    /// this only succeeds when the reader is at least two data files behind the writer. The data
+    /// file is deleted, and the buffer size adjusted accordingly, while the reader is left to skip
+    /// over it once it gets there.
+    ///
+    /// Returns `true` if a data file was evicted.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O error occurs while deleting the data file, an error variant will be returned
+    /// describing the error.
+    pub async fn evict_oldest_unread_data_file(&self) -> io::Result<bool> {
+        let mut evicted_data_files = self.evicted_data_files.lock().await;
+
+        // Any data files evicted previously are still ahead of the reader, so the oldest unread
+        // data file is the one right after the last of them.
+        let (reader_file_id, writer_file_id) = self.get_current_reader_writer_file_id();
+        let last_skipped_file_id = evicted_data_files.back().copied().unwrap_or(reader_file_id);
+        let file_id = (last_skipped_file_id + 1) % MAX_FILE_ID;
+        if reader_file_id == writer_file_id || file_id == writer_file_id {
+            return Ok(false);
+        }
+
+        let data_file_path = self.get_data_file_path(file_id);
+        let data_file = self
+            .filesystem()
+            .open_file_readable(&data_file_path)
+            .await?;
+        let data_file_size = data_file.metadata().await?.len();
+        drop(data_file);
+
+        self.filesystem().delete_file(&data_file_path).await?;
+        evicted_data_files.push_back(file_id);
+
+        self.decrement_total_buffer_size(data_file_size);
+        self.usage_handle
+            .increment_evicted_event_count_and_byte_size(0, data_file_size);
+
+        debug!(
+            data_file_path = data_file_path.to_string_lossy().as_ref(),
+            data_file_size, "Evicted oldest unread data file."
+        );
+
+        Ok(true)
+    }
 }
 
 impl<FS> Ledger<FS>
@@ -645,6 +737,7 @@ where
             writer_done: AtomicBool::new(false),
             pending_acks: AtomicU64::new(0),
             unacked_reader_file_id_offset: AtomicU16::new(0),
+            evicted_data_files: Mutex::new(VecDeque::new()),
             last_flush: AtomicCell::new(Instant::now()),
             usage_handle,
         };
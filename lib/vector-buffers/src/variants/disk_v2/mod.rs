@@ -52,6 +52,33 @@
 //! potentially changed based on the payload that a record contains. The only safe way to access the
 //! records in a disk buffer should be through the reader/writer interface in this module.
 //!
+//! #### Encryption
+//!
+//! Records can optionally be encrypted at rest. When enabled, the payload of a record is sealed
+//! using an authenticated cipher before the record is serialized, and opened again after the
+//! record is read and validated, but before it is decoded. The record structure itself is
+//! unchanged, and the checksum covers the sealed payload, so corruption is still detected in the
+//! same way regardless of whether or not encryption is enabled.
+//!
+//! Each sealed payload carries the ID of the key it was sealed with, which allows rotating keys
+//! without having to first drain the buffer: as long as the previous key is still provided, records
+//! sealed with it can be read, while all new records are sealed with the active key.
+//!
+//! Whether a record was sealed is stored in the third most significant bit of the record metadata,
+//! which is reserved for this purpose, so records written before encryption was enabled can still
+//! be read. Sealed payloads also carry their event count in the clear, which lets the buffer be
+//! opened without opening any record, even when the keys some records were sealed with are no
+//! longer provided: only reading those records then fails.
+//!
+//! #### Compression
+//!
+//! Records can optionally be compressed. When enabled, the payload of a record is compressed after
+//! being encoded, and before being sealed if encryption is also enabled, as encrypted data does not
+//! compress. The compression algorithm used is stored in the two most significant bits of the
+//! record metadata, which are reserved for this purpose, so records can always be decompressed
+//! regardless of the current configuration. Since the size of a record is measured after
+//! compression, compressed records take up correspondingly less of the maximum buffer size.
+//!
 //! ### Data files
 //!
 //! Data files contain the buffered records and nothing else. Records are written
@@ -184,6 +211,9 @@ use vector_common::finalization::Finalizable;
 
 mod backed_archive;
 mod common;
+mod compression;
+mod encryption;
+mod inspector;
 mod io;
 mod ledger;
 mod reader;
@@ -197,6 +227,9 @@ mod tests;
 use self::ledger::Ledger;
 pub use self::{
     common::{DiskBufferConfig, DiskBufferConfigBuilder},
+    compression::RecordCompression,
+    encryption::RecordCipher,
+    inspector::{BufferInspector, InspectedRecord},
     io::{Filesystem, ProductionFilesystem},
     ledger::LedgerLoadCreateError,
     reader::{BufferReader, ReaderError},
@@ -301,14 +334,24 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    encryption: Option<RecordCipher>,
+    compression: RecordCompression,
 }
 
 impl DiskV2Buffer {
-    pub fn new(id: String, data_dir: PathBuf, max_size: NonZeroU64) -> Self {
+    pub fn new(
+        id: String,
+        data_dir: PathBuf,
+        max_size: NonZeroU64,
+        encryption: Option<RecordCipher>,
+        compression: RecordCompression,
+    ) -> Self {
         Self {
             id,
             data_dir,
             max_size,
+            encryption,
+            compression,
         }
     }
 }
@@ -331,6 +374,8 @@ where
             &self.data_dir,
             self.id.as_str(),
             self.max_size,
+            self.encryption,
+            self.compression,
         )
         .await?;
 
@@ -343,6 +388,8 @@ async fn build_disk_v2_buffer<T>(
     data_dir: &Path,
     id: &str,
     max_size: NonZeroU64,
+    encryption: Option<RecordCipher>,
+    compression: RecordCompression,
 ) -> Result<
     (
         BufferWriter<T, ProductionFilesystem>,
@@ -356,14 +403,35 @@ where
     usage_handle.set_buffer_limits(Some(max_size.get()), None);
 
     let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
-    let config = DiskBufferConfigBuilder::from_path(buffer_path)
+    let mut builder = DiskBufferConfigBuilder::from_path(buffer_path)
         .max_buffer_size(max_size.get())
-        .build()?;
+        .compression(compression);
+    if let Some(cipher) = encryption {
+        builder = builder.encryption(cipher);
+    }
+    let config = builder.build()?;
     Buffer::from_config(config, usage_handle)
         .await
         .map_err(Into::into)
 }
 
+pub(crate) async fn open_disk_v2_buffer_for_inspection<T>(
+    data_dir: &Path,
+    id: &str,
+    encryption: Option<RecordCipher>,
+) -> Result<BufferInspector<T, ProductionFilesystem>, Box<dyn Error + Send + Sync>>
+where
+    T: Bufferable,
+{
+    let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
+    let mut builder = DiskBufferConfigBuilder::from_path(buffer_path);
+    if let Some(cipher) = encryption {
+        builder = builder.encryption(cipher);
+    }
+    let config = builder.build()?;
+    BufferInspector::open(config).await.map_err(Into::into)
+}
+
 pub(crate) fn get_disk_v2_data_dir_path(base_dir: &Path, buffer_id: &str) -> PathBuf {
     base_dir.join("buffer").join("v2").join(buffer_id)
 }
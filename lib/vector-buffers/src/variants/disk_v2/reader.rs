@@ -15,6 +15,11 @@ use vector_common::{finalization::BatchNotifier, finalizer::OrderedFinalizer};
 
 use super::{
     common::create_crc32c_hasher,
+    compression::RecordCompression,
+    encryption::{
+        is_sealed, open_record_payload, sealed_event_count, EncryptionError, RecordCipher,
+        SEALED_METADATA_BIT,
+    },
     ledger::Ledger,
     record::{validate_record_archive, ArchivedRecord, Record, RecordStatus},
     Filesystem,
@@ -100,6 +105,23 @@ where
         source: <T as Encodable>::DecodeError,
     },
 
+    /// The record payload could not be decrypted.
+    ///
+    /// This can occur when the record was encrypted with a key that is no longer provided, when
+    /// encryption was disabled after the record was written, or when the record was modified after
+    /// being written.  As with decoding errors, the checksum was validated, so only this record is
+    /// affected.
+    #[snafu(display("failed to decrypt record: {}", source))]
+    Decryption { source: EncryptionError },
+
+    /// The record payload could not be decompressed.
+    ///
+    /// As the checksum was validated, and the payload decrypted if encryption is enabled, this
+    /// indicates that the record was written with a broken compressor, and only this record is
+    /// affected.
+    #[snafu(display("failed to decompress record: {}", source))]
+    Decompression { source: io::Error },
+
     /// The record is not compatible with this version of Vector.
     ///
     /// This can occur when records written to a buffer in previous versions of Vector are read by
@@ -144,6 +166,8 @@ where
             ReaderError::Deserialization { .. } => "deser_failed",
             ReaderError::Checksum { .. } => "checksum_mismatch",
             ReaderError::Decode { .. } => "decode_failed",
+            ReaderError::Decryption { .. } => "decryption_failed",
+            ReaderError::Decompression { .. } => "decompression_failed",
             ReaderError::Incompatible { .. } => "incompatible_record_version",
             ReaderError::PartialWrite => "partial_write",
             ReaderError::EmptyRecord => "empty_record",
@@ -159,6 +183,8 @@ where
             ReaderError::Deserialization { .. }
             | ReaderError::Checksum { .. }
             | ReaderError::Decode { .. }
+            | ReaderError::Decryption { .. }
+            | ReaderError::Decompression { .. }
             | ReaderError::Incompatible { .. }
             | ReaderError::PartialWrite => Some(BufferReadError { error_code, error }),
         }
@@ -199,6 +225,7 @@ pub(super) struct RecordReader<R, T> {
     reader: BufReader<R>,
     aligned_buf: AlignedVec,
     checksummer: Hasher,
+    cipher: Option<Arc<RecordCipher>>,
     current_record_id: u64,
     _t: PhantomData<T>,
 }
@@ -212,11 +239,14 @@ where
     ///
     /// Internally, the reader is wrapped in a [`BufReader`], so callers should not pass in an
     /// already buffered reader.
-    pub fn new(reader: R) -> Self {
+    ///
+    /// If a cipher is provided, record payloads are opened with it before being decoded.
+    pub fn new(reader: R, cipher: Option<Arc<RecordCipher>>) -> Self {
         Self {
             reader: BufReader::with_capacity(256 * 1024, reader),
             aligned_buf: AlignedVec::new(),
             checksummer: create_crc32c_hasher(),
+            cipher,
             current_record_id: 0,
             _t: PhantomData,
         }
@@ -374,7 +404,7 @@ where
         // - `try_next_record` does all the archive checks, checksum validation, etc
         let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
 
-        decode_record_payload(record)
+        decode_record_payload(record, self.cipher.as_deref())
     }
 }
 
@@ -387,6 +417,7 @@ where
             .field("reader", &self.reader)
             .field("aligned_buf", &self.aligned_buf)
             .field("checksummer", &self.checksummer)
+            .field("cipher", &self.cipher)
             .field("current_record_id", &self.current_record_id)
             .finish()
     }
@@ -405,6 +436,8 @@ where
     data_file_start_record_id: Option<u64>,
     data_file_record_count: u64,
     data_file_marked_record_count: u64,
+    skipped_evicted_data_file: bool,
+    pending_evicted_events: u64,
     ready_to_read: bool,
     record_acks: OrderedAcknowledgements<u64, u64>,
     data_file_acks: OrderedAcknowledgements<u64, (PathBuf, u64)>,
@@ -431,6 +464,8 @@ where
             data_file_start_record_id: None,
             data_file_record_count: 0,
             data_file_marked_record_count: 0,
+            skipped_evicted_data_file: false,
+            pending_evicted_events: 0,
             ready_to_read: false,
             record_acks: OrderedAcknowledgements::from_acked(next_expected_record_id),
             data_file_acks: OrderedAcknowledgements::from_acked(0),
@@ -456,6 +491,7 @@ where
         // record, plus the event count, minus one.  Another way to look at it is that the "last"
         // reader record ID is always one behind the next expected record ID.  In the above example,
         // the next record ID we would expect would be 6, regardless of how many events the record has.
+        let next_expected_record_id = self.last_reader_record_id.wrapping_add(1);
         self.last_reader_record_id = record_id.wrapping_add(event_count.get() - 1);
         if self.data_file_start_record_id.is_none() {
             self.data_file_start_record_id = Some(record_id);
@@ -470,6 +506,16 @@ where
             return;
         }
 
+        // If we skipped over data files evicted by the writer to get here, the records they held
+        // show up as a gap in record IDs, which we keep track of so that the gap is reported as
+        // evicted events rather than lost ones.
+        if self.skipped_evicted_data_file {
+            self.skipped_evicted_data_file = false;
+            self.pending_evicted_events = self
+                .pending_evicted_events
+                .saturating_add(record_id.wrapping_sub(next_expected_record_id));
+        }
+
         // We've done a "real" record read, so we need to track it for acknowledgement.  Check our
         // acknowledge state first to see if this is the next record ID we expected.
         self.data_file_record_count += 1;
@@ -511,40 +557,56 @@ where
         // occur at all, so we're relying on this method to correct the buffer size for us.  This is
         // why `bytes_read` is optional: when it's specified, we calculate a delta for handling
         // partial-read scenarios, otherwise, we just use the entire data file size as is.
-        let data_file = self
+        //
+        // If the data file doesn't exist anymore, it was evicted by the writer, which already took
+        // care of adjusting the buffer size, so all that's left is to move past it.
+        match self
             .ledger
             .filesystem()
             .open_file_readable(&data_file_path)
-            .await?;
-        let metadata = data_file.metadata().await?;
-
-        let decrease_amount = bytes_read.map_or_else(
-            || metadata.len(),
-            |bytes_read| {
-                let size_delta = metadata.len() - bytes_read;
-                if size_delta > 0 {
-                    debug!(
-                        actual_file_size = metadata.len(),
-                        bytes_read,
-                        "Data file was only partially read. Adjusting buffer size to compensate.",
-                    );
+            .await
+        {
+            Ok(data_file) => {
+                let metadata = data_file.metadata().await?;
+
+                let decrease_amount = bytes_read.map_or_else(
+                    || metadata.len(),
+                    |bytes_read| {
+                        let size_delta = metadata.len() - bytes_read;
+                        if size_delta > 0 {
+                            debug!(
+                                actual_file_size = metadata.len(),
+                                bytes_read,
+                                "Data file was only partially read. Adjusting buffer size to compensate.",
+                            );
+                        }
+
+                        size_delta
+                    },
+                );
+
+                if decrease_amount > 0 {
+                    self.ledger.decrement_total_buffer_size(decrease_amount);
                 }
 
-                size_delta
-            },
-        );
+                drop(data_file);
 
-        if decrease_amount > 0 {
-            self.ledger.decrement_total_buffer_size(decrease_amount);
+                // Delete the current data file.
+                self.ledger
+                    .filesystem()
+                    .delete_file(&data_file_path)
+                    .await?;
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                debug!(
+                    data_file_path = data_file_path.to_string_lossy().as_ref(),
+                    "Data file was already evicted."
+                );
+            }
+            Err(e) => return Err(e),
         }
 
-        drop(data_file);
-
-        // Delete the current data file, and increment our actual reader file ID.
-        self.ledger
-            .filesystem()
-            .delete_file(&data_file_path)
-            .await?;
+        // Increment our actual reader file ID.
         self.ledger.increment_acked_reader_file_id();
         self.ledger.flush()?;
 
@@ -634,9 +696,19 @@ where
                     .add_acknowledgements(records_acknowledged);
             }
 
-            // If any events were skipped, do our logging/metrics for that.
+            // If any events were skipped, do our logging/metrics for that, telling apart the events
+            // that were evicted by the writer from the ones that were lost.
             if events_skipped > 0 {
-                self.ledger.track_dropped_events(events_skipped);
+                let events_evicted = events_skipped.min(self.pending_evicted_events);
+                self.pending_evicted_events -= events_evicted;
+
+                if events_evicted > 0 {
+                    self.ledger.track_evicted_events(events_evicted);
+                }
+                if events_skipped > events_evicted {
+                    self.ledger
+                        .track_dropped_events(events_skipped - events_evicted);
+                }
             }
         }
 
@@ -753,6 +825,10 @@ where
         // we'll simply wait for the writer to signal to us that progress has been made, which
         // implies a data file existing.
         loop {
+            // We hold the lock on evicted data files while opening the data file so that the writer
+            // can't evict it from under us.
+            let mut evicted_data_files = self.ledger.lock_evicted_data_files().await;
+
             let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
             let data_file_path = self.ledger.get_current_reader_data_file_path();
             let data_file = match self
@@ -764,12 +840,35 @@ where
                 Ok(data_file) => data_file,
                 Err(e) => match e.kind() {
                     ErrorKind::NotFound => {
+                        let was_evicted = evicted_data_files.front() == Some(&reader_file_id);
+                        if was_evicted {
+                            evicted_data_files.pop_front();
+                        }
+                        drop(evicted_data_files);
+
                         if reader_file_id == writer_file_id {
                             debug!(
                                 data_file_path = data_file_path.to_string_lossy().as_ref(),
                                 "Data file does not yet exist. Waiting for writer to create."
                             );
                             self.ledger.wait_for_writer().await;
+                            continue;
+                        }
+
+                        debug!(
+                            data_file_path = data_file_path.to_string_lossy().as_ref(),
+                            was_evicted, "Data file does not exist. Skipping."
+                        );
+                        self.skipped_evicted_data_file |= was_evicted;
+
+                        if self.ledger.has_unacked_data_files()
+                            && self.ledger.is_reader_behind_writer()
+                        {
+                            // The data file was written but is now gone, most likely evicted, and
+                            // as there are still data files waiting on acknowledgements, we have
+                            // to roll past it like any other data file, so that the acknowledged
+                            // reader file ID is advanced in the right order.
+                            self.roll_to_next_data_file();
                         } else {
                             self.ledger.increment_acked_reader_file_id();
                         }
@@ -779,13 +878,17 @@ where
                     _ => return Err(e),
                 },
             };
+            drop(evicted_data_files);
 
             debug!(
                 data_file_path = data_file_path.to_string_lossy().as_ref(),
                 "Opened data file for reading."
             );
 
-            self.reader = Some(RecordReader::new(data_file));
+            self.reader = Some(RecordReader::new(
+                data_file,
+                self.ledger.config().encryption.clone(),
+            ));
             return Ok(());
         }
     }
@@ -851,7 +954,7 @@ where
                     let record = try_as_record_archive(data_file_mmap.as_ref())
                         .expect("record was already validated");
 
-                    let Ok(item) = decode_record_payload::<T>(record) else {
+                    let Ok(record_events) = record_event_count::<T>(record) else {
                         // If there's an error decoding the item, just fall back to the slow path,
                         // because this file might actually be where we left off, so we don't want
                         // to incorrectly skip ahead or anything.
@@ -864,8 +967,6 @@ where
                     // the number of events in the record, which is how we can determine the event
                     // count from the record IDs alone, without having to read every record in the
                     // buffer during startup.)
-                    let record_events = u64::try_from(item.event_count())
-                        .expect("event count should never exceed u64");
                     let last_record_id_in_data_file =
                         last_record_id.wrapping_add(record_events.saturating_sub(1));
 
@@ -930,6 +1031,10 @@ where
                         break;
                     }
                 }
+                // The records we read here have already been acknowledged, so there's no need for
+                // them to be readable: records sealed with a key that is no longer provided are
+                // simply skipped over.
+                Err(ReaderError::Decryption { .. }) => {}
                 Err(e) => return Err(e),
             }
         }
@@ -1131,10 +1236,23 @@ where
 
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
 ) -> Result<T, ReaderError<T>> {
+    // The most significant bits of the record metadata are reserved by the buffer itself to track
+    // how the payload was compressed and whether it was sealed, so split those off before handing
+    // the rest to `T`.
+    let unsealed_metadata = record.metadata() & !SEALED_METADATA_BIT;
+    let (compression, record_metadata) = RecordCompression::from_record_metadata(unsealed_metadata)
+        .ok_or(ReaderError::Incompatible {
+            reason: format!(
+                "unknown record compression (metadata: {:#036b})",
+                record.metadata()
+            ),
+        })?;
+
     // Try and convert the raw record metadata into the true metadata type used by `T`, and then
     // also verify that `T` is able to decode records with the metadata used for this record in particular.
-    let metadata = T::Metadata::from_u32(record.metadata()).ok_or(ReaderError::Incompatible {
+    let metadata = T::Metadata::from_u32(record_metadata).ok_or(ReaderError::Incompatible {
         reason: format!("invalid metadata for {}", std::any::type_name::<T>()),
     })?;
 
@@ -1142,11 +1260,39 @@ pub(crate) fn decode_record_payload<T: Bufferable>(
         return Err(ReaderError::Incompatible {
             reason: format!(
                 "record metadata not supported (metadata: {:#036b})",
-                record.metadata()
+                record_metadata
             ),
         });
     }
 
+    // If the payload was sealed, we need to open it before we can decode it.
+    let payload = open_record_payload(record, cipher).context(DecryptionSnafu)?;
+
+    // Likewise, if the payload was compressed, we need to decompress it.
+    let payload = match compression {
+        RecordCompression::None => payload,
+        compression => compression
+            .decompress(&payload)
+            .context(DecompressionSnafu)?
+            .into(),
+    };
+
     // Now we can finally try decoding.
-    T::decode(metadata, record.payload()).context(DecodeSnafu)
+    T::decode(metadata, &payload[..]).context(DecodeSnafu)
+}
+
+/// Gets the number of events in the given record.
+///
+/// Sealed records carry their event count in the clear, so this never requires opening them, which
+/// allows finding where the buffer left off even if the key a record was sealed with is no longer
+/// provided.
+pub(crate) fn record_event_count<T: Bufferable>(
+    record: &ArchivedRecord<'_>,
+) -> Result<u64, ReaderError<T>> {
+    if is_sealed(record.metadata()) {
+        return sealed_event_count(record.payload()).context(DecryptionSnafu);
+    }
+
+    let item = decode_record_payload::<T>(record, None)?;
+    Ok(u64::try_from(item.event_count()).expect("event count should never exceed u64"))
 }
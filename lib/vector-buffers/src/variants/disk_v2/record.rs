@@ -10,11 +10,17 @@ use rkyv::{
 
 use super::{
     common::align16,
+    compression::COMPRESSION_METADATA_MASK,
+    encryption::SEALED_METADATA_BIT,
     ser::{try_as_archive, DeserializeError},
 };
 
 pub const RECORD_HEADER_LEN: usize = align16(mem::size_of::<ArchivedRecord<'_>>() + 8);
 
+/// Bits of the record metadata that are reserved for tracking how the payload was compressed, and
+/// whether it was sealed.
+pub(super) const RESERVED_METADATA_MASK: u32 = COMPRESSION_METADATA_MASK | SEALED_METADATA_BIT;
+
 /// Result of checking if a buffer contained a valid record.
 pub enum RecordStatus {
     /// The record was able to be read from the buffer, and the checksum is valid.
@@ -128,6 +134,11 @@ impl<'a> Record<'a> {
 }
 
 impl ArchivedRecord<'_> {
+    /// Gets the ID of this record.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Gets the metadata of this record.
     pub fn metadata(&self) -> u32 {
         self.metadata
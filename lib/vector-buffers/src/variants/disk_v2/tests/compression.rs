@@ -0,0 +1,132 @@
+use std::{path::Path, sync::Arc};
+
+use tracing::Instrument;
+
+use super::{read_next_some, FilesystemUnderTest};
+use crate::{
+    assert_buffer_is_empty, assert_buffer_size,
+    buffer_usage_data::BufferUsageHandle,
+    test::{acknowledge, install_tracing_helpers, with_temp_dir, SizedRecord},
+    variants::disk_v2::{
+        Buffer, BufferError, BufferReader, BufferWriter, DiskBufferConfigBuilder, Ledger,
+        RecordCompression,
+    },
+};
+
+async fn create_buffer_v2_with_compression<P>(
+    data_dir: P,
+    compression: RecordCompression,
+) -> Result<
+    (
+        BufferWriter<SizedRecord, FilesystemUnderTest>,
+        BufferReader<SizedRecord, FilesystemUnderTest>,
+        Arc<Ledger<FilesystemUnderTest>>,
+    ),
+    BufferError<SizedRecord>,
+>
+where
+    P: AsRef<Path>,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .compression(compression)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    Buffer::from_config_inner(config, usage_handle).await
+}
+
+#[tokio::test]
+async fn compressed_records_use_less_space_on_disk() {
+    let _a = install_tracing_helpers();
+
+    for compression in [RecordCompression::Zstd, RecordCompression::Lz4] {
+        let fut = with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let (mut writer, mut reader, ledger) =
+                    create_buffer_v2_with_compression(data_dir, compression)
+                        .await
+                        .expect("should not fail to create buffer");
+                assert_buffer_is_empty!(ledger);
+
+                // `SizedRecord` encodes itself as a run of 0x42 bytes, which compresses extremely
+                // well, so the record as written to disk should be a fraction of its encoded size.
+                let record = SizedRecord::new(8192);
+                let bytes_written = writer
+                    .write_record(record.clone())
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+                assert!(bytes_written < 1024);
+                assert_buffer_size!(ledger, 1, bytes_written);
+
+                let read_record = read_next_some(&mut reader).await;
+                assert_eq!(record, read_record);
+                acknowledge(read_record).await;
+
+                writer.close();
+                assert_eq!(None, reader.next().await.expect("read should not fail"));
+                assert_buffer_is_empty!(ledger);
+            }
+        });
+
+        let parent = trace_span!("compressed_records_use_less_space_on_disk");
+        fut.instrument(parent.or_current()).await;
+    }
+}
+
+#[tokio::test]
+async fn records_can_be_read_after_changing_compression() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a record with each compression algorithm, reopening the buffer in between, and
+            // then make sure they can all be read back regardless of the current setting.
+            let compressions = [
+                RecordCompression::Zstd,
+                RecordCompression::Lz4,
+                RecordCompression::None,
+            ];
+            for (i, compression) in compressions.into_iter().enumerate() {
+                let (mut writer, reader, ledger) =
+                    create_buffer_v2_with_compression(data_dir.clone(), compression)
+                        .await
+                        .expect("should not fail to create buffer");
+
+                let size = 1024 * (u32::try_from(i).unwrap() + 1);
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+                ledger.flush().expect("should not fail to flush ledger");
+
+                drop(reader);
+                drop(writer);
+                drop(ledger);
+            }
+
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_compression(data_dir, RecordCompression::Zstd)
+                    .await
+                    .expect("should not fail to reopen buffer");
+            writer.close();
+
+            for size in [1024, 2048, 3072] {
+                let record = read_next_some(&mut reader).await;
+                assert_eq!(SizedRecord::new(size), record);
+                acknowledge(record).await;
+            }
+
+            assert_eq!(None, reader.next().await.expect("read should not fail"));
+            assert_buffer_is_empty!(ledger);
+        }
+    });
+
+    let parent = trace_span!("records_can_be_read_after_changing_compression");
+    fut.instrument(parent.or_current()).await;
+}
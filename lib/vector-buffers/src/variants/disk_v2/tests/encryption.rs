@@ -0,0 +1,250 @@
+use std::{path::Path, sync::Arc};
+
+use tracing::Instrument;
+
+use super::{create_default_buffer_v2, read_next_some, FilesystemUnderTest};
+use crate::{
+    assert_buffer_is_empty,
+    buffer_usage_data::BufferUsageHandle,
+    test::{acknowledge, install_tracing_helpers, with_temp_dir, SizedRecord},
+    variants::disk_v2::{
+        Buffer, BufferError, BufferReader, BufferWriter, DiskBufferConfigBuilder, Ledger,
+        ReaderError, RecordCipher,
+    },
+};
+
+const KEY_A: [u8; 32] = [0x1a; 32];
+const KEY_B: [u8; 32] = [0x2b; 32];
+
+async fn create_buffer_v2_with_cipher<P>(
+    data_dir: P,
+    cipher: RecordCipher,
+) -> Result<
+    (
+        BufferWriter<SizedRecord, FilesystemUnderTest>,
+        BufferReader<SizedRecord, FilesystemUnderTest>,
+        Arc<Ledger<FilesystemUnderTest>>,
+    ),
+    BufferError<SizedRecord>,
+>
+where
+    P: AsRef<Path>,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .encryption(cipher)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    Buffer::from_config_inner(config, usage_handle).await
+}
+
+#[tokio::test]
+async fn encrypted_records_are_not_stored_in_plaintext() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+            let (mut writer, mut reader, ledger) = create_buffer_v2_with_cipher(data_dir, cipher)
+                .await
+                .expect("should not fail to create buffer");
+            assert_buffer_is_empty!(ledger);
+
+            // `SizedRecord` encodes itself as a run of 0x42 bytes, which makes it easy to check that
+            // the encoded record never made it to the data file as-is.
+            let record = SizedRecord::new(256);
+            writer
+                .write_record(record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+
+            let data_file_path = ledger.get_current_writer_data_file_path();
+            let data_file = tokio::fs::read(&data_file_path)
+                .await
+                .expect("should not fail to read data file");
+            assert!(!data_file.windows(64).any(|w| w.iter().all(|b| *b == 0x42)));
+
+            // Reading it back through the buffer should still give us the original record.
+            let read_record = read_next_some(&mut reader).await;
+            assert_eq!(record, read_record);
+            acknowledge(read_record).await;
+
+            writer.close();
+            assert_eq!(None, reader.next().await.expect("read should not fail"));
+            assert_buffer_is_empty!(ledger);
+        }
+    });
+
+    let parent = trace_span!("encrypted_records_are_not_stored_in_plaintext");
+    fut.instrument(parent.or_current()).await;
+}
+
+#[tokio::test]
+async fn records_written_before_key_rotation_can_still_be_read() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a couple of records with the original key, and then shut the buffer down.
+            let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+            let (mut writer, reader, ledger) =
+                create_buffer_v2_with_cipher(data_dir.clone(), cipher)
+                    .await
+                    .expect("should not fail to create buffer");
+
+            for size in [32, 64] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+            ledger.flush().expect("should not fail to flush ledger");
+
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // Now rotate to a new key, keeping the original key around so that existing records
+            // can still be read, and write another record with the new key.
+            let cipher = RecordCipher::new("b", &KEY_B)
+                .unwrap()
+                .with_previous_key("a", &KEY_A)
+                .unwrap();
+            let (mut writer, mut reader, ledger) = create_buffer_v2_with_cipher(data_dir, cipher)
+                .await
+                .expect("should not fail to reopen buffer");
+
+            writer
+                .write_record(SizedRecord::new(128))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            for size in [32, 64, 128] {
+                let record = read_next_some(&mut reader).await;
+                assert_eq!(SizedRecord::new(size), record);
+                acknowledge(record).await;
+            }
+
+            assert_eq!(None, reader.next().await.expect("read should not fail"));
+            assert_buffer_is_empty!(ledger);
+        }
+    });
+
+    let parent = trace_span!("records_written_before_key_rotation_can_still_be_read");
+    fut.instrument(parent.or_current()).await;
+}
+
+#[tokio::test]
+async fn records_written_before_encryption_was_enabled_can_still_be_read() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a record without encryption, and then shut the buffer down.
+            let (mut writer, reader, ledger) =
+                create_default_buffer_v2::<_, SizedRecord>(data_dir.clone()).await;
+
+            writer
+                .write_record(SizedRecord::new(32))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            ledger.flush().expect("should not fail to flush ledger");
+
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // Now enable encryption, and write another record, which gets sealed.
+            let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+            let (mut writer, mut reader, ledger) = create_buffer_v2_with_cipher(data_dir, cipher)
+                .await
+                .expect("should not fail to reopen buffer");
+
+            writer
+                .write_record(SizedRecord::new(64))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            for size in [32, 64] {
+                let record = read_next_some(&mut reader).await;
+                assert_eq!(SizedRecord::new(size), record);
+                acknowledge(record).await;
+            }
+
+            assert_eq!(None, reader.next().await.expect("read should not fail"));
+            assert_buffer_is_empty!(ledger);
+        }
+    });
+
+    let parent = trace_span!("records_written_before_encryption_was_enabled_can_still_be_read");
+    fut.instrument(parent.or_current()).await;
+}
+
+#[tokio::test]
+async fn buffer_opens_when_key_for_existing_records_is_missing() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let cipher = RecordCipher::new("a", &KEY_A).unwrap();
+            let (mut writer, reader, ledger) =
+                create_buffer_v2_with_cipher(data_dir.clone(), cipher)
+                    .await
+                    .expect("should not fail to create buffer");
+
+            writer
+                .write_record(SizedRecord::new(32))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            ledger.flush().expect("should not fail to flush ledger");
+
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // Rotating without keeping the original key around means the existing records can't be
+            // opened, which only affects reading them: the buffer itself still opens, and new
+            // records can be written and read.
+            let cipher = RecordCipher::new("b", &KEY_B).unwrap();
+            let (mut writer, mut reader, _ledger) = create_buffer_v2_with_cipher(data_dir, cipher)
+                .await
+                .expect("should not fail to reopen buffer");
+
+            writer
+                .write_record(SizedRecord::new(64))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+
+            match reader.next().await {
+                Err(ReaderError::Decryption { source }) => {
+                    assert!(source.to_string().contains("unknown key 'a'"));
+                }
+                result => panic!("unexpected read result: {result:?}"),
+            }
+
+            let record = read_next_some(&mut reader).await;
+            assert_eq!(SizedRecord::new(64), record);
+            acknowledge(record).await;
+        }
+    });
+
+    let parent = trace_span!("buffer_opens_when_key_for_existing_records_is_missing");
+    fut.instrument(parent.or_current()).await;
+}
@@ -0,0 +1,84 @@
+use tracing::Instrument;
+
+use super::{create_default_buffer_v2, read_next_some};
+use crate::{
+    assert_buffer_is_empty,
+    test::{acknowledge, install_tracing_helpers, with_temp_dir, SizedRecord},
+    variants::disk_v2::{BufferInspector, DiskBufferConfigBuilder},
+};
+
+#[tokio::test]
+async fn inspector_reads_unacknowledged_records_without_modifying_buffer() {
+    let _a = install_tracing_helpers();
+
+    let fut = with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write three records, and then read and acknowledge the first one.  We have to read the
+            // second record afterwards, without acknowledging it, so that the reader actually
+            // processes the acknowledgement and updates the ledger.
+            let (mut writer, mut reader, ledger) = create_default_buffer_v2(data_dir.clone()).await;
+            for size in [128, 256, 512] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+
+            let first = read_next_some(&mut reader).await;
+            assert_eq!(SizedRecord::new(128), first);
+            acknowledge(first).await;
+            let second = read_next_some(&mut reader).await;
+            assert_eq!(SizedRecord::new(256), second);
+
+            ledger.flush().expect("should not fail to flush ledger");
+            drop(second);
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // The inspector should see the two records that were never acknowledged, in order.
+            let config = DiskBufferConfigBuilder::from_path(data_dir.clone())
+                .build()
+                .expect("creating buffer config should not fail");
+            let mut inspector = BufferInspector::<SizedRecord>::open(config)
+                .await
+                .expect("should not fail to open buffer for inspection");
+            assert_eq!(inspector.pending_events(), 2);
+
+            let mut last_id = None;
+            for size in [256, 512] {
+                let record = inspector
+                    .next()
+                    .await
+                    .expect("read should not fail")
+                    .expect("record should be present");
+                assert_eq!(SizedRecord::new(size), record.item);
+                assert!(last_id < Some(record.id));
+                last_id = Some(record.id);
+            }
+            assert!(inspector
+                .next()
+                .await
+                .expect("read should not fail")
+                .is_none());
+            drop(inspector);
+
+            // Inspecting the buffer must not have consumed anything.
+            let (mut writer, mut reader, ledger) = create_default_buffer_v2(data_dir).await;
+            writer.close();
+            for size in [256, 512] {
+                let record = read_next_some(&mut reader).await;
+                assert_eq!(SizedRecord::new(size), record);
+                acknowledge(record).await;
+            }
+            assert_eq!(None, reader.next().await.expect("read should not fail"));
+            assert_buffer_is_empty!(ledger);
+        }
+    });
+
+    let parent = trace_span!("inspector_reads_unacknowledged_records_without_modifying_buffer");
+    fut.instrument(parent.or_current()).await;
+}
@@ -24,7 +24,10 @@ type FilesystemUnderTest = ProductionFilesystem;
 
 mod acknowledgements;
 mod basic;
+mod compression;
+mod encryption;
 mod initialization;
+mod inspector;
 mod invariants;
 mod known_errors;
 mod model;
@@ -2,7 +2,7 @@ use std::io::Cursor;
 
 use crate::{
     test::SizedRecord,
-    variants::disk_v2::{reader::RecordReader, writer::RecordWriter},
+    variants::disk_v2::{reader::RecordReader, writer::RecordWriter, RecordCompression},
 };
 
 #[tokio::test]
@@ -10,8 +10,16 @@ async fn roundtrip_through_record_writer_and_record_reader() {
     // Create a duplex stream that's more than big enough to ship a record through.
     let (writer_io, reader_io) = tokio::io::duplex(4096);
 
-    let mut record_writer = RecordWriter::new(writer_io, 0, 16_384, u64::MAX, 2048);
-    let mut record_reader = RecordReader::new(reader_io);
+    let mut record_writer = RecordWriter::new(
+        writer_io,
+        0,
+        16_384,
+        u64::MAX,
+        2048,
+        None,
+        RecordCompression::None,
+    );
+    let mut record_reader = RecordReader::new(reader_io, None);
 
     let record = SizedRecord::new(73);
 
@@ -42,7 +50,7 @@ async fn roundtrip_through_record_writer_and_record_reader() {
 async fn record_reader_always_returns_none_when_no_data() {
     let reader_io = Cursor::new(Vec::new());
 
-    let mut record_reader = RecordReader::<_, SizedRecord>::new(reader_io);
+    let mut record_reader = RecordReader::<_, SizedRecord>::new(reader_io, None);
     let read_token = record_reader
         .try_next_record(false)
         .await
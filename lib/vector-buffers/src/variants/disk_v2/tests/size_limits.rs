@@ -481,3 +481,103 @@ async fn writer_can_validate_last_write_when_buffer_is_full() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn writer_evicts_oldest_unread_data_file_when_buffer_is_full() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Create our buffer such that every record fills an entire data file, and the buffer is
+            // full after three writes.
+            let write_size = 96;
+            let records = (0..4)
+                .map(|_| SizedRecord::new(write_size))
+                .collect::<Vec<_>>();
+
+            let max_data_file_size = get_minimum_data_file_size_for_record_payload(&records[0]);
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_data_file_count_limit(data_dir, max_data_file_size, 4).await;
+
+            assert_buffer_is_empty!(ledger);
+
+            let mut bytes_written = Vec::new();
+            for record in records.iter().take(3) {
+                let record_bytes_written = writer
+                    .write_record(record.clone())
+                    .await
+                    .expect("write should not fail");
+                bytes_written.push(record_bytes_written);
+            }
+            writer.flush().await.expect("flush should not fail");
+            assert_buffer_size!(ledger, 3, bytes_written.iter().sum::<usize>());
+
+            // The buffer is now full, so the next write has to evict a data file. The reader
+            // hasn't started reading yet, so the oldest data file it hasn't started reading is the
+            // second one, which holds the second record.
+            let fourth_bytes_written = writer
+                .write_record_dropping_oldest(records[3].clone())
+                .await
+                .expect("write should not fail")
+                .expect("data file should have been evicted");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            assert_buffer_size!(
+                ledger,
+                3,
+                bytes_written[0] + bytes_written[2] + fourth_bytes_written
+            );
+
+            // Now read all of the records, making sure the reader skips over the evicted data file.
+            for record in [&records[0], &records[2], &records[3]] {
+                let record_read = read_next_some(&mut reader).await;
+                assert_eq!(&record_read, record);
+                acknowledge(record_read).await;
+            }
+
+            let final_empty_read = read_next(&mut reader).await;
+            assert_eq!(final_empty_read, None);
+
+            assert_buffer_is_empty!(ledger);
+            assert_reader_writer_v2_file_positions!(ledger, 3, 3);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn writer_returns_record_when_no_data_file_can_be_evicted() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Create our buffer such that it is full after a single write, which leaves only the
+            // data file being written to, and thus nothing that can be evicted.
+            let write_size = 96;
+            let first_record = SizedRecord::new(write_size);
+            let second_record = SizedRecord::new(write_size);
+
+            let max_data_file_size = get_minimum_data_file_size_for_record_payload(&second_record);
+            let (mut writer, _, ledger) =
+                create_buffer_v2_with_data_file_count_limit(data_dir, max_data_file_size, 2).await;
+
+            let first_bytes_written = writer
+                .write_record_dropping_oldest(first_record)
+                .await
+                .expect("write should not fail")
+                .expect("record should have been written");
+            writer.flush().await.expect("flush should not fail");
+
+            let second_write_result = writer
+                .write_record_dropping_oldest(second_record.clone())
+                .await
+                .expect("write should not fail");
+            assert_eq!(second_write_result, Err(second_record));
+            assert_buffer_size!(ledger, 1, first_bytes_written);
+        }
+    })
+    .await;
+}
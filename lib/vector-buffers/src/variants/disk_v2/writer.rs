@@ -25,15 +25,17 @@ use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use super::{
     common::{create_crc32c_hasher, DiskBufferConfig},
+    compression::RecordCompression,
+    encryption::{RecordCipher, SEALED_METADATA_BIT},
     io::Filesystem,
     ledger::Ledger,
-    record::{validate_record_archive, Record, RecordStatus},
+    record::{validate_record_archive, Record, RecordStatus, RESERVED_METADATA_MASK},
 };
 use crate::{
     encoding::{AsMetadata, Encodable},
     variants::disk_v2::{
         io::AsyncFile,
-        reader::decode_record_payload,
+        reader::{decode_record_payload, record_event_count},
         record::{try_as_record_archive, RECORD_HEADER_LEN},
     },
     Bufferable,
@@ -360,9 +362,13 @@ impl<W: fmt::Debug> fmt::Debug for TrackingBufWriter<W> {
 pub(super) struct RecordWriter<W, T> {
     writer: TrackingBufWriter<W>,
     encode_buf: Vec<u8>,
+    compress_buf: Vec<u8>,
+    seal_buf: Vec<u8>,
     ser_buf: AlignedVec,
     ser_scratch: AlignedVec,
     checksummer: Hasher,
+    cipher: Option<Arc<RecordCipher>>,
+    compression: RecordCompression,
     max_record_size: usize,
     current_data_file_size: u64,
     max_data_file_size: u64,
@@ -378,12 +384,17 @@ where
     ///
     /// Internally, the writer is wrapped in a [`BufWriter`], so callers should not pass in an
     /// already buffered writer.
+    ///
+    /// Record payloads are compressed with the given compression algorithm, and if a cipher is
+    /// provided, sealed with it, before being serialized.
     pub fn new(
         writer: W,
         current_data_file_size: u64,
         write_buffer_size: usize,
         max_data_file_size: u64,
         max_record_size: usize,
+        cipher: Option<Arc<RecordCipher>>,
+        compression: RecordCompression,
     ) -> Self {
         // These should also be getting checked at a higher level, but we're double-checking them here to be absolutely sure.
         let max_record_size_converted = u64::try_from(max_record_size)
@@ -410,9 +421,13 @@ where
         Self {
             writer: TrackingBufWriter::with_capacity(write_buffer_size, writer),
             encode_buf: Vec::with_capacity(16_384),
+            compress_buf: Vec::new(),
+            seal_buf: Vec::new(),
             ser_buf: AlignedVec::with_capacity(16_384),
             ser_scratch: AlignedVec::with_capacity(16_384),
             checksummer: create_crc32c_hasher(),
+            cipher,
+            compression,
             max_record_size,
             current_data_file_size,
             max_data_file_size,
@@ -480,9 +495,57 @@ where
             });
         }
 
+        // The most significant bits of the record metadata are reserved for tracking how the payload
+        // was compressed and whether it was sealed, so `T` must not be using them itself.
         let metadata = T::get_metadata().into_u32();
-        let wrapped_record =
-            Record::with_checksum(id, metadata, &self.encode_buf, &self.checksummer);
+        if metadata & RESERVED_METADATA_MASK != 0 {
+            return Err(WriterError::FailedToSerialize {
+                reason: format!(
+                    "record metadata uses bits reserved by the buffer (metadata: {metadata:#036b})"
+                ),
+            });
+        }
+
+        // If compression is enabled, compress the encoded record, and mark the compression
+        // algorithm that was actually used in the record metadata so that the reader knows how to
+        // decompress it.  Payloads that don't get any smaller when compressed are stored as-is.
+        let compression = self
+            .compression
+            .compress(&self.encode_buf, &mut self.compress_buf)
+            .map_err(|e| WriterError::FailedToSerialize {
+                reason: format!("failed to compress record: {e}"),
+            })?;
+        let metadata = compression.tag_record_metadata(metadata);
+        let payload = match compression {
+            RecordCompression::None => &self.encode_buf[..],
+            _ => &self.compress_buf[..],
+        };
+
+        // If encryption is enabled, seal the encoded record so that what we actually store on disk
+        // is the sealed form, and mark it as sealed in the record metadata so that the reader knows
+        // to open it.  Sealing adds a small amount of overhead, so we have to check the size limit
+        // again afterwards.
+        let (metadata, payload) = match self.cipher.as_deref() {
+            None => (metadata, payload),
+            Some(cipher) => {
+                let metadata = metadata | SEALED_METADATA_BIT;
+                let sealed_events =
+                    u64::try_from(event_count).expect("event count should never exceed u64");
+                cipher
+                    .seal(id, metadata, sealed_events, payload, &mut self.seal_buf)
+                    .map_err(|e| WriterError::FailedToSerialize {
+                        reason: e.to_string(),
+                    })?;
+                if self.seal_buf.len() > self.max_record_size {
+                    return Err(WriterError::RecordTooLarge {
+                        limit: self.max_record_size.saturating_sub(cipher.overhead()),
+                    });
+                }
+                (metadata, &self.seal_buf[..])
+            }
+        };
+
+        let wrapped_record = Record::with_checksum(id, metadata, payload, &self.checksummer);
 
         // Push 8 dummy bytes where our length delimiter will sit.  We'll fix this up after
         // serialization.  Notably, `AlignedSerializer` will report the serializer position as
@@ -665,17 +728,11 @@ where
             }
         })?;
 
-        // Now we can actually decode it as `T`.
-        let record_metadata = T::Metadata::from_u32(wrapped_record.metadata()).ok_or(
-            WriterError::InconsistentState {
-                reason: "failed to decode record metadata immediately after encoding it"
-                    .to_string(),
-            },
-        )?;
-
-        T::decode(record_metadata, wrapped_record.payload()).map_err(|_| {
+        // Now we can actually decode it as `T`, which includes opening and decompressing the
+        // payload as necessary.
+        decode_record_payload::<T>(wrapped_record, self.cipher.as_deref()).map_err(|e| {
             WriterError::InconsistentState {
-                reason: "failed to decode record immediately after encoding it".to_string(),
+                reason: format!("failed to decode record immediately after encoding it: {e}"),
             }
         })
     }
@@ -877,23 +934,21 @@ where
                 id: last_record_id, ..
             } => {
                 // We now know the record is valid from the perspective of being framed correctly,
-                // and the checksum matching, etc.  We'll attempt to actually decode it now so we
-                // can get the actual item that was written, which we need to understand where the
-                // next writer record ID should be.
+                // and the checksum matching, etc.  We only need the number of events it holds on
+                // top of its ID to understand where the next writer record ID should be, which we
+                // can get without opening the record if it was sealed, so that the buffer can still
+                // be opened if the key it was sealed with is no longer provided.
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = decode_record_payload::<T>(record).map_err(|e| {
-                    WriterError::FailedToValidate {
+                let record_events =
+                    record_event_count::<T>(record).map_err(|e| WriterError::FailedToValidate {
                         reason: e.to_string(),
-                    }
-                })?;
+                    })?;
 
                 // Since we have a valid record, checksum and all, see if the writer record ID
                 // in the ledger lines up with the record ID we have here.  Specifically, the record
                 // ID plus the number of events in the record should be the next record ID that gets used.
                 let ledger_next = self.ledger.state().get_next_writer_record_id();
-                let record_events =
-                    u64::try_from(item.event_count()).expect("event count should never exceed u64");
                 let record_next = last_record_id.wrapping_add(record_events);
 
                 match ledger_next.cmp(&record_next) {
@@ -1126,6 +1181,8 @@ where
                     self.config.write_buffer_size,
                     self.config.max_data_file_size,
                     self.config.max_record_size,
+                    self.config.encryption.clone(),
+                    self.config.compression,
                 ));
                 self.data_file_size = data_file_size;
 
@@ -1300,6 +1357,39 @@ where
         }
     }
 
+    /// Writes a record, evicting the oldest unread data files to make room for it if necessary.
+    ///
+    /// If the record was written successfully, the number of bytes written to the data file will be
+    /// returned.  If the buffer is full, and no data file could be evicted, the original record will
+    /// be returned instead.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurred while writing the record, or while evicting a data file, an error
+    /// variant will be returned describing the error.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn write_record_dropping_oldest(
+        &mut self,
+        mut record: T,
+    ) -> Result<Result<usize, T>, WriterError<T>> {
+        loop {
+            match self.try_write_record_inner(record).await? {
+                Ok(bytes_written) => return Ok(Ok(bytes_written)),
+                Err(old_record) => {
+                    record = old_record;
+                    if !self
+                        .ledger
+                        .evict_oldest_unread_data_file()
+                        .await
+                        .context(IoSnafu)?
+                    {
+                        return Ok(Err(record));
+                    }
+                }
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "debug")]
     async fn flush_inner(&mut self, force_full_flush: bool) -> io::Result<()> {
         // We always flush the `BufWriter` when this is called, but we don't always flush to disk or
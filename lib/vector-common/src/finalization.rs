@@ -3,7 +3,14 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    cmp,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    task::Poll,
+};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -86,6 +93,15 @@ impl EventFinalizers {
         }
     }
 
+    /// Records the reason for the failure of all event finalizers in the collection.
+    ///
+    /// Only the first reason recorded for a given batch is kept.
+    pub fn set_failure_reason(&self, reason: &Arc<str>) {
+        for finalizer in &self.0 {
+            finalizer.batch.set_failure_reason(reason);
+        }
+    }
+
     /// Consumes all event finalizers and updates their underlying batches immediately.
     pub fn update_sources(&mut self) {
         let finalizers = mem::take(&mut self.0);
@@ -160,14 +176,14 @@ impl Drop for EventFinalizer {
 /// A convenience newtype wrapper for the one-shot receiver for an
 /// individual batch status.
 #[pin_project::pin_project]
-pub struct BatchStatusReceiver(oneshot::Receiver<BatchStatus>);
+pub struct BatchStatusReceiver(oneshot::Receiver<(BatchStatus, Option<Arc<str>>)>);
 
 impl Future for BatchStatusReceiver {
     type Output = BatchStatus;
     fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         match self.0.poll_unpin(ctx) {
             Poll::Pending => Poll::Pending,
-            Poll::Ready(Ok(status)) => Poll::Ready(status),
+            Poll::Ready(Ok((status, _))) => Poll::Ready(status),
             Poll::Ready(Err(error)) => {
                 error!(%error, "Batch status receiver dropped before sending.");
                 Poll::Ready(BatchStatus::Errored)
@@ -184,7 +200,19 @@ impl BatchStatusReceiver {
     /// - `TryRecvError::Empty` if no value has been sent yet.
     /// - `TryRecvError::Closed` if the sender has dropped without sending a value.
     pub fn try_recv(&mut self) -> Result<BatchStatus, oneshot::error::TryRecvError> {
-        self.0.try_recv()
+        self.0.try_recv().map(|(status, _)| status)
+    }
+
+    /// Waits for the batch to be finalized, returning its status along with the failure reason
+    /// recorded for it, if any.
+    pub async fn recv_with_reason(self) -> (BatchStatus, Option<Arc<str>>) {
+        match self.0.await {
+            Ok(outcome) => outcome,
+            Err(error) => {
+                error!(%error, "Batch status receiver dropped before sending.");
+                (BatchStatus::Errored, None)
+            }
+        }
     }
 }
 
@@ -201,6 +229,7 @@ impl BatchNotifier {
         let (sender, receiver) = oneshot::channel();
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
+            reason: OnceLock::new(),
             notifier: Some(sender),
         };
         (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
@@ -250,13 +279,19 @@ impl BatchNotifier {
                 .unwrap_or_else(|_| unreachable!());
         }
     }
+
+    /// Records the reason for the failure of the batch, unless one was already recorded.
+    fn set_failure_reason(&self, reason: &Arc<str>) {
+        _ = self.0.reason.set(Arc::clone(reason));
+    }
 }
 
 /// The non-shared data underlying the shared `BatchNotifier`
 #[derive(Debug)]
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
-    notifier: Option<oneshot::Sender<BatchStatus>>,
+    reason: OnceLock<Arc<str>>,
+    notifier: Option<oneshot::Sender<(BatchStatus, Option<Arc<str>>)>>,
 }
 
 impl OwnedBatchNotifier {
@@ -264,9 +299,10 @@ impl OwnedBatchNotifier {
     fn send_status(&mut self) {
         if let Some(notifier) = self.notifier.take() {
             let status = self.status.load();
+            let reason = self.reason.take();
             // Ignore the error case, as it will happen during normal
             // source shutdown and we can't detect that here.
-            _ = notifier.send(status);
+            _ = notifier.send((status, reason));
         }
     }
 }
@@ -507,6 +543,18 @@ mod tests {
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
     }
 
+    #[tokio::test]
+    async fn sends_failure_reason() {
+        let (mut fin, receiver) = make_finalizer();
+        fin.update_status(EventStatus::Rejected);
+        fin.set_failure_reason(&Arc::from("first"));
+        fin.set_failure_reason(&Arc::from("second"));
+        fin.update_sources();
+        let (status, reason) = receiver.recv_with_reason().await;
+        assert_eq!(status, BatchStatus::Rejected);
+        assert_eq!(reason.as_deref(), Some("first"));
+    }
+
     fn make_finalizer() -> (EventFinalizers, BatchStatusReceiver) {
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         let finalizer = EventFinalizers::new(EventFinalizer::new(batch));
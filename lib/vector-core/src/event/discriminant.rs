@@ -35,6 +35,18 @@ impl Discriminant {
             .collect();
         Self { values }
     }
+
+    /// The values of the discriminant fields, in the order the fields were given. Fields that were
+    /// not present in the event are `None`.
+    pub fn values(&self) -> &[Option<Value>] {
+        &self.values
+    }
+}
+
+impl From<Vec<Option<Value>>> for Discriminant {
+    fn from(values: Vec<Option<Value>>) -> Self {
+        Self { values }
+    }
 }
 
 impl PartialEq for Discriminant {
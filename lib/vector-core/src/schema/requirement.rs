@@ -15,6 +15,9 @@ use super::Definition;
 pub struct Requirement {
     /// Semantic meanings configured for this requirement.
     meaning: BTreeMap<String, SemanticMeaning>,
+
+    /// Event fields configured for this requirement.
+    fields: BTreeMap<OwnedTargetPath, Field>,
 }
 
 /// The semantic meaning of an event.
@@ -31,6 +34,20 @@ struct SemanticMeaning {
     optional: bool,
 }
 
+/// A field the component reads from each event.
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    /// The type of values the component can handle at this field.
+    kind: Kind,
+
+    /// Whether the field is optional.
+    ///
+    /// A required field must be able to exist in the provided `Definition`. Both required and
+    /// optional fields are invalid if the `Definition` only allows values that can never match
+    /// the configured kind.
+    optional: bool,
+}
+
 impl Requirement {
     /// Create a new empty schema.
     ///
@@ -38,16 +55,16 @@ impl Requirement {
     pub fn empty() -> Self {
         Self {
             meaning: BTreeMap::default(),
+            fields: BTreeMap::default(),
         }
     }
 
     /// Check if the requirement is "empty", meaning:
     ///
-    /// 1. There are no required fields defined.
-    /// 2. The unknown fields are set to "any".
-    /// 3. There are no required meanings defined.
+    /// 1. There are no fields defined.
+    /// 2. There are no meanings defined.
     pub fn is_empty(&self) -> bool {
-        self.meaning.is_empty()
+        self.meaning.is_empty() && self.fields.is_empty()
     }
 
     /// Add a restriction to the schema.
@@ -73,6 +90,43 @@ impl Requirement {
         self.meaning.insert(identifier.into(), meaning);
     }
 
+    /// Remove all semantic meanings from the requirement, keeping only its fields.
+    #[must_use]
+    pub fn without_meanings(mut self) -> Self {
+        self.meaning.clear();
+        self
+    }
+
+    /// Add a field the event must be able to contain.
+    #[must_use]
+    pub fn required_field(mut self, path: OwnedTargetPath, kind: Kind) -> Self {
+        self.fields.insert(
+            path,
+            Field {
+                kind,
+                optional: false,
+            },
+        );
+        self
+    }
+
+    /// Add a field the event may contain.
+    ///
+    /// This differs from `required_field` in that it is valid for the field to be absent from the
+    /// event, but invalid for the field to only ever hold values not matching the configured
+    /// [`Kind`].
+    #[must_use]
+    pub fn optional_field(mut self, path: OwnedTargetPath, kind: Kind) -> Self {
+        self.fields.insert(
+            path,
+            Field {
+                kind,
+                optional: true,
+            },
+        );
+        self
+    }
+
     /// Validate the provided [`Definition`] against the current requirement.
     /// If `validate_schema_type` is true, validation ensure the types match,
     /// otherwise it only ensures the required fields exist.
@@ -142,6 +196,54 @@ impl Requirement {
             Err(ValidationErrors(errors))
         }
     }
+
+    /// Validate the fields of the provided [`Definition`] against the current requirement.
+    ///
+    /// Since most definitions only loosely describe the events flowing through a topology, this
+    /// only reports fields that are guaranteed to be wrong: required fields the definition never
+    /// contains, and fields whose values can never be of the required kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns a list of errors if validation fails.
+    pub fn validate_fields(&self, definition: &Definition) -> Result<(), ValidationErrors> {
+        let mut errors = vec![];
+
+        for (path, field) in &self.fields {
+            let definition_kind = definition.kind_at(path);
+
+            if definition_kind.is_undefined() {
+                if !field.optional {
+                    errors.push(ValidationError::FieldMissing { path: path.clone() });
+                }
+            } else if !kinds_overlap(&field.kind, &definition_kind) {
+                errors.push(ValidationError::FieldKind {
+                    path: path.clone(),
+                    want: field.kind.clone(),
+                    got: definition_kind,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+}
+
+/// Checks if a value can be of both kinds at the same time.
+fn kinds_overlap(a: &Kind, b: &Kind) -> bool {
+    (a.contains_bytes() && b.contains_bytes())
+        || (a.contains_integer() && b.contains_integer())
+        || (a.contains_float() && b.contains_float())
+        || (a.contains_boolean() && b.contains_boolean())
+        || (a.contains_timestamp() && b.contains_timestamp())
+        || (a.contains_regex() && b.contains_regex())
+        || (a.contains_null() && b.contains_null())
+        || (a.contains_array() && b.contains_array())
+        || (a.contains_object() && b.contains_object())
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -156,6 +258,14 @@ impl ValidationErrors {
         self.0.iter().any(ValidationError::is_meaning_kind)
     }
 
+    pub fn is_field_missing(&self) -> bool {
+        self.0.iter().any(ValidationError::is_field_missing)
+    }
+
+    pub fn is_field_kind(&self) -> bool {
+        self.0.iter().any(ValidationError::is_field_kind)
+    }
+
     pub fn errors(&self) -> &[ValidationError] {
         &self.0
     }
@@ -195,6 +305,16 @@ pub enum ValidationError {
         identifier: String,
         paths: BTreeSet<OwnedTargetPath>,
     },
+
+    /// A required field is never present.
+    FieldMissing { path: OwnedTargetPath },
+
+    /// A field can never hold a value of the required `[Kind]`.
+    FieldKind {
+        path: OwnedTargetPath,
+        want: Kind,
+        got: Kind,
+    },
 }
 
 impl ValidationError {
@@ -209,6 +329,14 @@ impl ValidationError {
     pub fn is_meaning_duplicate(&self) -> bool {
         matches!(self, Self::MeaningDuplicate { .. })
     }
+
+    pub fn is_field_missing(&self) -> bool {
+        matches!(self, Self::FieldMissing { .. })
+    }
+
+    pub fn is_field_kind(&self) -> bool {
+        matches!(self, Self::FieldKind { .. })
+    }
 }
 
 impl std::fmt::Display for ValidationError {
@@ -235,6 +363,10 @@ impl std::fmt::Display for ValidationError {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Self::FieldMissing { path } => write!(f, "missing required field: {path}"),
+            Self::FieldKind { path, want, got } => {
+                write!(f, "invalid field kind: {path} (expected {want}, got {got})")
+            }
         }
     }
 }
@@ -408,4 +540,112 @@ mod tests {
             assert_eq!(got, want, "{title}");
         }
     }
+
+    #[test]
+    fn test_validate_fields() {
+        struct TestCase {
+            requirement: Requirement,
+            definition: Definition,
+            errors: Vec<ValidationError>,
+        }
+
+        for (
+            title,
+            TestCase {
+                requirement,
+                definition,
+                errors,
+            },
+        ) in HashMap::from([
+            (
+                "empty",
+                TestCase {
+                    requirement: Requirement::empty(),
+                    definition: Definition::empty_legacy_namespace(),
+                    errors: vec![],
+                },
+            ),
+            (
+                "missing required field",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .required_field(parse_target_path("foo").unwrap(), Kind::any()),
+                    definition: Definition::empty_legacy_namespace(),
+                    errors: vec![ValidationError::FieldMissing {
+                        path: parse_target_path("foo").unwrap(),
+                    }],
+                },
+            ),
+            (
+                "missing optional field",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .optional_field(parse_target_path("foo").unwrap(), Kind::any()),
+                    definition: Definition::empty_legacy_namespace(),
+                    errors: vec![],
+                },
+            ),
+            (
+                "unknown required field",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .required_field(parse_target_path("foo").unwrap(), Kind::bytes()),
+                    definition: Definition::default_legacy_namespace(),
+                    errors: vec![],
+                },
+            ),
+            (
+                "maybe missing required field",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .required_field(parse_target_path("foo").unwrap(), Kind::bytes()),
+                    definition: Definition::empty_legacy_namespace().with_event_field(
+                        &owned_value_path!("foo"),
+                        Kind::bytes().or_undefined(),
+                        None,
+                    ),
+                    errors: vec![],
+                },
+            ),
+            (
+                "invalid field kind",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .optional_field(parse_target_path("foo").unwrap(), Kind::bytes()),
+                    definition: Definition::empty_legacy_namespace().with_event_field(
+                        &owned_value_path!("foo"),
+                        Kind::integer(),
+                        None,
+                    ),
+                    errors: vec![ValidationError::FieldKind {
+                        path: parse_target_path("foo").unwrap(),
+                        want: Kind::bytes(),
+                        got: Kind::integer(),
+                    }],
+                },
+            ),
+            (
+                "partially matching field kind",
+                TestCase {
+                    requirement: Requirement::empty()
+                        .required_field(parse_target_path("foo").unwrap(), Kind::bytes()),
+                    definition: Definition::empty_legacy_namespace().with_event_field(
+                        &owned_value_path!("foo"),
+                        Kind::integer().or_bytes(),
+                        None,
+                    ),
+                    errors: vec![],
+                },
+            ),
+        ]) {
+            let got = requirement.validate_fields(&definition);
+            let want = if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationErrors(errors))
+            };
+
+            assert_eq!(got, want, "{title}");
+        }
+    }
 }
@@ -29,6 +29,7 @@ pub enum Transform {
     Function(Box<dyn FunctionTransform>),
     Synchronous(Box<dyn SyncTransform>),
     Task(Box<dyn TaskTransform<EventArray>>),
+    MultiOutputTask(Box<dyn MultiOutputTaskTransform>),
 }
 
 impl Transform {
@@ -79,6 +80,18 @@ impl Transform {
         Transform::Task(Box::new(WrapEventTask(v)))
     }
 
+    /// Create a new task transform that can write to multiple outputs.
+    ///
+    /// Like [`Transform::event_task`], but each emitted event is paired with the output it is
+    /// written to. Those outputs must be known in advance and returned via
+    /// `TransformConfig::outputs`. Attempting to send to any output not registered in advance is
+    /// considered a bug and will cause a panic.
+    ///
+    /// **Note:** You should prefer to implement [`SyncTransform`] over this where possible.
+    pub fn multi_output_task(v: impl MultiOutputTaskTransform + 'static) -> Self {
+        Transform::MultiOutputTask(Box::new(v))
+    }
+
     /// Transmute the inner transform into a task transform.
     ///
     /// # Panics
@@ -137,6 +150,22 @@ pub trait TaskTransform<T: EventContainer + 'static>: Send + 'static {
     }
 }
 
+/// Task transforms that write to multiple outputs.
+///
+/// Each event of the output stream is paired with the name of the output it is written to, or
+/// `None` for the default output.
+///
+/// This covers transforms that need both named outputs and to emit events on their own, such as
+/// when a time window expires. A [`TaskTransform`] owns its output stream, but that stream is only
+/// connected to the default output, while a [`SyncTransform`] can write to named outputs, but
+/// only when it is handed an incoming event.
+pub trait MultiOutputTaskTransform: Send + 'static {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = (Option<&'static str>, Event)> + Send>>;
+}
+
 /// Broader than the simple [`FunctionTransform`], this trait allows transforms to write to
 /// multiple outputs. Those outputs must be known in advanced and returned via
 /// `TransformConfig::outputs`. Attempting to send to any output not registered in advance is
@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, fmt, future::poll_fn, task::Poll};
+use std::{collections::VecDeque, fmt, future::poll_fn, sync::Arc, task::Poll};
 
 use futures::{poll, FutureExt, Stream, StreamExt, TryFutureExt};
 use tokio::{pin, select};
@@ -24,6 +24,25 @@ pub trait DriverResponse {
     fn bytes_sent(&self) -> Option<usize> {
         None
     }
+
+    /// Return a description of why the request that returned this response was not delivered,
+    /// such as the status and body of an error response.
+    ///
+    /// This is recorded on the finalizers of the request so it can be attached to events that are
+    /// routed to a dead-letter sink.
+    fn failure_reason(&self) -> Option<String> {
+        None
+    }
+
+    /// Finalize the events of the request that returned this response individually, for responses
+    /// reporting the outcome of each event, such as the responses to bulk requests. Returns the
+    /// number of events that were rejected.
+    ///
+    /// This is called before the finalizers of all the events of the request are updated with
+    /// `event_status`, which does not override the status of the events rejected here.
+    fn finalize_events(&self) -> usize {
+        0
+    }
 }
 
 /// Drives the interaction between a stream of items and a service which processes them
@@ -205,11 +224,16 @@ where
     ) {
         match result {
             Err(error) => {
+                finalizers.set_failure_reason(&Arc::from(format!("{error:?}")));
                 Self::emit_call_error(Some(error), request_id, event_count);
                 finalizers.update_status(EventStatus::Rejected);
             }
             Ok(response) => {
                 trace!(message = "Service call succeeded.", request_id);
+                let rejected = response.finalize_events();
+                if rejected > 0 {
+                    Self::emit_call_error(None, request_id, rejected);
+                }
                 finalizers.update_status(response.event_status());
                 if response.event_status() == EventStatus::Delivered {
                     if let Some(bytes_sent) = bytes_sent {
@@ -222,6 +246,9 @@ where
 
                 // This condition occurs specifically when the `HttpBatchService::call()` is called *within* the `Service::call()`
                 } else if response.event_status() == EventStatus::Rejected {
+                    if let Some(reason) = response.failure_reason() {
+                        finalizers.set_failure_reason(&Arc::from(reason));
+                    }
                     Self::emit_call_error(None, request_id, event_count);
                     finalizers.update_status(EventStatus::Rejected);
                 }
@@ -14,13 +14,16 @@ impl<T> Emitter<T> {
     pub fn emit(&mut self, value: T) {
         self.values.push(value);
     }
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
 }
 
 /// Similar to `stream.filter_map(..).flatten(..)` but also allows checking for expired events
 /// and flushing when the input stream ends.
-pub fn map_with_expiration<S, T, M, E, F>(
+pub fn map_with_expiration<S, I, T, M, E, F>(
     initial_state: S,
-    input: impl Stream<Item = T> + 'static,
+    input: impl Stream<Item = I> + 'static,
     expiration_interval: Duration,
     // called for each event
     mut map_fn: M,
@@ -30,7 +33,7 @@ pub fn map_with_expiration<S, T, M, E, F>(
     mut flush_fn: F,
 ) -> impl Stream<Item = T>
 where
-    M: FnMut(&mut S, T, &mut Emitter<T>),
+    M: FnMut(&mut S, I, &mut Emitter<T>),
     E: FnMut(&mut S, &mut Emitter<T>),
     F: FnMut(&mut S, &mut Emitter<T>),
 {
@@ -75,7 +78,7 @@ mod test {
     async fn test_simple() {
         let input = futures::stream::iter([1, 2, 3]);
 
-        let map_fn = |state: &mut i32, event, emitter: &mut Emitter<i32>| {
+        let map_fn = |state: &mut i32, event: i32, emitter: &mut Emitter<i32>| {
             *state += event;
             emitter.emit(*state);
         };
@@ -105,7 +108,7 @@ mod test {
         // an input that never ends (to test expiration)
         let input = futures::stream::iter([1, 2, 3]).chain(futures::stream::pending());
 
-        let map_fn = |state: &mut i32, event, emitter: &mut Emitter<i32>| {
+        let map_fn = |state: &mut i32, event: i32, emitter: &mut Emitter<i32>| {
             *state += event;
             emitter.emit(*state);
         };
@@ -0,0 +1,403 @@
+#![allow(missing_docs)]
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use futures::{stream, StreamExt};
+use vector_lib::{
+    buffers::BufferInspector,
+    config::proxy::ProxyConfig,
+    finalization::{AddBatchNotifier, BatchNotifier, BatchStatus},
+};
+
+use crate::{
+    cli::handle_config_errors,
+    config::{self, BoxedSink, ComponentKey, Config, SinkContext},
+    event::{Event, EventArray, EventContainer, EventRef},
+    signal,
+    topology::builder::filter_events_type,
+};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    #[command(subcommand)]
+    sub_command: SubCommand,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+enum SubCommand {
+    /// Report the number of records and events in a disk buffer, along with their size on disk
+    /// and the oldest and newest event timestamps.
+    Inspect(BufferOpts),
+
+    /// Write every event in a disk buffer to stdout, as newline-delimited JSON.
+    Dump(BufferOpts),
+
+    /// Send every event in a disk buffer to a sink, then exit.
+    Replay(ReplayOpts),
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct BufferOpts {
+    /// ID of the sink whose buffer to open.
+    sink_id: String,
+
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    /// If zero files are specified the default config path
+    /// `/etc/vector/vector.yaml` will be targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    paths: Vec<PathBuf>,
+
+    /// Vector config files in TOML format.
+    #[arg(id = "config-toml", long, value_delimiter(','))]
+    paths_toml: Vec<PathBuf>,
+
+    /// Vector config files in JSON format.
+    #[arg(id = "config-json", long, value_delimiter(','))]
+    paths_json: Vec<PathBuf>,
+
+    /// Vector config files in YAML format.
+    #[arg(id = "config-yaml", long, value_delimiter(','))]
+    paths_yaml: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    config_dirs: Vec<PathBuf>,
+
+    /// The data directory of the Vector instance that owns the buffer. Defaults to the `data_dir`
+    /// set in the configuration.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+impl BufferOpts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        config::merge_path_lists(vec![
+            (&self.paths, None),
+            (&self.paths_toml, Some(config::Format::Toml)),
+            (&self.paths_json, Some(config::Format::Json)),
+            (&self.paths_yaml, Some(config::Format::Yaml)),
+        ])
+        .map(|(path, hint)| config::ConfigPath::File(path, hint))
+        .chain(
+            self.config_dirs
+                .iter()
+                .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+        )
+        .collect()
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct ReplayOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// Configuration file for the sink to send the events to, containing a single sink
+    /// definition. File format is detected from the file name. Secrets are retrieved from the
+    /// secret backends of the configuration.
+    #[arg(long)]
+    to: PathBuf,
+}
+
+/// Number of events, and read errors, seen while replaying a disk buffer.
+#[derive(Debug, Default)]
+struct ReplayCounts {
+    replayed_events: AtomicUsize,
+    skipped_events: AtomicUsize,
+    read_errors: AtomicUsize,
+}
+
+/// Summary of the contents of a disk buffer.
+#[derive(Debug, Default)]
+struct BufferStats {
+    records: usize,
+    events: usize,
+    bytes: usize,
+    read_errors: usize,
+    oldest_timestamp: Option<DateTime<Utc>>,
+    newest_timestamp: Option<DateTime<Utc>>,
+}
+
+impl BufferStats {
+    fn add_record(&mut self, size: usize, events: &EventArray) {
+        self.records += 1;
+        self.events += events.len();
+        self.bytes += size;
+
+        for timestamp in events.iter_events().filter_map(event_timestamp) {
+            self.oldest_timestamp = Some(
+                self.oldest_timestamp
+                    .map_or(timestamp, |t| t.min(timestamp)),
+            );
+            self.newest_timestamp = Some(
+                self.newest_timestamp
+                    .map_or(timestamp, |t| t.max(timestamp)),
+            );
+        }
+    }
+}
+
+fn event_timestamp(event: EventRef<'_>) -> Option<DateTime<Utc>> {
+    match event {
+        EventRef::Log(log) => log.get_timestamp().and_then(|v| v.as_timestamp()).copied(),
+        EventRef::Metric(metric) => metric.timestamp(),
+        EventRef::Trace(_) => None,
+    }
+}
+
+pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exitcode::ExitCode {
+    match &opts.sub_command {
+        SubCommand::Inspect(opts) => inspect(opts, signal_handler).await,
+        SubCommand::Dump(opts) => dump(opts, signal_handler).await,
+        SubCommand::Replay(opts) => replay(opts, signal_handler).await,
+    }
+}
+
+/// Loads the configuration and opens the disk buffer of the given sink for inspection.
+async fn open_buffer(
+    opts: &BufferOpts,
+    signal_handler: &mut signal::SignalHandler,
+) -> Result<(Config, BufferInspector<EventArray>), exitcode::ExitCode> {
+    let paths = opts.paths_with_formats();
+    let paths = config::process_paths(&paths).ok_or(exitcode::CONFIG)?;
+
+    let config = config::load_from_paths_with_provider_and_secrets(&paths, signal_handler, false)
+        .await
+        .map_err(handle_config_errors)?;
+
+    let Some(sink) = config.sink(&ComponentKey::from(opts.sink_id.as_str())) else {
+        error!(message = "Sink not found in configuration.", sink_id = %opts.sink_id);
+        return Err(exitcode::CONFIG);
+    };
+
+    // Buffers are always built with the global data directory, so that's where we look for them.
+    let data_dir = opts.data_dir.as_ref().or(config.global.data_dir.as_ref());
+    match sink
+        .buffer
+        .inspect(data_dir.map(PathBuf::as_path), &opts.sink_id)
+        .await
+    {
+        Ok(inspector) => Ok((config, inspector)),
+        Err(error) => {
+            error!(message = "Failed to open buffer.", sink_id = %opts.sink_id, %error);
+            Err(exitcode::NOINPUT)
+        }
+    }
+}
+
+#[allow(clippy::print_stdout)]
+async fn inspect(
+    opts: &BufferOpts,
+    signal_handler: &mut signal::SignalHandler,
+) -> exitcode::ExitCode {
+    let mut inspector = match open_buffer(opts, signal_handler).await {
+        Ok((_, inspector)) => inspector,
+        Err(code) => return code,
+    };
+
+    let mut stats = BufferStats::default();
+    loop {
+        match inspector.next().await {
+            Ok(Some(record)) => stats.add_record(record.size, &record.item),
+            Ok(None) => break,
+            Err(error) => {
+                error!(message = "Failed to read record from buffer.", %error);
+                stats.read_errors += 1;
+            }
+        }
+    }
+
+    let format_timestamp = |timestamp: Option<DateTime<Utc>>| {
+        timestamp.map_or_else(|| "-".to_string(), |t| t.to_rfc3339())
+    };
+
+    println!("Buffer: {}", inspector.data_dir().display());
+    println!("Records: {}", stats.records);
+    println!("Events: {}", stats.events);
+    println!("Size: {} bytes", stats.bytes);
+    println!("Oldest event: {}", format_timestamp(stats.oldest_timestamp));
+    println!("Newest event: {}", format_timestamp(stats.newest_timestamp));
+    println!(
+        "Unacknowledged events in ledger: {}",
+        inspector.pending_events()
+    );
+    println!("Read errors: {}", stats.read_errors);
+
+    if stats.read_errors == 0 {
+        exitcode::OK
+    } else {
+        exitcode::DATAERR
+    }
+}
+
+async fn dump(opts: &BufferOpts, signal_handler: &mut signal::SignalHandler) -> exitcode::ExitCode {
+    let mut inspector = match open_buffer(opts, signal_handler).await {
+        Ok((_, inspector)) => inspector,
+        Err(code) => return code,
+    };
+
+    let mut read_errors = 0;
+    loop {
+        let events = match inspector.next().await {
+            Ok(Some(record)) => record.item,
+            Ok(None) => break,
+            Err(error) => {
+                error!(message = "Failed to read record from buffer.", %error);
+                read_errors += 1;
+                continue;
+            }
+        };
+
+        let mut stdout = io::stdout().lock();
+        for event in events.into_events() {
+            let result = match &event {
+                Event::Log(log) => serde_json::to_writer(&mut stdout, log),
+                Event::Metric(metric) => serde_json::to_writer(&mut stdout, metric),
+                Event::Trace(trace) => serde_json::to_writer(&mut stdout, trace),
+            }
+            .map_err(io::Error::from)
+            .and_then(|()| stdout.write_all(b"\n"));
+            if let Err(error) = result {
+                error!(message = "Failed to write event.", %error);
+                return exitcode::IOERR;
+            }
+        }
+    }
+
+    if read_errors == 0 {
+        exitcode::OK
+    } else {
+        exitcode::DATAERR
+    }
+}
+
+#[allow(clippy::print_stdout)]
+async fn replay(
+    opts: &ReplayOpts,
+    signal_handler: &mut signal::SignalHandler,
+) -> exitcode::ExitCode {
+    // The sink can refer to the secret backends configured for the Vector instance.
+    let paths = opts.buffer.paths_with_formats();
+    let Some(paths) = config::process_paths(&paths) else {
+        return exitcode::CONFIG;
+    };
+    let target = config::load_component_from_path_with_secrets::<BoxedSink>(
+        &opts.to,
+        &paths,
+        signal_handler,
+    )
+    .await;
+    let target = match target {
+        Ok(target) => target,
+        Err(errors) => return handle_config_errors(errors),
+    };
+
+    let (config, inspector) = match open_buffer(&opts.buffer, signal_handler).await {
+        Ok(opened) => opened,
+        Err(code) => return code,
+    };
+
+    let cx = SinkContext {
+        globals: config.global.clone(),
+        proxy: ProxyConfig::merge_with_env(&config.global.proxy, &ProxyConfig::default()),
+        schema: config.schema,
+        ..Default::default()
+    };
+    let (sink, healthcheck) = match target.build(cx).await {
+        Ok(built) => built,
+        Err(error) => {
+            error!(message = "Failed to build sink.", %error);
+            return exitcode::CONFIG;
+        }
+    };
+    if let Err(error) = healthcheck.await {
+        error!(message = "Sink healthcheck failed.", %error);
+        return exitcode::UNAVAILABLE;
+    }
+
+    // All events share a single batch notifier, so that once the sink is done we know whether or
+    // not every single event was delivered.
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let input_type = target.input().data_type();
+    let counts = Arc::new(ReplayCounts::default());
+
+    let input = stream::unfold(
+        (inspector, batch, Arc::clone(&counts)),
+        move |(mut inspector, batch, counts)| async move {
+            loop {
+                match inspector.next().await {
+                    Ok(Some(record)) => {
+                        let mut events = record.item;
+                        if !filter_events_type(&events, input_type) {
+                            counts
+                                .skipped_events
+                                .fetch_add(events.len(), Ordering::Relaxed);
+                            continue;
+                        }
+
+                        counts
+                            .replayed_events
+                            .fetch_add(events.len(), Ordering::Relaxed);
+                        events.add_batch_notifier(batch.clone());
+                        return Some((events, (inspector, batch, counts)));
+                    }
+                    Ok(None) => return None,
+                    Err(error) => {
+                        error!(message = "Failed to read record from buffer.", %error);
+                        counts.read_errors.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        },
+    );
+
+    let result = sink.run(input).await;
+    let status = receiver.await;
+
+    let replayed_events = counts.replayed_events.load(Ordering::Relaxed);
+    let skipped_events = counts.skipped_events.load(Ordering::Relaxed);
+    let read_errors = counts.read_errors.load(Ordering::Relaxed);
+    println!("Replayed events: {replayed_events}");
+    println!("Skipped events (not accepted by sink): {skipped_events}");
+    println!("Read errors: {read_errors}");
+
+    if result.is_err() || status != BatchStatus::Delivered {
+        error!(
+            message = "Not all events were delivered by the sink.",
+            ?status
+        );
+        exitcode::UNAVAILABLE
+    } else if read_errors > 0 {
+        exitcode::DATAERR
+    } else {
+        exitcode::OK
+    }
+}
@@ -10,7 +10,9 @@ use crate::service;
 use crate::tap;
 #[cfg(feature = "api-client")]
 use crate::top;
-use crate::{config, convert_config, generate, get_version, graph, list, unit_test, validate};
+use crate::{
+    buffer, config, convert_config, generate, get_version, graph, list, unit_test, validate,
+};
 use crate::{generate_schema, signal};
 
 #[derive(Parser, Debug)]
@@ -37,7 +39,8 @@ impl Opts {
             | Some(SubCommand::Generate(_))
             | Some(SubCommand::ConvertConfig(_))
             | Some(SubCommand::List(_))
-            | Some(SubCommand::Test(_)) => {
+            | Some(SubCommand::Test(_))
+            | Some(SubCommand::Buffer(_)) => {
                 if self.root.verbose == 0 {
                     (self.root.quiet + 1, self.root.verbose)
                 } else {
@@ -308,6 +311,12 @@ pub enum SubCommand {
     /// Output the topology as visual representation using the DOT language which can be rendered by GraphViz
     Graph(graph::Opts),
 
+    /// Inspect, dump, or replay the contents of a sink's disk buffer, then exit.
+    ///
+    /// The buffer is opened read-only, so this can be used while Vector is running, and events are
+    /// never removed from the buffer.
+    Buffer(buffer::Opts),
+
     /// Display topology and metrics in the console, for a local or remote Vector instance
     #[cfg(feature = "api-client")]
     Top(top::Opts),
@@ -331,6 +340,7 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            Self::Buffer(b) => buffer::cmd(b, &mut signals.handler).await,
             Self::Config(c) => config::cmd(c),
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
             Self::Generate(g) => generate::cmd(g),
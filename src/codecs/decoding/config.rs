@@ -13,6 +13,9 @@ pub struct DecodingConfig {
     decoding: DeserializerConfig,
     /// The namespace used when decoding.
     log_namespace: LogNamespace,
+    /// Whether frames that fail to decode are emitted as error events.
+    #[serde(default)]
+    reroute_errors: bool,
 }
 
 impl DecodingConfig {
@@ -27,9 +30,17 @@ impl DecodingConfig {
             framing,
             decoding,
             log_namespace,
+            reroute_errors: false,
         }
     }
 
+    /// Sets whether frames that fail to decode are emitted as error events, to be routed to
+    /// the errors output, instead of being dropped.
+    pub const fn with_reroute_errors(mut self, reroute_errors: bool) -> Self {
+        self.reroute_errors = reroute_errors;
+        self
+    }
+
     /// Get the decoding configuration.
     pub const fn config(&self) -> &DeserializerConfig {
         &self.decoding
@@ -48,6 +59,8 @@ impl DecodingConfig {
         // Build the deserializer.
         let deserializer = self.decoding.build()?;
 
-        Ok(Decoder::new(framer, deserializer).with_log_namespace(self.log_namespace))
+        Ok(Decoder::new(framer, deserializer)
+            .with_log_namespace(self.log_namespace)
+            .with_reroute_errors(self.reroute_errors))
     }
 }
@@ -1,16 +1,48 @@
 use bytes::{Bytes, BytesMut};
-use smallvec::SmallVec;
+use smallvec::{smallvec, SmallVec};
 use vector_lib::codecs::decoding::{
-    format::Deserializer as _, BoxedFramingError, BytesDeserializer, Deserializer, Error, Framer,
-    NewlineDelimitedDecoder,
+    format::Deserializer as _, BoxedFramingError, BytesDeserializer, BytesDeserializerConfig,
+    Deserializer, Error, Framer, NewlineDelimitedDecoder,
 };
-use vector_lib::config::LogNamespace;
+use vector_lib::config::{DataType, LogNamespace, SourceOutput};
+use vector_lib::lookup::{event_path, metadata_path, owned_value_path, path};
+use vrl::value::Kind;
 
 use crate::{
     event::Event,
     internal_events::{DecoderDeserializeError, DecoderFramingError},
 };
 
+/// The name of the output receiving frames that failed to be deserialized, for sources that
+/// reroute them.
+pub const DECODING_ERRORS_OUTPUT: &str = "errors";
+
+/// Returns the output receiving frames that failed to be deserialized.
+pub fn decoding_errors_output(log_namespace: LogNamespace) -> SourceOutput {
+    let schema_definition = BytesDeserializerConfig
+        .schema_definition(log_namespace)
+        .unknown_fields(Kind::any())
+        .with_vector_metadata(
+            Some(&owned_value_path!("error")),
+            &owned_value_path!("decoding_error"),
+            Kind::bytes(),
+            None,
+        );
+
+    SourceOutput::new_maybe_logs(DataType::Log, schema_definition).with_port(DECODING_ERRORS_OUTPUT)
+}
+
+/// Checks if the event holds a frame that failed to be deserialized, and should be sent to the
+/// [`DECODING_ERRORS_OUTPUT`] of its source.
+pub fn is_decoding_error(event: &Event) -> bool {
+    event.maybe_as_log().is_some_and(|log| {
+        log.metadata()
+            .value()
+            .get(path!("vector", "decoding_error"))
+            .is_some()
+    })
+}
+
 /// A decoder that can decode structured events from a byte stream / byte
 /// messages.
 #[derive(Clone)]
@@ -21,6 +53,9 @@ pub struct Decoder {
     pub deserializer: Deserializer,
     /// The `log_namespace` being used.
     pub log_namespace: LogNamespace,
+    /// Whether frames that fail to be deserialized are turned into events for the
+    /// [`DECODING_ERRORS_OUTPUT`], instead of being dropped.
+    pub reroute_errors: bool,
 }
 
 impl Default for Decoder {
@@ -29,6 +64,7 @@ impl Default for Decoder {
             framer: Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
             deserializer: Deserializer::Bytes(BytesDeserializer),
             log_namespace: LogNamespace::Legacy,
+            reroute_errors: false,
         }
     }
 }
@@ -42,6 +78,7 @@ impl Decoder {
             framer,
             deserializer,
             log_namespace: LogNamespace::Legacy,
+            reroute_errors: false,
         }
     }
 
@@ -51,6 +88,13 @@ impl Decoder {
         self
     }
 
+    /// Sets whether frames that fail to be deserialized are rerouted to the
+    /// [`DECODING_ERRORS_OUTPUT`].
+    pub const fn with_reroute_errors(mut self, reroute_errors: bool) -> Self {
+        self.reroute_errors = reroute_errors;
+        self
+    }
+
     /// Handles the framing result and parses it into a structured event, if
     /// possible.
     ///
@@ -70,17 +114,38 @@ impl Decoder {
     }
 
     /// Parses a frame using the included deserializer, and handles any errors by logging.
+    ///
+    /// If errors are rerouted, a frame that can't be parsed results in a single event holding the
+    /// frame and the error message, instead of an error.
     pub fn deserializer_parse(&self, frame: Bytes) -> Result<(SmallVec<[Event; 1]>, usize), Error> {
         let byte_size = frame.len();
 
         // Parse structured events from the byte frame.
-        self.deserializer
-            .parse(frame, self.log_namespace)
-            .map(|events| (events, byte_size))
-            .map_err(|error| {
+        match self.deserializer.parse(frame.clone(), self.log_namespace) {
+            Ok(events) => Ok((events, byte_size)),
+            Err(error) => {
                 emit!(DecoderDeserializeError { error: &error });
-                Error::ParsingError(error)
-            })
+                if self.reroute_errors {
+                    Ok((smallvec![self.error_event(frame, &error)], byte_size))
+                } else {
+                    Err(Error::ParsingError(error))
+                }
+            }
+        }
+    }
+
+    /// Builds the event sent to the [`DECODING_ERRORS_OUTPUT`] for a frame that failed to be
+    /// deserialized.
+    fn error_event(&self, frame: Bytes, error: &crate::Error) -> Event {
+        let mut log = BytesDeserializer.parse_single(frame, self.log_namespace);
+        let message = error.to_string();
+        if self.log_namespace == LogNamespace::Legacy {
+            log.insert(event_path!("error"), message.clone());
+        }
+        // The error is always kept in the metadata, as this is what routes the event to the
+        // errors output, regardless of the namespace.
+        log.insert(metadata_path!("vector", "decoding_error"), message);
+        log.into()
     }
 }
 
@@ -101,7 +166,7 @@ impl tokio_util::codec::Decoder for Decoder {
 
 #[cfg(test)]
 mod tests {
-    use super::Decoder;
+    use super::{is_decoding_error, Decoder};
     use bytes::Bytes;
     use futures::{stream, StreamExt};
     use tokio_util::{codec::FramedRead, io::StreamReader};
@@ -138,4 +203,38 @@ mod tests {
         let event = next.unwrap().0.pop().unwrap().into_log();
         assert_eq!(event.get("bar").unwrap(), &Value::from(2));
     }
+
+    #[tokio::test]
+    async fn framed_read_reroute_errors() {
+        let iter = stream::iter(
+            ["{ \"foo\": 1 }\n", "invalid\n", "{ \"bar\": 2 }\n"]
+                .into_iter()
+                .map(Bytes::from),
+        );
+        let stream = iter.map(Ok::<_, std::io::Error>);
+        let reader = StreamReader::new(stream);
+        let decoder = Decoder::new(
+            Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
+            Deserializer::Json(JsonDeserializer::default()),
+        )
+        .with_reroute_errors(true);
+        let mut stream = FramedRead::new(reader, decoder);
+
+        let next = stream.next().await.unwrap();
+        let event = next.unwrap().0.pop().unwrap();
+        assert!(!is_decoding_error(&event));
+        assert_eq!(event.as_log().get("foo").unwrap(), &Value::from(1));
+
+        let next = stream.next().await.unwrap();
+        let event = next.unwrap().0.pop().unwrap();
+        assert!(is_decoding_error(&event));
+        let log = event.as_log();
+        assert_eq!(log.get("message").unwrap(), &Value::from("invalid"));
+        assert!(log.get("error").is_some());
+
+        let next = stream.next().await.unwrap();
+        let event = next.unwrap().0.pop().unwrap();
+        assert!(!is_decoding_error(&event));
+        assert_eq!(event.as_log().get("bar").unwrap(), &Value::from(2));
+    }
 }
@@ -2,4 +2,4 @@ mod config;
 mod decoder;
 
 pub use config::DecodingConfig;
-pub use decoder::Decoder;
+pub use decoder::{decoding_errors_output, is_decoding_error, Decoder, DECODING_ERRORS_OUTPUT};
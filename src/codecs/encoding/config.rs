@@ -1,9 +1,14 @@
-use crate::codecs::Transformer;
+#[cfg(feature = "codecs-parquet")]
+use crate::codecs::BatchEncoder;
+use crate::codecs::{Encoder, EncoderKind, Transformer};
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializerConfig;
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig, Serializer, SerializerConfig},
-    CharacterDelimitedEncoder, LengthDelimitedEncoder, NewlineDelimitedEncoder,
+    BytesEncoder, CharacterDelimitedEncoder, LengthDelimitedEncoder, NewlineDelimitedEncoder,
 };
 use vector_lib::configurable::configurable_component;
+use vector_lib::schema;
 
 /// Encoding configuration.
 #[configurable_component]
@@ -36,6 +41,14 @@ impl EncodingConfig {
         &self.encoding
     }
 
+    /// Get the schema requirement for the fields read by the serializer.
+    ///
+    /// Semantic meanings required by the serializer are left out, as they are not enforced for
+    /// sinks.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        self.encoding.schema_requirement().without_meanings()
+    }
+
     /// Build the `Serializer` for this config.
     pub fn build(&self) -> crate::Result<Serializer> {
         self.encoding.build()
@@ -93,6 +106,11 @@ impl EncodingConfigWithFraming {
         (&self.framing, &self.encoding.encoding)
     }
 
+    /// Get the schema requirement for the fields read by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        self.encoding.schema_requirement()
+    }
+
     /// Build the `Framer` and `Serializer` for this config.
     pub fn build(&self, sink_type: SinkType) -> crate::Result<(Framer, Serializer)> {
         let framer = self.framing.as_ref().map(|framing| framing.build());
@@ -112,6 +130,22 @@ impl EncodingConfigWithFraming {
                 // https://github.com/Graylog2/graylog2-server/issues/1240
                 CharacterDelimitedEncoder::new(0).into()
             }
+            (None, Serializer::Cbor(_) | Serializer::Msgpack(_)) => match sink_type {
+                // Both formats are self-delimiting, but streams are length-delimited so that
+                // receivers can split them into frames without decoding them first.
+                SinkType::StreamBased => LengthDelimitedEncoder::default().into(),
+                SinkType::MessageBased => BytesEncoder.into(),
+            },
+            #[cfg(feature = "codecs-opentelemetry")]
+            (None, Serializer::Otlp(serializer)) => match sink_type {
+                // Each message holds a single export request, while streams of Protobuf requests
+                // are length-delimited.
+                SinkType::StreamBased if serializer.is_binary() => {
+                    LengthDelimitedEncoder::default().into()
+                }
+                SinkType::StreamBased => NewlineDelimitedEncoder::default().into(),
+                SinkType::MessageBased => BytesEncoder.into(),
+            },
             (None, Serializer::Protobuf(_)) => {
                 // Protobuf uses length-delimited messages, see:
                 // https://developers.google.com/protocol-buffers/docs/techniques#streaming
@@ -130,6 +164,27 @@ impl EncodingConfigWithFraming {
 
         Ok((framer, serializer))
     }
+
+    /// Build the `EncoderKind` for this config.
+    ///
+    /// If a batch encoding is given, it takes the place of the configured codec and framing.
+    #[cfg(feature = "codecs-parquet")]
+    pub fn build_kind(
+        &self,
+        batch_encoding: Option<&BatchSerializerConfig>,
+        sink_type: SinkType,
+    ) -> crate::Result<EncoderKind> {
+        match batch_encoding {
+            Some(batch_encoding) => Ok(BatchEncoder::new(batch_encoding.build()?).into()),
+            None => self.build_framed_kind(sink_type),
+        }
+    }
+
+    /// Build an `EncoderKind` that encodes and frames each event individually.
+    pub fn build_framed_kind(&self, sink_type: SinkType) -> crate::Result<EncoderKind> {
+        let (framer, serializer) = self.build(sink_type)?;
+        Ok(Encoder::<Framer>::new(framer, serializer).into())
+    }
 }
 
 /// The way a sink processes outgoing events.
@@ -1,5 +1,9 @@
+#[cfg(feature = "codecs-parquet")]
+use bytes::Bytes;
 use bytes::BytesMut;
 use tokio_util::codec::Encoder as _;
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializer;
 use vector_lib::codecs::{
     encoding::{Error, Framer, Serializer},
     CharacterDelimitedEncoder, NewlineDelimitedEncoder, TextSerializerConfig,
@@ -114,6 +118,10 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) | (Serializer::Protobuf(_), _) => "application/octet-stream",
+            (Serializer::Cbor(_), _) => "application/cbor",
+            (Serializer::Msgpack(_), _) => "application/msgpack",
+            #[cfg(feature = "codecs-opentelemetry")]
+            (Serializer::Otlp(serializer), _) => serializer.content_type(),
             (
                 Serializer::Avro(_)
                 | Serializer::Cef(_)
@@ -182,6 +190,74 @@ impl tokio_util::codec::Encoder<Event> for Encoder<()> {
     }
 }
 
+/// An encoder that encodes a whole batch of events at once, such as into a columnar file.
+#[cfg(feature = "codecs-parquet")]
+#[derive(Debug, Clone)]
+pub struct BatchEncoder {
+    serializer: BatchSerializer,
+}
+
+#[cfg(feature = "codecs-parquet")]
+impl BatchEncoder {
+    /// Creates a new `BatchEncoder` with the specified `BatchSerializer`.
+    pub const fn new(serializer: BatchSerializer) -> Self {
+        Self { serializer }
+    }
+
+    /// Get the batch serializer.
+    pub const fn serializer(&self) -> &BatchSerializer {
+        &self.serializer
+    }
+
+    /// Get the HTTP content type.
+    pub const fn content_type(&self) -> &'static str {
+        self.serializer.content_type()
+    }
+
+    /// Encode the batch of events.
+    pub fn encode_batch(&self, events: &[Event]) -> Result<Bytes, Error> {
+        self.serializer.encode_batch(events).map_err(|error| {
+            emit!(EncoderSerializeError { error: &error });
+            Error::SerializingError(error)
+        })
+    }
+}
+
+/// An encoder for sinks that can either encode and frame each event individually, or encode a
+/// whole batch of events at once.
+#[derive(Debug, Clone)]
+pub enum EncoderKind {
+    /// Encodes and frames each event individually.
+    Framed(Box<Encoder<Framer>>),
+    /// Encodes each batch of events at once.
+    #[cfg(feature = "codecs-parquet")]
+    Batch(BatchEncoder),
+}
+
+impl EncoderKind {
+    /// Get the HTTP content type.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            Self::Framed(encoder) => encoder.content_type(),
+            #[cfg(feature = "codecs-parquet")]
+            Self::Batch(encoder) => encoder.content_type(),
+        }
+    }
+}
+
+impl From<Encoder<Framer>> for EncoderKind {
+    fn from(encoder: Encoder<Framer>) -> Self {
+        Self::Framed(Box::new(encoder))
+    }
+}
+
+#[cfg(feature = "codecs-parquet")]
+impl From<BatchEncoder> for EncoderKind {
+    fn from(encoder: BatchEncoder) -> Self {
+        Self::Batch(encoder)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::BufMut;
@@ -3,5 +3,7 @@ mod encoder;
 mod transformer;
 
 pub use config::{EncodingConfig, EncodingConfigWithFraming, SinkType};
-pub use encoder::Encoder;
+#[cfg(feature = "codecs-parquet")]
+pub use encoder::BatchEncoder;
+pub use encoder::{Encoder, EncoderKind};
 pub use transformer::{TimestampFormat, Transformer};
@@ -7,8 +7,13 @@ mod decoding;
 mod encoding;
 mod ready_frames;
 
-pub use decoding::{Decoder, DecodingConfig};
+pub use decoding::{
+    decoding_errors_output, is_decoding_error, Decoder, DecodingConfig, DECODING_ERRORS_OUTPUT,
+};
+#[cfg(feature = "codecs-parquet")]
+pub use encoding::BatchEncoder;
 pub use encoding::{
-    Encoder, EncodingConfig, EncodingConfigWithFraming, SinkType, TimestampFormat, Transformer,
+    Encoder, EncoderKind, EncodingConfig, EncodingConfigWithFraming, SinkType, TimestampFormat,
+    Transformer,
 };
 pub use ready_frames::ReadyFrames;
@@ -8,14 +8,15 @@ use vector_lib::{
     codecs::{
         decoding::{self, DeserializerConfig},
         encoding::{
-            self, Framer, FramingConfig, JsonSerializerConfig, SerializerConfig,
+            self, CefSerializerConfig, CefSerializerOptions, CsvSerializerConfig,
+            CsvSerializerOptions, Framer, FramingConfig, JsonSerializerConfig, SerializerConfig,
             TextSerializerConfig,
         },
         BytesEncoder,
     },
     config::LogNamespace,
 };
-use vector_lib::{config::DataType, event::Event};
+use vector_lib::{config::DataType, event::Event, lookup::lookup_v2::ConfigTargetPath};
 
 use crate::codecs::{Decoder, DecodingConfig, Encoder, EncodingConfig, EncodingConfigWithFraming};
 
@@ -152,13 +153,50 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         // `message` field... but it's close enough for now.
         DeserializerConfig::Bytes => SerializerConfig::Text(TextSerializerConfig::default()),
         DeserializerConfig::Json { .. } => SerializerConfig::Json(JsonSerializerConfig::default()),
+        DeserializerConfig::Csv(config) => {
+            SerializerConfig::Csv(CsvSerializerConfig::new(CsvSerializerOptions {
+                delimiter: config.csv.delimiter,
+                double_quote: config.csv.double_quote,
+                escape: config.csv.escape,
+                fields: config
+                    .csv
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        ConfigTargetPath::try_from(column.clone())
+                            .expect("CSV column should be a valid path")
+                    })
+                    .collect(),
+                ..Default::default()
+            }))
+        }
+        DeserializerConfig::Logfmt(_) => SerializerConfig::Logfmt,
+        DeserializerConfig::Msgpack => SerializerConfig::Msgpack,
+        DeserializerConfig::Cbor => SerializerConfig::Cbor,
+        // TODO: The device header fields and the extensions of the CEF serializer are configured
+        // rather than read from the fields produced by the CEF decoder, so only the name and the
+        // severity of the test events make it through.
+        DeserializerConfig::Cef(_) => {
+            SerializerConfig::Cef(CefSerializerConfig::new(CefSerializerOptions {
+                name: ConfigTargetPath::try_from("name".to_string())
+                    .expect("CEF name should be a valid path"),
+                severity: ConfigTargetPath::try_from("severity".to_string())
+                    .expect("CEF severity should be a valid path"),
+                ..Default::default()
+            }))
+        }
+        // There is no LEEF serializer, so the test events are expected to hold complete LEEF
+        // messages in their `message` field, which are sent as-is.
+        DeserializerConfig::Leef(_) => SerializerConfig::Text(TextSerializerConfig::default()),
         DeserializerConfig::Protobuf(config) => {
-            SerializerConfig::Protobuf(vector_lib::codecs::encoding::ProtobufSerializerConfig {
-                protobuf: vector_lib::codecs::encoding::ProtobufSerializerOptions {
+            SerializerConfig::Protobuf(vector_lib::codecs::encoding::ProtobufSerializerConfig::new(
+                vector_lib::codecs::encoding::ProtobufSerializerOptions {
                     desc_file: config.protobuf.desc_file.clone(),
                     message_type: config.protobuf.message_type.clone(),
+                    #[cfg(feature = "codecs-schema-registry")]
+                    schema_registry: config.protobuf.schema_registry.clone(),
                 },
-            })
+            ))
         }
         // TODO: We need to create an Avro serializer because, certainly, for any source decoding
         // the data as Avro, we can't possibly send anything else without the source just
@@ -200,6 +238,13 @@ fn decoder_framing_to_encoding_framer(framing: &decoding::FramingConfig) -> enco
         decoding::FramingConfig::OctetCounting(_) => todo!(),
         // TODO: chunked gelf is not supported yet in encoding
         decoding::FramingConfig::ChunkedGelf(_) => todo!(),
+        decoding::FramingConfig::VarintLengthDelimited(config) => {
+            encoding::FramingConfig::VarintLengthDelimited(
+                encoding::VarintLengthDelimitedEncoderConfig {
+                    varint_length_delimited: config.varint_length_delimited.clone(),
+                },
+            )
+        }
     };
 
     framing_config.build()
@@ -210,18 +255,35 @@ fn serializer_config_to_deserializer(
 ) -> vector_lib::Result<decoding::Deserializer> {
     let deserializer_config = match config {
         SerializerConfig::Avro { .. } => todo!(),
+        SerializerConfig::Cbor => DeserializerConfig::Cbor,
         SerializerConfig::Cef { .. } => todo!(),
-        SerializerConfig::Csv { .. } => todo!(),
+        SerializerConfig::Csv(config) => DeserializerConfig::Csv(
+            decoding::CsvDeserializerConfig::new(decoding::CsvDeserializerOptions {
+                delimiter: config.csv.delimiter,
+                double_quote: config.csv.double_quote,
+                escape: config.csv.escape,
+                columns: config.csv.fields.iter().map(ToString::to_string).collect(),
+                ..Default::default()
+            }),
+        ),
         SerializerConfig::Gelf => DeserializerConfig::Gelf(Default::default()),
         SerializerConfig::Json(_) => DeserializerConfig::Json(Default::default()),
-        SerializerConfig::Logfmt => todo!(),
+        SerializerConfig::Logfmt => DeserializerConfig::Logfmt(Default::default()),
+        SerializerConfig::Msgpack => DeserializerConfig::Msgpack,
         SerializerConfig::Native => DeserializerConfig::Native,
         SerializerConfig::NativeJson => DeserializerConfig::NativeJson(Default::default()),
+        // There is no OTLP decoder, as the `opentelemetry` source decodes the requests itself.
+        #[cfg(feature = "codecs-opentelemetry")]
+        SerializerConfig::Otlp(_) => {
+            return Err("The `otlp` codec has no decoder to validate it with.".into())
+        }
         SerializerConfig::Protobuf(config) => {
             DeserializerConfig::Protobuf(vector_lib::codecs::decoding::ProtobufDeserializerConfig {
                 protobuf: vector_lib::codecs::decoding::ProtobufDeserializerOptions {
                     desc_file: config.protobuf.desc_file.clone(),
                     message_type: config.protobuf.message_type.clone(),
+                    #[cfg(feature = "codecs-schema-registry")]
+                    schema_registry: config.protobuf.schema_registry.clone(),
                 },
             })
         }
@@ -250,6 +312,13 @@ fn encoder_framing_to_decoding_framer(framing: encoding::FramingConfig) -> decod
         encoding::FramingConfig::NewlineDelimited => {
             decoding::FramingConfig::NewlineDelimited(Default::default())
         }
+        encoding::FramingConfig::VarintLengthDelimited(config) => {
+            decoding::FramingConfig::VarintLengthDelimited(
+                decoding::VarintLengthDelimitedDecoderConfig {
+                    varint_length_delimited: config.varint_length_delimited,
+                },
+            )
+        }
     };
 
     framing_config.build()
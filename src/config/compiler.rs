@@ -133,7 +133,14 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
 
         config.propagate_acknowledgements()?;
 
-        let warnings = validation::warnings(&config);
+        let mut warnings = validation::warnings(&config);
+
+        if let Err(field_errors) = validation::check_sink_fields(&config) {
+            if config.schema.validation {
+                return Err(field_errors);
+            }
+            warnings.extend(field_errors);
+        }
 
         Ok((config, warnings))
     } else {
@@ -68,6 +68,7 @@ where
                     healthcheck: Default::default(),
                     buffer: Default::default(),
                     proxy: Default::default(),
+                    dead_letter: None,
                     inner: sink,
                 },
             )
@@ -239,6 +239,28 @@ pub fn load_secret_backends_from_paths(
     loader_from_paths(SecretBackendLoader::new(), config_paths)
 }
 
+/// Loads a single component, such as a sink, from a file. Secret placeholders are resolved with the
+/// secret backends configured in `config_paths`.
+pub async fn load_component_from_path_with_secrets<T>(
+    path: &Path,
+    config_paths: &[ConfigPath],
+    signal_handler: &mut signal::SignalHandler,
+) -> Result<T, Vec<String>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let format = Format::from_path(path)
+        .map_err(|path| vec![format!("Unable to detect format of config file: {path:?}.")])?;
+    let file = File::open(path).map_err(|err| vec![format!("Could not open {path:?}: {err}")])?;
+    let input = prepare_input(file)?;
+
+    let input = load_secret_backends_from_paths(config_paths)?
+        .interpolate_secrets(&input, &mut signal_handler.subscribe())
+        .await?;
+
+    format::deserialize(&input, format)
+}
+
 pub fn load_from_str(input: &str, format: Format) -> Result<Config, Vec<String>> {
     let builder = load_from_inputs(std::iter::once((input.as_bytes(), format)))?;
     let (config, build_warnings) = builder.build_with_warnings()?;
@@ -85,6 +85,26 @@ impl SecretBackendLoader {
     pub(crate) fn has_secrets_to_retrieve(&self) -> bool {
         !self.secret_keys.is_empty()
     }
+
+    /// Retrieves the secrets referenced by the placeholders in `input` from the loaded backends,
+    /// and replaces these placeholders with their values.
+    ///
+    /// Only the secrets referenced in `input` are retrieved, not the ones collected while loading
+    /// the backends.
+    pub(crate) async fn interpolate_secrets(
+        &mut self,
+        input: &str,
+        signal_rx: &mut signal::SignalRx,
+    ) -> Result<String, Vec<String>> {
+        self.secret_keys.clear();
+        collect_secret_keys(input, &mut self.secret_keys);
+        if !self.has_secrets_to_retrieve() {
+            return Ok(input.to_string());
+        }
+
+        let secrets = self.retrieve(signal_rx).await.map_err(|e| vec![e])?;
+        interpolate(input, &secrets)
+    }
 }
 
 impl Process for SecretBackendLoader {
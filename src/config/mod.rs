@@ -54,16 +54,17 @@ pub use diff::ConfigDiff;
 pub use enrichment_table::{EnrichmentTableConfig, EnrichmentTableOuter};
 pub use format::{Format, FormatHint};
 pub use loading::{
-    load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
-    load_from_str, load_source_from_paths, merge_path_lists, process_paths, COLLECTOR,
-    CONFIG_PATHS,
+    load, load_builder_from_paths, load_component_from_path_with_secrets, load_from_paths,
+    load_from_paths_with_provider_and_secrets, load_from_str, load_source_from_paths,
+    merge_path_lists, process_paths, COLLECTOR, CONFIG_PATHS,
 };
 pub use provider::ProviderConfig;
 pub use secret::SecretBackend;
 pub use sink::{BoxedSink, SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter};
 pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
 pub use transform::{
-    get_transform_output_ids, BoxedTransform, TransformConfig, TransformContext, TransformOuter,
+    get_transform_output_ids, BoxedTransform, TopologyShutdown, TransformConfig, TransformContext,
+    TransformOuter,
 };
 pub use unit_test::{build_unit_tests, build_unit_tests_main, UnitTestResult};
 pub use validation::warnings;
@@ -564,7 +565,7 @@ mod tests {
     use std::{collections::HashMap, path::PathBuf};
 
     use crate::{config, topology};
-    use indoc::indoc;
+    use indoc::{formatdoc, indoc};
 
     use super::{builder::ConfigBuilder, format, load_from_str, ComponentKey, ConfigDiff, Format};
 
@@ -777,6 +778,37 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sink_field_warnings() {
+        let config = |validation: bool| {
+            formatdoc! {r#"
+                [schema]
+                enabled = true
+                validation = {validation}
+
+                [sources.in]
+                type = "file"
+                include = ["/var/log/**/*.log"]
+
+                [sinks.out]
+                type = "console"
+                inputs = ["in"]
+                encoding.codec = "csv"
+                encoding.csv.fields = ["message", "missing"]
+            "#}
+        };
+        let expected =
+            vec![r#"Sink "out" cannot encode events from "in": missing required field: .missing"#];
+
+        let builder: ConfigBuilder = format::deserialize(&config(false), Format::Toml).unwrap();
+        let (_, warnings) = builder.build_with_warnings().unwrap();
+        assert_eq!(warnings, expected);
+
+        let builder: ConfigBuilder = format::deserialize(&config(true), Format::Toml).unwrap();
+        let errors = builder.build_with_warnings().unwrap_err();
+        assert_eq!(errors, expected);
+    }
+
     #[tokio::test]
     async fn cycle() {
         let errors = load(
@@ -13,6 +13,10 @@ pub struct Options {
     pub enabled: bool,
 
     /// Whether or not schema validation is enabled.
+    ///
+    /// When enabled, fields read by a sink's encoder that are missing from the schema of its
+    /// inputs, or that can only hold values of the wrong type, are reported as errors instead of
+    /// warnings.
     #[serde(default = "default_validation")]
     pub validation: bool,
 
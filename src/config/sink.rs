@@ -80,6 +80,20 @@ where
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     pub proxy: ProxyConfig,
 
+    /// A fallback sink that receives the events this sink permanently failed to deliver.
+    ///
+    /// Events rejected by the downstream service with a non-retriable error, such as an HTTP 400
+    /// response, are sent to this sink instead of being dropped. Each event is annotated with the
+    /// status and the reason for the rejection, such as the error returned by the service and
+    /// truncated to 1024 bytes, under the `dead_letter` metadata field. Acknowledgements for these
+    /// events are only sent once the fallback sink has handled them.
+    ///
+    /// The fallback sink reports its internal metrics under the component ID of this sink suffixed
+    /// with `.dead_letter`.
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dead_letter: Option<BoxedSink>,
+
     #[serde(flatten)]
     #[configurable(metadata(docs::hidden))]
     pub inner: BoxedSink,
@@ -101,12 +115,16 @@ where
             healthcheck_uri: None,
             inner: inner.into(),
             proxy: Default::default(),
+            dead_letter: None,
             graph: Default::default(),
         }
     }
 
     pub fn resources(&self, id: &ComponentKey) -> Vec<Resource> {
         let mut resources = self.inner.resources();
+        if let Some(dead_letter) = &self.dead_letter {
+            resources.extend(dead_letter.resources());
+        }
         for stage in self.buffer.stages() {
             match stage {
                 BufferType::Memory { .. } => {}
@@ -158,6 +176,7 @@ where
             healthcheck: self.healthcheck,
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
+            dead_letter: self.dead_letter,
             graph: self.graph,
         }
     }
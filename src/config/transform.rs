@@ -1,5 +1,9 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use async_trait::async_trait;
 use dyn_clone::DynClone;
@@ -136,6 +140,28 @@ pub struct TransformContext {
     /// Extra context data provided by the running app and shared across all components. This can be
     /// used to pass shared settings or other data from outside the components.
     pub extra_context: ExtraContext,
+
+    /// Signals that the topology running the transform is shutting down.
+    pub topology_shutdown: TopologyShutdown,
+}
+
+/// Tells whether the topology running a transform is shutting down.
+///
+/// The input of a transform ends both when the topology shuts down and when the transform is
+/// removed or replaced by a configuration reload. Transforms that keep their state across restarts
+/// can use this to tell the two apart, as their state is only picked up again in the former case.
+#[derive(Clone, Debug, Default)]
+pub struct TopologyShutdown(Arc<AtomicBool>);
+
+impl TopologyShutdown {
+    /// Returns whether the topology has started shutting down.
+    pub fn is_shutting_down(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn trigger(&self) {
+        self.0.store(true, Ordering::Release);
+    }
 }
 
 impl Default for TransformContext {
@@ -148,6 +174,7 @@ impl Default for TransformContext {
             merged_schema_definition: schema::Definition::any(),
             schema: SchemaOptions::default(),
             extra_context: Default::default(),
+            topology_shutdown: Default::default(),
         }
     }
 }
@@ -230,6 +257,19 @@ pub trait TransformConfig: DynClone + NamedComponent + core::fmt::Debug + Send +
         Ok(())
     }
 
+    /// Validates the configuration of the transform against the IDs of its inputs.
+    ///
+    /// This is where options referring to specific inputs of the transform should be checked, once
+    /// any wildcards in `inputs` have been expanded.
+    ///
+    /// # Errors
+    ///
+    /// If validation does not succeed, an error variant containing a list of all validation errors
+    /// is returned.
+    fn validate_inputs(&self, _inputs: &[String]) -> Result<(), Vec<String>> {
+        Ok(())
+    }
+
     /// Whether or not concurrency should be enabled for this transform.
     ///
     /// When enabled, this transform may be run in parallel in order to attempt to maximize
@@ -174,6 +174,10 @@ pub fn check_outputs(config: &ConfigBuilder) -> Result<(), Vec<String>> {
             errors.extend(errs.into_iter().map(|msg| format!("Transform {key} {msg}")));
         }
 
+        if let Err(errs) = transform.inner.validate_inputs(&transform.inputs) {
+            errors.extend(errs.into_iter().map(|msg| format!("Transform {key} {msg}")));
+        }
+
         if get_transform_output_ids(
             transform.inner.as_ref(),
             key.clone(),
@@ -322,6 +326,33 @@ async fn process_partitions(partitions: Vec<Partition>) -> heim::Result<IndexMap
         .await
 }
 
+/// Checks the fields read by each sink against the schema definitions of its inputs.
+///
+/// Only fields that are guaranteed to be missing or to hold values of the wrong type are reported,
+/// so this is only effective if schema support is enabled.
+pub fn check_sink_fields(config: &Config) -> Result<(), Vec<String>> {
+    let mut errors = vec![];
+    let mut cache = HashMap::default();
+
+    for (key, sink) in config.sinks() {
+        if let Err(sink_errors) = crate::topology::schema::validate_sink_fields(
+            key,
+            sink,
+            config,
+            vector_lib::enrichment::TableRegistry::default(),
+            &mut cache,
+        ) {
+            errors.extend(sink_errors);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 pub fn warnings(config: &Config) -> Vec<String> {
     let mut warnings = vec![];
 
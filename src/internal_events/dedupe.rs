@@ -1,3 +1,4 @@
+use metrics::counter;
 use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
 
 #[derive(Debug)]
@@ -13,3 +14,14 @@ impl InternalEvent for DedupeEventsDropped {
         });
     }
 }
+
+#[derive(Debug)]
+pub struct DedupeEventSuppressed {
+    pub key: String,
+}
+
+impl InternalEvent for DedupeEventSuppressed {
+    fn emit(self) {
+        counter!("dedupe_events_suppressed_total", "key" => self.key).increment(1);
+    }
+}
@@ -119,10 +119,20 @@ mod splunk_hec;
 mod statsd_sink;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sampling")]
+mod tail_sampling;
 mod tcp;
 mod template;
 #[cfg(feature = "transforms-throttle")]
 mod throttle;
+#[cfg(feature = "transforms-top_k")]
+mod top_k;
+#[cfg(any(
+    feature = "transforms-impl-dedupe",
+    feature = "transforms-impl-reduce",
+    feature = "transforms-tag_cardinality_limit"
+))]
+mod transform_state;
 mod udp;
 mod unix;
 #[cfg(feature = "sinks-websocket")]
@@ -257,8 +267,18 @@ pub(crate) use self::splunk_hec::*;
 pub(crate) use self::statsd_sink::*;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub(crate) use self::tag_cardinality_limit::*;
+#[cfg(feature = "transforms-tail_sampling")]
+pub(crate) use self::tail_sampling::*;
 #[cfg(feature = "transforms-throttle")]
 pub(crate) use self::throttle::*;
+#[cfg(feature = "transforms-top_k")]
+pub(crate) use self::top_k::*;
+#[cfg(any(
+    feature = "transforms-impl-dedupe",
+    feature = "transforms-impl-reduce",
+    feature = "transforms-tag_cardinality_limit"
+))]
+pub(crate) use self::transform_state::*;
 #[cfg(unix)]
 pub(crate) use self::unix::*;
 #[cfg(feature = "sinks-websocket")]
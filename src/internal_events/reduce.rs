@@ -12,6 +12,17 @@ impl InternalEvent for ReduceStaleEventFlushed {
     }
 }
 
+#[derive(Debug)]
+pub struct ReduceGroupEvicted {
+    pub reason: &'static str,
+}
+
+impl InternalEvent for ReduceGroupEvicted {
+    fn emit(self) {
+        counter!("reduce_groups_evicted_total", "reason" => self.reason).increment(1);
+    }
+}
+
 #[derive(Debug)]
 pub struct ReduceAddEventError {
     pub error: PathParseError,
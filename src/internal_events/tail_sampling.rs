@@ -0,0 +1,15 @@
+use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct TailSamplingTraceDiscarded {
+    pub count: usize,
+}
+
+impl InternalEvent for TailSamplingTraceDiscarded {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "Trace was not sampled.",
+        })
+    }
+}
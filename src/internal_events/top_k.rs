@@ -0,0 +1,18 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+
+pub struct TopKTagValueReplaced<'a> {
+    pub tag_key: &'a str,
+}
+
+impl InternalEvent for TopKTagValueReplaced<'_> {
+    fn emit(self) {
+        trace!(
+            message = "Replacing tag value outside of the top values.",
+            tag_key = self.tag_key,
+            internal_log_rate_limit = true,
+        );
+        counter!("top_k_tag_values_replaced_total", "tag_key" => self.tag_key.to_string())
+            .increment(1);
+    }
+}
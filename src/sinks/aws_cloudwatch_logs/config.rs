@@ -5,7 +5,6 @@ use std::collections::HashMap;
 use tower::ServiceBuilder;
 use vector_lib::codecs::JsonSerializerConfig;
 use vector_lib::configurable::configurable_component;
-use vector_lib::schema;
 use vrl::value::Kind;
 
 use crate::{
@@ -233,8 +232,10 @@ impl SinkConfig for CloudwatchLogsSinkConfig {
     }
 
     fn input(&self) -> Input {
-        let requirement =
-            schema::Requirement::empty().optional_meaning("timestamp", Kind::timestamp());
+        let requirement = self
+            .encoding
+            .schema_requirement()
+            .optional_meaning("timestamp", Kind::timestamp());
 
         Input::new(self.encoding.config().input_type() & DataType::Log)
             .with_schema_requirement(requirement)
@@ -76,6 +76,7 @@ pub struct KinesisSinkBaseConfig {
 impl KinesisSinkBaseConfig {
     pub fn input(&self) -> Input {
         Input::new(self.encoding.config().input_type() & DataType::Log)
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     pub const fn acknowledgements(&self) -> &AcknowledgementsConfig {
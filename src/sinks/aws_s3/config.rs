@@ -1,9 +1,8 @@
 use aws_sdk_s3::Client as S3Client;
 use tower::ServiceBuilder;
-use vector_lib::codecs::{
-    encoding::{Framer, FramingConfig},
-    TextSerializerConfig,
-};
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializerConfig;
+use vector_lib::codecs::{encoding::FramingConfig, TextSerializerConfig};
 use vector_lib::configurable::configurable_component;
 use vector_lib::sink::VectorSink;
 use vector_lib::TimeZone;
@@ -11,7 +10,7 @@ use vector_lib::TimeZone;
 use super::sink::S3RequestOptions;
 use crate::{
     aws::{AwsAuthentication, RegionOrEndpoint},
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
+    codecs::{EncoderKind, EncodingConfigWithFraming, SinkType},
     config::{AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext},
     sinks::{
         s3_common::{
@@ -103,6 +102,11 @@ pub struct S3SinkConfig {
     #[serde(flatten)]
     pub encoding: EncodingConfigWithFraming,
 
+    #[cfg(feature = "codecs-parquet")]
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_encoding: Option<BatchSerializerConfig>,
+
     /// Compression configuration.
     ///
     /// All compression algorithms use the default compression level unless otherwise specified.
@@ -166,6 +170,8 @@ impl GenerateConfig for S3SinkConfig {
             options: S3Options::default(),
             region: RegionOrEndpoint::default(),
             encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            #[cfg(feature = "codecs-parquet")]
+            batch_encoding: None,
             compression: Compression::gzip_default(),
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
@@ -190,7 +196,14 @@ impl SinkConfig for S3SinkConfig {
     }
 
     fn input(&self) -> Input {
+        #[cfg(feature = "codecs-parquet")]
+        if let Some(batch_encoding) = &self.batch_encoding {
+            return Input::new(batch_encoding.input_type())
+                .with_schema_requirement(batch_encoding.schema_requirement());
+        }
+
         Input::new(self.encoding.config().1.input_type())
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -234,17 +247,41 @@ impl S3SinkConfig {
         let partitioner = S3KeyPartitioner::new(key_prefix, ssekms_key_id, None);
 
         let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        #[cfg(feature = "codecs-parquet")]
+        let encoder = self
+            .encoding
+            .build_kind(self.batch_encoding.as_ref(), SinkType::MessageBased)?;
+        #[cfg(not(feature = "codecs-parquet"))]
+        let encoder = self.encoding.build_framed_kind(SinkType::MessageBased)?;
+
+        let (api_options, filename_extension, compression) = match &encoder {
+            EncoderKind::Framed(_) => (
+                self.options.clone(),
+                self.filename_extension.clone(),
+                self.compression,
+            ),
+            #[cfg(feature = "codecs-parquet")]
+            EncoderKind::Batch(encoder) => {
+                let mut api_options = self.options.clone();
+                api_options
+                    .content_type
+                    .get_or_insert_with(|| encoder.content_type().to_string());
+                let filename_extension = self
+                    .filename_extension
+                    .clone()
+                    .unwrap_or_else(|| encoder.serializer().extension().to_string());
+                (api_options, Some(filename_extension), Compression::None)
+            }
+        };
 
         let request_options = S3RequestOptions {
             bucket: self.bucket.clone(),
-            api_options: self.options.clone(),
-            filename_extension: self.filename_extension.clone(),
+            api_options,
+            filename_extension,
             filename_time_format: self.filename_time_format.clone(),
             filename_append_uuid: self.filename_append_uuid,
             encoder: (transformer, encoder),
-            compression: self.compression,
+            compression,
             filename_tz_offset: offset,
         };
 
@@ -97,6 +97,7 @@ impl SinkConfig for SqsSinkConfig {
 
     fn input(&self) -> Input {
         Input::new(self.base_config.encoding.config().input_type() & DataType::Log)
+            .with_schema_requirement(self.base_config.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
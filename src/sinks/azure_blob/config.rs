@@ -2,14 +2,16 @@ use std::sync::Arc;
 
 use azure_storage_blobs::prelude::*;
 use tower::ServiceBuilder;
-use vector_lib::codecs::{encoding::Framer, JsonSerializerConfig, NewlineDelimitedEncoderConfig};
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializerConfig;
+use vector_lib::codecs::{JsonSerializerConfig, NewlineDelimitedEncoderConfig};
 use vector_lib::configurable::configurable_component;
 use vector_lib::sensitive_string::SensitiveString;
 
 use super::request_builder::AzureBlobRequestOptions;
 use crate::sinks::util::service::TowerRequestConfigDefaults;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
+    codecs::{EncoderKind, EncodingConfigWithFraming, SinkType},
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     sinks::{
         azure_common::{
@@ -143,6 +145,11 @@ pub struct AzureBlobSinkConfig {
     #[serde(flatten)]
     pub encoding: EncodingConfigWithFraming,
 
+    #[cfg(feature = "codecs-parquet")]
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_encoding: Option<BatchSerializerConfig>,
+
     /// Compression configuration.
     ///
     /// All compression algorithms use the default compression level unless otherwise specified.
@@ -185,6 +192,8 @@ impl GenerateConfig for AzureBlobSinkConfig {
             blob_time_format: Some(String::from("%s")),
             blob_append_uuid: Some(true),
             encoding: (Some(NewlineDelimitedEncoderConfig::new()), JsonSerializerConfig::default()).into(),
+            #[cfg(feature = "codecs-parquet")]
+            batch_encoding: None,
             compression: Compression::gzip_default(),
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
@@ -216,7 +225,14 @@ impl SinkConfig for AzureBlobSinkConfig {
     }
 
     fn input(&self) -> Input {
+        #[cfg(feature = "codecs-parquet")]
+        if let Some(batch_encoding) = &self.batch_encoding {
+            return Input::new(batch_encoding.input_type() & DataType::Log)
+                .with_schema_requirement(batch_encoding.schema_requirement());
+        }
+
         Input::new(self.encoding.config().1.input_type() & DataType::Log)
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -248,15 +264,25 @@ impl AzureBlobSinkConfig {
             .unwrap_or(DEFAULT_FILENAME_APPEND_UUID);
 
         let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        #[cfg(feature = "codecs-parquet")]
+        let encoder = self
+            .encoding
+            .build_kind(self.batch_encoding.as_ref(), SinkType::MessageBased)?;
+        #[cfg(not(feature = "codecs-parquet"))]
+        let encoder = self.encoding.build_framed_kind(SinkType::MessageBased)?;
+        // Batch encoded blobs are compressed internally, so they are not compressed as a whole.
+        let compression = match encoder {
+            #[cfg(feature = "codecs-parquet")]
+            EncoderKind::Batch(_) => Compression::None,
+            EncoderKind::Framed(_) => self.compression,
+        };
 
         let request_options = AzureBlobRequestOptions {
             container_name: self.container_name.clone(),
             blob_time_format,
             blob_append_uuid,
             encoder: (transformer, encoder),
-            compression: self.compression,
+            compression,
         };
 
         let sink = AzureBlobSink::new(
@@ -231,6 +231,8 @@ impl AzureBlobSinkConfig {
                 blob_time_format: None,
                 blob_append_uuid: None,
                 encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+                #[cfg(feature = "codecs-parquet")]
+                batch_encoding: None,
                 compression: Compression::None,
                 batch: Default::default(),
                 request: TowerRequestConfig::default(),
@@ -1,5 +1,7 @@
 use bytes::Bytes;
 use chrono::Utc;
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializerConfig;
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig},
     NewlineDelimitedEncoder, TextSerializerConfig,
@@ -9,6 +11,8 @@ use vector_lib::{partition::Partitioner, EstimatedJsonEncodedSizeOf};
 
 use super::config::AzureBlobSinkConfig;
 use super::request_builder::AzureBlobRequestOptions;
+#[cfg(feature = "codecs-parquet")]
+use crate::codecs::BatchEncoder;
 use crate::codecs::EncodingConfigWithFraming;
 use crate::event::{Event, LogEvent};
 use crate::sinks::util::{request_builder::RequestBuilder, Compression};
@@ -24,6 +28,8 @@ fn default_config(encoding: EncodingConfigWithFraming) -> AzureBlobSinkConfig {
         blob_time_format: Default::default(),
         blob_append_uuid: Default::default(),
         encoding,
+        #[cfg(feature = "codecs-parquet")]
+        batch_encoding: None,
         compression: Compression::gzip_default(),
         batch: Default::default(),
         request: Default::default(),
@@ -64,7 +70,8 @@ fn azure_blob_build_request_without_compression() {
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            )
+            .into(),
         ),
         compression,
     };
@@ -112,7 +119,8 @@ fn azure_blob_build_request_with_compression() {
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            )
+            .into(),
         ),
         compression,
     };
@@ -160,7 +168,8 @@ fn azure_blob_build_request_with_time_format() {
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            )
+            .into(),
         ),
         compression,
     };
@@ -211,7 +220,8 @@ fn azure_blob_build_request_with_uuid() {
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            )
+            .into(),
         ),
         compression,
     };
@@ -230,3 +240,49 @@ fn azure_blob_build_request_with_uuid() {
     assert_eq!(request.content_encoding, None);
     assert_eq!(request.content_type, "text/plain");
 }
+
+#[cfg(feature = "codecs-parquet")]
+#[test]
+fn azure_blob_build_request_with_batch_encoding() {
+    let log = Event::Log(LogEvent::from("test message"));
+    let sink_config = AzureBlobSinkConfig {
+        blob_prefix: "blob".try_into().unwrap(),
+        ..default_config((None::<FramingConfig>, TextSerializerConfig::default()).into())
+    };
+
+    let key = sink_config
+        .key_partitioner()
+        .unwrap()
+        .partition(&log)
+        .expect("key wasn't provided");
+
+    let request_options = AzureBlobRequestOptions {
+        container_name: String::from("logs"),
+        blob_time_format: String::from(""),
+        blob_append_uuid: false,
+        encoder: (
+            Default::default(),
+            BatchEncoder::new(
+                BatchSerializerConfig::Parquet(Default::default())
+                    .build()
+                    .unwrap(),
+            )
+            .into(),
+        ),
+        compression: Compression::None,
+    };
+
+    let mut byte_size = GroupedCountByteSize::new_untagged();
+    byte_size.add_event(&log, log.estimated_json_encoded_size_of());
+
+    let (metadata, request_metadata_builder, _events) =
+        request_options.split_input((key, vec![log]));
+
+    let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
+    let request_metadata = request_metadata_builder.build(&payload);
+    let request = request_options.build_request(metadata, request_metadata, payload);
+
+    assert_eq!(request.metadata.partition_key, "blob.parquet".to_string());
+    assert_eq!(request.content_encoding, None);
+    assert_eq!(request.content_type, "application/vnd.apache.parquet");
+}
@@ -97,6 +97,7 @@ impl SinkConfig for ConsoleSinkConfig {
 
     fn input(&self) -> Input {
         Input::new(self.encoding.config().1.input_type())
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -23,7 +23,7 @@ pub struct ElasticsearchRequestBuilder {
 }
 
 pub struct Metadata {
-    finalizers: EventFinalizers,
+    finalizers: Vec<EventFinalizers>,
     batch_size: usize,
     events_byte_size: JsonSize,
 }
@@ -57,7 +57,10 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
         let metadata_builder = RequestMetadataBuilder::from_events(&events);
 
         let es_metadata = Metadata {
-            finalizers: events.take_finalizers(),
+            finalizers: events
+                .iter_mut()
+                .map(Finalizable::take_finalizers)
+                .collect(),
             batch_size: events.len(),
             events_byte_size,
         };
@@ -72,7 +75,8 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
     ) -> Self::Request {
         ElasticsearchRequest {
             payload: payload.into_payload(),
-            finalizers: es_metadata.finalizers,
+            finalizers: es_metadata.finalizers.iter().cloned().collect(),
+            item_finalizers: es_metadata.finalizers,
             batch_size: es_metadata.batch_size,
             events_byte_size: es_metadata.events_byte_size,
             metadata,
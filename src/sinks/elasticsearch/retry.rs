@@ -1,3 +1,5 @@
+use std::fmt;
+
 use http::StatusCode;
 use serde::Deserialize;
 
@@ -10,12 +12,12 @@ use crate::{
 };
 
 #[derive(Deserialize, Debug)]
-struct EsResultResponse {
+pub(super) struct EsResultResponse {
     items: Vec<EsResultItem>,
 }
 
 impl EsResultResponse {
-    fn parse(body: &str) -> Result<Self, String> {
+    pub(super) fn parse(body: &str) -> Result<Self, String> {
         serde_json::from_str::<EsResultResponse>(body).map_err(|json_error| {
             format!(
                 "some messages failed, could not parse response, error: {}",
@@ -48,6 +50,38 @@ impl EsResultResponse {
             None => format!("error response: {}", body),
         }
     }
+
+    /// Returns the number of items in the response.
+    pub(super) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the position and the reason for the failure of each item that was not indexed.
+    pub(super) fn iter_failures(&self) -> impl Iterator<Item = (usize, String)> + '_ {
+        self.items.iter().enumerate().filter_map(|(index, item)| {
+            let result = item.result();
+            let failed = result.error.is_some()
+                || result
+                    .status
+                    .is_some_and(|status| !(200..300).contains(&status));
+            failed.then(|| (index, result.failure_reason()))
+        })
+    }
+}
+
+/// The body of a response to a request that failed as a whole.
+#[derive(Deserialize, Debug)]
+pub(super) struct EsErrorResponse {
+    error: EsErrorDetails,
+}
+
+impl EsErrorResponse {
+    /// Returns the reason for the failure, if the body describes one.
+    pub(super) fn parse_reason(body: &[u8]) -> Option<String> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .map(|response| response.error.to_string())
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -77,6 +111,17 @@ struct EsIndexResult {
     error: Option<EsErrorDetails>,
 }
 
+impl EsIndexResult {
+    fn failure_reason(&self) -> String {
+        match (self.status, &self.error) {
+            (Some(status), Some(error)) => format!("status: {}, {}", status, error),
+            (Some(status), None) => format!("status: {}", status),
+            (None, Some(error)) => error.to_string(),
+            (None, None) => "unknown error".to_string(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 struct EsErrorDetails {
     reason: String,
@@ -84,6 +129,12 @@ struct EsErrorDetails {
     err_type: String,
 }
 
+impl fmt::Display for EsErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error type: {}, reason: {}", self.err_type, self.reason)
+    }
+}
+
 #[derive(Clone)]
 pub struct ElasticsearchRetryLogic {
     pub retry_partial: bool,
@@ -183,6 +234,7 @@ mod tests {
                 http_response: response,
                 event_status: EventStatus::Rejected,
                 events_byte_size: CountByteSize(1, JsonSize::new(1)).into(),
+                rejected_items: Vec::new(),
             }),
             RetryAction::DontRetry(_)
         ));
@@ -203,6 +255,7 @@ mod tests {
                 http_response: response,
                 event_status: EventStatus::Errored,
                 events_byte_size: CountByteSize(1, JsonSize::new(1)).into(),
+                rejected_items: Vec::new(),
             }),
             RetryAction::Retry(_)
         ));
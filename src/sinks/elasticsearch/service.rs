@@ -15,7 +15,10 @@ use vector_lib::{
     request_metadata::{GroupedCountByteSize, MetaDescriptive, RequestMetadata},
 };
 
-use super::{ElasticsearchCommon, ElasticsearchConfig};
+use super::{
+    retry::{EsErrorResponse, EsResultResponse},
+    ElasticsearchCommon, ElasticsearchConfig,
+};
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
     http::HttpClient,
@@ -30,6 +33,8 @@ use crate::{
 pub struct ElasticsearchRequest {
     pub payload: Bytes,
     pub finalizers: EventFinalizers,
+    /// The finalizers of each event, in the order of the items of the bulk request.
+    pub item_finalizers: Vec<EventFinalizers>,
     pub batch_size: usize,
     pub events_byte_size: JsonSize,
     pub metadata: RequestMetadata,
@@ -162,6 +167,9 @@ pub struct ElasticsearchResponse {
     pub http_response: Response<Bytes>,
     pub event_status: EventStatus,
     pub events_byte_size: GroupedCountByteSize,
+    /// The finalizers of the events whose items failed in an otherwise delivered bulk request,
+    /// along with the reason for their failure.
+    pub rejected_items: Vec<(EventFinalizers, String)>,
 }
 
 impl DriverResponse for ElasticsearchResponse {
@@ -172,6 +180,29 @@ impl DriverResponse for ElasticsearchResponse {
     fn events_sent(&self) -> &GroupedCountByteSize {
         &self.events_byte_size
     }
+
+    fn failure_reason(&self) -> Option<String> {
+        (self.event_status != EventStatus::Delivered).then(|| {
+            let status = self.http_response.status();
+            let body = self.http_response.body();
+            let reason = match EsResultResponse::parse(&String::from_utf8_lossy(body)) {
+                Ok(response) => response.iter_failures().next().map(|(_, reason)| reason),
+                Err(_) => EsErrorResponse::parse_reason(body),
+            };
+            match reason {
+                Some(reason) => format!("{}: {}", status, reason),
+                None => status.to_string(),
+            }
+        })
+    }
+
+    fn finalize_events(&self) -> usize {
+        for (finalizers, reason) in &self.rejected_items {
+            finalizers.set_failure_reason(&Arc::from(reason.as_str()));
+            finalizers.update_status(EventStatus::Rejected);
+        }
+        self.rejected_items.len()
+    }
 }
 
 impl Service<ElasticsearchRequest> for ElasticsearchService {
@@ -191,13 +222,20 @@ impl Service<ElasticsearchRequest> for ElasticsearchService {
             http_service.ready().await?;
             let events_byte_size =
                 std::mem::take(req.metadata_mut()).into_events_estimated_json_encoded_byte_size();
+            let mut item_finalizers = std::mem::take(&mut req.item_finalizers);
             let http_response = http_service.call(req).await?;
 
-            let event_status = get_event_status(&http_response);
+            let (event_status, failed_items) =
+                get_event_status(&http_response, item_finalizers.len());
+            let rejected_items = failed_items
+                .into_iter()
+                .map(|(index, reason)| (std::mem::take(&mut item_finalizers[index]), reason))
+                .collect();
             Ok(ElasticsearchResponse {
                 event_status,
                 http_response,
                 events_byte_size,
+                rejected_items,
             })
         })
     }
@@ -216,21 +254,106 @@ fn emit_bad_response_error(response: &Response<Bytes>) {
     );
 }
 
-fn get_event_status(response: &Response<Bytes>) -> EventStatus {
+/// Returns the status of the request, along with the position and the reason for the failure of
+/// the items that failed if the others were indexed.
+fn get_event_status(
+    response: &Response<Bytes>,
+    item_count: usize,
+) -> (EventStatus, Vec<(usize, String)>) {
     let status = response.status();
     if status.is_success() {
         let body = String::from_utf8_lossy(response.body());
         if body.contains("\"errors\":true") {
             emit_bad_response_error(response);
-            EventStatus::Rejected
+            match EsResultResponse::parse(&body) {
+                // Only the items that failed are rejected, as the others were indexed.
+                Ok(result) if result.len() == item_count => {
+                    let failed = result.iter_failures().collect::<Vec<_>>();
+                    if failed.len() < item_count {
+                        return (EventStatus::Delivered, failed);
+                    }
+                    (EventStatus::Rejected, Vec::new())
+                }
+                _ => (EventStatus::Rejected, Vec::new()),
+            }
         } else {
-            EventStatus::Delivered
+            (EventStatus::Delivered, Vec::new())
         }
     } else if status.is_server_error() {
         emit_bad_response_error(response);
-        EventStatus::Errored
+        (EventStatus::Errored, Vec::new())
     } else {
         emit_bad_response_error(response);
-        EventStatus::Rejected
+        (EventStatus::Rejected, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use vector_lib::{internal_event::CountByteSize, json_size::JsonSize};
+
+    use super::*;
+
+    fn response(status: StatusCode, body: &'static str) -> Response<Bytes> {
+        Response::builder()
+            .status(status)
+            .body(Bytes::from(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn rejects_only_failed_items() {
+        let response = response(
+            StatusCode::OK,
+            r#"{"took":3,"errors":true,"items":[{"index":{"_index":"logs","_id":"1","status":201}},{"index":{"_index":"logs","_id":"2","status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse field [host]"}}}]}"#,
+        );
+
+        let (status, failed) = get_event_status(&response, 2);
+
+        assert_eq!(status, EventStatus::Delivered);
+        assert_eq!(
+            failed,
+            vec![(
+                1,
+                "status: 400, error type: mapper_parsing_exception, reason: failed to parse field [host]"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_whole_batch_when_all_items_failed() {
+        let response = response(
+            StatusCode::OK,
+            r#"{"took":3,"errors":true,"items":[{"index":{"_index":"logs","_id":"1","status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse field [host]"}}}]}"#,
+        );
+
+        let (status, failed) = get_event_status(&response, 1);
+        assert_eq!(status, EventStatus::Rejected);
+        assert!(failed.is_empty());
+
+        let response = ElasticsearchResponse {
+            http_response: response,
+            event_status: status,
+            events_byte_size: CountByteSize(1, JsonSize::new(1)).into(),
+            rejected_items: Vec::new(),
+        };
+        assert_eq!(
+            response.failure_reason().unwrap(),
+            "200 OK: status: 400, error type: mapper_parsing_exception, reason: failed to parse field [host]"
+        );
+    }
+
+    #[test]
+    fn failure_reason_omits_response_body() {
+        let response = ElasticsearchResponse {
+            http_response: response(StatusCode::BAD_REQUEST, "not a JSON error body"),
+            event_status: EventStatus::Rejected,
+            events_byte_size: CountByteSize(1, JsonSize::new(1)).into(),
+            rejected_items: Vec::new(),
+        };
+
+        assert_eq!(response.failure_reason().unwrap(), "400 Bad Request");
     }
 }
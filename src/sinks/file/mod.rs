@@ -195,6 +195,7 @@ impl SinkConfig for FileSinkConfig {
 
     fn input(&self) -> Input {
         Input::new(self.encoding.config().1.input_type())
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -9,7 +9,8 @@ use snafu::ResultExt;
 use snafu::Snafu;
 use tower::ServiceBuilder;
 use uuid::Uuid;
-use vector_lib::codecs::encoding::Framer;
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializerConfig;
 use vector_lib::configurable::configurable_component;
 use vector_lib::event::{EventFinalizers, Finalizable};
 use vector_lib::{request_metadata::RequestMetadata, TimeZone};
@@ -17,7 +18,7 @@ use vector_lib::{request_metadata::RequestMetadata, TimeZone};
 use crate::sinks::util::metadata::RequestMetadataBuilder;
 use crate::sinks::util::service::TowerRequestConfigDefaults;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType, Transformer},
+    codecs::{EncoderKind, EncodingConfigWithFraming, SinkType, Transformer},
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     gcp::{GcpAuthConfig, GcpAuthenticator, Scope},
@@ -148,6 +149,11 @@ pub struct GcsSinkConfig {
     #[serde(flatten)]
     encoding: EncodingConfigWithFraming,
 
+    #[cfg(feature = "codecs-parquet")]
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    batch_encoding: Option<BatchSerializerConfig>,
+
     /// Compression configuration.
     ///
     /// All compression algorithms use the default compression level unless otherwise specified.
@@ -207,6 +213,8 @@ fn default_config(encoding: EncodingConfigWithFraming) -> GcsSinkConfig {
         filename_append_uuid: true,
         filename_extension: Default::default(),
         encoding,
+        #[cfg(feature = "codecs-parquet")]
+        batch_encoding: None,
         compression: Compression::gzip_default(),
         batch: Default::default(),
         endpoint: Default::default(),
@@ -251,7 +259,14 @@ impl SinkConfig for GcsSinkConfig {
     }
 
     fn input(&self) -> Input {
+        #[cfg(feature = "codecs-parquet")]
+        if let Some(batch_encoding) = &self.batch_encoding {
+            return Input::new(batch_encoding.input_type() & DataType::Log)
+                .with_schema_requirement(batch_encoding.schema_requirement());
+        }
+
         Input::new(self.encoding.config().1.input_type() & DataType::Log)
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -308,7 +323,7 @@ struct RequestSettings {
     extension: String,
     time_format: String,
     append_uuid: bool,
-    encoder: (Transformer, Encoder<Framer>),
+    encoder: (Transformer, EncoderKind),
     compression: Compression,
     tz_offset: Option<FixedOffset>,
 }
@@ -316,7 +331,7 @@ struct RequestSettings {
 impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
     type Metadata = (String, EventFinalizers);
     type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Encoder = (Transformer, EncoderKind);
     type Payload = Bytes;
     type Request = GcsRequest;
     type Error = io::Error;
@@ -386,14 +401,23 @@ impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
 impl RequestSettings {
     fn new(config: &GcsSinkConfig, cx: SinkContext) -> crate::Result<Self> {
         let transformer = config.encoding.transformer();
-        let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        #[cfg(feature = "codecs-parquet")]
+        let encoder = config
+            .encoding
+            .build_kind(config.batch_encoding.as_ref(), SinkType::MessageBased)?;
+        #[cfg(not(feature = "codecs-parquet"))]
+        let encoder = config.encoding.build_framed_kind(SinkType::MessageBased)?;
+        // Batch encoded objects are compressed internally, so they are not compressed as a whole.
+        let (compression, default_extension) = match &encoder {
+            #[cfg(feature = "codecs-parquet")]
+            EncoderKind::Batch(encoder) => (Compression::None, encoder.serializer().extension()),
+            EncoderKind::Framed(_) => (config.compression, config.compression.extension()),
+        };
         let acl = config
             .acl
             .map(|acl| HeaderValue::from_str(&to_string(acl)).unwrap());
         let content_type = HeaderValue::from_str(encoder.content_type()).unwrap();
-        let content_encoding = config
-            .compression
+        let content_encoding = compression
             .content_encoding()
             .map(|ce| HeaderValue::from_str(&to_string(ce)).unwrap());
         let storage_class = config.storage_class.unwrap_or_default();
@@ -411,7 +435,7 @@ impl RequestSettings {
         let extension = config
             .filename_extension
             .clone()
-            .unwrap_or_else(|| config.compression.extension().into());
+            .unwrap_or_else(|| default_extension.into());
         let time_format = config.filename_time_format.clone();
         let append_uuid = config.filename_append_uuid;
         let offset = config
@@ -428,7 +452,7 @@ impl RequestSettings {
             extension,
             time_format,
             append_uuid,
-            compression: config.compression,
+            compression,
             encoder: (transformer, encoder),
             tz_offset: offset,
         })
@@ -566,4 +590,33 @@ mod tests {
         let req = build_request(None, true, Compression::gzip_default());
         assert_ne!(req.key, "key/date.log.gz".to_string());
     }
+
+    #[cfg(feature = "codecs-parquet")]
+    #[test]
+    fn gcs_build_request_with_batch_encoding() {
+        let sink_config = GcsSinkConfig {
+            key_prefix: Some("key/".into()),
+            filename_time_format: "date".into(),
+            filename_append_uuid: false,
+            batch_encoding: Some(BatchSerializerConfig::Parquet(Default::default())),
+            ..default_config((None::<FramingConfig>, JsonSerializerConfig::default()).into())
+        };
+        let request_settings = request_settings(&sink_config, SinkContext::default());
+
+        assert!(matches!(request_settings.compression, Compression::None));
+        assert!(request_settings.content_encoding.is_none());
+        assert_eq!(
+            request_settings.content_type,
+            "application/vnd.apache.parquet"
+        );
+
+        let (metadata, metadata_request_builder, _events) =
+            request_settings.split_input(("key/".into(), vec![]));
+        let payload =
+            EncodeResult::uncompressed(Bytes::new(), GroupedCountByteSize::new_untagged());
+        let request_metadata = metadata_request_builder.build(&payload);
+        let req = request_settings.build_request(metadata, request_metadata, payload);
+
+        assert_eq!(req.key, "key/date.parquet".to_string());
+    }
 }
@@ -300,6 +300,7 @@ impl SinkConfig for HttpSinkConfig {
 
     fn input(&self) -> Input {
         Input::new(self.encoding.config().1.input_type())
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn files_to_watch(&self) -> Vec<&PathBuf> {
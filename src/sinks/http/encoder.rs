@@ -1,5 +1,7 @@
 //! Encoding for the `http` sink.
 
+#[cfg(feature = "codecs-opentelemetry")]
+use crate::sinks::util::encoding::encode_otlp_batch;
 use crate::{
     event::Event,
     sinks::util::encoding::{write_all, Encoder as SinkEncoder},
@@ -7,6 +9,8 @@ use crate::{
 use bytes::{BufMut, BytesMut};
 use std::io;
 use tokio_util::codec::Encoder as _;
+#[cfg(feature = "codecs-opentelemetry")]
+use vector_lib::codecs::encoding::Serializer::Otlp;
 use vector_lib::codecs::{
     encoding::{
         Framer,
@@ -49,6 +53,13 @@ impl SinkEncoder<Vec<Event>> for HttpEncoder {
         events: Vec<Event>,
         writer: &mut dyn io::Write,
     ) -> io::Result<(usize, GroupedCountByteSize)> {
+        #[cfg(feature = "codecs-opentelemetry")]
+        if let (Framer::Bytes(_), Otlp(serializer)) =
+            (self.encoder.framer(), self.encoder.serializer())
+        {
+            return encode_otlp_batch(&self.transformer, serializer, events, writer);
+        }
+
         let mut encoder = self.encoder.clone();
         let mut byte_size = telemetry().create_request_count_byte_size();
         let mut body = BytesMut::new();
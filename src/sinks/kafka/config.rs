@@ -263,7 +263,10 @@ impl SinkConfig for KafkaSinkConfig {
     }
 
     fn input(&self) -> Input {
-        let requirements = Requirement::empty().optional_meaning("timestamp", Kind::timestamp());
+        let requirements = self
+            .encoding
+            .schema_requirement()
+            .optional_meaning("timestamp", Kind::timestamp());
 
         Input::new(self.encoding.config().input_type() & (DataType::Log | DataType::Metric))
             .with_schema_requirement(requirements)
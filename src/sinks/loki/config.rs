@@ -5,7 +5,6 @@ use vrl::value::Kind;
 use super::{healthcheck::healthcheck, sink::LokiSink};
 use crate::{
     http::{Auth, HttpClient, MaybeAuth},
-    schema,
     sinks::{prelude::*, util::UriSerde},
 };
 
@@ -241,8 +240,10 @@ impl SinkConfig for LokiConfig {
     }
 
     fn input(&self) -> Input {
-        let requirement =
-            schema::Requirement::empty().optional_meaning("timestamp", Kind::timestamp());
+        let requirement = self
+            .encoding
+            .schema_requirement()
+            .optional_meaning("timestamp", Kind::timestamp());
 
         Input::new(self.encoding.config().input_type() & DataType::Log)
             .with_schema_requirement(requirement)
@@ -8,7 +8,6 @@ use crate::{
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     internal_events::TemplateRenderingError,
-    schema,
     sinks::util::{tcp::TcpSinkConfig, UriSerde},
     tcp::TcpKeepaliveConfig,
     template::Template,
@@ -111,7 +110,10 @@ impl SinkConfig for PapertrailConfig {
     }
 
     fn input(&self) -> Input {
-        let requirement = schema::Requirement::empty().optional_meaning("host", Kind::bytes());
+        let requirement = self
+            .encoding
+            .schema_requirement()
+            .optional_meaning("host", Kind::bytes());
 
         Input::new(self.encoding.config().input_type() & DataType::Log)
             .with_schema_requirement(requirement)
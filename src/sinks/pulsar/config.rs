@@ -1,9 +1,6 @@
-use crate::{
-    schema,
-    sinks::{
-        prelude::*,
-        pulsar::sink::{healthcheck, PulsarSink},
-    },
+use crate::sinks::{
+    prelude::*,
+    pulsar::sink::{healthcheck, PulsarSink},
 };
 use futures_util::{FutureExt, TryFutureExt};
 use pulsar::{
@@ -394,8 +391,10 @@ impl SinkConfig for PulsarSinkConfig {
     }
 
     fn input(&self) -> Input {
-        let requirement =
-            schema::Requirement::empty().optional_meaning("timestamp", Kind::timestamp());
+        let requirement = self
+            .encoding
+            .schema_requirement()
+            .optional_meaning("timestamp", Kind::timestamp());
 
         Input::new(self.encoding.config().input_type() & (DataType::Log | DataType::Metric))
             .with_schema_requirement(requirement)
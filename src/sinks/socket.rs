@@ -187,13 +187,25 @@ impl SinkConfig for SocketSinkConfig {
     }
 
     fn input(&self) -> Input {
-        let encoder_input_type = match &self.mode {
-            Mode::Tcp(TcpMode { encoding, .. }) => encoding.config().1.input_type(),
-            Mode::Udp(UdpMode { encoding, .. }) => encoding.config().input_type(),
-            Mode::UnixStream(UnixMode { encoding, .. }) => encoding.config().1.input_type(),
-            Mode::UnixDatagram(UnixMode { encoding, .. }) => encoding.config().1.input_type(),
+        let (encoder_input_type, requirement) = match &self.mode {
+            Mode::Tcp(TcpMode { encoding, .. }) => (
+                encoding.config().1.input_type(),
+                encoding.schema_requirement(),
+            ),
+            Mode::Udp(UdpMode { encoding, .. }) => (
+                encoding.config().input_type(),
+                encoding.schema_requirement(),
+            ),
+            Mode::UnixStream(UnixMode { encoding, .. }) => (
+                encoding.config().1.input_type(),
+                encoding.schema_requirement(),
+            ),
+            Mode::UnixDatagram(UnixMode { encoding, .. }) => (
+                encoding.config().1.input_type(),
+                encoding.schema_requirement(),
+            ),
         };
-        Input::new(encoder_input_type)
+        Input::new(encoder_input_type).with_schema_requirement(requirement)
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -4,6 +4,8 @@ use bytes::BytesMut;
 use itertools::{Itertools, Position};
 use tokio_util::codec::Encoder as _;
 use vector_lib::codecs::encoding::Framer;
+#[cfg(feature = "codecs-opentelemetry")]
+use vector_lib::codecs::encoding::{OtlpSerializer, Serializer};
 use vector_lib::request_metadata::GroupedCountByteSize;
 use vector_lib::{config::telemetry, EstimatedJsonEncodedSizeOf};
 
@@ -28,50 +30,141 @@ impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
         events: Vec<Event>,
         writer: &mut dyn io::Write,
     ) -> io::Result<(usize, GroupedCountByteSize)> {
-        let mut encoder = self.1.clone();
-        let mut bytes_written = 0;
-        let mut n_events_pending = events.len();
-        let batch_prefix = encoder.batch_prefix();
-        write_all(writer, n_events_pending, batch_prefix)?;
-        bytes_written += batch_prefix.len();
+        encode_framed(&self.0, &self.1, events, writer)
+    }
+}
 
-        let mut byte_size = telemetry().create_request_count_byte_size();
+impl Encoder<Vec<Event>> for (Transformer, crate::codecs::EncoderKind) {
+    fn encode_input(
+        &self,
+        events: Vec<Event>,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        match &self.1 {
+            crate::codecs::EncoderKind::Framed(encoder) => {
+                encode_framed(&self.0, encoder, events, writer)
+            }
+            #[cfg(feature = "codecs-parquet")]
+            crate::codecs::EncoderKind::Batch(encoder) => {
+                encode_batch(&self.0, encoder, events, writer)
+            }
+        }
+    }
+}
 
-        for (position, mut event) in events.into_iter().with_position() {
-            self.0.transform(&mut event);
+/// Encode and frame each of the events individually.
+fn encode_framed(
+    transformer: &Transformer,
+    encoder: &crate::codecs::Encoder<Framer>,
+    events: Vec<Event>,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    #[cfg(feature = "codecs-opentelemetry")]
+    if let (Framer::Bytes(_), Serializer::Otlp(serializer)) =
+        (encoder.framer(), encoder.serializer())
+    {
+        return encode_otlp_batch(transformer, serializer, events, writer);
+    }
 
-            // Ensure the json size is calculated after any fields have been removed
-            // by the transformer.
-            byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+    let mut encoder = encoder.clone();
+    let mut bytes_written = 0;
+    let mut n_events_pending = events.len();
+    let batch_prefix = encoder.batch_prefix();
+    write_all(writer, n_events_pending, batch_prefix)?;
+    bytes_written += batch_prefix.len();
 
-            let mut bytes = BytesMut::new();
-            match (position, encoder.framer()) {
-                (
-                    Position::Last | Position::Only,
-                    Framer::CharacterDelimited(_) | Framer::NewlineDelimited(_),
-                ) => {
-                    encoder
-                        .serialize(event, &mut bytes)
-                        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
-                }
-                _ => {
-                    encoder
-                        .encode(event, &mut bytes)
-                        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
-                }
+    let mut byte_size = telemetry().create_request_count_byte_size();
+
+    for (position, mut event) in events.into_iter().with_position() {
+        transformer.transform(&mut event);
+
+        // Ensure the json size is calculated after any fields have been removed
+        // by the transformer.
+        byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+
+        let mut bytes = BytesMut::new();
+        match (position, encoder.framer()) {
+            (
+                Position::Last | Position::Only,
+                Framer::CharacterDelimited(_) | Framer::NewlineDelimited(_),
+            ) => {
+                encoder
+                    .serialize(event, &mut bytes)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            }
+            _ => {
+                encoder
+                    .encode(event, &mut bytes)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
             }
-            write_all(writer, n_events_pending, &bytes)?;
-            bytes_written += bytes.len();
-            n_events_pending -= 1;
         }
+        write_all(writer, n_events_pending, &bytes)?;
+        bytes_written += bytes.len();
+        n_events_pending -= 1;
+    }
 
-        let batch_suffix = encoder.batch_suffix();
-        assert!(n_events_pending == 0);
-        write_all(writer, 0, batch_suffix)?;
-        bytes_written += batch_suffix.len();
+    let batch_suffix = encoder.batch_suffix();
+    assert!(n_events_pending == 0);
+    write_all(writer, 0, batch_suffix)?;
+    bytes_written += batch_suffix.len();
 
-        Ok((bytes_written, byte_size))
+    Ok((bytes_written, byte_size))
+}
+
+/// Encode all of the events at once.
+#[cfg(feature = "codecs-parquet")]
+fn encode_batch(
+    transformer: &Transformer,
+    encoder: &crate::codecs::BatchEncoder,
+    mut events: Vec<Event>,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    let mut byte_size = telemetry().create_request_count_byte_size();
+
+    for event in events.iter_mut() {
+        transformer.transform(event);
+
+        // Ensure the json size is calculated after any fields have been removed
+        // by the transformer.
+        byte_size.add_event(event, event.estimated_json_encoded_size_of());
     }
+
+    let bytes = encoder
+        .encode_batch(&events)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    write_all(writer, events.len(), &bytes)?;
+
+    Ok((bytes.len(), byte_size))
+}
+
+/// Encode all of the events as a single OTLP export request.
+///
+/// Batches that aren't framed are sent as a single message, which must hold a single request.
+#[cfg(feature = "codecs-opentelemetry")]
+pub(crate) fn encode_otlp_batch(
+    transformer: &Transformer,
+    serializer: &OtlpSerializer,
+    mut events: Vec<Event>,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    let mut byte_size = telemetry().create_request_count_byte_size();
+
+    for event in events.iter_mut() {
+        transformer.transform(event);
+
+        // Ensure the json size is calculated after any fields have been removed
+        // by the transformer.
+        byte_size.add_event(event, event.estimated_json_encoded_size_of());
+    }
+
+    let n_events = events.len();
+    let mut bytes = BytesMut::new();
+    serializer
+        .encode_batch(events, &mut bytes)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    write_all(writer, n_events, &bytes)?;
+
+    Ok((bytes.len(), byte_size))
 }
 
 impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
@@ -151,6 +244,8 @@ mod tests {
     use std::path::PathBuf;
 
     use bytes::{BufMut, Bytes};
+    #[cfg(feature = "codecs-parquet")]
+    use vector_lib::codecs::encoding::BatchSerializerConfig;
     use vector_lib::codecs::encoding::{ProtobufSerializerConfig, ProtobufSerializerOptions};
     use vector_lib::codecs::{
         CharacterDelimitedEncoder, JsonSerializerConfig, LengthDelimitedEncoder,
@@ -399,12 +494,12 @@ mod tests {
         buf.extend_from_slice(&message_raw[..]);
         let expected_bytes = buf.freeze();
 
-        let config = ProtobufSerializerConfig {
-            protobuf: ProtobufSerializerOptions {
-                desc_file: test_data_dir().join("test_proto.desc"),
-                message_type: "test_proto.User".to_string(),
-            },
-        };
+        let config = ProtobufSerializerConfig::new(ProtobufSerializerOptions {
+            desc_file: test_data_dir().join("test_proto.desc"),
+            message_type: "test_proto.User".to_string(),
+            #[cfg(feature = "codecs-schema-registry")]
+            schema_registry: None,
+        });
 
         let encoding = (
             Transformer::default(),
@@ -453,12 +548,12 @@ mod tests {
         }
         let expected_bytes = buf.freeze();
 
-        let config = ProtobufSerializerConfig {
-            protobuf: ProtobufSerializerOptions {
-                desc_file: test_data_dir().join("test_proto.desc"),
-                message_type: "test_proto.User".to_string(),
-            },
-        };
+        let config = ProtobufSerializerConfig::new(ProtobufSerializerOptions {
+            desc_file: test_data_dir().join("test_proto.desc"),
+            message_type: "test_proto.User".to_string(),
+            #[cfg(feature = "codecs-schema-registry")]
+            schema_registry: None,
+        });
 
         let encoding = (
             Transformer::default(),
@@ -502,4 +597,71 @@ mod tests {
         assert_eq!(CountByteSize(2, input_json_size), size.size().unwrap());
         assert_eq!(Bytes::copy_from_slice(&writer), expected_bytes);
     }
+
+    #[cfg(feature = "codecs-opentelemetry")]
+    #[test]
+    fn test_encode_batch_otlp_json() {
+        use vector_lib::codecs::{encoding::OtlpFormat, BytesEncoder};
+
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::Encoder::<Framer>::new(
+                BytesEncoder.into(),
+                OtlpSerializer::new(OtlpFormat::Json).into(),
+            ),
+        );
+
+        let input = vec![
+            Event::Log(LogEvent::from("hello")),
+            Event::Log(LogEvent::from("world")),
+        ];
+
+        let mut writer = Vec::new();
+        let (written, json_size) = encoding.encode_input(input, &mut writer).unwrap();
+        assert_eq!(written, writer.len());
+        assert_eq!(json_size.size().unwrap().0, 2);
+
+        let request: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+        assert_eq!(request["resourceLogs"].as_array().unwrap().len(), 2);
+    }
+
+    #[cfg(feature = "codecs-parquet")]
+    #[test]
+    fn test_encode_batch_parquet() {
+        let config = serde_json::from_value::<BatchSerializerConfig>(serde_json::json!({
+            "codec": "parquet",
+            "parquet": { "schema": [{ "name": "key", "type": "string" }] },
+        }))
+        .unwrap();
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::EncoderKind::from(crate::codecs::BatchEncoder::new(
+                config.build().unwrap(),
+            )),
+        );
+
+        let input = vec![
+            Event::Log(LogEvent::from(BTreeMap::from([(
+                KeyString::from("key"),
+                Value::from("value1"),
+            )]))),
+            Event::Log(LogEvent::from(BTreeMap::from([(
+                KeyString::from("key"),
+                Value::from("value2"),
+            )]))),
+        ];
+
+        let input_json_size = input
+            .iter()
+            .map(|event| event.estimated_json_encoded_size_of())
+            .sum::<JsonSize>();
+
+        let mut writer = Vec::new();
+        let (written, json_size) = encoding.encode_input(input, &mut writer).unwrap();
+
+        assert_eq!(written, writer.len());
+        assert!(writer.starts_with(b"PAR1"));
+        assert!(writer.ends_with(b"PAR1"));
+        assert_eq!(CountByteSize(2, input_json_size), json_size.size().unwrap());
+    }
 }
@@ -689,6 +689,16 @@ impl DriverResponse for HttpResponse {
     fn bytes_sent(&self) -> Option<usize> {
         Some(self.raw_byte_size)
     }
+
+    fn failure_reason(&self) -> Option<String> {
+        (!self.http_response.is_successful()).then(|| {
+            format!(
+                "{}: {}",
+                self.http_response.status(),
+                String::from_utf8_lossy(self.http_response.body())
+            )
+        })
+    }
 }
 
 /// Creates a `RetryLogic` for use with `HttpResponse`.
@@ -111,6 +111,7 @@ impl SinkConfig for WebHdfsConfig {
 
     fn input(&self) -> Input {
         Input::new(self.encoding.config().1.input_type() & DataType::Log)
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -95,6 +95,7 @@ impl SinkConfig for WebSocketSinkConfig {
 
     fn input(&self) -> Input {
         Input::new(self.encoding.config().input_type())
+            .with_schema_requirement(self.encoding.schema_requirement())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -25,6 +25,7 @@ use vrl::value::Value;
 
 mod errors;
 
+use crate::codecs::{is_decoding_error, DECODING_ERRORS_OUTPUT};
 use crate::config::{ComponentKey, OutputId};
 use crate::schema::Definition;
 pub use errors::{ClosedError, StreamSendError};
@@ -96,6 +97,7 @@ pub struct Builder {
     buf_size: usize,
     inner: Option<Inner>,
     named_inners: HashMap<String, Inner>,
+    errors: Option<Inner>,
     lag_time: Option<Histogram>,
 }
 
@@ -107,6 +109,7 @@ impl Builder {
             buf_size: n,
             inner: self.inner,
             named_inners: self.named_inners,
+            errors: self.errors,
             lag_time: self.lag_time,
         }
     }
@@ -142,7 +145,11 @@ impl Builder {
                     log_definition,
                     output_id,
                 );
-                self.named_inners.insert(name, inner);
+                if name == DECODING_ERRORS_OUTPUT {
+                    self.errors = Some(inner);
+                } else {
+                    self.named_inners.insert(name, inner);
+                }
                 rx
             }
         }
@@ -154,6 +161,7 @@ impl Builder {
         SourceSender {
             inner: self.inner,
             named_inners: self.named_inners,
+            errors: self.errors,
         }
     }
 }
@@ -162,6 +170,8 @@ impl Builder {
 pub struct SourceSender {
     inner: Option<Inner>,
     named_inners: HashMap<String, Inner>,
+    /// The output receiving the events that failed to be decoded, if the source reroutes them.
+    errors: Option<Inner>,
 }
 
 impl SourceSender {
@@ -170,6 +180,7 @@ impl SourceSender {
             buf_size: CHUNK_SIZE,
             inner: None,
             named_inners: Default::default(),
+            errors: None,
             lag_time: Some(histogram!(LAG_TIME_NAME)),
         }
     }
@@ -187,6 +198,7 @@ impl SourceSender {
             Self {
                 inner: Some(inner),
                 named_inners: Default::default(),
+                errors: None,
             },
             rx,
         )
@@ -263,7 +275,11 @@ impl SourceSender {
             });
             item
         });
-        self.named_inners.insert(name, inner);
+        if name == DECODING_ERRORS_OUTPUT {
+            self.errors = Some(inner);
+        } else {
+            self.named_inners.insert(name, inner);
+        }
         recv
     }
 
@@ -271,11 +287,14 @@ impl SourceSender {
     ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
     pub async fn send_event(&mut self, event: impl Into<EventArray>) -> Result<(), ClosedError> {
-        self.inner
-            .as_mut()
-            .expect("no default output")
-            .send_event(event)
-            .await
+        let inner = self.inner.as_mut().expect("no default output");
+        match self.errors.as_mut() {
+            Some(errors) => {
+                let events: EventArray = event.into();
+                send_splitting_errors(inner, errors, events.into_events()).await
+            }
+            None => inner.send_event(event).await,
+        }
     }
 
     /// Send a stream of events to the default output.
@@ -286,11 +305,15 @@ impl SourceSender {
         S: Stream<Item = E> + Unpin,
         E: Into<Event> + ByteSizeOf,
     {
-        self.inner
-            .as_mut()
-            .expect("no default output")
-            .send_event_stream(events)
-            .await
+        let inner = self.inner.as_mut().expect("no default output");
+        let Some(errors) = self.errors.as_mut() else {
+            return inner.send_event_stream(events).await;
+        };
+        let mut stream = events.ready_chunks(CHUNK_SIZE);
+        while let Some(events) = stream.next().await {
+            send_splitting_errors(inner, errors, events.into_iter().map(Into::into)).await?;
+        }
+        Ok(())
     }
 
     /// Send a batch of events to the default output.
@@ -302,11 +325,13 @@ impl SourceSender {
         I: IntoIterator<Item = E>,
         <I as IntoIterator>::IntoIter: ExactSizeIterator,
     {
-        self.inner
-            .as_mut()
-            .expect("no default output")
-            .send_batch(events)
-            .await
+        let inner = self.inner.as_mut().expect("no default output");
+        match self.errors.as_mut() {
+            Some(errors) => {
+                send_splitting_errors(inner, errors, events.into_iter().map(Into::into)).await
+            }
+            None => inner.send_batch(events).await,
+        }
     }
 
     /// Send a batch of events event to a named output.
@@ -318,14 +343,34 @@ impl SourceSender {
         I: IntoIterator<Item = E>,
         <I as IntoIterator>::IntoIter: ExactSizeIterator,
     {
-        self.named_inners
-            .get_mut(name)
-            .expect("unknown output")
-            .send_batch(events)
-            .await
+        let inner = self.named_inners.get_mut(name).expect("unknown output");
+        match self.errors.as_mut() {
+            Some(errors) => {
+                send_splitting_errors(inner, errors, events.into_iter().map(Into::into)).await
+            }
+            None => inner.send_batch(events).await,
+        }
     }
 }
 
+/// Sends the events that failed to be decoded to the errors output, and the others to the given
+/// output.
+async fn send_splitting_errors(
+    inner: &mut Inner,
+    errors: &mut Inner,
+    events: impl IntoIterator<Item = Event>,
+) -> Result<(), ClosedError> {
+    let (decoding_errors, events): (Vec<_>, Vec<_>) =
+        events.into_iter().partition(is_decoding_error);
+    if !events.is_empty() {
+        inner.send_batch(events).await?;
+    }
+    if !decoding_errors.is_empty() {
+        errors.send_batch(decoding_errors).await?;
+    }
+    Ok(())
+}
+
 /// UnsentEvents tracks the number of events yet to be sent in the buffer. This is used to
 /// increment the appropriate counters when a future is not polled to completion. Particularly,
 /// this is known to happen in a Warp server when a client sends a new HTTP request on a TCP
@@ -705,4 +750,34 @@ mod tests {
         };
         assert_eq!(*value, expected_drop as f64);
     }
+
+    #[tokio::test]
+    async fn sends_decoding_errors_to_errors_output() {
+        let (mut sender, recv) = SourceSender::new_test();
+        let errors = sender.add_outputs(EventStatus::Delivered, DECODING_ERRORS_OUTPUT.to_owned());
+
+        let mut error = LogEvent::from("invalid");
+        error.insert(
+            vector_lib::lookup::metadata_path!("vector", "decoding_error"),
+            "failed to parse",
+        );
+        let events = vec![
+            Event::Log(LogEvent::from("first")),
+            Event::Log(error),
+            Event::Log(LogEvent::from("second")),
+        ];
+        sender.send_batch(events).await.unwrap();
+        drop(sender);
+
+        let events = recv.collect::<Vec<_>>().await;
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|event| !is_decoding_error(event)));
+
+        let errors = errors.flat_map(into_event_stream).collect::<Vec<_>>().await;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].as_log().get("message").unwrap(),
+            &Value::from("invalid")
+        );
+    }
 }
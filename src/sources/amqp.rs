@@ -2,7 +2,7 @@
 //! Handles version AMQP 0.9.1 which is used by RabbitMQ.
 use crate::{
     amqp::AmqpConfig,
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{SourceConfig, SourceContext, SourceOutput},
     event::{BatchNotifier, BatchStatus},
     internal_events::{
@@ -97,6 +97,13 @@ pub struct AmqpSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub(crate) decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub(crate) reroute_decoding_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub(crate) acknowledgements: SourceAcknowledgementsConfig,
@@ -126,7 +133,9 @@ impl_generate_config_from_default!(AmqpSourceConfig);
 
 impl AmqpSourceConfig {
     fn decoder(&self, log_namespace: LogNamespace) -> vector_lib::Result<Decoder> {
-        DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build()
+        DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+            .with_reroute_errors(self.reroute_decoding_errors)
+            .build()
     }
 }
 
@@ -178,10 +187,14 @@ impl SourceConfig for AmqpSourceConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
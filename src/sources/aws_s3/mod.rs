@@ -15,7 +15,7 @@ use vector_lib::lookup::owned_value_path;
 use vrl::value::{kind::Collection, Kind};
 
 use super::util::MultilineConfig;
-use crate::codecs::DecodingConfig;
+use crate::codecs::{decoding_errors_output, DecodingConfig};
 use crate::{
     aws::{auth::AwsAuthentication, create_client, create_client_and_region, RegionOrEndpoint},
     common::{s3::S3ClientBuilder, sqs::SqsClientBuilder},
@@ -131,6 +131,13 @@ pub struct AwsS3Config {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pubreroute_decoding_errors: bool,
+
     /// Specifies which addressing style to use.
     ///
     /// This controls whether the bucket name is in the hostname, or part of the URL.
@@ -221,10 +228,14 @@ impl SourceConfig for AwsS3Config {
             schema_definition = schema_definition.unknown_fields(Kind::bytes());
         }
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -257,6 +268,7 @@ impl AwsS3Config {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_reroute_errors(self.reroute_decoding_errors)
                 .build()?;
 
         match self.sqs {
@@ -7,7 +7,7 @@ use vector_lib::lookup::owned_value_path;
 use vrl::value::Kind;
 
 use crate::aws::create_client;
-use crate::codecs::DecodingConfig;
+use crate::codecs::{decoding_errors_output, DecodingConfig};
 use crate::common::sqs::SqsClientBuilder;
 use crate::tls::TlsConfig;
 use crate::{
@@ -90,6 +90,13 @@ pub struct AwsSqsConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pubreroute_decoding_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub acknowledgements: SourceAcknowledgementsConfig,
@@ -112,6 +119,7 @@ impl SourceConfig for AwsSqsConfig {
         let client = self.build_client(&cx).await?;
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_reroute_errors(self.reroute_decoding_errors)
                 .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
@@ -135,9 +143,10 @@ impl SourceConfig for AwsSqsConfig {
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
         let schema_definition = self
             .decoding
-            .schema_definition(global_log_namespace.merge(self.log_namespace))
+            .schema_definition(log_namespace)
             .with_standard_vector_source_metadata()
             .with_source_metadata(
                 Self::NAME,
@@ -147,10 +156,14 @@ impl SourceConfig for AwsSqsConfig {
                 Some("timestamp"),
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
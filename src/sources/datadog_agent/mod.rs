@@ -50,7 +50,7 @@ use warp::{filters::BoxedFilter, reject::Rejection, reply::Response, Filter, Rep
 use crate::common::http::ErrorMessage;
 use crate::http::{build_http_trace_layer, KeepaliveConfig, MaxConnectionAgeLayer};
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{
         log_schema, DataType, GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig,
         SourceContext, SourceOutput,
@@ -135,6 +135,13 @@ pub struct DatadogAgentConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decoding_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -152,6 +159,7 @@ impl GenerateConfig for DatadogAgentConfig {
             store_api_key: true,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             acknowledgements: SourceAcknowledgementsConfig::default(),
             disable_logs: false,
             disable_metrics: false,
@@ -179,6 +187,7 @@ impl SourceConfig for DatadogAgentConfig {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_reroute_errors(self.reroute_decoding_errors)
                 .build()?;
 
         let tls = MaybeTlsSettings::from_config(self.tls.as_ref(), true)?;
@@ -237,9 +246,10 @@ impl SourceConfig for DatadogAgentConfig {
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
         let definition = self
             .decoding
-            .schema_definition(global_log_namespace.merge(self.log_namespace))
+            .schema_definition(log_namespace)
             // NOTE: "status" is intentionally semantically mapped to "severity",
             //       since that is what DD designates as the semantic meaning of status
             // https://docs.datadoghq.com/logs/log_configuration/attributes_naming_convention/?s=severity#reserved-attributes
@@ -309,6 +319,9 @@ impl SourceConfig for DatadogAgentConfig {
                 definition,
             ))
         }
+        if self.reroute_decoding_errors && !self.disable_logs {
+            output.push(decoding_errors_output(log_namespace));
+        }
         output
     }
 
@@ -1570,6 +1570,7 @@ fn test_config_outputs_with_disabled_data_types() {
             store_api_key: true,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             acknowledgements: Default::default(),
             multiple_outputs,
             disable_logs,
@@ -2012,6 +2013,7 @@ fn test_config_outputs() {
             store_api_key: true,
             framing: default_framing_message_based(),
             decoding,
+            reroute_decoding_errors: false,
             acknowledgements: Default::default(),
             multiple_outputs,
             disable_logs: false,
@@ -2519,6 +2521,7 @@ impl ValidatableComponent for DatadogAgentConfig {
             }
             .into(),
             decoding: BytesDeserializerConfig::new().into(),
+            reroute_decoding_errors: false,
             acknowledgements: Default::default(),
             multiple_outputs: false,
             disable_logs: false,
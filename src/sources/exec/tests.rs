@@ -183,6 +183,7 @@ fn test_build_command() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         log_namespace: None,
     };
 
@@ -217,6 +218,7 @@ fn test_build_command_custom_environment() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         log_namespace: None,
     };
 
@@ -247,6 +249,7 @@ fn test_build_command_clear_environment() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         log_namespace: None,
     };
 
@@ -455,6 +458,7 @@ fn standard_streaming_test_config() -> ExecConfig {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         log_namespace: None,
     }
 }
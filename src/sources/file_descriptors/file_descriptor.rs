@@ -36,6 +36,13 @@ pub struct FileDescriptorSourceConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pubreroute_decoding_errors: bool,
+
     /// The file descriptor number to read from.
     #[configurable(metadata(docs::examples = 10))]
     #[configurable(metadata(docs::human_name = "File Descriptor Number"))]
@@ -60,6 +67,10 @@ impl FileDescriptorConfig for FileDescriptorSourceConfig {
         self.decoding.clone()
     }
 
+    fn reroute_decoding_errors(&self) -> bool {
+        self.reroute_decoding_errors
+    }
+
     fn description(&self) -> String {
         format!("file descriptor {}", self.fd)
     }
@@ -100,7 +111,13 @@ impl SourceConfig for FileDescriptorSourceConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
 
-        outputs(log_namespace, &self.host_key, &self.decoding, Self::NAME)
+        outputs(
+            log_namespace,
+            &self.host_key,
+            &self.decoding,
+            self.reroute_decoding_errors,
+            Self::NAME,
+        )
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -143,6 +160,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decoding_errors: false,
                 fd: read_fd as u32,
                 log_namespace: None,
             };
@@ -184,6 +202,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decoding_errors: false,
                 fd: read_fd as u32,
                 log_namespace: Some(true),
             };
@@ -233,6 +252,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decoding_errors: false,
                 fd: write_fd as u32, // intentionally giving the source a write-only fd
                 log_namespace: None,
             };
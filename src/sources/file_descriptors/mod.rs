@@ -22,7 +22,7 @@ use vector_lib::{
 use vrl::value::Kind;
 
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{log_schema, SourceOutput},
     internal_events::{EventsReceived, FileDescriptorReadError, StreamClosedError},
     shutdown::ShutdownSignal,
@@ -38,6 +38,7 @@ pub trait FileDescriptorConfig: NamedComponent {
     fn host_key(&self) -> Option<OptionalValuePath>;
     fn framing(&self) -> Option<FramingConfig>;
     fn decoding(&self) -> DeserializerConfig;
+    fn reroute_decoding_errors(&self) -> bool;
     fn description(&self) -> String;
 
     fn source<R>(
@@ -62,7 +63,9 @@ pub trait FileDescriptorConfig: NamedComponent {
         let framing = self
             .framing()
             .unwrap_or_else(|| decoding.default_stream_framing());
-        let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+        let decoder = DecodingConfig::new(framing, decoding, log_namespace)
+            .with_reroute_errors(self.reroute_decoding_errors())
+            .build()?;
 
         let (sender, receiver) = mpsc::channel(1024);
 
@@ -206,6 +209,7 @@ fn outputs(
     log_namespace: LogNamespace,
     host_key: &Option<OptionalValuePath>,
     decoding: &DeserializerConfig,
+    reroute_decoding_errors: bool,
     source_name: &'static str,
 ) -> Vec<SourceOutput> {
     let schema_definition = decoding
@@ -222,8 +226,12 @@ fn outputs(
         )
         .with_standard_vector_source_metadata();
 
-    vec![SourceOutput::new_maybe_logs(
+    let mut outputs = vec![SourceOutput::new_maybe_logs(
         decoding.output_type(),
         schema_definition,
-    )]
+    )];
+    if reroute_decoding_errors {
+        outputs.push(decoding_errors_output(log_namespace));
+    }
+    outputs
 }
@@ -39,6 +39,13 @@ pub struct StdinConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pubreroute_decoding_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -58,6 +65,10 @@ impl FileDescriptorConfig for StdinConfig {
         self.decoding.clone()
     }
 
+    fn reroute_decoding_errors(&self) -> bool {
+        self.reroute_decoding_errors
+    }
+
     fn description(&self) -> String {
         Self::NAME.to_string()
     }
@@ -70,6 +81,7 @@ impl Default for StdinConfig {
             host_key: Default::default(),
             framing: None,
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             log_namespace: None,
         }
     }
@@ -93,7 +105,13 @@ impl SourceConfig for StdinConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
 
-        outputs(log_namespace, &self.host_key, &self.decoding, Self::NAME)
+        outputs(
+            log_namespace,
+            &self.host_key,
+            &self.decoding,
+            self.reroute_decoding_errors,
+            Self::NAME,
+        )
     }
 
     fn resources(&self) -> Vec<Resource> {
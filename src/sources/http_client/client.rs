@@ -13,7 +13,7 @@ use tokio_util::codec::Decoder as _;
 use crate::http::{QueryParameterValue, QueryParameters};
 use crate::sources::util::http_client;
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{SourceConfig, SourceContext},
     http::Auth,
     serde::{default_decoding, default_framing_message_based},
@@ -86,6 +86,13 @@ pub struct HttpClientConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pubreroute_decoding_errors: bool,
+
     /// Framing to use in the decoding.
     #[configurable(derived)]
     #[serde(default = "default_framing_message_based")]
@@ -166,6 +173,7 @@ impl Default for HttpClientConfig {
             interval: default_interval(),
             timeout: default_timeout(),
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             framing: default_framing_message_based(),
             headers: HashMap::new(),
             method: default_http_method(),
@@ -232,10 +240,14 @@ impl SourceConfig for HttpClientConfig {
             .schema_definition(log_namespace)
             .with_standard_vector_source_metadata();
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -251,6 +263,7 @@ impl HttpClientConfig {
             log_namespace.unwrap_or_else(|| self.log_namespace.unwrap_or(false).into());
 
         DecodingConfig::new(framing, decoding, log_namespace)
+            .with_reroute_errors(self.reroute_decoding_errors)
     }
 }
 
@@ -56,6 +56,7 @@ async fn invalid_endpoint() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -75,6 +76,7 @@ async fn collected_logs_bytes() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Bytes,
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -100,6 +102,7 @@ async fn collected_logs_json() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -125,6 +128,7 @@ async fn collected_metrics_native_json() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::NativeJson(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -155,6 +159,7 @@ async fn collected_trace_native_json() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::NativeJson(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -180,6 +185,7 @@ async fn unauthorized_no_auth() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -199,6 +205,7 @@ async fn unauthorized_wrong_auth() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -221,6 +228,7 @@ async fn authorized() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -243,6 +251,7 @@ async fn tls_invalid_ca() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -265,6 +274,7 @@ async fn tls_valid() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -288,6 +298,7 @@ async fn shutdown() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -91,6 +91,7 @@ async fn bytes_decoding() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -120,6 +121,7 @@ async fn json_decoding_newline_delimited() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: FramingConfig::NewlineDelimited(Default::default()),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -149,6 +151,7 @@ async fn json_decoding_character_delimited() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: FramingConfig::CharacterDelimited(CharacterDelimitedDecoderConfig {
             character_delimited: CharacterDelimitedDecoderOptions {
                 delimiter: b',',
@@ -191,6 +194,7 @@ async fn request_query_applied() {
             ),
         ]),
         decoding: DeserializerConfig::Json(Default::default()),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
@@ -252,6 +256,7 @@ async fn headers_applied() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: default_decoding(),
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::from([(
             "f00".to_string(),
@@ -284,6 +289,7 @@ async fn accept_header_override() {
         timeout: TIMEOUT,
         query: HashMap::new(),
         decoding: DeserializerConfig::Bytes,
+        reroute_decoding_errors: false,
         framing: default_framing_message_based(),
         headers: HashMap::from([("ACCEPT".to_string(), vec!["application/json".to_string()])]),
         method: HttpMethod::Get,
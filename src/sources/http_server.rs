@@ -22,7 +22,7 @@ use vector_lib::{
 };
 
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{
         GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
         SourceOutput,
@@ -163,6 +163,14 @@ pub struct SimpleHttpConfig {
     #[configurable(derived)]
     decoding: Option<DeserializerConfig>,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message. Requests
+    /// holding such frames are accepted instead of being rejected with a `400` status code.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decoding_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -262,7 +270,8 @@ impl SimpleHttpConfig {
             framing,
             decoding,
             self.log_namespace.unwrap_or(false).into(),
-        ))
+        )
+        .with_reroute_errors(self.reroute_decoding_errors))
     }
 }
 
@@ -283,6 +292,7 @@ impl Default for SimpleHttpConfig {
             strict_path: true,
             framing: None,
             decoding: Some(default_decoding()),
+            reroute_decoding_errors: false,
             acknowledgements: SourceAcknowledgementsConfig::default(),
             log_namespace: None,
             keepalive: KeepaliveConfig::default(),
@@ -395,13 +405,17 @@ impl SourceConfig for SimpleHttpConfig {
 
         let schema_definition = self.schema_definition(log_namespace);
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding
                 .as_ref()
                 .map(|d| d.output_type())
                 .unwrap_or(DataType::Log),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -532,7 +546,7 @@ mod tests {
         write::{GzEncoder, ZlibEncoder},
         Compression,
     };
-    use futures::Stream;
+    use futures::{Stream, StreamExt};
     use headers::authorization::Credentials;
     use headers::Authorization;
     use http::header::AUTHORIZATION;
@@ -543,12 +557,13 @@ mod tests {
         BytesDecoderConfig, JsonDeserializerConfig,
     };
     use vector_lib::config::LogNamespace;
-    use vector_lib::event::LogEvent;
+    use vector_lib::event::{into_event_stream, LogEvent};
     use vector_lib::lookup::lookup_v2::OptionalValuePath;
     use vector_lib::lookup::{event_path, owned_value_path, OwnedTargetPath, PathPrefix};
     use vector_lib::schema::Definition;
     use vrl::value::{kind::Collection, Kind, ObjectMap};
 
+    use crate::codecs::DECODING_ERRORS_OUTPUT;
     use crate::common::http::server_auth::HttpServerAuthConfig;
     use crate::sources::http_server::HttpMethod;
     use crate::{
@@ -556,6 +571,7 @@ mod tests {
         config::{log_schema, SourceConfig, SourceContext},
         event::{Event, EventStatus, Value},
         test_util::{
+            collect_n,
             components::{self, assert_source_compliance, HTTP_PUSH_SOURCE_TAGS},
             next_addr, spawn_collect_n, wait_for_tcp,
         },
@@ -613,6 +629,7 @@ mod tests {
                 method,
                 framing,
                 decoding,
+                reroute_decoding_errors: false,
                 acknowledgements: acknowledgements.into(),
                 log_namespace: None,
                 keepalive: Default::default(),
@@ -866,6 +883,39 @@ mod tests {
         assert!(events.remove(0).as_log().get_timestamp().is_some());
     }
 
+    #[tokio::test]
+    async fn http_json_parsing_reroute_errors() {
+        let (mut sender, recv) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let errors = sender.add_outputs(EventStatus::Delivered, DECODING_ERRORS_OUTPUT.to_owned());
+        let address = next_addr();
+        let context = SourceContext::new_test(sender, None);
+        tokio::spawn(async move {
+            SimpleHttpConfig {
+                address,
+                decoding: Some(JsonDeserializerConfig::default().into()),
+                reroute_decoding_errors: true,
+                ..Default::default()
+            }
+            .build(context)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+        });
+        wait_for_tcp(address).await;
+
+        assert_eq!(200, send(address, "{").await); //malformed
+        assert_eq!(200, send(address, r#"{"key":"value"}"#).await);
+
+        let events = collect_n(recv, 1).await;
+        assert_eq!(events[0].as_log()["key"], "value".into());
+
+        let errors = collect_n(errors.flat_map(into_event_stream), 1).await;
+        let error = errors[0].as_log();
+        assert_eq!(*error.get_message().unwrap(), "{".into());
+        assert!(error.get("error").is_some());
+    }
+
     #[tokio::test]
     async fn http_json_values() {
         let mut events = assert_source_compliance(&HTTP_PUSH_SOURCE_TAGS, async {
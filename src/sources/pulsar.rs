@@ -34,7 +34,7 @@ use vector_lib::{
 use vrl::{owned_value_path, path, value::Kind};
 
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{SourceConfig, SourceContext},
     event::BatchNotifier,
     internal_events::{
@@ -93,6 +93,13 @@ pub struct PulsarSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decoding_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -213,6 +220,7 @@ impl SourceConfig for PulsarSourceConfig {
         let consumer = self.create_consumer().await?;
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_reroute_errors(self.reroute_decoding_errors)
                 .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
@@ -254,10 +262,14 @@ impl SourceConfig for PulsarSourceConfig {
                 Kind::bytes(),
                 Some("producer_name"),
             );
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -642,6 +654,7 @@ mod integration_tests {
             dead_letter_queue_policy: None,
             framing: FramingConfig::Bytes,
             decoding: DeserializerConfig::Bytes,
+            reroute_decoding_errors: false,
             acknowledgements: acknowledgements.into(),
             log_namespace: None,
             tls: tls.clone(),
@@ -10,7 +10,7 @@ use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path};
 use vrl::value::{kind::Collection, Kind};
 
 use crate::{
-    codecs::DecodingConfig,
+    codecs::{decoding_errors_output, DecodingConfig},
     config::{GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput},
     sources::util::net::TcpSource,
     tls::MaybeTlsSettings,
@@ -67,6 +67,17 @@ impl SocketConfig {
         }
     }
 
+    const fn reroute_decoding_errors(&self) -> bool {
+        match &self.mode {
+            Mode::Tcp(config) => config.reroute_decoding_errors,
+            Mode::Udp(config) => config.reroute_decoding_errors,
+            #[cfg(unix)]
+            Mode::UnixDatagram(config) => config.reroute_decoding_errors,
+            #[cfg(unix)]
+            Mode::UnixStream(config) => config.reroute_decoding_errors,
+        }
+    }
+
     fn log_namespace(&self, global_log_namespace: LogNamespace) -> LogNamespace {
         match &self.mode {
             Mode::Tcp(config) => global_log_namespace.merge(config.log_namespace),
@@ -122,6 +133,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
+                .with_reroute_errors(config.reroute_decoding_errors)
                 .build()?;
 
                 let tcp = tcp::RawTcpSource::new(config.clone(), decoder, log_namespace);
@@ -155,7 +167,9 @@ impl SourceConfig for SocketConfig {
                     .framing()
                     .clone()
                     .unwrap_or_else(|| decoding.default_message_based_framing());
-                let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+                let decoder = DecodingConfig::new(framing, decoding, log_namespace)
+                    .with_reroute_errors(config.reroute_decoding_errors)
+                    .build()?;
                 Ok(udp::udp(
                     config,
                     decoder,
@@ -172,7 +186,9 @@ impl SourceConfig for SocketConfig {
                     .framing
                     .clone()
                     .unwrap_or_else(|| decoding.default_message_based_framing());
-                let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+                let decoder = DecodingConfig::new(framing, decoding, log_namespace)
+                    .with_reroute_errors(config.reroute_decoding_errors)
+                    .build()?;
 
                 unix::unix_datagram(config, decoder, cx.shutdown, cx.out, log_namespace)
             }
@@ -189,6 +205,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
+                .with_reroute_errors(config.reroute_decoding_errors)
                 .build()?;
 
                 unix::unix_stream(config, decoder, cx.shutdown, cx.out, log_namespace)
@@ -288,10 +305,14 @@ impl SourceConfig for SocketConfig {
             }
         };
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding().output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors() {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
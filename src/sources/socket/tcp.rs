@@ -85,6 +85,13 @@ pub struct TcpConfig {
     #[serde(default = "default_decoding")]
     pub(super) decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub(super) reroute_decoding_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -113,6 +120,7 @@ impl TcpConfig {
             max_connection_duration_secs: None,
             framing: None,
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             connection_limit: None,
             log_namespace: None,
         }
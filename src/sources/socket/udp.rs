@@ -80,6 +80,13 @@ pub struct UdpConfig {
     #[serde(default = "default_decoding")]
     pub(super) decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub(super) reroute_decoding_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -124,6 +131,7 @@ impl UdpConfig {
             receive_buffer_bytes: None,
             framing: None,
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             log_namespace: None,
         }
     }
@@ -61,6 +61,13 @@ pub struct UnixConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decoding_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -75,6 +82,7 @@ impl UnixConfig {
             host_key: default_host_key(),
             framing: None,
             decoding: default_decoding(),
+            reroute_decoding_errors: false,
             log_namespace: None,
         }
     }
@@ -5,23 +5,24 @@ use vector_lib::ipallowlist::IpAllowlistConfig;
 
 use bytes::Bytes;
 use chrono::Utc;
+use derivative::Derivative;
 use futures::StreamExt;
 use listenfd::ListenFd;
 use smallvec::SmallVec;
 use tokio_util::udp::UdpFramed;
 use vector_lib::codecs::{
-    decoding::{Deserializer, Framer},
-    BytesDecoder, OctetCountingDecoder, SyslogDeserializerConfig,
+    decoding::{DeserializerConfig, FramingConfig, OctetCountingDecoderOptions},
+    OctetCountingDecoderConfig, SyslogDeserializerConfig,
 };
 use vector_lib::config::{LegacyKey, LogNamespace};
 use vector_lib::configurable::configurable_component;
-use vector_lib::lookup::{lookup_v2::OptionalValuePath, path, OwnedValuePath};
-use vrl::event_path;
+use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path, path, OwnedValuePath};
+use vrl::{event_path, value::Kind};
 
 #[cfg(unix)]
 use crate::sources::util::build_unix_stream_source;
 use crate::{
-    codecs::Decoder,
+    codecs::{decoding_errors_output, Decoder, DecodingConfig},
     config::{
         log_schema, DataType, GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput,
     },
@@ -60,6 +61,21 @@ pub struct SyslogConfig {
     /// [global_host_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.host_key
     host_key: Option<OptionalValuePath>,
 
+    /// Configures how the received messages are decoded.
+    ///
+    /// By default, messages are decoded as syslog. The `cef` and `leef` codecs skip the syslog
+    /// header of each message and decode the security event that follows it instead.
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default = "default_decoding")]
+    decoding: DeserializerConfig,
+
+    /// Whether to route frames that fail to decode to the `errors` output, instead of dropping them.
+    ///
+    /// Each routed event holds the raw bytes of the frame and the decoding error message.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decoding_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -135,9 +151,48 @@ impl SyslogConfig {
             mode,
             host_key: None,
             max_length: crate::serde::default_max_length(),
+            decoding: default_decoding(),
+            reroute_decoding_errors: false,
             log_namespace: None,
         }
     }
+
+    /// The configured deserializer. The `syslog` codec is tied to this source, which lays out the
+    /// syslog fields as source metadata when using the Vector namespace.
+    fn deserializer_config(&self) -> DeserializerConfig {
+        match &self.decoding {
+            DeserializerConfig::Syslog(config) => {
+                let mut syslog = SyslogDeserializerConfig::from_source(SyslogConfig::NAME);
+                syslog.syslog = config.syslog.clone();
+                syslog.into()
+            }
+            decoding => decoding.clone(),
+        }
+    }
+
+    /// Builds the decoder of the messages, which are split into frames by `framing`.
+    fn decoder(
+        &self,
+        framing: FramingConfig,
+        log_namespace: LogNamespace,
+    ) -> crate::Result<Decoder> {
+        DecodingConfig::new(framing, self.deserializer_config(), log_namespace)
+            .with_reroute_errors(self.reroute_decoding_errors)
+            .build()
+    }
+
+    /// The framing of messages sent over streams.
+    const fn octet_counting(&self) -> FramingConfig {
+        FramingConfig::OctetCounting(OctetCountingDecoderConfig {
+            octet_counting: OctetCountingDecoderOptions {
+                max_length: Some(self.max_length),
+            },
+        })
+    }
+}
+
+fn default_decoding() -> DeserializerConfig {
+    SyslogDeserializerConfig::default().into()
 }
 
 impl Default for SyslogConfig {
@@ -153,6 +208,8 @@ impl Default for SyslogConfig {
             },
             host_key: None,
             max_length: crate::serde::default_max_length(),
+            decoding: default_decoding(),
+            reroute_decoding_errors: false,
             log_namespace: None,
         }
     }
@@ -185,7 +242,7 @@ impl SourceConfig for SyslogConfig {
                 connection_limit,
             } => {
                 let source = SyslogTcpSource {
-                    max_length: self.max_length,
+                    decoder: self.decoder(self.octet_counting(), log_namespace)?,
                     host_key,
                     log_namespace,
                 };
@@ -218,6 +275,7 @@ impl SourceConfig for SyslogConfig {
             } => Ok(udp(
                 address,
                 self.max_length,
+                self.decoder(FramingConfig::Bytes, log_namespace)?,
                 host_key,
                 receive_buffer_bytes,
                 cx.shutdown,
@@ -229,14 +287,7 @@ impl SourceConfig for SyslogConfig {
                 path,
                 socket_file_mode,
             } => {
-                let decoder = Decoder::new(
-                    Framer::OctetCounting(OctetCountingDecoder::new_with_max_length(
-                        self.max_length,
-                    )),
-                    Deserializer::Syslog(
-                        SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build(),
-                    ),
-                );
+                let decoder = self.decoder(self.octet_counting(), log_namespace)?;
 
                 build_unix_stream_source(
                     path,
@@ -252,14 +303,43 @@ impl SourceConfig for SyslogConfig {
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
-        let schema_definition = SyslogDeserializerConfig::from_source(SyslogConfig::NAME)
+        let mut schema_definition = self
+            .deserializer_config()
             .schema_definition(log_namespace)
             .with_standard_vector_source_metadata();
 
-        vec![SourceOutput::new_maybe_logs(
+        // The syslog codec already accounts for the fields added by this source.
+        if !matches!(self.decoding, DeserializerConfig::Syslog(_)) {
+            let host_key = self
+                .host_key
+                .clone()
+                .and_then(|k| k.path)
+                .or(log_schema().host_key().cloned());
+            schema_definition = schema_definition
+                .with_source_metadata(
+                    SyslogConfig::NAME,
+                    host_key.map(LegacyKey::Overwrite),
+                    &owned_value_path!("host"),
+                    Kind::bytes().or_undefined(),
+                    Some("host"),
+                )
+                .with_source_metadata(
+                    SyslogConfig::NAME,
+                    Some(LegacyKey::Overwrite(owned_value_path!("source_ip"))),
+                    &owned_value_path!("source_ip"),
+                    Kind::bytes().or_undefined(),
+                    None,
+                );
+        }
+
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             DataType::Log,
             schema_definition,
-        )]
+        )];
+        if self.reroute_decoding_errors {
+            outputs.push(decoding_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -276,9 +356,11 @@ impl SourceConfig for SyslogConfig {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
 struct SyslogTcpSource {
-    max_length: usize,
+    #[derivative(Debug = "ignore")]
+    decoder: Decoder,
     host_key: Option<OwnedValuePath>,
     log_namespace: LogNamespace,
 }
@@ -290,10 +372,7 @@ impl TcpSource for SyslogTcpSource {
     type Acker = TcpNullAcker;
 
     fn decoder(&self) -> Self::Decoder {
-        Decoder::new(
-            Framer::OctetCounting(OctetCountingDecoder::new_with_max_length(self.max_length)),
-            Deserializer::Syslog(SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build()),
-        )
+        self.decoder.clone()
     }
 
     fn handle_events(&self, events: &mut [Event], host: SocketAddr) {
@@ -313,6 +392,7 @@ impl TcpSource for SyslogTcpSource {
 pub fn udp(
     addr: SocketListenAddr,
     _max_length: usize,
+    decoder: Decoder,
     host_key: Option<OwnedValuePath>,
     receive_buffer_bytes: Option<usize>,
     shutdown: ShutdownSignal,
@@ -340,36 +420,33 @@ pub fn udp(
             r#type = "udp"
         );
 
-        let mut stream = UdpFramed::new(
-            socket,
-            Decoder::new(
-                Framer::Bytes(BytesDecoder::new()),
-                Deserializer::Syslog(
-                    SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build(),
-                ),
-            ),
-        )
-        .take_until(shutdown)
-        .filter_map(|frame| {
-            let host_key = host_key.clone();
-            async move {
-                match frame {
-                    Ok(((mut events, _byte_size), received_from)) => {
-                        let received_from = received_from.ip().to_string().into();
-                        handle_events(&mut events, &host_key, Some(received_from), log_namespace);
-                        Some(events.remove(0))
-                    }
-                    Err(error) => {
-                        emit!(SocketReceiveError {
-                            mode: SocketMode::Udp,
-                            error: &error,
-                        });
-                        None
+        let mut stream = UdpFramed::new(socket, decoder)
+            .take_until(shutdown)
+            .filter_map(|frame| {
+                let host_key = host_key.clone();
+                async move {
+                    match frame {
+                        Ok(((mut events, _byte_size), received_from)) => {
+                            let received_from = received_from.ip().to_string().into();
+                            handle_events(
+                                &mut events,
+                                &host_key,
+                                Some(received_from),
+                                log_namespace,
+                            );
+                            Some(events.remove(0))
+                        }
+                        Err(error) => {
+                            emit!(SocketReceiveError {
+                                mode: SocketMode::Udp,
+                                error: &error,
+                            });
+                            None
+                        }
                     }
                 }
-            }
-        })
-        .boxed();
+            })
+            .boxed();
 
         match out.send_event_stream(&mut stream).await {
             Ok(()) => {
@@ -650,6 +727,21 @@ mod test {
         assert_eq!(definitions, Some(expected_definition));
     }
 
+    #[test]
+    fn output_decoding_errors() {
+        let config = SyslogConfig {
+            reroute_decoding_errors: true,
+            ..Default::default()
+        };
+
+        let outputs = config.outputs(LogNamespace::Legacy);
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs[1].port.as_deref(),
+            Some(crate::codecs::DECODING_ERRORS_OUTPUT)
+        );
+    }
+
     #[test]
     fn config_tcp() {
         let config: SyslogConfig = toml::from_str(
@@ -759,6 +851,43 @@ mod test {
         assert_eq!(receive_buffer_bytes, Some(256));
     }
 
+    #[test]
+    fn config_decoding_cef() {
+        let config: SyslogConfig = toml::from_str(
+            r#"
+            mode = "udp"
+            address = "127.0.0.1:1235"
+            decoding.codec = "cef"
+          "#,
+        )
+        .unwrap();
+        assert!(matches!(config.decoding, DeserializerConfig::Cef(_)));
+
+        let mut events = config
+            .deserializer_config()
+            .build()
+            .unwrap()
+            .parse(
+                Bytes::from(
+                    "<134>Feb 14 19:04:54 fw01 CEF:0|Security|threatmanager|1.0|100|worm stopped|10|src=10.0.0.1 spt=1232",
+                ),
+                LogNamespace::Legacy,
+            )
+            .unwrap();
+        handle_events(
+            &mut events,
+            &Some(owned_value_path!("host")),
+            Some(Bytes::from("192.0.2.1")),
+            LogNamespace::Legacy,
+        );
+
+        let log = events[0].as_log();
+        assert_eq!(log["deviceVendor"], "Security".into());
+        assert_eq!(log["spt"], 1232.into());
+        assert_eq!(log["host"], "192.0.2.1".into());
+        assert_eq!(log["source_ip"], "192.0.2.1".into());
+    }
+
     #[cfg(unix)]
     #[test]
     fn config_unix() {
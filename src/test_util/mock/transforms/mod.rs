@@ -22,4 +22,8 @@ pub enum TransformType {
 
     /// Transforms events in an asynchronous iterator.
     Task,
+
+    /// Transforms events in an asynchronous iterator, but can send the transformed event(s) to
+    /// different downstream outputs.
+    MultiOutputTask,
 }
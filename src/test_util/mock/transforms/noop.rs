@@ -1,14 +1,16 @@
 use std::pin::Pin;
 
 use async_trait::async_trait;
-use futures_util::Stream;
+use futures_util::{Stream, StreamExt};
 use vector_lib::config::LogNamespace;
 use vector_lib::configurable::configurable_component;
 use vector_lib::{
     config::{DataType, Input, TransformOutput},
     event::{Event, EventContainer},
     schema::Definition,
-    transform::{FunctionTransform, OutputBuffer, TaskTransform, Transform},
+    transform::{
+        FunctionTransform, MultiOutputTaskTransform, OutputBuffer, TaskTransform, Transform,
+    },
 };
 
 use crate::config::{GenerateConfig, OutputId, TransformConfig, TransformContext};
@@ -59,6 +61,9 @@ impl TransformConfig for NoopTransformConfig {
             TransformType::Function => Ok(Transform::Function(Box::new(NoopTransform))),
             TransformType::Synchronous => Ok(Transform::Synchronous(Box::new(NoopTransform))),
             TransformType::Task => Ok(Transform::Task(Box::new(NoopTransform))),
+            TransformType::MultiOutputTask => {
+                Ok(Transform::MultiOutputTask(Box::new(NoopTransform)))
+            }
         }
     }
 }
@@ -89,3 +94,12 @@ where
         Box::pin(task)
     }
 }
+
+impl MultiOutputTaskTransform for NoopTransform {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn futures_util::Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = (Option<&'static str>, Event)> + Send>> {
+        Box::pin(task.map(|event| (None, event)))
+    }
+}
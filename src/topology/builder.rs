@@ -6,7 +6,7 @@ use std::{
     time::Instant,
 };
 
-use futures::{stream::FuturesOrdered, FutureExt, StreamExt, TryStreamExt};
+use futures::{future, stream::FuturesOrdered, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use futures_util::stream::FuturesUnordered;
 use stream_cancel::{StreamExt as StreamCancelExt, Trigger, Tripwire};
 use tokio::{
@@ -33,6 +33,7 @@ use vector_lib::{
 };
 
 use super::{
+    dead_letter,
     fanout::{self, Fanout},
     schema,
     task::{Task, TaskOutput, TaskResult},
@@ -41,16 +42,20 @@ use super::{
 use crate::{
     config::{
         ComponentKey, Config, DataType, EnrichmentTableConfig, Input, Inputs, OutputId,
-        ProxyConfig, SinkContext, SourceContext, TransformContext, TransformOuter, TransformOutput,
+        ProxyConfig, SinkContext, SinkHealthcheckOptions, SourceContext, TopologyShutdown,
+        TransformContext, TransformOuter, TransformOutput,
     },
-    event::{EventArray, EventContainer},
+    event::{into_event_stream, EventArray, EventContainer},
     extra_context::ExtraContext,
     internal_events::EventsReceived,
     shutdown::SourceShutdownCoordinator,
     source_sender::{SourceSenderItem, CHUNK_SIZE},
     spawn_named,
     topology::task::TaskError,
-    transforms::{SyncTransform, TaskTransform, Transform, TransformOutputs, TransformOutputsBuf},
+    transforms::{
+        MultiOutputTaskTransform, SyncTransform, TaskTransform, Transform, TransformOutputs,
+        TransformOutputsBuf,
+    },
     utilization::wrap,
     SourceSender,
 };
@@ -84,6 +89,7 @@ struct Builder<'a> {
     healthchecks: HashMap<ComponentKey, Task>,
     detach_triggers: HashMap<ComponentKey, Trigger>,
     extra_context: ExtraContext,
+    topology_shutdowns: HashMap<ComponentKey, TopologyShutdown>,
 }
 
 impl<'a> Builder<'a> {
@@ -105,6 +111,7 @@ impl<'a> Builder<'a> {
             healthchecks: HashMap::new(),
             detach_triggers: HashMap::new(),
             extra_context,
+            topology_shutdowns: HashMap::new(),
         }
     }
 
@@ -128,6 +135,7 @@ impl<'a> Builder<'a> {
                 healthchecks: self.healthchecks,
                 shutdown_coordinator: self.shutdown_coordinator,
                 detach_triggers: self.detach_triggers,
+                topology_shutdowns: self.topology_shutdowns,
             })
         } else {
             Err(self.errors)
@@ -480,6 +488,11 @@ impl<'a> Builder<'a> {
                 merged_schema_definition: merged_definition.clone(),
                 schema: self.config.schema,
                 extra_context: self.extra_context.clone(),
+                topology_shutdown: self
+                    .topology_shutdowns
+                    .entry(key.clone())
+                    .or_default()
+                    .clone(),
             };
 
             let node = TransformNode::from_parts(
@@ -604,6 +617,50 @@ impl<'a> Builder<'a> {
                 extra_context: self.extra_context.clone(),
             };
 
+            let dead_letter = match sink.dead_letter.as_ref() {
+                None => None,
+                Some(dead_letter) => {
+                    // The dead-letter sink is a component of its own, so its span isn't nested in
+                    // the one of the sink and it doesn't inherit the proxy or healthcheck URI of
+                    // the sink.
+                    let dead_letter_span = error_span!(
+                        parent: None,
+                        "sink",
+                        component_kind = "sink",
+                        component_id = %key.join("dead_letter").id(),
+                        component_type = %dead_letter.get_component_name(),
+                    );
+                    let dead_letter_cx = SinkContext {
+                        healthcheck: SinkHealthcheckOptions {
+                            enabled: cx.healthcheck.enabled,
+                            uri: None,
+                        },
+                        proxy: ProxyConfig::merge_with_env(
+                            &self.config.global.proxy,
+                            &ProxyConfig::default(),
+                        ),
+                        ..cx.clone()
+                    };
+                    match dead_letter
+                        .build(dead_letter_cx)
+                        .instrument(dead_letter_span.clone())
+                        .await
+                    {
+                        Err(error) => {
+                            self.errors
+                                .push(format!("Sink \"{}\": Dead-letter sink: {}", key, error));
+                            continue;
+                        }
+                        Ok((dead_letter_sink, dead_letter_healthcheck)) => Some((
+                            dead_letter_sink,
+                            dead_letter_healthcheck.instrument(dead_letter_span.clone()),
+                            dead_letter.input().data_type(),
+                            dead_letter_span,
+                        )),
+                    }
+                }
+            };
+
             let (sink, healthcheck) = match sink.inner.build(cx).await {
                 Err(error) => {
                     self.errors.push(format!("Sink \"{}\": {}", key, error));
@@ -612,8 +669,24 @@ impl<'a> Builder<'a> {
                 Ok(built) => built,
             };
 
+            let (dead_letter, healthcheck) = match dead_letter {
+                None => (None, healthcheck),
+                Some((
+                    dead_letter_sink,
+                    dead_letter_healthcheck,
+                    dead_letter_type,
+                    dead_letter_span,
+                )) => (
+                    Some((dead_letter_sink, dead_letter_type, dead_letter_span)),
+                    future::try_join(healthcheck, dead_letter_healthcheck)
+                        .map_ok(|_| ())
+                        .boxed(),
+                ),
+            };
+
             let (trigger, tripwire) = Tripwire::new();
 
+            let component_key = key.clone();
             let sink = async move {
                 debug!("Sink starting.");
 
@@ -632,26 +705,42 @@ impl<'a> Builder<'a> {
                 let mut rx = wrap(rx);
 
                 let events_received = register!(EventsReceived);
-                sink.run(
-                    rx.by_ref()
-                        .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
-                        .inspect(|events| {
-                            events_received.emit(CountByteSize(
-                                events.len(),
-                                events.estimated_json_encoded_size_of(),
-                            ))
-                        })
-                        .take_until_if(tripwire),
-                )
-                .await
-                .map(|_| {
-                    debug!("Sink finished normally.");
-                    TaskOutput::Sink(rx)
-                })
-                .map_err(|_| {
-                    debug!("Sink finished with an error.");
-                    TaskError::Opaque
-                })
+                let input = rx
+                    .by_ref()
+                    .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
+                    .inspect(|events| {
+                        events_received.emit(CountByteSize(
+                            events.len(),
+                            events.estimated_json_encoded_size_of(),
+                        ))
+                    })
+                    .take_until_if(tripwire);
+
+                let result = match dead_letter {
+                    None => sink.run(input).await,
+                    Some((dead_letter_sink, dead_letter_type, dead_letter_span)) => {
+                        let (input, dead_letters) =
+                            dead_letter::route(input, component_key, typetag, dead_letter_type);
+                        let (result, dead_letter_result) = future::join(
+                            sink.run(input),
+                            dead_letter_sink
+                                .run(dead_letters)
+                                .instrument(dead_letter_span),
+                        )
+                        .await;
+                        result.and(dead_letter_result)
+                    }
+                };
+
+                result
+                    .map(|_| {
+                        debug!("Sink finished normally.");
+                        TaskOutput::Sink(rx)
+                    })
+                    .map_err(|_| {
+                        debug!("Sink finished with an error.");
+                        TaskError::Opaque
+                    })
             };
 
             let task = Task::new(key.clone(), typetag, sink);
@@ -711,6 +800,7 @@ pub struct TopologyPieces {
     pub(super) healthchecks: HashMap<ComponentKey, Task>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(crate) topology_shutdowns: HashMap<ComponentKey, TopologyShutdown>,
 }
 
 impl TopologyPieces {
@@ -744,7 +834,7 @@ impl TopologyPieces {
     }
 }
 
-const fn filter_events_type(events: &EventArray, data_type: DataType) -> bool {
+pub(crate) const fn filter_events_type(events: &EventArray, data_type: DataType) -> bool {
     match events {
         EventArray::Logs(_) => data_type.contains(DataType::Log),
         EventArray::Metrics(_) => data_type.contains(DataType::Metric),
@@ -802,6 +892,7 @@ fn build_transform(
             &node.key,
             &node.outputs,
         ),
+        Transform::MultiOutputTask(t) => build_multi_output_task_transform(t, node, input_rx),
     }
 }
 
@@ -1061,3 +1152,62 @@ fn build_task_transform(
 
     (task, outputs)
 }
+
+fn build_multi_output_task_transform(
+    t: Box<dyn MultiOutputTaskTransform>,
+    node: TransformNode,
+    input_rx: BufferReceiver<EventArray>,
+) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
+    // 128 is an arbitrary, smallish constant
+    const OUTPUT_BATCH_SIZE: usize = 128;
+
+    let (mut outputs, controls) = TransformOutputs::new(node.outputs, &node.key);
+
+    let input_type = node.input_details.data_type();
+    let input_rx = crate::utilization::wrap(input_rx.into_stream());
+
+    let events_received = register!(EventsReceived);
+    let filtered = input_rx
+        .filter(move |events| ready(filter_events_type(events, input_type)))
+        .inspect(move |events| {
+            events_received.emit(CountByteSize(
+                events.len(),
+                events.estimated_json_encoded_size_of(),
+            ))
+        })
+        .flat_map(into_event_stream);
+
+    let mut stream = t
+        .transform(Box::pin(filtered))
+        .ready_chunks(OUTPUT_BATCH_SIZE);
+    let transform = async move {
+        debug!("Multi-output task transform starting.");
+
+        while let Some(events) = stream.next().await {
+            let mut outputs_buf = outputs.new_buf_with_capacity(events.len());
+            for (output, event) in events {
+                outputs_buf.push(output, event);
+            }
+            if let Err(e) = outputs.send(&mut outputs_buf).await {
+                debug!("Multi-output task transform finished with an error.");
+                return Err(TaskError::wrapped(e));
+            }
+        }
+
+        debug!("Multi-output task transform finished normally.");
+        Ok(TaskOutput::Transform)
+    }
+    .boxed();
+
+    let mut output_controls = HashMap::new();
+    for (name, control) in controls {
+        let id = name
+            .map(|name| OutputId::from((&node.key, name)))
+            .unwrap_or_else(|| OutputId::from(&node.key));
+        output_controls.insert(id, control);
+    }
+
+    let task = Task::new(node.key.clone(), node.typetag, transform);
+
+    (task, output_controls)
+}
@@ -0,0 +1,388 @@
+//! Routing of events that a sink permanently failed to deliver to its dead-letter sink.
+//!
+//! Each event entering the sink is tracked by its own batch notifier, while the array of events,
+//! holding the original finalizers, is kept aside. The logs and traces kept aside share their fields
+//! with the ones handed to the sink, so that they are only copied if the sink modifies them. Once
+//! the sink finalizes the events, the rejected ones are sent to the dead-letter sink, and the
+//! original finalizers of the others are updated with the status reported by the sink. This way,
+//! acknowledgements for rejected events are only sent once the dead-letter sink has handled them.
+//!
+//! The number of events kept aside is bounded, so that the sink stops receiving events while the
+//! dead-letter sink falls behind.
+
+use std::sync::Arc;
+
+use futures::{future, stream, Stream, StreamExt};
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use vector_lib::config::LogNamespace;
+use vector_lib::event::{
+    array::events_into_arrays, BatchNotifier, BatchStatus, BatchStatusReceiver, EventFinalizer,
+    EventFinalizers, EventStatus,
+};
+use vector_lib::lookup::{metadata_path, PathPrefix};
+use vrl::path;
+
+use crate::{
+    config::{log_schema, ComponentKey, DataType},
+    event::{Event, EventArray, EventContainer, ObjectMap, Value},
+    source_sender::CHUNK_SIZE,
+};
+
+/// Identifies the sink that rejected the events routed to a dead-letter sink.
+struct Origin {
+    component_key: ComponentKey,
+    component_type: &'static str,
+}
+
+/// The maximum length of the failure reasons attached to dead letters, in bytes.
+///
+/// Failure reasons can hold whole responses of the sink's downstream service, which would otherwise
+/// be copied into every event of the rejected batch.
+const MAX_MESSAGE_LENGTH: usize = 1024;
+
+/// The data attached to the events rejected for the same reason.
+struct DeadLetter {
+    message: String,
+    data: Value,
+}
+
+impl Origin {
+    fn dead_letter(&self, reason: &str) -> DeadLetter {
+        let mut end = reason.len().min(MAX_MESSAGE_LENGTH);
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        let message = reason[..end].to_string();
+        let data = Value::Object(ObjectMap::from([
+            ("status".into(), "rejected".into()),
+            ("message".into(), Value::Bytes(message.clone().into())),
+            ("component_id".into(), self.component_key.to_string().into()),
+            ("component_type".into(), self.component_type.into()),
+            ("component_kind".into(), "sink".into()),
+        ]));
+        DeadLetter { message, data }
+    }
+
+    fn annotate(&self, event: &mut Event, dead_letter: &DeadLetter) {
+        match event {
+            Event::Log(ref mut log) => match log.namespace() {
+                LogNamespace::Legacy => {
+                    if let Some(metadata_key) = log_schema().metadata_key() {
+                        log.insert(
+                            (PathPrefix::Event, metadata_key.concat(path!("dead_letter"))),
+                            dead_letter.data.clone(),
+                        );
+                    }
+                }
+                LogNamespace::Vector => {
+                    log.insert(
+                        metadata_path!("vector", "dead_letter"),
+                        dead_letter.data.clone(),
+                    );
+                }
+            },
+            Event::Metric(ref mut metric) => {
+                if let Some(metadata_key) = log_schema().metadata_key() {
+                    metric.replace_tag(
+                        format!("{}.dead_letter.status", metadata_key),
+                        "rejected".into(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dead_letter.message", metadata_key),
+                        dead_letter.message.clone(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dead_letter.component_id", metadata_key),
+                        self.component_key.to_string(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dead_letter.component_type", metadata_key),
+                        self.component_type.into(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dead_letter.component_kind", metadata_key),
+                        "sink".into(),
+                    );
+                }
+            }
+            Event::Trace(ref mut trace) => {
+                trace.maybe_insert(log_schema().metadata_key_target_path(), || {
+                    dead_letter.data.clone()
+                });
+            }
+        }
+    }
+}
+
+/// The maximum number of events kept aside until the sink finalizes them.
+///
+/// This is large enough for the sink to fill its batches, which are flushed once their timeout
+/// expires anyway.
+const MAX_PENDING_EVENTS: usize = 100_000;
+
+/// An array of events whose delivery through the sink has not been finalized yet.
+struct Pending {
+    events: EventArray,
+    trackers: Vec<(BatchStatusReceiver, EventFinalizers)>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Pending {
+    /// Replaces the finalizers of the events with ones reporting the status of each event, and
+    /// keeps the events aside along with their original finalizers.
+    fn track(events: &mut EventArray, permit: OwnedSemaphorePermit) -> Self {
+        let finalizers = events
+            .iter_events_mut()
+            .map(|mut event| event.metadata_mut().take_finalizers())
+            .collect::<Vec<_>>();
+        let kept = events.clone();
+        let trackers = events
+            .iter_events_mut()
+            .zip(finalizers)
+            .map(|(mut event, finalizers)| {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                event
+                    .metadata_mut()
+                    .add_finalizer(EventFinalizer::new(batch));
+                (receiver, finalizers)
+            })
+            .collect();
+
+        Self {
+            events: kept,
+            trackers,
+            _permit: permit,
+        }
+    }
+
+    /// Hands the original finalizers back to the events, once they can't be routed anymore.
+    fn untrack(self, events: &mut EventArray) {
+        for (mut event, (_, finalizers)) in events.iter_events_mut().zip(self.trackers) {
+            event.metadata_mut().merge_finalizers(finalizers);
+        }
+    }
+
+    /// Waits for the sink to finalize the events, returning the rejected ones.
+    async fn resolve(self, origin: Arc<Origin>) -> Vec<EventArray> {
+        let Self {
+            events, trackers, ..
+        } = self;
+        let rejections = future::join_all(
+            trackers
+                .into_iter()
+                .map(|(receiver, finalizers)| rejection(receiver, finalizers)),
+        )
+        .await;
+
+        // The events of a batch share the same reason, so their dead-letter data is only built once
+        // for each distinct reason in a row.
+        let mut rejected = Vec::new();
+        let mut last: Option<(Option<Arc<str>>, DeadLetter)> = None;
+        for (mut event, rejection) in events.into_events().zip(rejections) {
+            let Some((reason, finalizers)) = rejection else {
+                continue;
+            };
+            let (_, dead_letter) = match last.take() {
+                Some((cached, dead_letter)) if same_reason(&cached, &reason) => {
+                    last.insert((cached, dead_letter))
+                }
+                _ => {
+                    let message = reason.as_deref().unwrap_or("Request was rejected.");
+                    let dead_letter = origin.dead_letter(message);
+                    last.insert((reason, dead_letter))
+                }
+            };
+            origin.annotate(&mut event, dead_letter);
+            event.metadata_mut().merge_finalizers(finalizers);
+            rejected.push(event);
+        }
+        events_into_arrays(rejected, Some(CHUNK_SIZE)).collect()
+    }
+}
+
+fn same_reason(a: &Option<Arc<str>>, b: &Option<Arc<str>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Waits for the sink to finalize an event, returning the reason for the rejection along with the
+/// original finalizers if it was rejected, or updating these finalizers otherwise.
+async fn rejection(
+    receiver: BatchStatusReceiver,
+    finalizers: EventFinalizers,
+) -> Option<(Option<Arc<str>>, EventFinalizers)> {
+    let (status, reason) = receiver.recv_with_reason().await;
+    let status = match status {
+        BatchStatus::Rejected => return Some((reason, finalizers)),
+        BatchStatus::Errored => EventStatus::Errored,
+        BatchStatus::Delivered => EventStatus::Delivered,
+    };
+    finalizers.update_status(status);
+    None
+}
+
+const fn accepts(data_type: DataType, events: &EventArray) -> bool {
+    match events {
+        EventArray::Logs(_) => data_type.contains(DataType::Log),
+        EventArray::Metrics(_) => data_type.contains(DataType::Metric),
+        EventArray::Traces(_) => data_type.contains(DataType::Trace),
+    }
+}
+
+/// Tracks the events of `input` so that the ones rejected by the sink consuming the returned input
+/// stream are emitted, annotated with the reason for the rejection, on the returned dead-letter
+/// stream.
+///
+/// Events not accepted by `data_type` are not tracked, so they are reported as rejected when the
+/// sink rejects them. The dead-letter stream ends once the input stream has been dropped and all of
+/// its events have been finalized.
+pub(super) fn route<S>(
+    input: S,
+    component_key: ComponentKey,
+    component_type: &'static str,
+    data_type: DataType,
+) -> (
+    impl Stream<Item = EventArray>,
+    impl Stream<Item = EventArray>,
+)
+where
+    S: Stream<Item = EventArray>,
+{
+    // The channel is bounded by the permits held by the arrays sent through it.
+    let (pending_tx, pending_rx) = mpsc::unbounded_channel();
+    let permits = Arc::new(Semaphore::new(MAX_PENDING_EVENTS));
+
+    let input = input.then(move |mut events| {
+        let pending_tx = pending_tx.clone();
+        let permits = Arc::clone(&permits);
+        async move {
+            if events.is_empty() || !accepts(data_type, &events) {
+                return events;
+            }
+            let count = events.len().min(MAX_PENDING_EVENTS) as u32;
+            let permit = permits
+                .acquire_many_owned(count)
+                .await
+                .expect("semaphore should not be closed");
+            let pending = Pending::track(&mut events, permit);
+            if let Err(mpsc::error::SendError(pending)) = pending_tx.send(pending) {
+                pending.untrack(&mut events);
+            }
+            events
+        }
+    });
+
+    let origin = Arc::new(Origin {
+        component_key,
+        component_type,
+    });
+    let dead_letters = UnboundedReceiverStream::new(pending_rx)
+        .map(move |pending: Pending| pending.resolve(Arc::clone(&origin)))
+        .buffer_unordered(MAX_PENDING_EVENTS)
+        .flat_map(stream::iter);
+
+    (input, dead_letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::{Finalizable, LogEvent};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn routes_rejected_events_to_dead_letters() {
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let events = EventArray::Logs(vec![
+            LogEvent::from("delivered").with_batch_notifier(&batch),
+            LogEvent::from("rejected").with_batch_notifier(&batch),
+        ]);
+        drop(batch);
+
+        let (input, dead_letters) = route(
+            stream::iter(vec![events]),
+            ComponentKey::from("out"),
+            "test",
+            DataType::all_bits(),
+        );
+
+        let mut sent = input.collect::<Vec<_>>().await.remove(0).into_events();
+        let mut delivered = sent.next().unwrap();
+        let mut rejected = sent.next().unwrap();
+        let finalizers = rejected.take_finalizers();
+        finalizers.set_failure_reason(&Arc::from("400 Bad Request: mapping error"));
+        finalizers.update_status(EventStatus::Rejected);
+        drop(finalizers);
+        delivered
+            .take_finalizers()
+            .update_status(EventStatus::Delivered);
+
+        let mut dead_letters = dead_letters.collect::<Vec<_>>().await;
+        assert_eq!(dead_letters.len(), 1);
+        let mut dead_letters = dead_letters.remove(0).into_events();
+        let mut dead_letter = dead_letters.next().unwrap().into_log();
+        assert!(dead_letters.next().is_none());
+        assert_eq!(dead_letter["message"], "rejected".into());
+        assert_eq!(
+            dead_letter["metadata"],
+            serde_json::json!({
+                "dead_letter": {
+                    "status": "rejected",
+                    "message": "400 Bad Request: mapping error",
+                    "component_id": "out",
+                    "component_type": "test",
+                    "component_kind": "sink",
+                }
+            })
+            .into()
+        );
+
+        // The source is only notified once the dead-letter sink has finalized the event.
+        assert!(receiver.try_recv().is_err());
+        dead_letter
+            .take_finalizers()
+            .update_status(EventStatus::Delivered);
+        drop(dead_letter);
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+    }
+
+    #[test]
+    fn truncates_long_reasons() {
+        let origin = Origin {
+            component_key: ComponentKey::from("out"),
+            component_type: "test",
+        };
+        let reason = "é".repeat(MAX_MESSAGE_LENGTH);
+
+        let dead_letter = origin.dead_letter(&reason);
+
+        assert_eq!(dead_letter.message, "é".repeat(MAX_MESSAGE_LENGTH / 2));
+    }
+
+    #[tokio::test]
+    async fn does_not_route_events_not_accepted() {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let events = EventArray::Logs(vec![LogEvent::from("rejected").with_batch_notifier(&batch)]);
+        drop(batch);
+
+        let (input, dead_letters) = route(
+            stream::iter(vec![events]),
+            ComponentKey::from("out"),
+            "test",
+            DataType::Metric,
+        );
+
+        let mut sent = input.collect::<Vec<_>>().await.remove(0).into_events();
+        sent.next()
+            .unwrap()
+            .take_finalizers()
+            .update_status(EventStatus::Rejected);
+
+        assert!(dead_letters.collect::<Vec<_>>().await.is_empty());
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+    }
+}
@@ -15,7 +15,10 @@ use super::{
     BuiltBuffer, TaskHandle,
 };
 use crate::{
-    config::{ComponentKey, Config, ConfigDiff, HealthcheckOptions, Inputs, OutputId, Resource},
+    config::{
+        ComponentKey, Config, ConfigDiff, HealthcheckOptions, Inputs, OutputId, Resource,
+        TopologyShutdown,
+    },
     event::EventArray,
     extra_context::ExtraContext,
     shutdown::SourceShutdownCoordinator,
@@ -44,6 +47,8 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    /// The shutdown signals given to each running transform.
+    topology_shutdowns: HashMap<ComponentKey, TopologyShutdown>,
     pub(crate) config: Config,
     pub(crate) abort_tx: mpsc::UnboundedSender<ShutdownError>,
     watch: (WatchTx, WatchRx),
@@ -60,6 +65,7 @@ impl RunningTopology {
             outputs_tap_metadata: HashMap::new(),
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            topology_shutdowns: HashMap::new(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -109,6 +115,10 @@ impl RunningTopology {
     pub fn stop(self) -> impl Future<Output = ()> {
         // Update the API's health endpoint to signal shutdown
         self.running.store(false, Ordering::Relaxed);
+        // Let transforms know that their inputs are about to end because of the shutdown.
+        for topology_shutdown in self.topology_shutdowns.values() {
+            topology_shutdown.trigger();
+        }
         // Create handy handles collections of all tasks for the subsequent
         // operations.
         let mut wait_handles = Vec::new();
@@ -403,6 +413,7 @@ impl RunningTopology {
 
             let previous = self.tasks.remove(key).unwrap();
             drop(previous); // detach and forget
+            self.topology_shutdowns.remove(key);
 
             self.remove_inputs(key, diff, new_config).await;
             self.remove_outputs(key);
@@ -1053,6 +1064,11 @@ impl RunningTopology {
 
     fn spawn_transform(&mut self, key: &ComponentKey, new_pieces: &mut builder::TopologyPieces) {
         let task = new_pieces.tasks.remove(key).unwrap();
+        // Replaces the shutdown signal of the previous instance of the transform, if it was rebuilt.
+        if let Some(topology_shutdown) = new_pieces.topology_shutdowns.remove(key) {
+            self.topology_shutdowns
+                .insert(key.clone(), topology_shutdown);
+        }
         let span = error_span!(
             "transform",
             component_kind = "transform",
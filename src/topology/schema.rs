@@ -342,6 +342,51 @@ pub(super) fn validate_sink_expectations(
     Ok(())
 }
 
+/// Validates the fields read by the given sink, such as the fields required by its encoder,
+/// against each pipeline definition feeding into it.
+pub(crate) fn validate_sink_fields(
+    key: &ComponentKey,
+    sink: &SinkOuter<OutputId>,
+    config: &topology::Config,
+    enrichment_tables: vector_lib::enrichment::TableRegistry,
+    cache: &mut Cache,
+) -> Result<(), Vec<String>> {
+    let requirement = sink.inner.input().schema_requirement();
+    if requirement.is_empty() {
+        return Ok(());
+    }
+
+    let definitions = expanded_definitions(enrichment_tables, &sink.inputs, config, cache)
+        .map_err(|err| vec![err.to_string()])?;
+
+    let errors = definitions
+        .iter()
+        .filter_map(|(output, definition)| {
+            requirement
+                .validate_fields(definition)
+                .err()
+                .map(|err| (output, err))
+        })
+        .flat_map(|(output, err)| {
+            err.errors()
+                .iter()
+                .map(|err| {
+                    format!(
+                        "Sink \"{}\" cannot encode events from \"{}\": {}",
+                        key, output, err
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(())
+}
+
 pub trait ComponentContainer {
     fn schema_enabled(&self) -> bool;
 
@@ -118,3 +118,28 @@ async fn test_task_transform_single_event() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn test_multi_output_task_transform_single_event() {
+    assert_transform_compliance(async {
+        let mut original_event = Event::Log(LogEvent::from("function transform being tested"));
+
+        let (topology, rx) =
+            create_topology(original_event.clone(), TransformType::MultiOutputTask).await;
+        topology.stop().await;
+
+        let events = rx.await.expect("must get back event from rx");
+        let mut events = events.into_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+
+        original_event.set_source_id(Arc::new(ComponentKey::from("in")));
+        original_event.set_upstream_id(Arc::new(OutputId::from("transform")));
+        original_event
+            .metadata_mut()
+            .set_schema_definition(&Arc::new(Definition::default_legacy_namespace()));
+
+        let event = events.remove(0);
+        assert_eq!(original_event, event);
+    })
+    .await;
+}
@@ -26,7 +26,7 @@ use tokio::{
     task::yield_now,
     time::{sleep, Duration},
 };
-use vector_lib::buffers::{BufferConfig, BufferType, WhenFull};
+use vector_lib::buffers::{config::DiskBufferCompression, BufferConfig, BufferType, WhenFull};
 use vector_lib::config::ComponentKey;
 use vector_lib::config::OutputId;
 
@@ -874,6 +874,8 @@ async fn topology_disk_buffer_flushes_on_idle() {
     sink1_outer.buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: std::num::NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::DropNewest,
+        encryption: None,
+        compression: DiskBufferCompression::None,
     });
     config.add_sink_outer("out1", sink1_outer);
 
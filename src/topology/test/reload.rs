@@ -7,7 +7,7 @@ use std::{
 use futures::StreamExt;
 use tokio::time::sleep;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use vector_lib::buffers::{BufferConfig, BufferType, WhenFull};
+use vector_lib::buffers::{config::DiskBufferCompression, BufferConfig, BufferType, WhenFull};
 use vector_lib::config::ComponentKey;
 
 use crate::{
@@ -188,6 +188,8 @@ async fn topology_disk_buffer_conflict() {
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        encryption: None,
+        compression: DiskBufferCompression::None,
     });
 
     let mut new_config = old_config.clone();
@@ -195,6 +197,8 @@ async fn topology_disk_buffer_conflict() {
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        encryption: None,
+        compression: DiskBufferCompression::None,
     });
 
     reload_sink_test(
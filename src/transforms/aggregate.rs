@@ -6,10 +6,14 @@ use std::{
 
 use async_stream::stream;
 use futures::{Stream, StreamExt};
-use vector_lib::{config::LogNamespace, event::MetricValue};
+use snafu::Snafu;
+use vector_lib::{config::LogNamespace, event::MetricValue, metrics::AgentDDSketch};
 use vector_lib::{
     configurable::configurable_component,
-    event::metric::{Metric, MetricData, MetricKind, MetricSeries},
+    event::metric::{
+        samples_to_buckets, Metric, MetricData, MetricKind, MetricSeries, MetricSketch, Quantile,
+        Sample, StatisticKind,
+    },
 };
 
 use crate::{
@@ -22,7 +26,7 @@ use crate::{
 
 /// Configuration for the `aggregate` transform.
 #[configurable_component(transform("aggregate", "Aggregate metrics passing through a topology."))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct AggregateConfig {
     /// The interval between flushes, in milliseconds.
@@ -37,6 +41,33 @@ pub struct AggregateConfig {
     #[serde(default = "default_mode")]
     #[configurable(derived)]
     pub mode: AggregationMode,
+
+    /// The upper limits of the buckets to count values into when `mode` is `Histogram`.
+    ///
+    /// The limits must be finite and in strictly increasing order.
+    #[serde(default = "default_histogram_buckets")]
+    #[configurable(metadata(docs::advanced))]
+    pub buckets: Vec<f64>,
+
+    /// The quantiles to calculate when `mode` is `Summary`.
+    ///
+    /// Quantiles are estimated using a sketch, so they have a bounded relative error of about 1%.
+    #[serde(default = "default_summary_quantiles")]
+    #[configurable(metadata(docs::advanced))]
+    pub quantiles: Vec<f64>,
+}
+
+impl Default for AggregateConfig {
+    fn default() -> Self {
+        // `interval_ms` keeps the zero value it has always had here, so that generated configs are
+        // unchanged.
+        Self {
+            interval_ms: 0,
+            mode: default_mode(),
+            buckets: default_histogram_buckets(),
+            quantiles: default_summary_quantiles(),
+        }
+    }
 }
 
 #[configurable_component]
@@ -70,6 +101,18 @@ pub enum AggregationMode {
 
     /// Stdev value of absolute metric, ignores incremental
     Stdev,
+
+    /// Collects the values of absolute gauges and incremental counters into a distribution, ignores
+    /// other metrics
+    Distribution,
+
+    /// Counts the values of absolute gauges and incremental counters into a histogram with the
+    /// configured `buckets`, ignores other metrics
+    Histogram,
+
+    /// Summarizes the values of absolute gauges and incremental counters into the configured
+    /// `quantiles`, ignores other metrics
+    Summary,
 }
 
 const fn default_mode() -> AggregationMode {
@@ -80,6 +123,33 @@ const fn default_interval_ms() -> u64 {
     10 * 1000
 }
 
+fn default_histogram_buckets() -> Vec<f64> {
+    vec![
+        0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+    ]
+}
+
+fn default_summary_quantiles() -> Vec<f64> {
+    vec![0.5, 0.75, 0.9, 0.95, 0.99]
+}
+
+#[derive(Debug, Snafu)]
+enum AggregateConfigError {
+    #[snafu(display("Quantiles must be between 0 and 1, got {quantile}"))]
+    QuantileOutOfRange { quantile: f64 },
+
+    #[snafu(display("At least one bucket must be configured"))]
+    NoBuckets,
+
+    #[snafu(display("Bucket limits must be finite, got {bucket}"))]
+    BucketNotFinite { bucket: f64 },
+
+    #[snafu(display(
+        "Bucket limits must be in strictly increasing order, got {bucket} after {previous}"
+    ))]
+    BucketsNotIncreasing { previous: f64, bucket: f64 },
+}
+
 impl_generate_config_from_default!(AggregateConfig);
 
 #[async_trait::async_trait]
@@ -103,6 +173,34 @@ impl TransformConfig for AggregateConfig {
     }
 }
 
+fn validate_buckets(buckets: &[f64]) -> Result<(), AggregateConfigError> {
+    if buckets.is_empty() {
+        return Err(AggregateConfigError::NoBuckets);
+    }
+    if let Some(bucket) = buckets.iter().find(|bucket| !bucket.is_finite()) {
+        return Err(AggregateConfigError::BucketNotFinite { bucket: *bucket });
+    }
+    if let Some(pair) = buckets.windows(2).find(|pair| pair[0] >= pair[1]) {
+        return Err(AggregateConfigError::BucketsNotIncreasing {
+            previous: pair[0],
+            bucket: pair[1],
+        });
+    }
+    Ok(())
+}
+
+fn validate_quantiles(quantiles: &[f64]) -> Result<(), AggregateConfigError> {
+    match quantiles
+        .iter()
+        .find(|quantile| !(0.0..=1.0).contains(*quantile))
+    {
+        Some(quantile) => Err(AggregateConfigError::QuantileOutOfRange {
+            quantile: *quantile,
+        }),
+        None => Ok(()),
+    }
+}
+
 type MetricEntry = (MetricData, EventMetadata);
 
 #[derive(Debug)]
@@ -112,16 +210,26 @@ pub struct Aggregate {
     prev_map: HashMap<MetricSeries, MetricEntry>,
     multi_map: HashMap<MetricSeries, Vec<MetricEntry>>,
     mode: AggregationMode,
+    buckets: Vec<f64>,
+    quantiles: Vec<f64>,
 }
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
+        match config.mode {
+            AggregationMode::Histogram => validate_buckets(&config.buckets)?,
+            AggregationMode::Summary => validate_quantiles(&config.quantiles)?,
+            _ => {}
+        }
+
         Ok(Self {
             interval: Duration::from_millis(config.interval_ms),
             map: Default::default(),
             prev_map: Default::default(),
             multi_map: Default::default(),
             mode: config.mode.clone(),
+            buckets: config.buckets.clone(),
+            quantiles: config.quantiles.clone(),
         })
     }
 
@@ -162,6 +270,9 @@ impl Aggregate {
                     }
                 }
             },
+            AggregationMode::Distribution
+            | AggregationMode::Histogram
+            | AggregationMode::Summary => self.record_distribution(series, data, metadata),
         }
 
         emit!(AggregateEventRecorded);
@@ -207,6 +318,84 @@ impl Aggregate {
         }
     }
 
+    fn record_distribution(
+        &mut self,
+        series: MetricSeries,
+        mut data: MetricData,
+        metadata: EventMetadata,
+    ) {
+        let value = match (data.kind, data.value()) {
+            (MetricKind::Absolute, MetricValue::Gauge { value })
+            | (MetricKind::Incremental, MetricValue::Counter { value }) => *value,
+            _ => return,
+        };
+        let sample = Sample { value, rate: 1 };
+
+        // Each value is turned into a distribution of its own, which can then be added to the
+        // distribution collected so far.
+        *data.value_mut() = match self.mode {
+            AggregationMode::Histogram => {
+                let (buckets, count, sum) = samples_to_buckets(&[sample], &self.buckets);
+                MetricValue::AggregatedHistogram {
+                    buckets,
+                    count,
+                    sum,
+                }
+            }
+            AggregationMode::Summary => {
+                let mut sketch = AgentDDSketch::with_agent_defaults();
+                sketch.insert(value);
+                MetricValue::Sketch {
+                    sketch: MetricSketch::AgentDDSketch(sketch),
+                }
+            }
+            _ => MetricValue::Distribution {
+                samples: vec![sample],
+                statistic: StatisticKind::Histogram,
+            },
+        };
+        data.kind = MetricKind::Incremental;
+
+        match self.map.entry(series) {
+            Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                if existing.0.update(&data) {
+                    existing.1.merge(metadata);
+                } else {
+                    emit!(AggregateUpdateFailed);
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((data, metadata));
+            }
+        }
+    }
+
+    /// Replaces the sketch collected for a series with a summary of the configured quantiles.
+    fn summarize(&self, data: &mut MetricData) {
+        if let MetricValue::Sketch {
+            sketch: MetricSketch::AgentDDSketch(sketch),
+        } = data.value()
+        {
+            let quantiles = self
+                .quantiles
+                .iter()
+                .map(|quantile| Quantile {
+                    quantile: *quantile,
+                    value: sketch.quantile(*quantile).unwrap_or(0.0),
+                })
+                .collect();
+            let count = u64::from(sketch.count());
+            let sum = sketch.sum().unwrap_or(0.0);
+            *data.value_mut() = MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            };
+            data.kind = MetricKind::Absolute;
+        }
+    }
+
     fn record_comparison(
         &mut self,
         series: MetricSeries,
@@ -249,7 +438,10 @@ impl Aggregate {
 
     fn flush_into(&mut self, output: &mut Vec<Event>) {
         let map = std::mem::take(&mut self.map);
-        for (series, entry) in map.clone().into_iter() {
+        for (series, mut entry) in map.clone().into_iter() {
+            if matches!(self.mode, AggregationMode::Summary) {
+                self.summarize(&mut entry.0);
+            }
             let mut metric = Metric::from_parts(series, entry.0, entry.1);
             if matches!(self.mode, AggregationMode::Diff) {
                 if let Some(prev_entry) = self.prev_map.get(metric.series()) {
@@ -400,6 +592,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -471,6 +664,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -537,6 +731,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Count,
+            ..Default::default()
         })
         .unwrap();
 
@@ -593,6 +788,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Max,
+            ..Default::default()
         })
         .unwrap();
 
@@ -639,6 +835,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Min,
+            ..Default::default()
         })
         .unwrap();
 
@@ -685,6 +882,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Diff,
+            ..Default::default()
         })
         .unwrap();
 
@@ -741,6 +939,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Diff,
+            ..Default::default()
         })
         .unwrap();
 
@@ -776,6 +975,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Mean,
+            ..Default::default()
         })
         .unwrap();
 
@@ -833,6 +1033,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Stdev,
+            ..Default::default()
         })
         .unwrap();
 
@@ -888,11 +1089,173 @@ mod tests {
         assert_eq!(&stdev_result, &out[0]);
     }
 
+    #[test]
+    fn absolute_distribution() {
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Distribution,
+            ..Default::default()
+        })
+        .unwrap();
+
+        agg.record(make_metric(
+            "gauge_a",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 2.0 },
+        ));
+        agg.record(make_metric(
+            "gauge_a",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 5.0 },
+        ));
+        // Absolute counters are cumulative, so they are ignored
+        agg.record(make_metric(
+            "counter_a",
+            MetricKind::Absolute,
+            MetricValue::Counter { value: 1.0 },
+        ));
+
+        let result = make_metric(
+            "gauge_a",
+            MetricKind::Incremental,
+            MetricValue::Distribution {
+                samples: vector_lib::samples![2.0 => 1, 5.0 => 1],
+                statistic: StatisticKind::Histogram,
+            },
+        );
+
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(1, out.len());
+        assert_eq!(&result, &out[0]);
+    }
+
+    #[test]
+    fn histogram_agg() {
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Histogram,
+            buckets: vec![1.0, 10.0],
+            ..Default::default()
+        })
+        .unwrap();
+
+        for value in [0.5, 3.0, 7.0] {
+            agg.record(make_metric(
+                "counter_a",
+                MetricKind::Incremental,
+                MetricValue::Counter { value },
+            ));
+        }
+
+        let result = make_metric(
+            "counter_a",
+            MetricKind::Incremental,
+            MetricValue::AggregatedHistogram {
+                buckets: vector_lib::buckets![1.0 => 1, 10.0 => 2],
+                count: 3,
+                sum: 10.5,
+            },
+        );
+
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(1, out.len());
+        assert_eq!(&result, &out[0]);
+    }
+
+    #[test]
+    fn summary_agg() {
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Summary,
+            quantiles: vec![0.5, 0.99],
+            ..Default::default()
+        })
+        .unwrap();
+
+        for value in 1..=100 {
+            agg.record(make_metric(
+                "gauge_a",
+                MetricKind::Absolute,
+                MetricValue::Gauge {
+                    value: f64::from(value),
+                },
+            ));
+        }
+
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(1, out.len());
+        let metric = out[0].as_metric();
+        assert_eq!(metric.kind(), MetricKind::Absolute);
+        match metric.value() {
+            MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            } => {
+                assert_eq!(*count, 100);
+                assert_eq!(*sum, 5050.0);
+                assert_eq!(quantiles.len(), 2);
+                // The sketch has a relative error of about 1%
+                assert!((quantiles[0].value - 50.0).abs() <= 2.0);
+                assert!((quantiles[1].value - 99.0).abs() <= 2.0);
+            }
+            value => panic!("Unexpected metric value {value:?}"),
+        }
+    }
+
+    #[test]
+    fn quantiles_out_of_range() {
+        assert!(Aggregate::new(&AggregateConfig {
+            mode: AggregationMode::Summary,
+            quantiles: vec![0.5, 1.5],
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_buckets() {
+        for buckets in [
+            vec![],
+            vec![1.0, f64::INFINITY],
+            vec![1.0, f64::NAN],
+            vec![1.0, 0.5],
+            vec![1.0, 1.0],
+        ] {
+            assert!(Aggregate::new(&AggregateConfig {
+                mode: AggregationMode::Histogram,
+                buckets,
+                ..Default::default()
+            })
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn unused_buckets_and_quantiles_are_not_validated() {
+        assert!(Aggregate::new(&AggregateConfig {
+            mode: AggregationMode::Summary,
+            buckets: vec![],
+            ..Default::default()
+        })
+        .is_ok());
+        assert!(Aggregate::new(&AggregateConfig {
+            mode: AggregationMode::Histogram,
+            quantiles: vec![1.5],
+            ..Default::default()
+        })
+        .is_ok());
+    }
+
     #[test]
     fn conflicting_value_type() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -951,6 +1314,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
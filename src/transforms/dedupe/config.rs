@@ -13,7 +13,10 @@ use crate::{
 };
 
 use super::{
-    common::{default_cache_config, fill_default_fields_match, CacheConfig, FieldMatchConfig},
+    common::{
+        default_cache_config, fill_default_fields_match, CacheConfig, DedupeInternalMetricsConfig,
+        FieldMatchConfig, TtlMode,
+    },
     transform::Dedupe,
 };
 
@@ -29,6 +32,10 @@ pub struct DedupeConfig {
     #[configurable(derived)]
     #[serde(default = "default_cache_config")]
     pub cache: CacheConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub internal_metrics: DedupeInternalMetricsConfig,
 }
 
 impl GenerateConfig for DedupeConfig {
@@ -36,6 +43,7 @@ impl GenerateConfig for DedupeConfig {
         toml::Value::try_from(Self {
             fields: None,
             cache: default_cache_config(),
+            internal_metrics: Default::default(),
         })
         .unwrap()
     }
@@ -44,11 +52,27 @@ impl GenerateConfig for DedupeConfig {
 #[async_trait::async_trait]
 #[typetag::serde(name = "dedupe")]
 impl TransformConfig for DedupeConfig {
-    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::event_task(Dedupe::new(
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let mut dedupe = Dedupe::new(
             self.cache.num_events,
             fill_default_fields_match(self.fields.as_ref()),
-        )))
+        )
+        .with_internal_metrics(&self.internal_metrics);
+        match (self.cache.ttl_secs, self.cache.ttl_mode) {
+            (Some(ttl), mode) => dedupe = dedupe.with_ttl(ttl, mode),
+            (None, TtlMode::Window) => {
+                return Err("`ttl_mode` of `window` requires `ttl_secs` to be set".into());
+            }
+            (None, TtlMode::Sliding) => {}
+        }
+        if let Some(persistence) = &self.cache.persistence {
+            let subdir = context.key.as_ref().map_or("dedupe", |key| key.id());
+            let data_dir = context
+                .globals
+                .resolve_and_make_data_subdir(persistence.data_dir.as_ref(), subdir)?;
+            dedupe = dedupe.with_persistence(&data_dir, persistence.snapshot_interval_secs);
+        }
+        Ok(Transform::event_task(dedupe))
     }
 
     fn input(&self) -> Input {
@@ -83,7 +107,9 @@ mod tests {
         event::{Event, LogEvent, ObjectMap, Value},
         test_util::components::assert_transform_compliance,
         transforms::{
-            dedupe::config::{CacheConfig, DedupeConfig, FieldMatchConfig},
+            dedupe::config::{
+                CacheConfig, DedupeConfig, DedupeInternalMetricsConfig, FieldMatchConfig, TtlMode,
+            },
             test::create_topology,
         },
     };
@@ -100,8 +126,14 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
+                ttl_mode: TtlMode::Sliding,
+                persistence: None,
             },
             fields: Some(FieldMatchConfig::MatchFields(fields)),
+            internal_metrics: DedupeInternalMetricsConfig {
+                emit_events_suppressed_per_key: false,
+            },
         }
     }
 
@@ -116,8 +148,12 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
+                ttl_mode: Default::default(),
+                persistence: None,
             },
             fields: Some(FieldMatchConfig::IgnoreFields(fields)),
+            internal_metrics: Default::default(),
         }
     }
 
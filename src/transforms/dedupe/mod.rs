@@ -4,29 +4,126 @@ pub mod config;
 #[cfg(feature = "transforms-impl-dedupe")]
 pub mod transform;
 
+#[cfg(feature = "transforms-impl-dedupe")]
+mod persistence;
+
 #[cfg(feature = "transforms-impl-dedupe")]
 pub mod common {
-    use std::num::NonZeroUsize;
+    use std::{num::NonZeroUsize, path::PathBuf, time::Duration};
 
+    use serde_with::serde_as;
     use vector_lib::{configurable::configurable_component, lookup::lookup_v2::ConfigTargetPath};
 
     use crate::config::log_schema;
 
     /// Caching configuration for deduplication.
+    #[serde_as]
     #[configurable_component]
     #[derive(Clone, Debug)]
     #[serde(deny_unknown_fields)]
     pub struct CacheConfig {
         /// Number of events to cache and use for comparing incoming events to previously seen events.
         pub num_events: NonZeroUsize,
+
+        /// The maximum amount of time, in seconds, that an entry is kept in the cache.
+        ///
+        /// How the age of an entry is measured depends on `ttl_mode`. Once an entry is older than
+        /// this, the next matching event is forwarded as if it had never been seen before. When
+        /// unset, entries are only evicted when the cache is full.
+        #[serde_as(as = "Option<serde_with::DurationSeconds<u64>>")]
+        #[configurable(metadata(docs::examples = 600))]
+        #[configurable(metadata(docs::human_name = "Time To Live"))]
+        pub ttl_secs: Option<Duration>,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        pub ttl_mode: TtlMode,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        pub persistence: Option<PersistenceConfig>,
     }
 
     pub const fn default_cache_config() -> CacheConfig {
         CacheConfig {
             num_events: NonZeroUsize::new(5000).expect("static non-zero number"),
+            ttl_secs: None,
+            ttl_mode: TtlMode::Sliding,
+            persistence: None,
         }
     }
 
+    /// How the age of a cache entry is measured when `ttl_secs` is set.
+    #[configurable_component]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum TtlMode {
+        /// The age of an entry is measured from the last time a matching event was seen.
+        ///
+        /// A steady stream of duplicates is suppressed indefinitely.
+        #[default]
+        Sliding,
+
+        /// The age of an entry is measured from the time the first matching event was forwarded.
+        ///
+        /// Duplicates are suppressed for `ttl_secs` after an event is forwarded, after which the
+        /// next matching event is forwarded and starts a new window. This is useful for
+        /// suppressing repeated alerts for a fixed amount of time.
+        Window,
+    }
+
+    /// Configuration of internal metrics for the `dedupe` transform.
+    #[configurable_component]
+    #[derive(Clone, Debug, PartialEq, Eq, Default)]
+    #[serde(deny_unknown_fields)]
+    pub struct DedupeInternalMetricsConfig {
+        /// Whether or not to emit the `dedupe_events_suppressed_total` internal metric with the `key` tag.
+        ///
+        /// If true, the counter is incremented for each suppressed duplicate, tagged with the
+        /// values of the fields used to match it. If false, the number of suppressed events can
+        /// still be seen through the `component_discarded_events_total` internal metric.
+        ///
+        /// Note that this defaults to false because the `key` tag has potentially unbounded cardinality.
+        /// Only set this to true if you know that the number of unique keys is bounded.
+        #[serde(default)]
+        pub emit_events_suppressed_per_key: bool,
+    }
+
+    /// Configuration for persisting the deduplication cache to disk.
+    ///
+    /// When enabled, the cache is periodically snapshotted to the data directory and reloaded when
+    /// the transform starts, so events that were seen before a restart or configuration reload are
+    /// still recognized as duplicates afterwards.
+    #[serde_as]
+    #[configurable_component]
+    #[derive(Clone, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct PersistenceConfig {
+        /// The directory used to persist the cache snapshot.
+        ///
+        /// By default, the [global `data_dir` option][global_data_dir] is used.
+        /// Make sure the running user has write permissions to this directory.
+        ///
+        /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+        #[serde(default)]
+        #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+        #[configurable(metadata(docs::human_name = "Data Directory"))]
+        pub data_dir: Option<PathBuf>,
+
+        /// The interval between snapshots of the cache, in seconds.
+        ///
+        /// A snapshot is always written when the transform shuts down. This interval bounds how
+        /// many entries are lost if Vector exits uncleanly.
+        #[serde(default = "default_snapshot_interval_secs")]
+        #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+        #[configurable(metadata(docs::human_name = "Snapshot Interval"))]
+        pub snapshot_interval_secs: Duration,
+    }
+
+    const fn default_snapshot_interval_secs() -> Duration {
+        Duration::from_secs(60)
+    }
+
     /// Options to control what fields to match against.
     ///
     /// When no field matching configuration is specified, events are matched using the `timestamp`,
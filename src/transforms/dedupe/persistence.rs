@@ -0,0 +1,44 @@
+use std::{path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use super::transform::{CacheEntry, CacheValue};
+use crate::transforms::snapshot::Snapshotter;
+
+const SNAPSHOT_NAME: &str = "dedupe_cache";
+
+pub(super) type CacheSnapshotter = Snapshotter<State>;
+
+pub(super) fn snapshotter(directory: &Path, interval: Duration) -> CacheSnapshotter {
+    Snapshotter::new(directory, SNAPSHOT_NAME, interval)
+}
+
+/// The file format of cache snapshots persisted to disk. Any incompatible change to the format
+/// requires a new variant to be added here and handled when loading.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "version", rename_all = "snake_case")]
+pub(super) enum State {
+    #[serde(rename = "1")]
+    V1 { entries: Vec<SnapshotEntry> },
+}
+
+impl State {
+    /// Entries are expected to be ordered from least to most recently seen so that the cache's
+    /// eviction order can be restored on load.
+    pub(super) const fn new(entries: Vec<SnapshotEntry>) -> Self {
+        Self::V1 { entries }
+    }
+
+    pub(super) fn into_entries(self) -> Vec<SnapshotEntry> {
+        let Self::V1 { entries } = self;
+        entries
+    }
+}
+
+/// A single cache entry along with when matching events were seen.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SnapshotEntry {
+    pub(super) entry: CacheEntry,
+    #[serde(flatten)]
+    pub(super) value: CacheValue,
+}
@@ -1,23 +1,67 @@
-use std::{future::ready, num::NonZeroUsize, pin::Pin};
+use std::{fmt, future::ready, num::NonZeroUsize, path::Path, pin::Pin, time::Duration};
 
 use bytes::Bytes;
+use chrono::{DateTime, TimeDelta, Utc};
 use futures::{Stream, StreamExt};
 use lru::LruCache;
+use serde::{Deserialize, Serialize};
 use vector_lib::lookup::lookup_v2::ConfigTargetPath;
-use vrl::path::OwnedTargetPath;
 
 use crate::{
     event::{Event, Value},
-    internal_events::DedupeEventsDropped,
-    transforms::TaskTransform,
+    internal_events::{DedupeEventSuppressed, DedupeEventsDropped},
+    transforms::{
+        snapshot::{run_with_snapshots, SnapshotTransform},
+        TaskTransform,
+    },
 };
 
-use super::common::FieldMatchConfig;
+use super::{
+    common::{DedupeInternalMetricsConfig, FieldMatchConfig, TtlMode},
+    persistence::{self, CacheSnapshotter, SnapshotEntry, State},
+};
 
 #[derive(Clone)]
 pub struct Dedupe {
     fields: FieldMatchConfig,
-    cache: LruCache<CacheEntry, bool>,
+    cache: LruCache<CacheEntry, CacheValue>,
+    expiration: Option<Expiration>,
+    snapshotter: Option<CacheSnapshotter>,
+    emit_events_suppressed_per_key: bool,
+}
+
+/// Tracks when events matching a cache entry were seen.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub(super) struct CacheValue {
+    /// When the event that created this entry was forwarded.
+    first_seen: DateTime<Utc>,
+    /// When a matching event was most recently seen.
+    last_seen: DateTime<Utc>,
+}
+
+impl CacheValue {
+    const fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            first_seen: now,
+            last_seen: now,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Expiration {
+    ttl: TimeDelta,
+    mode: TtlMode,
+}
+
+impl Expiration {
+    fn is_expired(self, value: &CacheValue, now: DateTime<Utc>) -> bool {
+        let since = match self.mode {
+            TtlMode::Sliding => value.last_seen,
+            TtlMode::Window => value.first_seen,
+        };
+        now.signed_duration_since(since) >= self.ttl
+    }
 }
 
 type TypeId = u8;
@@ -45,10 +89,37 @@ type TypeId = u8;
 /// iterating over the fields of the incoming Events, we know that the
 /// CacheEntries for 2 equivalent events will always contain the fields in the
 /// same order.
-#[derive(Clone, PartialEq, Eq, Hash)]
-enum CacheEntry {
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(super) enum CacheEntry {
     Match(Vec<Option<(TypeId, Bytes)>>),
-    Ignore(Vec<(OwnedTargetPath, TypeId, Bytes)>),
+    Ignore(Vec<(ConfigTargetPath, TypeId, Bytes)>),
+}
+
+/// Renders the matched field values, used as the `key` tag of the suppressed events metric.
+impl fmt::Display for CacheEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheEntry::Match(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    if let Some((_, bytes)) = value {
+                        f.write_str(&String::from_utf8_lossy(bytes))?;
+                    }
+                }
+            }
+            CacheEntry::Ignore(values) => {
+                for (i, (path, _, bytes)) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}={}", path.0, String::from_utf8_lossy(bytes))?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Assigns a unique number to each of the types supported by Event::Value.
@@ -71,18 +142,99 @@ impl Dedupe {
         Self {
             fields,
             cache: LruCache::new(num_entries),
+            expiration: None,
+            snapshotter: None,
+            emit_events_suppressed_per_key: false,
         }
     }
 
+    /// Expires cache entries once they are older than the given duration, with their age measured
+    /// according to `mode`.
+    pub fn with_ttl(mut self, ttl: Duration, mode: TtlMode) -> Self {
+        self.expiration = Some(Expiration {
+            ttl: TimeDelta::from_std(ttl).unwrap_or(TimeDelta::MAX),
+            mode,
+        });
+        self
+    }
+
+    pub fn with_internal_metrics(mut self, config: &DedupeInternalMetricsConfig) -> Self {
+        self.emit_events_suppressed_per_key = config.emit_events_suppressed_per_key;
+        self
+    }
+
+    /// Restores the cache from the most recent snapshot in the given directory once the transform
+    /// starts, and periodically snapshots it back to that directory.
+    pub fn with_persistence(mut self, data_dir: &Path, interval: Duration) -> Self {
+        self.snapshotter = Some(persistence::snapshotter(data_dir, interval));
+        self
+    }
+
     pub fn transform_one(&mut self, event: Event) -> Option<Event> {
+        self.transform_one_at(event, Utc::now())
+    }
+
+    fn transform_one_at(&mut self, event: Event, now: DateTime<Utc>) -> Option<Event> {
         let cache_entry = build_cache_entry(&event, &self.fields);
-        if self.cache.put(cache_entry, true).is_some() {
-            emit!(DedupeEventsDropped { count: 1 });
-            None
-        } else {
-            Some(event)
+        let expiration = self.expiration;
+        match self.cache.get_mut(&cache_entry) {
+            Some(value) if !expiration.is_some_and(|e| e.is_expired(value, now)) => {
+                value.last_seen = now;
+                emit!(DedupeEventsDropped { count: 1 });
+                if self.emit_events_suppressed_per_key {
+                    emit!(DedupeEventSuppressed {
+                        key: cache_entry.to_string(),
+                    });
+                }
+                None
+            }
+            _ => {
+                self.cache.put(cache_entry, CacheValue::new(now));
+                Some(event)
+            }
         }
     }
+
+    fn is_expired(&self, value: &CacheValue, now: DateTime<Utc>) -> bool {
+        self.expiration.is_some_and(|e| e.is_expired(value, now))
+    }
+}
+
+impl SnapshotTransform for Dedupe {
+    type State = State;
+
+    fn restore_snapshot(&mut self, state: State) {
+        let now = Utc::now();
+        for SnapshotEntry { entry, value } in state.into_entries() {
+            if !self.is_expired(&value, now) {
+                self.cache.put(entry, value);
+            }
+        }
+        debug!(
+            message = "Loaded dedupe cache snapshot.",
+            entries = self.cache.len()
+        );
+    }
+
+    fn take_snapshot(&self) -> State {
+        // Iterate from least to most recently seen so the eviction order survives a reload.
+        let now = Utc::now();
+        let entries = self
+            .cache
+            .iter()
+            .rev()
+            .filter(|(_, value)| !self.is_expired(value, now))
+            .map(|(entry, value)| SnapshotEntry {
+                entry: entry.clone(),
+                value: *value,
+            })
+            .collect();
+        State::new(entries)
+    }
+
+    fn transform_one(&mut self, event: Event) -> Option<Event> {
+        Dedupe::transform_one(self, event)
+    }
 }
 
 /// Takes in an Event and returns a CacheEntry to place into the LRU cache
@@ -110,7 +262,7 @@ fn build_cache_entry(event: &Event, fields: &FieldMatchConfig) -> CacheEntry {
                         if let Ok(path) = ConfigTargetPath::try_from(field_name) {
                             if !fields.contains(&path) {
                                 entry.push((
-                                    path.0,
+                                    path,
                                     type_id_for_value(value),
                                     value.coerce_to_bytes(),
                                 ));
@@ -133,7 +285,153 @@ impl TaskTransform<Event> for Dedupe {
     where
         Self: 'static,
     {
-        let mut inner = self;
-        Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))))
+        let Some(snapshotter) = self.snapshotter.clone() else {
+            let mut inner = self;
+            return Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))));
+        };
+
+        run_with_snapshots(self, &snapshotter, task)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn make_dedupe() -> Dedupe {
+        Dedupe::new(
+            NonZeroUsize::new(5).unwrap(),
+            FieldMatchConfig::MatchFields(vec!["message".into()]),
+        )
+    }
+
+    async fn load_snapshot(dedupe: &mut Dedupe) {
+        let snapshotter = dedupe.snapshotter.clone().unwrap();
+        snapshotter.load(dedupe).await;
+    }
+
+    async fn write_snapshot(dedupe: &Dedupe) {
+        let snapshotter = dedupe.snapshotter.as_ref().unwrap();
+        snapshotter.persist(dedupe.take_snapshot()).await;
+    }
+
+    #[test]
+    fn ttl_expires_entries() {
+        let mut dedupe = make_dedupe().with_ttl(Duration::from_secs(10), TtlMode::Sliding);
+        let event = Event::Log(LogEvent::from("message"));
+        let start = Utc::now();
+
+        assert!(dedupe.transform_one_at(event.clone(), start).is_some());
+        assert!(dedupe
+            .transform_one_at(event.clone(), start + TimeDelta::seconds(5))
+            .is_none());
+        // The duplicate refreshed the entry, so it is still within the TTL here.
+        assert!(dedupe
+            .transform_one_at(event.clone(), start + TimeDelta::seconds(14))
+            .is_none());
+        assert!(dedupe
+            .transform_one_at(event, start + TimeDelta::seconds(24))
+            .is_some());
+    }
+
+    #[test]
+    fn ttl_window_expires_from_first_seen() {
+        let mut dedupe = make_dedupe().with_ttl(Duration::from_secs(10), TtlMode::Window);
+        let event = Event::Log(LogEvent::from("message"));
+        let start = Utc::now();
+
+        assert!(dedupe.transform_one_at(event.clone(), start).is_some());
+        assert!(dedupe
+            .transform_one_at(event.clone(), start + TimeDelta::seconds(5))
+            .is_none());
+        // Duplicates do not extend the window.
+        assert!(dedupe
+            .transform_one_at(event.clone(), start + TimeDelta::seconds(10))
+            .is_some());
+        assert!(dedupe
+            .transform_one_at(event, start + TimeDelta::seconds(15))
+            .is_none());
+    }
+
+    #[test]
+    fn cache_entry_key() {
+        let mut event = Event::Log(LogEvent::from("message"));
+        event.as_mut_log().insert("host", "localhost");
+
+        let entry = build_cache_entry(
+            &event,
+            &FieldMatchConfig::MatchFields(vec!["message".into(), "missing".into(), "host".into()]),
+        );
+        assert_eq!(entry.to_string(), "message,,localhost");
+    }
+
+    #[tokio::test]
+    async fn persisted_cache_survives_restart() {
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let event = Event::Log(LogEvent::from("message"));
+        let other = Event::Log(LogEvent::from("other message"));
+
+        let mut dedupe = make_dedupe().with_persistence(&data_dir, Duration::from_secs(60));
+        load_snapshot(&mut dedupe).await;
+        assert!(dedupe.transform_one(event.clone()).is_some());
+        write_snapshot(&dedupe).await;
+
+        let mut dedupe = make_dedupe().with_persistence(&data_dir, Duration::from_secs(60));
+        load_snapshot(&mut dedupe).await;
+        assert!(dedupe.transform_one(event).is_none());
+        assert!(dedupe.transform_one(other).is_some());
+    }
+
+    #[tokio::test]
+    async fn persisted_cache_drops_expired_entries() {
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let event = Event::Log(LogEvent::from("message"));
+
+        let mut dedupe = make_dedupe().with_persistence(&data_dir, Duration::from_secs(60));
+        load_snapshot(&mut dedupe).await;
+        assert!(dedupe
+            .transform_one_at(event.clone(), Utc::now() - TimeDelta::seconds(120))
+            .is_some());
+        write_snapshot(&dedupe).await;
+
+        let mut dedupe = make_dedupe()
+            .with_ttl(Duration::from_secs(60), TtlMode::Sliding)
+            .with_persistence(&data_dir, Duration::from_secs(60));
+        load_snapshot(&mut dedupe).await;
+        assert!(dedupe.transform_one(event).is_some());
+    }
+
+    #[tokio::test]
+    async fn reloaded_transform_waits_for_final_snapshot() {
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let event = Event::Log(LogEvent::from("message"));
+        let interval = Duration::from_secs(60);
+
+        let (old_tx, old_rx) = mpsc::channel(1);
+        let mut old = Box::new(make_dedupe().with_persistence(&data_dir, interval))
+            .transform(Box::pin(ReceiverStream::new(old_rx)));
+        old_tx.send(event.clone()).await.unwrap();
+        assert!(old.next().await.is_some());
+
+        // The new instance is built and started while the old one is still running, as happens on
+        // reload, and must not load the snapshot until the old one has written its final one.
+        let (new_tx, new_rx) = mpsc::channel(1);
+        let mut new = Box::new(make_dedupe().with_persistence(&data_dir, interval))
+            .transform(Box::pin(ReceiverStream::new(new_rx)));
+        new_tx.send(event).await.unwrap();
+        drop(new_tx);
+        assert!(new.next().now_or_never().is_none());
+
+        drop(old_tx);
+        assert!(old.next().await.is_none());
+        assert!(new.next().await.is_none());
     }
 }
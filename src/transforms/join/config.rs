@@ -0,0 +1,285 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use serde_with::serde_as;
+use snafu::Snafu;
+use vector_lib::config::{clone_input_definitions, LogNamespace};
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::{ConfigTargetPath, OptionalValuePath};
+use vrl::value::Kind;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    schema::{self, Definition},
+    transforms::Transform,
+};
+
+use super::transform::{Join, UNMATCHED};
+
+#[derive(Debug, Snafu)]
+pub enum JoinConfigError {
+    #[snafu(display("At least one input must be provided for each of `left` and `right`"))]
+    MissingSide,
+
+    #[snafu(display("Input `{input}` can't be on both sides of the join"))]
+    InputOnBothSides { input: String },
+
+    #[snafu(display("Only non-zero durations are allowed values for `window_secs`"))]
+    InvalidWindow,
+
+    #[snafu(display("Input `{input}` of the join is not listed in `inputs`"))]
+    UnknownInput { input: String },
+}
+
+/// The kind of join to perform.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinType {
+    /// Only merged events are sent to the default output.
+    ///
+    /// Events from either side that don't match any event from the other side are sent to the
+    /// `unmatched` output.
+    #[default]
+    Inner,
+
+    /// Merged events, and events from the left side that don't match any event from the right side,
+    /// are sent to the default output.
+    ///
+    /// Events from the right side that don't match any event from the left side are sent to the
+    /// `unmatched` output.
+    Left,
+
+    /// Merged events, and events from either side that don't match any event from the other side,
+    /// are sent to the default output.
+    Outer,
+}
+
+/// Configuration for the `join` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "join",
+    "Correlate and merge events from two sets of inputs that share a key."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinConfig {
+    /// The IDs of the upstream components whose events are on the left side of the join.
+    ///
+    /// Each of these components must also be listed in `inputs`. A specific output of a component
+    /// can be selected with `<component_id>.<output>`.
+    #[configurable(metadata(docs::examples = "requests"))]
+    pub left: Vec<String>,
+
+    /// The IDs of the upstream components whose events are on the right side of the join.
+    ///
+    /// Each of these components must also be listed in `inputs`. A specific output of a component
+    /// can be selected with `<component_id>.<output>`.
+    #[configurable(metadata(docs::examples = "responses"))]
+    pub right: Vec<String>,
+
+    /// The field containing the key that events are joined on.
+    ///
+    /// Events from the left side are merged with events from the right side that have the same
+    /// value for this field. Events without this field can't be joined, and are handled like
+    /// events that didn't match any event.
+    #[configurable(metadata(docs::examples = "request_id"))]
+    pub key: ConfigTargetPath,
+
+    /// The field containing the key of events from the right side, if it differs from `key`.
+    #[configurable(metadata(docs::examples = "trace_id"))]
+    pub right_key: Option<ConfigTargetPath>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub join_type: JoinType,
+
+    /// The amount of time to buffer events for a key after the first event with that key is seen,
+    /// in seconds.
+    ///
+    /// Events are only joined with events of the same key that are seen within this window. Once
+    /// it has elapsed, the events that didn't match any event are sent to the output determined by
+    /// `join_type`.
+    #[serde(default = "default_window_secs")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Window"))]
+    pub window_secs: Duration,
+
+    /// The maximum number of keys to buffer events for.
+    ///
+    /// When this limit is reached, the window of the oldest key is ended early to make room for the
+    /// new one.
+    #[serde(default = "default_max_keys")]
+    #[configurable(metadata(docs::type_unit = "keys"))]
+    pub max_keys: NonZeroUsize,
+
+    /// The field of merged events in which the fields of the right event are stored.
+    ///
+    /// By default, the fields of the right event are added to the top level of the left event, with
+    /// the values of the left event taking precedence when both events have the same field.
+    #[configurable(metadata(docs::examples = "response"))]
+    #[serde(default)]
+    pub right_field: OptionalValuePath,
+}
+
+const fn default_window_secs() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_keys() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl JoinConfig {
+    fn validate_options(&self) -> Result<(), JoinConfigError> {
+        if self.left.is_empty() || self.right.is_empty() {
+            return Err(JoinConfigError::MissingSide);
+        }
+        if let Some(input) = self.left.iter().find(|input| self.right.contains(input)) {
+            return Err(JoinConfigError::InputOnBothSides {
+                input: input.clone(),
+            });
+        }
+        if self.window_secs.is_zero() {
+            return Err(JoinConfigError::InvalidWindow);
+        }
+        Ok(())
+    }
+
+    /// Checks that each side of the join only refers to inputs of the transform. A component
+    /// refers to any of its outputs listed in `inputs`.
+    fn validate_sides(&self, inputs: &[String]) -> Result<(), JoinConfigError> {
+        let is_input = |id: &String| {
+            inputs.iter().any(|input| {
+                input == id
+                    || input
+                        .strip_prefix(id.as_str())
+                        .is_some_and(|port| port.starts_with('.'))
+            })
+        };
+        match self.left.iter().chain(&self.right).find(|id| !is_input(id)) {
+            Some(input) => Err(JoinConfigError::UnknownInput {
+                input: input.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl GenerateConfig for JoinConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            left: vec!["requests".to_string()],
+            right: vec!["responses".to_string()],
+            key: ConfigTargetPath::try_from("request_id".to_string()).expect("valid static path"),
+            right_key: None,
+            join_type: JoinType::default(),
+            window_secs: default_window_secs(),
+            max_keys: default_max_keys(),
+            right_field: OptionalValuePath::none(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join")]
+impl TransformConfig for JoinConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        self.validate_options()?;
+
+        Ok(Transform::multi_output_task(Join::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(DataType::Log | DataType::Trace)
+    }
+
+    fn validate(&self, _: &schema::Definition) -> Result<(), Vec<String>> {
+        self.validate_options().map_err(|e| vec![e.to_string()])
+    }
+
+    fn validate_inputs(&self, inputs: &[String]) -> Result<(), Vec<String>> {
+        self.validate_sides(inputs).map_err(|e| vec![e.to_string()])
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Merged events combine events from both sides, so all of the definitions must be merged.
+        let mut merged_definition = input_definitions
+            .iter()
+            .map(|(_output, definition)| definition.clone())
+            .reduce(Definition::merge)
+            .unwrap_or_else(Definition::any);
+        if let Some(path) = &self.right_field.path {
+            merged_definition = merged_definition.with_event_field(path, Kind::any(), None);
+        }
+        let merged_definitions = input_definitions
+            .iter()
+            .map(|(output, _definition)| (output.clone(), merged_definition.clone()))
+            .collect();
+
+        vec![
+            TransformOutput::new(DataType::Log | DataType::Trace, merged_definitions),
+            TransformOutput::new(
+                DataType::Log | DataType::Trace,
+                clone_input_definitions(input_definitions),
+            )
+            .with_port(UNMATCHED),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JoinConfig>();
+    }
+
+    #[test]
+    fn rejects_input_on_both_sides() {
+        let config = toml::from_str::<JoinConfig>(
+            r#"
+left = ["a", "b"]
+right = ["b"]
+key = "id"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate_options().unwrap_err().to_string(),
+            "Input `b` can't be on both sides of the join"
+        );
+    }
+
+    #[test]
+    fn rejects_sides_not_in_inputs() {
+        let config = toml::from_str::<JoinConfig>(
+            r#"
+left = ["requests", "router.api"]
+right = ["responses"]
+key = "id"
+"#,
+        )
+        .unwrap();
+
+        let inputs = ["requests", "router.api", "responses.errors"].map(String::from);
+        assert!(config.validate_sides(&inputs).is_ok());
+
+        let inputs = ["requests", "router.web", "responses"].map(String::from);
+        assert_eq!(
+            config.validate_sides(&inputs).unwrap_err().to_string(),
+            "Input `router.api` of the join is not listed in `inputs`"
+        );
+    }
+}
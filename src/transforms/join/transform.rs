@@ -0,0 +1,430 @@
+use std::{
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use futures::{Stream, StreamExt};
+use indexmap::IndexMap;
+use vector_lib::lookup::{OwnedTargetPath, OwnedValuePath};
+use vector_lib::stream::expiration_map::{map_with_expiration, Emitter};
+
+use crate::{
+    config::OutputId,
+    event::{Event, Value},
+    transforms::MultiOutputTaskTransform,
+};
+
+use super::config::{JoinConfig, JoinType};
+
+pub(super) const UNMATCHED: &str = "unmatched";
+
+/// The longest interval between checks for keys whose window has elapsed.
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// An event paired with the output it is sent to.
+type Output = (Option<&'static str>, Event);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// An upstream component, or one of its outputs, configured on one side of the join.
+#[derive(Clone, Debug)]
+struct JoinInput {
+    component: String,
+    port: Option<String>,
+}
+
+impl JoinInput {
+    fn new(id: &str) -> Self {
+        match id.split_once('.') {
+            Some((component, port)) => Self {
+                component: component.to_string(),
+                port: Some(port.to_string()),
+            },
+            None => Self {
+                component: id.to_string(),
+                port: None,
+            },
+        }
+    }
+
+    fn matches(&self, id: &OutputId) -> bool {
+        self.component == id.component.id()
+            && self
+                .port
+                .as_ref()
+                .is_none_or(|port| id.port.as_ref() == Some(port))
+    }
+}
+
+struct BufferedEvent {
+    event: Event,
+    /// Whether the event was merged with at least one event from the other side.
+    matched: bool,
+}
+
+/// The events of both sides seen for a key within its window.
+struct PendingKey {
+    left: Vec<BufferedEvent>,
+    right: Vec<BufferedEvent>,
+    first_seen: Instant,
+}
+
+pub struct Join {
+    left: Vec<JoinInput>,
+    right: Vec<JoinInput>,
+    left_key: OwnedTargetPath,
+    right_key: OwnedTargetPath,
+    join_type: JoinType,
+    window: Duration,
+    max_keys: NonZeroUsize,
+    right_field: Option<OwnedValuePath>,
+    /// Keys within their window, in the order their first event was seen.
+    pending: IndexMap<String, PendingKey>,
+}
+
+impl Join {
+    pub fn new(config: &JoinConfig) -> Self {
+        let left_key = config.key.0.clone();
+        Self {
+            left: config.left.iter().map(|id| JoinInput::new(id)).collect(),
+            right: config.right.iter().map(|id| JoinInput::new(id)).collect(),
+            right_key: config
+                .right_key
+                .as_ref()
+                .map_or_else(|| left_key.clone(), |key| key.0.clone()),
+            left_key,
+            join_type: config.join_type,
+            window: config.window_secs,
+            max_keys: config.max_keys,
+            right_field: config.right_field.path.clone(),
+            pending: IndexMap::new(),
+        }
+    }
+
+    fn side(&self, event: &Event) -> Option<Side> {
+        let upstream_id = event.metadata().upstream_id()?;
+        if self.left.iter().any(|input| input.matches(upstream_id)) {
+            Some(Side::Left)
+        } else if self.right.iter().any(|input| input.matches(upstream_id)) {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    /// The output that events from the given side are sent to when they don't match any event.
+    const fn unmatched_output(&self, side: Side) -> Option<&'static str> {
+        match (self.join_type, side) {
+            (JoinType::Outer, _) | (JoinType::Left, Side::Left) => None,
+            _ => Some(UNMATCHED),
+        }
+    }
+
+    fn transform_one(&mut self, event: Event, emitter: &mut Emitter<Output>) {
+        let Some(side) = self.side(&event) else {
+            emitter.emit((Some(UNMATCHED), event));
+            return;
+        };
+
+        let key_path = match side {
+            Side::Left => &self.left_key,
+            Side::Right => &self.right_key,
+        };
+        let Some(key) = get(&event, key_path).map(|key| key.to_string_lossy().into_owned()) else {
+            emitter.emit((self.unmatched_output(side), event));
+            return;
+        };
+
+        if !self.pending.contains_key(&key) && self.pending.len() >= self.max_keys.get() {
+            self.expire_oldest(emitter);
+        }
+
+        let right_field = self.right_field.as_ref();
+        let pending = self.pending.entry(key).or_insert_with(|| PendingKey {
+            left: Vec::new(),
+            right: Vec::new(),
+            first_seen: Instant::now(),
+        });
+        let (own, other) = match side {
+            Side::Left => (&mut pending.left, &mut pending.right),
+            Side::Right => (&mut pending.right, &mut pending.left),
+        };
+
+        let mut matched = false;
+        for buffered in other.iter_mut() {
+            let merged = match side {
+                Side::Left => merge(&event, &buffered.event, right_field),
+                Side::Right => merge(&buffered.event, &event, right_field),
+            };
+            emitter.emit((None, merged));
+            buffered.matched = true;
+            matched = true;
+        }
+        own.push(BufferedEvent { event, matched });
+    }
+
+    fn expire_oldest(&mut self, emitter: &mut Emitter<Output>) {
+        if let Some((_, pending)) = self.pending.shift_remove_index(0) {
+            self.expire(pending, emitter);
+        }
+    }
+
+    /// Sends the events of a key that didn't match any event to their output. The events that did
+    /// match have already been sent as part of merged events.
+    fn expire(&self, pending: PendingKey, emitter: &mut Emitter<Output>) {
+        for (side, events) in [(Side::Left, pending.left), (Side::Right, pending.right)] {
+            let output = self.unmatched_output(side);
+            for buffered in events.into_iter().filter(|buffered| !buffered.matched) {
+                emitter.emit((output, buffered.event));
+            }
+        }
+    }
+
+    /// Ends the window of every key for which it has elapsed.
+    fn flush_expired(&mut self, emitter: &mut Emitter<Output>) {
+        while let Some((_, pending)) = self.pending.first() {
+            if pending.first_seen.elapsed() < self.window {
+                break;
+            }
+            self.expire_oldest(emitter);
+        }
+    }
+
+    fn flush_all(&mut self, emitter: &mut Emitter<Output>) {
+        while !self.pending.is_empty() {
+            self.expire_oldest(emitter);
+        }
+    }
+}
+
+fn get<'a>(event: &'a Event, path: &OwnedTargetPath) -> Option<&'a Value> {
+    match event {
+        Event::Log(log) => log.get(path),
+        Event::Trace(trace) => trace.get(path),
+        Event::Metric(_) => unreachable!("metrics are not accepted by the join transform"),
+    }
+}
+
+fn value(event: &Event) -> &Value {
+    match event {
+        Event::Log(log) => log.value(),
+        Event::Trace(trace) => trace.value(),
+        Event::Metric(_) => unreachable!("metrics are not accepted by the join transform"),
+    }
+}
+
+fn value_mut(event: &mut Event) -> &mut Value {
+    match event {
+        Event::Log(log) => log.value_mut(),
+        Event::Trace(trace) => trace.value_mut(),
+        Event::Metric(_) => unreachable!("metrics are not accepted by the join transform"),
+    }
+}
+
+/// Merges the fields of the right event into a copy of the left event.
+fn merge(left: &Event, right: &Event, right_field: Option<&OwnedValuePath>) -> Event {
+    let mut merged = left.clone();
+    merged.metadata_mut().merge(right.metadata().clone());
+
+    let right_value = value(right).clone();
+    match right_field {
+        Some(path) => {
+            value_mut(&mut merged).insert(path, right_value);
+        }
+        None => {
+            if let (Value::Object(fields), Value::Object(right_fields)) =
+                (value_mut(&mut merged), right_value)
+            {
+                for (key, value) in right_fields {
+                    fields.entry(key).or_insert(value);
+                }
+            }
+        }
+    }
+    merged
+}
+
+impl MultiOutputTaskTransform for Join {
+    fn transform(
+        self: Box<Self>,
+        input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Output> + Send>> {
+        let check_interval = self.window.min(MAX_CHECK_INTERVAL);
+        Box::pin(map_with_expiration(
+            self,
+            input_rx,
+            check_interval,
+            |me: &mut Box<Join>, event: Event, emitter: &mut Emitter<Output>| {
+                me.transform_one(event, emitter);
+            },
+            |me: &mut Box<Join>, emitter: &mut Emitter<Output>| me.flush_expired(emitter),
+            |me: &mut Box<Join>, emitter: &mut Emitter<Output>| me.flush_all(emitter),
+        ))
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn join(config: &str) -> Join {
+        Join::new(&toml::from_str::<JoinConfig>(config).unwrap())
+    }
+
+    fn event(upstream: &str, id: &str, field: &str, value: &str) -> Event {
+        let mut log = LogEvent::default();
+        log.insert("id", id);
+        log.insert(field, value);
+        Event::Log(log).with_upstream_id(Arc::new(OutputId::from(upstream)))
+    }
+
+    fn outputs(emitter: Emitter<Output>) -> Vec<(Option<&'static str>, Value)> {
+        emitter
+            .into_values()
+            .into_iter()
+            .map(|(output, event)| (output, event.into_log().value().clone()))
+            .collect()
+    }
+
+    #[test]
+    fn inner_join_merges_matching_events() {
+        let mut join = join(
+            r#"
+            left = ["requests"]
+            right = ["responses"]
+            key = "id"
+            "#,
+        );
+        let mut emitter = Emitter::new();
+
+        join.transform_one(event("requests", "1", "path", "/a"), &mut emitter);
+        join.transform_one(event("requests", "2", "path", "/b"), &mut emitter);
+        join.transform_one(event("responses", "1", "status", "200"), &mut emitter);
+        join.transform_one(event("responses", "3", "status", "500"), &mut emitter);
+        join.flush_all(&mut emitter);
+
+        assert_eq!(
+            outputs(emitter),
+            vec![
+                (
+                    None,
+                    Value::from(btreemap! {"id" => "1", "path" => "/a", "status" => "200"})
+                ),
+                (
+                    Some(UNMATCHED),
+                    Value::from(btreemap! {"id" => "2", "path" => "/b"})
+                ),
+                (
+                    Some(UNMATCHED),
+                    Value::from(btreemap! {"id" => "3", "status" => "500"})
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn left_join_keeps_unmatched_left_events() {
+        let mut join = join(
+            r#"
+            left = ["requests"]
+            right = ["responses"]
+            key = "id"
+            join_type = "left"
+            right_field = "response"
+            "#,
+        );
+        let mut emitter = Emitter::new();
+
+        join.transform_one(event("responses", "1", "status", "200"), &mut emitter);
+        join.transform_one(event("requests", "1", "path", "/a"), &mut emitter);
+        join.transform_one(event("requests", "2", "path", "/b"), &mut emitter);
+        join.transform_one(event("responses", "3", "status", "500"), &mut emitter);
+        join.flush_all(&mut emitter);
+
+        assert_eq!(
+            outputs(emitter),
+            vec![
+                (
+                    None,
+                    Value::from(btreemap! {
+                        "id" => "1",
+                        "path" => "/a",
+                        "response" => btreemap! {"id" => "1", "status" => "200"},
+                    })
+                ),
+                (None, Value::from(btreemap! {"id" => "2", "path" => "/b"})),
+                (
+                    Some(UNMATCHED),
+                    Value::from(btreemap! {"id" => "3", "status" => "500"})
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn outer_join_keeps_all_unmatched_events() {
+        let mut join = join(
+            r#"
+            left = ["requests"]
+            right = ["responses"]
+            key = "id"
+            join_type = "outer"
+            "#,
+        );
+        let mut emitter = Emitter::new();
+
+        join.transform_one(event("requests", "1", "path", "/a"), &mut emitter);
+        join.transform_one(event("responses", "2", "status", "500"), &mut emitter);
+        join.transform_one(event("other", "3", "status", "200"), &mut emitter);
+        join.flush_all(&mut emitter);
+
+        assert_eq!(
+            outputs(emitter),
+            vec![
+                (
+                    Some(UNMATCHED),
+                    Value::from(btreemap! {"id" => "3", "status" => "200"})
+                ),
+                (None, Value::from(btreemap! {"id" => "1", "path" => "/a"})),
+                (
+                    None,
+                    Value::from(btreemap! {"id" => "2", "status" => "500"})
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn expires_oldest_key_when_full() {
+        let mut join = join(
+            r#"
+            left = ["requests"]
+            right = ["responses"]
+            key = "id"
+            max_keys = 1
+            "#,
+        );
+        let mut emitter = Emitter::new();
+
+        join.transform_one(event("requests", "1", "path", "/a"), &mut emitter);
+        join.transform_one(event("requests", "2", "path", "/b"), &mut emitter);
+
+        assert_eq!(
+            outputs(emitter),
+            vec![(
+                Some(UNMATCHED),
+                Value::from(btreemap! {"id" => "1", "path" => "/a"})
+            )]
+        );
+        assert_eq!(join.pending.len(), 1);
+    }
+}
@@ -6,6 +6,11 @@ pub mod dedupe;
 pub mod reduce;
 #[cfg(feature = "transforms-impl-sample")]
 pub mod sample;
+#[cfg(any(
+    feature = "transforms-impl-dedupe",
+    feature = "transforms-tag_cardinality_limit"
+))]
+pub(crate) mod snapshot;
 
 #[cfg(feature = "transforms-aggregate")]
 pub mod aggregate;
@@ -15,6 +20,8 @@ pub mod aws_ec2_metadata;
 mod exclusive_route;
 #[cfg(feature = "transforms-filter")]
 pub mod filter;
+#[cfg(feature = "transforms-join")]
+pub mod join;
 #[cfg(feature = "transforms-log_to_metric")]
 pub mod log_to_metric;
 #[cfg(feature = "transforms-lua")]
@@ -27,12 +34,16 @@ pub mod remap;
 pub mod route;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sampling")]
+pub mod tail_sampling;
 #[cfg(feature = "transforms-throttle")]
 pub mod throttle;
+#[cfg(feature = "transforms-top_k")]
+pub mod top_k;
 
 pub use vector_lib::transform::{
-    FunctionTransform, OutputBuffer, SyncTransform, TaskTransform, Transform, TransformOutputs,
-    TransformOutputsBuf,
+    FunctionTransform, MultiOutputTaskTransform, OutputBuffer, SyncTransform, TaskTransform,
+    Transform, TransformOutputs, TransformOutputsBuf,
 };
 
 #[cfg(test)]
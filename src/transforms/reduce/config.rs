@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use indexmap::IndexMap;
@@ -53,6 +54,24 @@ pub struct ReduceConfig {
     /// The maximum number of events to group together.
     pub max_events: Option<NonZeroUsize>,
 
+    /// The maximum number of groups to reduce at once.
+    ///
+    /// When a new group would exceed this limit, the group that was least recently updated is
+    /// flushed early to make room for it.
+    #[configurable(metadata(docs::type_unit = "groups"))]
+    pub max_groups: Option<NonZeroUsize>,
+
+    /// The maximum amount of memory to use for the groups being reduced, in bytes.
+    ///
+    /// The memory used by a group is estimated from the size of the events added to it. When this
+    /// limit is exceeded, groups are flushed early, starting with the one that was least recently
+    /// updated, until the estimate is back under the limit.
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_memory_bytes: Option<NonZeroUsize>,
+
+    #[configurable(derived)]
+    pub persistence: Option<ReducePersistenceConfig>,
+
     /// An ordered list of fields by which to group events.
     ///
     /// Each group with matching values for the specified keys is reduced independently, allowing
@@ -100,6 +119,31 @@ pub struct ReduceConfig {
     pub starts_when: Option<AnyCondition>,
 }
 
+/// Configuration for persisting the groups being reduced across restarts.
+///
+/// When enabled, the groups that are still open when Vector shuts down are written to disk instead
+/// of being flushed, and are loaded again when the transform starts. Time spent while Vector is
+/// stopped does not count towards `expire_after_ms` or `end_every_period_ms`. When the transform is
+/// removed or changed by a configuration reload, its open groups are flushed as usual.
+///
+/// Events in persisted groups are acknowledged to their sources once the groups have been written
+/// to disk, rather than when the reduced event is delivered.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ReducePersistenceConfig {
+    /// The directory used to persist the groups being reduced.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+}
+
 const fn default_expire_after_ms() -> Duration {
     Duration::from_millis(30000)
 }
@@ -114,7 +158,15 @@ impl_generate_config_from_default!(ReduceConfig);
 #[typetag::serde(name = "reduce")]
 impl TransformConfig for ReduceConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Reduce::new(self, &context.enrichment_tables).map(Transform::event_task)
+        let mut reduce = Reduce::new(self, &context.enrichment_tables)?;
+        if let Some(persistence) = &self.persistence {
+            let subdir = context.key.as_ref().map_or("reduce", |key| key.id());
+            let data_dir = context
+                .globals
+                .resolve_and_make_data_subdir(persistence.data_dir.as_ref(), subdir)?;
+            reduce = reduce.with_persistence(&data_dir, context.topology_shutdown.clone());
+        }
+        Ok(Transform::event_task(reduce))
     }
 
     fn input(&self) -> Input {
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::event::{LogEvent, Value};
+use crate::event::{LogEvent, ObjectMap, Value};
 use bytes::{Bytes, BytesMut};
 use chrono::{DateTime, Utc};
 use dyn_clone::DynClone;
@@ -77,6 +77,10 @@ impl ReduceValueMerger for DiscardMerger {
         v.insert(path, self.v);
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("discard", self.v.clone())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -107,6 +111,10 @@ impl ReduceValueMerger for RetainMerger {
         v.insert(path, self.v);
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("retain", self.v.clone())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -151,6 +159,16 @@ impl ReduceValueMerger for ConcatMerger {
         v.insert(path, Value::Bytes(self.v.into()));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        let mut state = ObjectMap::new();
+        state.insert("type".into(), "concat".into());
+        state.insert("v".into(), Value::Bytes(self.v.clone().freeze()));
+        if let Some(join_by) = &self.join_by {
+            state.insert("join_by".into(), Value::Bytes(join_by.clone().into()));
+        }
+        Value::Object(state)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -182,6 +200,10 @@ impl ReduceValueMerger for ConcatArrayMerger {
         v.insert(path, Value::Array(self.v));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("concat_array", Value::Array(self.v.clone()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -209,6 +231,10 @@ impl ReduceValueMerger for ArrayMerger {
         v.insert(path, Value::Array(self.v));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("array", Value::Array(self.v.clone()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -245,6 +271,10 @@ impl ReduceValueMerger for LongestArrayMerger {
         v.insert(path, Value::Array(self.v));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("longest_array", Value::Array(self.v.clone()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -281,6 +311,10 @@ impl ReduceValueMerger for ShortestArrayMerger {
         v.insert(path, Value::Array(self.v));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("shortest_array", Value::Array(self.v.clone()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -330,6 +364,13 @@ impl ReduceValueMerger for FlatUniqueMerger {
         v.insert(path, Value::Array(self.v.into_iter().collect()));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot(
+            "flat_unique",
+            Value::Array(self.v.iter().cloned().collect()),
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -372,6 +413,16 @@ impl ReduceValueMerger for TimestampWindowMerger {
         v.insert(path, Value::Timestamp(self.started));
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot(
+            "timestamp_window",
+            Value::Array(vec![
+                Value::Timestamp(self.started),
+                Value::Timestamp(self.latest),
+            ]),
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -439,6 +490,10 @@ impl ReduceValueMerger for AddNumbersMerger {
         };
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("sum", self.v.clone().into())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -502,6 +557,10 @@ impl ReduceValueMerger for MaxNumberMerger {
         };
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("max", self.v.clone().into())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -565,12 +624,117 @@ impl ReduceValueMerger for MinNumberMerger {
         };
         Ok(())
     }
+
+    fn snapshot(&self) -> Value {
+        snapshot("min", self.v.clone().into())
+    }
 }
 
 pub trait ReduceValueMerger: std::fmt::Debug + Send + Sync + DynClone {
     fn add(&mut self, v: Value) -> Result<(), String>;
     fn insert_into(self: Box<Self>, path: &OwnedTargetPath, v: &mut LogEvent)
         -> Result<(), String>;
+
+    /// Captures the state of the merger as a value that the merger can be recreated from with
+    /// [`restore_value_merger`].
+    fn snapshot(&self) -> Value;
+}
+
+fn snapshot(merger_type: &str, v: Value) -> Value {
+    Value::Object(ObjectMap::from([
+        ("type".into(), merger_type.into()),
+        ("v".into(), v),
+    ]))
+}
+
+impl From<NumberMergerValue> for Value {
+    fn from(v: NumberMergerValue) -> Self {
+        match v {
+            NumberMergerValue::Int(i) => Value::Integer(i),
+            NumberMergerValue::Float(f) => Value::Float(f),
+        }
+    }
+}
+
+impl TryFrom<Value> for NumberMergerValue {
+    type Error = String;
+
+    fn try_from(v: Value) -> Result<Self, Self::Error> {
+        match v {
+            Value::Integer(i) => Ok(NumberMergerValue::Int(i)),
+            Value::Float(f) => Ok(NumberMergerValue::Float(f)),
+            _ => Err(format!(
+                "expected number value, found: '{}'",
+                v.to_string_lossy()
+            )),
+        }
+    }
+}
+
+fn snapshot_array(v: Value) -> Result<Vec<Value>, String> {
+    match v {
+        Value::Array(a) => Ok(a),
+        _ => Err(format!(
+            "expected array value, found: '{}'",
+            v.to_string_lossy()
+        )),
+    }
+}
+
+/// Recreates a merger from the state captured by [`ReduceValueMerger::snapshot`].
+#[allow(clippy::mutable_key_type)] // false positive due to bytes::Bytes
+pub(crate) fn restore_value_merger(snapshot: Value) -> Result<Box<dyn ReduceValueMerger>, String> {
+    let Value::Object(mut state) = snapshot else {
+        return Err("expected merger state to be an object".to_string());
+    };
+    let merger_type = match state.remove("type") {
+        Some(Value::Bytes(merger_type)) => merger_type,
+        _ => return Err("missing merger type".to_string()),
+    };
+    let v = state.remove("v").unwrap_or(Value::Null);
+
+    match merger_type.as_ref() {
+        b"discard" => Ok(Box::new(DiscardMerger::new(v))),
+        b"retain" => Ok(Box::new(RetainMerger::new(v))),
+        b"concat" => match v {
+            Value::Bytes(b) => Ok(Box::new(ConcatMerger {
+                v: BytesMut::from(&b[..]),
+                join_by: match state.remove("join_by") {
+                    Some(Value::Bytes(join_by)) => Some(join_by.to_vec()),
+                    _ => None,
+                },
+            })),
+            _ => Err(format!(
+                "expected string value, found: '{}'",
+                v.to_string_lossy()
+            )),
+        },
+        b"concat_array" => Ok(Box::new(ConcatArrayMerger::new(snapshot_array(v)?))),
+        b"array" => Ok(Box::new(ArrayMerger {
+            v: snapshot_array(v)?,
+        })),
+        b"longest_array" => Ok(Box::new(LongestArrayMerger::new(snapshot_array(v)?))),
+        b"shortest_array" => Ok(Box::new(ShortestArrayMerger::new(snapshot_array(v)?))),
+        b"flat_unique" => Ok(Box::new(FlatUniqueMerger {
+            v: snapshot_array(v)?.into_iter().collect(),
+        })),
+        b"timestamp_window" => match snapshot_array(v)?.as_slice() {
+            [Value::Timestamp(started), Value::Timestamp(latest)] => {
+                Ok(Box::new(TimestampWindowMerger {
+                    started: *started,
+                    latest: *latest,
+                }))
+            }
+            _ => Err("expected start and end timestamps".to_string()),
+        },
+        b"sum" => Ok(Box::new(AddNumbersMerger::new(v.try_into()?))),
+        b"max" => Ok(Box::new(MaxNumberMerger::new(v.try_into()?))),
+        b"min" => Ok(Box::new(MinNumberMerger::new(v.try_into()?))),
+        _ => Err(format!(
+            "unknown merger type: '{}'",
+            String::from_utf8_lossy(&merger_type)
+        )),
+    }
 }
 
 dyn_clone::clone_trait_object!(ReduceValueMerger);
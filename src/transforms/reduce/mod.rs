@@ -6,3 +6,6 @@ pub mod merge_strategy;
 
 #[cfg(feature = "transforms-impl-reduce")]
 pub mod transform;
+
+#[cfg(feature = "transforms-impl-reduce")]
+mod persistence;
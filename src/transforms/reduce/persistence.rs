@@ -0,0 +1,71 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use bytes::{Buf, Bytes};
+use prost::Message;
+
+use crate::event::{proto::EventWrapper, Event, LogEvent};
+
+const TMP_FILE_NAME: &str = "reduce_state.new.pb";
+const STATE_FILE_NAME: &str = "reduce_state.pb";
+
+/// Reads and writes the state of the groups being reduced.
+///
+/// Each group is stored as a length-delimited native protobuf event, which preserves the types of
+/// the values being merged. The state is first written to a temporary file which is then
+/// atomically renamed, so a partially written file is never loaded.
+#[derive(Clone, Debug)]
+pub(super) struct StatePersister {
+    tmp_file_path: PathBuf,
+    file_path: PathBuf,
+}
+
+impl StatePersister {
+    pub(super) fn new(directory: &Path) -> Self {
+        Self {
+            tmp_file_path: directory.join(TMP_FILE_NAME),
+            file_path: directory.join(STATE_FILE_NAME),
+        }
+    }
+
+    pub(super) fn file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    pub(super) fn write(&self, groups: Vec<LogEvent>) -> Result<(), io::Error> {
+        let mut buf = Vec::new();
+        for group in groups {
+            EventWrapper::from(Event::Log(group)).encode_length_delimited(&mut buf)?;
+        }
+
+        let mut f = fs::File::create(&self.tmp_file_path)?;
+        f.write_all(&buf)?;
+        f.sync_all()?;
+
+        fs::rename(&self.tmp_file_path, &self.file_path)
+    }
+
+    /// Loads the persisted groups and removes them from disk, so that they are not loaded again
+    /// if Vector exits without persisting its state.
+    pub(super) fn take(&self) -> Result<Vec<LogEvent>, io::Error> {
+        let mut buf = match fs::read(&self.file_path) {
+            Ok(buf) => Bytes::from(buf),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+
+        let mut groups = Vec::new();
+        while buf.has_remaining() {
+            let wrapper = EventWrapper::decode_length_delimited(&mut buf)?;
+            if wrapper.event.is_some() {
+                groups.push(Event::from(wrapper).into_log());
+            }
+        }
+
+        fs::remove_file(&self.file_path)?;
+        Ok(groups)
+    }
+}
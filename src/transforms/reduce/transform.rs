@@ -1,24 +1,35 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::path::Path;
 use std::pin::Pin;
 use std::time::{Duration, Instant};
 
-use crate::internal_events::ReduceAddEventError;
+use crate::internal_events::{
+    ReduceAddEventError, ReduceGroupEvicted, TransformStateLoadError, TransformStatePersistError,
+};
 use crate::transforms::reduce::merge_strategy::{
-    get_value_merger, MergeStrategy, ReduceValueMerger,
+    get_value_merger, restore_value_merger, MergeStrategy, ReduceValueMerger,
 };
 use crate::{
     conditions::Condition,
-    event::{discriminant::Discriminant, Event, EventMetadata, LogEvent},
+    config::TopologyShutdown,
+    event::{discriminant::Discriminant, Event, EventMetadata, LogEvent, ObjectMap, Value},
     internal_events::ReduceStaleEventFlushed,
     transforms::{reduce::config::ReduceConfig, TaskTransform},
 };
-use futures::Stream;
+use futures::{future, stream, Stream, StreamExt};
 use indexmap::IndexMap;
+use lru::LruCache;
 use vector_lib::stream::expiration_map::{map_with_expiration, Emitter};
+use vector_lib::ByteSizeOf;
 use vrl::path::{parse_target_path, OwnedTargetPath};
 use vrl::prelude::KeyString;
 
+use super::persistence::StatePersister;
+
+/// The version of the format used to persist the state of a group.
+const STATE_VERSION: i64 = 1;
+
 #[derive(Clone, Debug)]
 struct ReduceState {
     events: usize,
@@ -26,6 +37,8 @@ struct ReduceState {
     stale_since: Instant,
     creation: Instant,
     metadata: EventMetadata,
+    /// The estimated memory used by the state, in bytes, based on the size of the events added.
+    size: usize,
 }
 
 fn is_covered_by_strategy(
@@ -50,10 +63,12 @@ impl ReduceState {
             creation: Instant::now(),
             fields: HashMap::new(),
             metadata: EventMetadata::default(),
+            size: 0,
         }
     }
 
     fn add_event(&mut self, e: LogEvent, strategies: &IndexMap<OwnedTargetPath, MergeStrategy>) {
+        self.size += e.size_of();
         self.metadata.merge(e.metadata().clone());
 
         for (path, strategy) in strategies {
@@ -130,6 +145,107 @@ impl ReduceState {
         self.events = 0;
         event
     }
+
+    /// Captures the state of the group as an event that can be persisted and restored with
+    /// [`ReduceState::restore`].
+    fn snapshot(&self, group: &Discriminant) -> LogEvent {
+        let now = Instant::now();
+        let group = group
+            .values()
+            .iter()
+            .map(|value| Value::Array(value.iter().cloned().collect()))
+            .collect::<Vec<_>>();
+        let fields = self
+            .fields
+            .iter()
+            .map(|(path, merger)| {
+                Value::Object(ObjectMap::from([
+                    ("path".into(), path.to_string().into()),
+                    ("merger".into(), merger.snapshot()),
+                ]))
+            })
+            .collect::<Vec<_>>();
+
+        let mut event = LogEvent::new_with_metadata(self.metadata.clone());
+        event.insert("version", STATE_VERSION);
+        event.insert("group", group);
+        event.insert("events", self.events as i64);
+        event.insert("size", self.size as i64);
+        event.insert("age_ms", (now - self.creation).as_millis() as i64);
+        event.insert("stale_ms", (now - self.stale_since).as_millis() as i64);
+        event.insert("fields", fields);
+        event
+    }
+
+    /// Recreates a group from the state captured by [`ReduceState::snapshot`]. The time the group
+    /// was persisted for is not counted towards its age.
+    fn restore(event: LogEvent) -> Result<(Discriminant, Self), String> {
+        let now = Instant::now();
+        let (value, metadata) = event.into_parts();
+        let Value::Object(mut state) = value else {
+            return Err("expected persisted group to be an object".to_string());
+        };
+
+        let version = snapshot_integer(&mut state, "version")?;
+        if version != STATE_VERSION {
+            return Err(format!("unsupported persisted group version: {version}"));
+        }
+
+        let group = match state.remove("group") {
+            Some(Value::Array(values)) => values
+                .into_iter()
+                .map(|value| match value {
+                    Value::Array(mut value) => value.pop(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            _ => return Err("missing group values".to_string()),
+        };
+
+        let mut fields = HashMap::new();
+        if let Some(Value::Array(values)) = state.remove("fields") {
+            for value in values {
+                let Value::Object(mut field) = value else {
+                    return Err("expected persisted field to be an object".to_string());
+                };
+                let path = match field.remove("path") {
+                    Some(Value::Bytes(path)) => parse_target_path(&String::from_utf8_lossy(&path))
+                        .map_err(|error| error.to_string())?,
+                    _ => return Err("missing field path".to_string()),
+                };
+                let merger = restore_value_merger(field.remove("merger").unwrap_or(Value::Null))?;
+                fields.insert(path, merger);
+            }
+        }
+
+        let elapsed = |key| {
+            snapshot_integer(&mut state, key).map(|ms| {
+                now.checked_sub(Duration::from_millis(ms as u64))
+                    .unwrap_or(now)
+            })
+        };
+        let creation = elapsed("age_ms")?;
+        let stale_since = elapsed("stale_ms")?;
+
+        Ok((
+            Discriminant::from(group),
+            Self {
+                events: snapshot_integer(&mut state, "events")? as usize,
+                fields,
+                stale_since,
+                creation,
+                metadata,
+                size: snapshot_integer(&mut state, "size")? as usize,
+            },
+        ))
+    }
+}
+
+fn snapshot_integer(state: &mut ObjectMap, key: &str) -> Result<i64, String> {
+    match state.remove(key) {
+        Some(Value::Integer(i)) if i >= 0 => Ok(i),
+        _ => Err(format!("missing or invalid `{key}`")),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -139,10 +255,17 @@ pub struct Reduce {
     end_every_period: Option<Duration>,
     group_by: Vec<String>,
     merge_strategies: IndexMap<OwnedTargetPath, MergeStrategy>,
-    reduce_merge_states: HashMap<Discriminant, ReduceState>,
+    /// The groups being reduced, ordered from most to least recently updated.
+    reduce_merge_states: LruCache<Discriminant, ReduceState>,
     ends_when: Option<Condition>,
     starts_when: Option<Condition>,
     max_events: Option<usize>,
+    max_groups: Option<usize>,
+    max_memory_bytes: Option<usize>,
+    /// The estimated memory used by all of the groups, in bytes.
+    memory_bytes: usize,
+    persister: Option<StatePersister>,
+    topology_shutdown: TopologyShutdown,
 }
 
 fn validate_merge_strategies(strategies: IndexMap<KeyString, MergeStrategy>) -> crate::Result<()> {
@@ -207,17 +330,97 @@ impl Reduce {
                     parsed_path.map(|path| (path, strategy.clone()))
                 })
                 .collect(),
-            reduce_merge_states: HashMap::new(),
+            reduce_merge_states: LruCache::unbounded(),
             ends_when,
             starts_when,
             max_events,
+            max_groups: config.max_groups.map(|max| max.into()),
+            max_memory_bytes: config.max_memory_bytes.map(|max| max.into()),
+            memory_bytes: 0,
+            persister: None,
+            topology_shutdown: TopologyShutdown::default(),
         })
     }
 
+    /// Persists the groups that are still open when `topology_shutdown` is triggered to `data_dir`,
+    /// and restores the groups persisted by a previous run once the transform starts.
+    ///
+    /// Groups are flushed as usual when the transform is removed or replaced by a reload.
+    pub fn with_persistence(
+        mut self,
+        data_dir: &Path,
+        topology_shutdown: TopologyShutdown,
+    ) -> Self {
+        self.persister = Some(StatePersister::new(data_dir));
+        self.topology_shutdown = topology_shutdown;
+        self
+    }
+
+    fn load_persisted(&mut self) {
+        let Some(persister) = &self.persister else {
+            return;
+        };
+        match persister.take() {
+            Ok(groups) => {
+                for group in groups {
+                    match ReduceState::restore(group) {
+                        Ok((discriminant, state)) => {
+                            self.memory_bytes += state.size;
+                            self.reduce_merge_states.put(discriminant, state);
+                        }
+                        Err(error) => {
+                            warn!(message = "Discarding invalid persisted reduce group.", %error);
+                        }
+                    }
+                }
+                debug!(
+                    message = "Loaded persisted reduce groups.",
+                    groups = self.reduce_merge_states.len()
+                );
+            }
+            Err(error) => emit!(TransformStateLoadError {
+                error,
+                path: persister.file_path(),
+            }),
+        }
+    }
+
+    fn remove_state(&mut self, discriminant: &Discriminant) -> Option<ReduceState> {
+        let state = self.reduce_merge_states.pop(discriminant)?;
+        self.memory_bytes = self.memory_bytes.saturating_sub(state.size);
+        Some(state)
+    }
+
+    /// Flushes the least recently updated groups until the configured limits are respected.
+    fn evict_over_limits(&mut self, emitter: &mut Emitter<Event>) {
+        loop {
+            let reason = if self
+                .max_groups
+                .is_some_and(|max| self.reduce_merge_states.len() > max)
+            {
+                "max_groups"
+            } else if self
+                .max_memory_bytes
+                .is_some_and(|max| self.memory_bytes > max)
+            {
+                "max_memory_bytes"
+            } else {
+                return;
+            };
+
+            let Some((_, state)) = self.reduce_merge_states.pop_lru() else {
+                return;
+            };
+            self.memory_bytes = self.memory_bytes.saturating_sub(state.size);
+            emit!(ReduceGroupEvicted { reason });
+            emitter.emit(Event::from(state.flush()));
+        }
+    }
+
     fn flush_into(&mut self, emitter: &mut Emitter<Event>) {
         let mut flush_discriminants = Vec::new();
         let now = Instant::now();
-        for (k, t) in &self.reduce_merge_states {
+        for (k, t) in self.reduce_merge_states.iter() {
             if let Some(period) = self.end_every_period {
                 if (now - t.creation) >= period {
                     flush_discriminants.push(k.clone());
@@ -229,7 +432,7 @@ impl Reduce {
             }
         }
         for k in &flush_discriminants {
-            if let Some(t) = self.reduce_merge_states.remove(k) {
+            if let Some(t) = self.remove_state(k) {
                 emit!(ReduceStaleEventFlushed);
                 emitter.emit(Event::from(t.flush()));
             }
@@ -237,20 +440,58 @@ impl Reduce {
     }
 
     fn flush_all_into(&mut self, emitter: &mut Emitter<Event>) {
-        self.reduce_merge_states
-            .drain()
-            .for_each(|(_, s)| emitter.emit(Event::from(s.flush())));
+        let persister = self
+            .persister
+            .as_ref()
+            .filter(|_| self.topology_shutdown.is_shutting_down());
+        if let Some(persister) = persister {
+            // Persist the least recently updated groups first so that the order is kept on restore.
+            // The persisted groups don't keep the finalizers of their events, so those events are
+            // acknowledged once the groups have been written.
+            let groups = self
+                .reduce_merge_states
+                .iter()
+                .rev()
+                .map(|(discriminant, state)| state.snapshot(discriminant))
+                .collect();
+            match persister.write(groups) {
+                Ok(()) => {
+                    debug!(
+                        message = "Persisted open reduce groups.",
+                        groups = self.reduce_merge_states.len()
+                    );
+                    self.reduce_merge_states.clear();
+                    self.memory_bytes = 0;
+                    return;
+                }
+                Err(error) => {
+                    emit!(TransformStatePersistError {
+                        error,
+                        path: persister.file_path(),
+                    });
+                    warn!(message = "Flushing open reduce groups instead of persisting them.");
+                }
+            }
+        }
+
+        while let Some((_, s)) = self.reduce_merge_states.pop_lru() {
+            emitter.emit(Event::from(s.flush()));
+        }
+        self.memory_bytes = 0;
     }
 
     fn push_or_new_reduce_state(&mut self, event: LogEvent, discriminant: Discriminant) {
-        match self.reduce_merge_states.entry(discriminant) {
-            Entry::Vacant(entry) => {
-                let mut state = ReduceState::new();
+        match self.reduce_merge_states.get_mut(&discriminant) {
+            Some(state) => {
+                let size = state.size;
                 state.add_event(event, &self.merge_strategies);
-                entry.insert(state);
+                self.memory_bytes += state.size - size;
             }
-            Entry::Occupied(mut entry) => {
-                entry.get_mut().add_event(event, &self.merge_strategies);
+            None => {
+                let mut state = ReduceState::new();
+                state.add_event(event, &self.merge_strategies);
+                self.memory_bytes += state.size;
+                self.reduce_merge_states.put(discriminant, state);
             }
         };
     }
@@ -272,7 +513,7 @@ impl Reduce {
         if let Some(max_events) = self.max_events {
             if max_events == 1 {
                 ends_here = true;
-            } else if let Some(entry) = self.reduce_merge_states.get(&discriminant) {
+            } else if let Some(entry) = self.reduce_merge_states.peek(&discriminant) {
                 // The current event will finish this set
                 if entry.events + 1 == max_events {
                     ends_here = true;
@@ -281,13 +522,13 @@ impl Reduce {
         }
 
         if starts_here {
-            if let Some(state) = self.reduce_merge_states.remove(&discriminant) {
+            if let Some(state) = self.remove_state(&discriminant) {
                 emitter.emit(state.flush().into());
             }
 
             self.push_or_new_reduce_state(event, discriminant)
         } else if ends_here {
-            emitter.emit(match self.reduce_merge_states.remove(&discriminant) {
+            emitter.emit(match self.remove_state(&discriminant) {
                 Some(mut state) => {
                     state.add_event(event, &self.merge_strategies);
                     state.flush().into()
@@ -301,6 +542,8 @@ impl Reduce {
         } else {
             self.push_or_new_reduce_state(event, discriminant)
         }
+
+        self.evict_over_limits(emitter);
     }
 }
 
@@ -329,20 +572,29 @@ where
     Reduce: 'static,
 {
     let flush_period = reduce.flush_period;
-    Box::pin(map_with_expiration(
-        reduce,
-        input_rx,
-        flush_period,
-        move |me, event, emitter| {
-            transform_fn(me, event, emitter);
-        },
-        |me, emitter| {
-            me.flush_into(emitter);
-        },
-        |me, emitter| {
-            me.flush_all_into(emitter);
-        },
-    ))
+    // The persisted groups are only loaded once the transform runs, so that building it (such as
+    // when validating the configuration) doesn't consume them.
+    Box::pin(
+        stream::once(future::lazy(move |_| {
+            let mut reduce = reduce;
+            reduce.load_persisted();
+            map_with_expiration(
+                reduce,
+                input_rx,
+                flush_period,
+                move |me, event, emitter| {
+                    transform_fn(me, event, emitter);
+                },
+                |me, emitter| {
+                    me.flush_into(emitter);
+                },
+                |me, emitter| {
+                    me.flush_all_into(emitter);
+                },
+            )
+        }))
+        .flatten(),
+    )
 }
 
 #[cfg(test)]
@@ -1042,4 +1294,120 @@ merge_strategies.bar = "concat"
         })
         .await
     }
+
+    fn reduce_event(request_id: &str, counter: i64) -> Event {
+        let mut event = LogEvent::from(format!("message {counter}"));
+        event.insert("request_id", request_id);
+        event.insert("counter", counter);
+        event.into()
+    }
+
+    #[test]
+    fn max_groups_evicts_least_recently_updated_group() {
+        let config = toml::from_str::<ReduceConfig>(indoc!(
+            r#"
+            group_by = [ "request_id" ]
+            max_groups = 2
+            "#,
+        ))
+        .unwrap();
+        let mut reduce = Reduce::new(&config, &TableRegistry::default()).unwrap();
+        let mut emitter = Emitter::new();
+
+        reduce.transform_one(&mut emitter, reduce_event("1", 1));
+        reduce.transform_one(&mut emitter, reduce_event("2", 2));
+        reduce.transform_one(&mut emitter, reduce_event("1", 3));
+        reduce.transform_one(&mut emitter, reduce_event("3", 4));
+
+        let output = emitter.into_values();
+        assert_eq!(output.len(), 1);
+        let output = output[0].as_log();
+        assert_eq!(output["request_id"], "2".into());
+        assert_eq!(output["counter"], Value::from(2));
+        assert_eq!(reduce.reduce_merge_states.len(), 2);
+    }
+
+    #[test]
+    fn persists_open_groups_on_shutdown() {
+        let config = toml::from_str::<ReduceConfig>(indoc!(
+            r#"
+            group_by = [ "request_id" ]
+            merge_strategies.message = "concat_newline"
+
+            [ends_when]
+              type = "vrl"
+              source = "exists(.test_end)"
+            "#,
+        ))
+        .unwrap();
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let topology_shutdown = TopologyShutdown::default();
+
+        let mut reduce = Reduce::new(&config, &TableRegistry::default())
+            .unwrap()
+            .with_persistence(&data_dir, topology_shutdown.clone());
+        reduce.load_persisted();
+        let mut emitter = Emitter::new();
+        reduce.transform_one(&mut emitter, reduce_event("1", 1));
+        reduce.transform_one(&mut emitter, reduce_event("2", 2));
+        reduce.transform_one(&mut emitter, reduce_event("1", 3));
+        topology_shutdown.trigger();
+        reduce.flush_all_into(&mut emitter);
+        assert!(emitter.into_values().is_empty());
+
+        let mut reduce = Reduce::new(&config, &TableRegistry::default())
+            .unwrap()
+            .with_persistence(&data_dir, TopologyShutdown::default());
+        reduce.load_persisted();
+        assert_eq!(reduce.reduce_merge_states.len(), 2);
+        assert!(reduce.memory_bytes > 0);
+
+        let mut emitter = Emitter::new();
+        let mut end = reduce_event("1", 5).into_log();
+        end.insert("test_end", "yep");
+        reduce.transform_one(&mut emitter, end.into());
+
+        let output = emitter.into_values();
+        assert_eq!(output.len(), 1);
+        let output = output[0].as_log();
+        assert_eq!(output["message"], "message 1\nmessage 3\nmessage 5".into());
+        assert_eq!(output["counter"], Value::from(9));
+
+        // The state file is consumed on load, so a crash doesn't replay the same groups twice.
+        let mut reduce = Reduce::new(&config, &TableRegistry::default())
+            .unwrap()
+            .with_persistence(&data_dir, TopologyShutdown::default());
+        reduce.load_persisted();
+        assert_eq!(reduce.reduce_merge_states.len(), 0);
+    }
+
+    #[test]
+    fn flushes_open_groups_when_removed() {
+        let config = toml::from_str::<ReduceConfig>(indoc!(
+            r#"
+            group_by = [ "request_id" ]
+            "#,
+        ))
+        .unwrap();
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+
+        // Without a topology shutdown, such as when the transform is removed by a reload, the
+        // open groups are flushed rather than persisted.
+        let mut reduce = Reduce::new(&config, &TableRegistry::default())
+            .unwrap()
+            .with_persistence(&data_dir, TopologyShutdown::default());
+        let mut emitter = Emitter::new();
+        reduce.transform_one(&mut emitter, reduce_event("1", 1));
+        reduce.transform_one(&mut emitter, reduce_event("2", 2));
+        reduce.flush_all_into(&mut emitter);
+        assert_eq!(emitter.into_values().len(), 2);
+
+        let mut reduce = Reduce::new(&config, &TableRegistry::default())
+            .unwrap()
+            .with_persistence(&data_dir, TopologyShutdown::default());
+        reduce.load_persisted();
+        assert_eq!(reduce.reduce_merge_states.len(), 0);
+    }
 }
@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use serde_with::serde_as;
 use snafu::Snafu;
 use vector_lib::config::{LegacyKey, LogNamespace};
 use vector_lib::configurable::configurable_component;
@@ -29,9 +32,51 @@ pub enum SampleError {
     InvalidRate,
 
     #[snafu(display(
-        "Exactly one value must be provided for either 'rate' or 'ratio', but not both"
+        "Exactly one value must be provided for either 'rate', 'ratio', or 'adaptive'"
     ))]
     InvalidConfiguration,
+
+    #[snafu(display(
+        "Only positive, non-zero numbers are allowed values for `adaptive.target_events_per_sec`, value: {target}"
+    ))]
+    InvalidAdaptiveTarget { target: f64 },
+
+    #[snafu(display("Only non-zero durations are allowed values for `adaptive.window_secs`"))]
+    InvalidAdaptiveWindow,
+}
+
+/// Configuration for adaptive sampling.
+///
+/// Instead of applying the same rate to every bucket of events, the throughput of each bucket is
+/// measured over `window_secs` and its ratio is adjusted so that it forwards roughly
+/// `target_events_per_sec`. Buckets with a throughput below the target are not sampled at all, so
+/// rare buckets are kept in full while busy ones are sampled down.
+///
+/// Buckets are determined by `group_by`. If `key_field` is also set, its value is hashed to decide
+/// which events of a bucket are forwarded.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptiveSampleConfig {
+    /// The number of events per second to forward for each bucket.
+    #[configurable(metadata(docs::examples = 100.0))]
+    pub target_events_per_sec: f64,
+
+    /// The window over which the throughput of each bucket is measured, in seconds.
+    ///
+    /// The ratio applied to a bucket is recomputed from the throughput of its previous window. All
+    /// events of a new bucket are forwarded until its first window has elapsed. Buckets that
+    /// receive no events for two windows are forgotten, and start over as new buckets if events
+    /// arrive for them again.
+    #[serde(default = "default_adaptive_window_secs")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Measurement Window"))]
+    pub window_secs: Duration,
+}
+
+const fn default_adaptive_window_secs() -> Duration {
+    Duration::from_secs(10)
 }
 
 /// Configuration for the `sample` transform.
@@ -91,19 +136,36 @@ pub struct SampleConfig {
 
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
+
+    #[configurable(derived)]
+    pub adaptive: Option<AdaptiveSampleConfig>,
 }
 
 impl SampleConfig {
     fn sample_rate(&self) -> Result<SampleMode, SampleError> {
-        match (self.rate, self.ratio) {
-            (None, Some(ratio)) => {
+        match (self.rate, self.ratio, &self.adaptive) {
+            (None, None, Some(adaptive)) => {
+                if adaptive.target_events_per_sec <= 0.0 {
+                    Err(SampleError::InvalidAdaptiveTarget {
+                        target: adaptive.target_events_per_sec,
+                    })
+                } else if adaptive.window_secs.is_zero() {
+                    Err(SampleError::InvalidAdaptiveWindow)
+                } else {
+                    Ok(SampleMode::new_adaptive(
+                        adaptive.target_events_per_sec,
+                        adaptive.window_secs,
+                    ))
+                }
+            }
+            (None, Some(ratio), None) => {
                 if ratio <= 0.0 {
                     Err(SampleError::InvalidRatio { ratio })
                 } else {
                     Ok(SampleMode::new_ratio(ratio))
                 }
             }
-            (Some(rate), None) => {
+            (Some(rate), None, None) => {
                 if rate == 0 {
                     Err(SampleError::InvalidRate)
                 } else {
@@ -124,6 +186,7 @@ impl GenerateConfig for SampleConfig {
             group_by: None,
             exclude: None::<AnyCondition>,
             sample_rate_key: default_sample_rate_key(),
+            adaptive: None,
         })
         .unwrap()
     }
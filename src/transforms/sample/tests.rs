@@ -13,6 +13,7 @@ use crate::{
     transforms::test::transform_one,
 };
 use approx::assert_relative_eq;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use vector_lib::lookup::lookup_v2::OptionalValuePath;
@@ -28,6 +29,7 @@ async fn emits_internal_events() {
             group_by: None,
             exclude: None,
             sample_rate_key: default_sample_rate_key(),
+            adaptive: None,
         };
         let (tx, rx) = mpsc::channel(1);
         let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
@@ -322,6 +324,50 @@ fn sample_at_rates_higher_then_half() {
     }
 }
 
+#[tokio::test(start_paused = true)]
+async fn adaptive_sampling_limits_busy_buckets() {
+    let mut sampler = Sample::new(
+        "sample".to_string(),
+        SampleMode::new_adaptive(100.0, Duration::from_millis(200)),
+        None,
+        Some(Template::try_from("{{ service }}").unwrap()),
+        None,
+        default_sample_rate_key(),
+    );
+    let event = |service: &str| {
+        let mut log = LogEvent::from("message");
+        log.insert("service", service);
+        Event::Log(log)
+    };
+    let mut sample = |service: &str, count: usize| {
+        (0..count)
+            .filter_map(|_| transform_one(&mut sampler, event(service)))
+            .collect::<Vec<_>>()
+    };
+
+    // All events are forwarded until the throughput of a bucket is known.
+    assert_eq!(sample("busy", 1000).len(), 1000);
+    assert_eq!(sample("quiet", 2).len(), 2);
+    tokio::time::advance(Duration::from_millis(250)).await;
+
+    // The busy bucket sent far more than 100 events per second during the first window, so it is
+    // now sampled down, while the quiet bucket is still kept in full.
+    let busy = sample("busy", 1000);
+    assert!(busy.len() < 100, "forwarded {} busy events", busy.len());
+    let sample_rate = busy[0].as_log()["sample_rate"].to_string_lossy();
+    assert!(sample_rate.parse::<f64>().unwrap() < 0.1);
+
+    let quiet = sample("quiet", 2);
+    assert_eq!(quiet.len(), 2);
+    assert_eq!(quiet[0].as_log()["sample_rate"], "1".into());
+
+    // Once the busy bucket has been idle for long enough it is forgotten, and starts over as a new
+    // bucket with all of its events forwarded.
+    tokio::time::advance(Duration::from_millis(500)).await;
+    assert_eq!(sample("quiet", 2).len(), 2);
+    assert_eq!(sample("busy", 1000).len(), 1000);
+}
+
 fn condition_contains(key: &str, needle: &str) -> Condition {
     let vrl_config = VrlConfig {
         source: format!(r#"contains!(."{}", "{}")"#, key, needle),
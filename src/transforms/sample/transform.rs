@@ -1,4 +1,5 @@
-use std::{borrow::Cow, collections::HashMap, fmt};
+use std::{borrow::Cow, collections::HashMap, fmt, time::Duration};
+use tokio::time::Instant;
 use vector_lib::config::LegacyKey;
 
 use crate::{
@@ -26,6 +27,69 @@ pub enum SampleMode {
         values: HashMap<Option<String>, f64>,
         hash_ratio_threshold: u64,
     },
+    Adaptive {
+        target_events_per_sec: f64,
+        window: Duration,
+        buckets: HashMap<Option<String>, AdaptiveBucket>,
+        last_eviction: Instant,
+    },
+}
+
+/// The number of windows a bucket can go without events before it is forgotten.
+const ADAPTIVE_BUCKET_IDLE_WINDOWS: u32 = 2;
+
+/// Tracks the throughput of a single bucket in the adaptive sampling mode.
+#[derive(Clone, Debug)]
+pub struct AdaptiveBucket {
+    window_start: Instant,
+    last_seen: Instant,
+    count: u64,
+    /// The ratio applied to this bucket, derived from the throughput of the previous window.
+    ratio: f64,
+    value: f64,
+}
+
+impl AdaptiveBucket {
+    const fn new(now: Instant) -> Self {
+        // Until the throughput of a bucket is known, all of its events are forwarded.
+        Self {
+            window_start: now,
+            last_seen: now,
+            count: 0,
+            ratio: 1.0,
+            value: 0.0,
+        }
+    }
+
+    /// Counts an event, recomputing the ratio once the window has elapsed.
+    fn observe(&mut self, now: Instant, target_events_per_sec: f64, window: Duration) {
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed >= window {
+            let events_per_sec = self.count as f64 / elapsed.as_secs_f64();
+            self.ratio = (target_events_per_sec / events_per_sec).min(1.0);
+            self.window_start = now;
+            self.count = 0;
+        }
+        self.last_seen = now;
+        self.count += 1;
+    }
+
+    /// Accumulates the ratio the same way as `SampleMode::Ratio`, so that the ratio can change
+    /// between events without losing the progress made towards the next sampled event.
+    fn accumulate(&mut self) -> bool {
+        self.value += self.ratio;
+        if self.value >= 1.0 {
+            self.value -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Scales a ratio to the width of the hash to avoid precision issues when comparing against it.
+fn hash_ratio_threshold(ratio: f64) -> u64 {
+    (ratio * (u64::MAX as u128) as f64) as u64
 }
 
 impl SampleMode {
@@ -47,19 +111,36 @@ impl SampleMode {
             // To do one option would be to convert the hash to a number between 0 and 1 and compare
             // to the ratio. However to address issues with precision, here the ratio is scaled to
             // meet the width of the type of the hash.
-            hash_ratio_threshold: (ratio * (u64::MAX as u128) as f64) as u64,
+            hash_ratio_threshold: hash_ratio_threshold(ratio),
+        }
+    }
+
+    pub fn new_adaptive(target_events_per_sec: f64, window: Duration) -> Self {
+        Self::Adaptive {
+            target_events_per_sec,
+            window,
+            buckets: HashMap::default(),
+            last_eviction: Instant::now(),
         }
     }
 
     fn increment(&mut self, group_by_key: &Option<String>, value: &Option<Cow<'_, str>>) -> bool {
-        let threshold_exceeded = match self {
+        let hash = value.as_ref().map(|value| seahash::hash(value.as_bytes()));
+        match self {
             Self::Rate { rate, counters } => {
                 let counter_value = counters.entry(group_by_key.clone()).or_default();
                 let old_counter_value = *counter_value;
                 *counter_value += 1;
-                old_counter_value % *rate == 0
+                match hash {
+                    Some(hash) => hash % *rate == 0,
+                    None => old_counter_value % *rate == 0,
+                }
             }
-            Self::Ratio { ratio, values, .. } => {
+            Self::Ratio {
+                ratio,
+                values,
+                hash_ratio_threshold,
+            } => {
                 let value = values.entry(group_by_key.clone()).or_insert(1.0 - *ratio);
                 let increment: f64 = *value + *ratio;
                 *value = if increment >= 1.0 {
@@ -67,24 +148,48 @@ impl SampleMode {
                 } else {
                     increment
                 };
-                increment >= 1.0
+                match hash {
+                    Some(hash) => hash <= *hash_ratio_threshold,
+                    None => increment >= 1.0,
+                }
+            }
+            Self::Adaptive {
+                target_events_per_sec,
+                window,
+                buckets,
+                last_eviction,
+            } => {
+                let now = Instant::now();
+                // Forget buckets that stopped receiving events, so that the number of buckets is
+                // bounded by the number of distinct `group_by` values seen recently.
+                if now.saturating_duration_since(*last_eviction) >= *window {
+                    let idle_timeout = *window * ADAPTIVE_BUCKET_IDLE_WINDOWS;
+                    buckets.retain(|_, bucket| {
+                        now.saturating_duration_since(bucket.last_seen) < idle_timeout
+                    });
+                    *last_eviction = now;
+                }
+
+                let bucket = buckets
+                    .entry(group_by_key.clone())
+                    .or_insert_with(|| AdaptiveBucket::new(now));
+                bucket.observe(now, *target_events_per_sec, *window);
+                match hash {
+                    Some(hash) => hash <= hash_ratio_threshold(bucket.ratio),
+                    None => bucket.accumulate(),
+                }
             }
-        };
-        if let Some(value) = value {
-            self.hash_within_ratio(value.as_bytes())
-        } else {
-            threshold_exceeded
         }
     }
 
-    fn hash_within_ratio(&self, value: &[u8]) -> bool {
-        let hash = seahash::hash(value);
+    /// The sample rate recorded in forwarded events of the given bucket.
+    fn sample_rate(&self, group_by_key: &Option<String>) -> String {
         match self {
-            Self::Rate { rate, .. } => hash % rate == 0,
-            Self::Ratio {
-                hash_ratio_threshold,
-                ..
-            } => hash <= *hash_ratio_threshold,
+            Self::Adaptive { buckets, .. } => buckets
+                .get(group_by_key)
+                .map_or(1.0, |bucket| bucket.ratio)
+                .to_string(),
+            _ => self.to_string(),
         }
     }
 }
@@ -96,6 +201,10 @@ impl fmt::Display for SampleMode {
         match self {
             Self::Rate { rate, .. } => write!(f, "{rate}"),
             Self::Ratio { ratio, .. } => write!(f, "{ratio}"),
+            Self::Adaptive {
+                target_events_per_sec,
+                ..
+            } => write!(f, "{target_events_per_sec}"),
         }
     }
 }
@@ -137,6 +246,7 @@ impl Sample {
         match self.rate {
             SampleMode::Rate { rate, .. } => 1.0f64 / rate as f64,
             SampleMode::Ratio { ratio, .. } => ratio,
+            SampleMode::Adaptive { .. } => 1.0,
         }
     }
 }
@@ -201,6 +311,7 @@ impl FunctionTransform for Sample {
         let should_sample = self.rate.increment(&group_by_key, &value);
         if should_sample {
             if let Some(path) = &self.sample_rate_key.path {
+                let sample_rate = self.rate.sample_rate(&group_by_key);
                 match event {
                     Event::Log(ref mut event) => {
                         event.namespace().insert_source_metadata(
@@ -208,11 +319,11 @@ impl FunctionTransform for Sample {
                             event,
                             Some(LegacyKey::Overwrite(path)),
                             path,
-                            self.rate.to_string(),
+                            sample_rate,
                         );
                     }
                     Event::Trace(ref mut event) => {
-                        event.insert(&OwnedTargetPath::event(path.clone()), self.rate.to_string());
+                        event.insert(&OwnedTargetPath::event(path.clone()), sample_rate);
                     }
                     Event::Metric(_) => panic!("component can never receive metric events"),
                 };
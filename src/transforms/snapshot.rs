@@ -0,0 +1,272 @@
+//! Persistence of the state of transforms through periodic snapshots written to disk.
+
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    io::{self, BufReader, BufWriter},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::{
+    event::Event,
+    internal_events::{TransformStateLoadError, TransformStatePersistError},
+};
+
+/// The lock of each snapshot file in use, see [`run_with_snapshots`]. Entries are removed once the
+/// last snapshotter of their file is dropped.
+static SNAPSHOT_LOCKS: LazyLock<Mutex<HashMap<PathBuf, Arc<AsyncMutex<()>>>>> =
+    LazyLock::new(Default::default);
+
+/// Reads and writes JSON snapshots of the state of a transform.
+///
+/// Snapshots are first written to a temporary file which is then atomically renamed over the
+/// stable file, so there is always one complete snapshot to recover from. `S` is the format of the
+/// snapshots, which should be tagged with a version so that any incompatible change to it can be
+/// handled when loading snapshots written by older versions of Vector.
+///
+/// Reading and writing snapshots blocks, so the async methods run it on the blocking thread pool.
+pub(crate) struct Snapshotter<S> {
+    tmp_file_path: PathBuf,
+    stable_file_path: PathBuf,
+    seed_file_path: Option<PathBuf>,
+    interval: Duration,
+    lock: Arc<AsyncMutex<()>>,
+    format: PhantomData<fn() -> S>,
+}
+
+impl<S> Clone for Snapshotter<S> {
+    fn clone(&self) -> Self {
+        Self {
+            tmp_file_path: self.tmp_file_path.clone(),
+            stable_file_path: self.stable_file_path.clone(),
+            seed_file_path: self.seed_file_path.clone(),
+            interval: self.interval,
+            lock: Arc::clone(&self.lock),
+            format: PhantomData,
+        }
+    }
+}
+
+impl<S> fmt::Debug for Snapshotter<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Snapshotter")
+            .field("stable_file_path", &self.stable_file_path)
+            .field("seed_file_path", &self.seed_file_path)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Drop for Snapshotter<S> {
+    fn drop(&mut self) {
+        let mut locks = SNAPSHOT_LOCKS.lock().expect("snapshot locks poisoned");
+        // The map holds the only other reference once the last snapshotter of the file is dropped.
+        if Arc::strong_count(&self.lock) == 2 {
+            locks.remove(&self.stable_file_path);
+        }
+    }
+}
+
+impl<S: Serialize + DeserializeOwned + Send + 'static> Snapshotter<S> {
+    /// Creates a snapshotter writing to `<name>.json` in the given directory every `interval`.
+    pub(crate) fn new(directory: &Path, name: &str, interval: Duration) -> Self {
+        let stable_file_path = directory.join(format!("{name}.json"));
+        let lock = SNAPSHOT_LOCKS
+            .lock()
+            .expect("snapshot locks poisoned")
+            .entry(stable_file_path.clone())
+            .or_default()
+            .clone();
+
+        Self {
+            tmp_file_path: directory.join(format!("{name}.new.json")),
+            stable_file_path,
+            seed_file_path: None,
+            interval,
+            lock,
+            format: PhantomData,
+        }
+    }
+
+    /// Restores the state from the given file instead when no snapshot has been written yet.
+    pub(crate) fn with_seed_file(mut self, seed_file_path: Option<&Path>) -> Self {
+        self.seed_file_path = seed_file_path.map(Path::to_path_buf);
+        self
+    }
+
+    /// Restores the state of the transform from the most recent snapshot, or from the seed file if
+    /// there is none yet.
+    pub(crate) async fn load<T: SnapshotTransform<State = S>>(&self, transform: &mut T) {
+        let snapshotter = self.clone();
+        let result = tokio::task::spawn_blocking(move || match snapshotter.read() {
+            Ok(None) => match &snapshotter.seed_file_path {
+                Some(path) => Self::read_file(path)
+                    .map(Some)
+                    .map_err(|error| (error, path.clone())),
+                None => Ok(None),
+            },
+            result => result.map_err(|error| (error, snapshotter.stable_file_path.clone())),
+        })
+        .await
+        .unwrap_or_else(|error| Err((io::Error::other(error), self.stable_file_path.clone())));
+
+        match result {
+            Ok(Some(state)) => transform.restore_snapshot(state),
+            Ok(None) => {}
+            Err((error, path)) => emit!(TransformStateLoadError { error, path: &path }),
+        }
+    }
+
+    /// Writes the given snapshot of the state of the transform.
+    pub(crate) async fn persist(&self, state: S) {
+        let snapshotter = self.clone();
+        let result = tokio::task::spawn_blocking(move || snapshotter.write(&state))
+            .await
+            .unwrap_or_else(|error| Err(io::Error::other(error)));
+
+        if let Err(error) = result {
+            emit!(TransformStatePersistError {
+                error,
+                path: &self.stable_file_path,
+            });
+        }
+    }
+
+    /// Persist the given state to disk.
+    fn write(&self, state: &S) -> Result<(), io::Error> {
+        let mut f = BufWriter::new(fs::File::create(&self.tmp_file_path)?);
+        serde_json::to_writer(&mut f, state)?;
+        f.into_inner()?.sync_all()?;
+
+        fs::rename(&self.tmp_file_path, &self.stable_file_path)
+    }
+
+    /// Load the most recent snapshot from disk, or `None` if no snapshot has been written yet.
+    ///
+    /// A leftover temporary file means the previous process was interrupted after fully writing a
+    /// snapshot but before renaming it, so it is preferred over the stable file when readable.
+    fn read(&self) -> Result<Option<S>, io::Error> {
+        match Self::read_file(&self.tmp_file_path) {
+            Ok(state) => {
+                warn!(
+                    message = "Recovered snapshot from interrupted process.",
+                    path = ?self.stable_file_path,
+                );
+                if let Err(error) = fs::rename(&self.tmp_file_path, &self.stable_file_path) {
+                    warn!(message = "Error persisting recovered snapshot.", path = ?self.stable_file_path, %error);
+                }
+                return Ok(Some(state));
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                warn!(message = "Unable to recover snapshot from interrupted process.", path = ?self.tmp_file_path, %error);
+            }
+        }
+
+        match Self::read_file(&self.stable_file_path) {
+            Ok(state) => Ok(Some(state)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Load a snapshot from the given file.
+    fn read_file(path: &Path) -> Result<S, io::Error> {
+        let reader = BufReader::new(fs::File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+/// A transform whose state is persisted with a [`Snapshotter`].
+pub(crate) trait SnapshotTransform: Send + 'static {
+    /// The format of the snapshots of the transform.
+    type State: Serialize + DeserializeOwned + Send + 'static;
+
+    /// Restores the state of the transform from a snapshot.
+    fn restore_snapshot(&mut self, state: Self::State);
+
+    /// Takes a snapshot of the current state of the transform.
+    fn take_snapshot(&self) -> Self::State;
+
+    fn transform_one(&mut self, event: Event) -> Option<Event>;
+}
+
+/// Runs the transform over `input`, writing a snapshot of its state periodically and once its
+/// input ends.
+///
+/// When a reload replaces the transform, the new instance is built while the old one is still
+/// running, and the old one only writes its final snapshot once its input ends. The lock of the
+/// snapshot is held for as long as the transform runs, and the snapshot is only loaded once the
+/// lock is acquired, so that the new instance starts from that final snapshot rather than an older
+/// one.
+pub(crate) fn run_with_snapshots<T>(
+    mut transform: Box<T>,
+    snapshotter: &Snapshotter<T::State>,
+    mut input: Pin<Box<dyn Stream<Item = Event> + Send>>,
+) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+where
+    T: SnapshotTransform,
+{
+    let snapshotter = snapshotter.clone();
+
+    Box::pin(stream! {
+        // Released before the captured snapshotter is dropped, so that the lock is still in
+        // `SNAPSHOT_LOCKS` until then.
+        let _guard = Arc::clone(&snapshotter.lock).lock_owned().await;
+        snapshotter.load(transform.as_mut()).await;
+
+        let mut interval = tokio::time::interval(snapshotter.interval);
+        let mut done = false;
+        while !done {
+            let mut output = None;
+            tokio::select! {
+                _ = interval.tick() => snapshotter.persist(transform.take_snapshot()).await,
+                maybe_event = input.next() => {
+                    match maybe_event {
+                        None => {
+                            snapshotter.persist(transform.take_snapshot()).await;
+                            done = true;
+                        }
+                        Some(event) => output = transform.transform_one(event),
+                    }
+                }
+            };
+            if let Some(event) = output {
+                yield event;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_is_removed_with_last_snapshotter() {
+        let data_dir = crate::test_util::temp_dir();
+        let is_locked = |path: &Path| {
+            SNAPSHOT_LOCKS
+                .lock()
+                .expect("snapshot locks poisoned")
+                .contains_key(path)
+        };
+
+        let snapshotter = Snapshotter::<()>::new(&data_dir, "test", Duration::from_secs(60));
+        let path = snapshotter.stable_file_path.clone();
+        let other = snapshotter.clone();
+        drop(snapshotter);
+        assert!(is_locked(&path));
+        drop(other);
+        assert!(!is_locked(&path));
+    }
+}
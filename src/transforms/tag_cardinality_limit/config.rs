@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use serde_with::serde_as;
 
 use crate::config::{
     DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext, TransformOutput,
@@ -26,6 +28,77 @@ pub struct TagCardinalityLimitConfig {
     )]
     #[serde(default)]
     pub per_metric_limits: HashMap<String, PerMetricConfig>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub tracking_scope: TrackingScope,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub persistence: Option<PersistenceConfig>,
+}
+
+/// Controls whether the accepted values of metrics without per-metric limits are shared.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackingScope {
+    /// The accepted values of each tag key are shared by all metrics without per-metric limits.
+    ///
+    /// The `value_limit` then caps the number of distinct values of a tag key across all of these
+    /// metrics.
+    #[default]
+    Global,
+
+    /// The accepted values of each tag key are tracked separately for each metric name and
+    /// namespace.
+    ///
+    /// The `value_limit` then caps the number of distinct values of a tag key for each metric,
+    /// at the cost of keeping a set of accepted values for every metric.
+    PerMetric,
+}
+
+/// Configuration for persisting the accepted tag values to disk.
+///
+/// When enabled, the accepted values are periodically snapshotted to the data directory and
+/// reloaded when the transform starts, so the same values keep being accepted after a restart or
+/// configuration reload instead of a fresh set of values being accepted.
+///
+/// Only the `exact` mode supports persistence.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PersistenceConfig {
+    /// The directory used to persist the snapshot of accepted tag values.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+
+    /// The interval between snapshots of the accepted tag values, in seconds.
+    ///
+    /// A snapshot is always written when the transform shuts down. This interval bounds how
+    /// many accepted values are lost if Vector exits uncleanly.
+    #[serde(default = "default_snapshot_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Snapshot Interval"))]
+    pub snapshot_interval_secs: Duration,
+
+    /// A snapshot to load the accepted tag values from when none has been persisted to the data
+    /// directory yet.
+    ///
+    /// The file is only read once, when the transform starts without a snapshot of its own, for
+    /// example to start a new instance from a copy of the snapshot of another instance. It is
+    /// never written to, and instances don't share the values they accept while running, so
+    /// instances seeded from the same file still accept different values afterwards.
+    #[configurable(metadata(docs::examples = "/etc/vector/tag_cardinality_limit.json"))]
+    pub seed_file: Option<PathBuf>,
 }
 
 /// Configuration for the `tag_cardinality_limit` transform for a specific group of metrics.
@@ -117,6 +190,10 @@ pub(crate) const fn default_cache_size() -> usize {
     5 * 1024 // 5KB
 }
 
+const fn default_snapshot_interval_secs() -> Duration {
+    Duration::from_secs(60)
+}
+
 impl GenerateConfig for TagCardinalityLimitConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
@@ -126,6 +203,8 @@ impl GenerateConfig for TagCardinalityLimitConfig {
                 limit_exceeded_action: default_limit_exceeded_action(),
             },
             per_metric_limits: HashMap::default(),
+            tracking_scope: TrackingScope::default(),
+            persistence: None,
         })
         .unwrap()
     }
@@ -134,10 +213,30 @@ impl GenerateConfig for TagCardinalityLimitConfig {
 #[async_trait::async_trait]
 #[typetag::serde(name = "tag_cardinality_limit")]
 impl TransformConfig for TagCardinalityLimitConfig {
-    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::event_task(TagCardinalityLimit::new(
-            self.clone(),
-        )))
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let mut transform = TagCardinalityLimit::new(self.clone());
+        if let Some(persistence) = &self.persistence {
+            let probabilistic = std::iter::once(&self.global)
+                .chain(self.per_metric_limits.values().map(|config| &config.config))
+                .any(|config| matches!(config.mode, Mode::Probabilistic(_)));
+            if probabilistic {
+                return Err("`persistence` is only supported with a `mode` of `exact`".into());
+            }
+
+            let subdir = context
+                .key
+                .as_ref()
+                .map_or("tag_cardinality_limit", |key| key.id());
+            let data_dir = context
+                .globals
+                .resolve_and_make_data_subdir(persistence.data_dir.as_ref(), subdir)?;
+            transform = transform.with_persistence(
+                &data_dir,
+                persistence.snapshot_interval_secs,
+                persistence.seed_file.as_deref(),
+            );
+        }
+        Ok(Transform::event_task(transform))
     }
 
     fn input(&self) -> Input {
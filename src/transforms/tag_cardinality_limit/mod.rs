@@ -1,18 +1,22 @@
 use futures::{Stream, StreamExt};
 use hashbrown::HashMap;
-use std::{future::ready, pin::Pin};
+use std::{future::ready, path::Path, pin::Pin, time::Duration};
 
-use crate::transforms::tag_cardinality_limit::config::LimitExceededAction;
+use crate::transforms::tag_cardinality_limit::config::{LimitExceededAction, TrackingScope};
 use crate::{
     event::Event,
     internal_events::{
         TagCardinalityLimitRejectingEvent, TagCardinalityLimitRejectingTag,
         TagCardinalityValueLimitReached,
     },
-    transforms::TaskTransform,
+    transforms::{
+        snapshot::{run_with_snapshots, SnapshotTransform},
+        TaskTransform,
+    },
 };
 
 mod config;
+mod persistence;
 mod tag_value_set;
 
 #[cfg(test)]
@@ -20,6 +24,7 @@ mod tests;
 
 use crate::event::metric::TagValueSet;
 pub use config::{TagCardinalityLimitConfig, TagCardinalityLimitInnerConfig};
+use persistence::{SnapshotEntry, SnapshotMetric, State, TagValueSnapshotter};
 use tag_value_set::AcceptedTagValueSet;
 
 type MetricId = (Option<String>, String);
@@ -28,6 +33,7 @@ type MetricId = (Option<String>, String);
 pub struct TagCardinalityLimit {
     config: TagCardinalityLimitConfig,
     accepted_tags: HashMap<Option<MetricId>, HashMap<String, AcceptedTagValueSet>>,
+    snapshotter: Option<TagValueSnapshotter>,
 }
 
 impl TagCardinalityLimit {
@@ -35,9 +41,69 @@ impl TagCardinalityLimit {
         Self {
             config,
             accepted_tags: HashMap::new(),
+            snapshotter: None,
         }
     }
 
+    /// Restores the accepted tag values from the most recent snapshot in the given directory once
+    /// the transform starts, or from `seed_file` if there is none yet, and periodically snapshots
+    /// them back to that directory.
+    fn with_persistence(
+        mut self,
+        data_dir: &Path,
+        interval: Duration,
+        seed_file: Option<&Path>,
+    ) -> Self {
+        self.snapshotter =
+            Some(persistence::snapshotter(data_dir, interval).with_seed_file(seed_file));
+        self
+    }
+
+    /// Restores the accepted values of a single tag key, unless they would no longer be tracked
+    /// with the current configuration.
+    fn restore_entry(&mut self, entry: SnapshotEntry) {
+        let metric_key = entry.metric.map(|metric| (metric.namespace, metric.name));
+        let tracked = match &metric_key {
+            Some((namespace, name)) => {
+                self.metric_key(namespace.as_deref(), name).as_ref() == metric_key.as_ref()
+            }
+            None => self.config.tracking_scope == TrackingScope::Global,
+        };
+        if !tracked {
+            return;
+        }
+
+        let config = self.get_config_for_metric(metric_key.as_ref()).clone();
+        let tag_value_set = self
+            .accepted_tags
+            .entry(metric_key)
+            .or_default()
+            .entry(entry.key)
+            .or_insert_with(|| AcceptedTagValueSet::new(config.value_limit, &config.mode));
+        // The limit may have been lowered since the snapshot was written.
+        for value in entry.values {
+            if tag_value_set.len() >= config.value_limit {
+                break;
+            }
+            tag_value_set.insert(value.into());
+        }
+    }
+
+    /// The key under which the accepted tag values of a metric are tracked, or `None` if they are
+    /// shared with all other metrics without per-metric limits.
+    fn metric_key(&self, namespace: Option<&str>, name: &str) -> Option<MetricId> {
+        let has_per_metric_config = self
+            .config
+            .per_metric_limits
+            .iter()
+            .any(|(metric, config)| {
+                metric == name
+                    && (config.namespace.is_none() || config.namespace.as_deref() == namespace)
+            });
+        (has_per_metric_config || self.config.tracking_scope == TrackingScope::PerMetric)
+            .then(|| (namespace.map(str::to_string), name.to_string()))
+    }
+
     fn get_config_for_metric(
         &self,
         metric_key: Option<&MetricId>,
@@ -129,16 +195,7 @@ impl TagCardinalityLimit {
     fn transform_one(&mut self, mut event: Event) -> Option<Event> {
         let metric = event.as_mut_metric();
         let metric_name = metric.name().to_string();
-        let metric_namespace = metric.namespace().map(|n| n.to_string());
-        let has_per_metric_config = self.config.per_metric_limits.iter().any(|(name, config)| {
-            *name == metric_name
-                && (config.namespace.is_none() || config.namespace == metric_namespace)
-        });
-        let metric_key = if has_per_metric_config {
-            Some((metric_namespace, metric_name.clone()))
-        } else {
-            None
-        };
+        let metric_key = self.metric_key(metric.namespace(), &metric_name);
         if let Some(tags_map) = metric.tags_mut() {
             match self
                 .get_config_for_metric(metric_key.as_ref())
@@ -182,6 +239,47 @@ impl TagCardinalityLimit {
     }
 }
 
+impl SnapshotTransform for TagCardinalityLimit {
+    type State = State;
+
+    fn restore_snapshot(&mut self, state: State) {
+        for entry in state.into_entries() {
+            self.restore_entry(entry);
+        }
+        debug!(
+            message = "Loaded tag cardinality snapshot.",
+            metrics = self.accepted_tags.len()
+        );
+    }
+
+    fn take_snapshot(&self) -> State {
+        let entries = self
+            .accepted_tags
+            .iter()
+            .flat_map(|(metric_key, tags)| {
+                tags.iter().filter_map(move |(key, tag_value_set)| {
+                    Some(SnapshotEntry {
+                        metric: metric_key.as_ref().map(|(namespace, name)| SnapshotMetric {
+                            namespace: namespace.clone(),
+                            name: name.clone(),
+                        }),
+                        key: key.clone(),
+                        values: tag_value_set
+                            .values()?
+                            .map(|value| value.clone().into_iter().collect())
+                            .collect(),
+                    })
+                })
+            })
+            .collect();
+        State::new(entries)
+    }
+
+    fn transform_one(&mut self, event: Event) -> Option<Event> {
+        TagCardinalityLimit::transform_one(self, event)
+    }
+}
+
 impl TaskTransform<Event> for TagCardinalityLimit {
     fn transform(
         self: Box<Self>,
@@ -190,7 +288,11 @@ impl TaskTransform<Event> for TagCardinalityLimit {
     where
         Self: 'static,
     {
-        let mut inner = self;
-        Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))))
+        let Some(snapshotter) = self.snapshotter.clone() else {
+            let mut inner = self;
+            return Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))));
+        };
+
+        run_with_snapshots(self, &snapshotter, task)
     }
 }
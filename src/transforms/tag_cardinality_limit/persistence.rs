@@ -0,0 +1,50 @@
+use std::{path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{event::metric::TagValue, transforms::snapshot::Snapshotter};
+
+const SNAPSHOT_NAME: &str = "tag_cardinality_limit";
+
+pub(super) type TagValueSnapshotter = Snapshotter<State>;
+
+pub(super) fn snapshotter(directory: &Path, interval: Duration) -> TagValueSnapshotter {
+    Snapshotter::new(directory, SNAPSHOT_NAME, interval)
+}
+
+/// The file format of snapshots persisted to disk. Any incompatible change to the format requires
+/// a new variant to be added here and handled when loading.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "version", rename_all = "snake_case")]
+pub(super) enum State {
+    #[serde(rename = "1")]
+    V1 { entries: Vec<SnapshotEntry> },
+}
+
+impl State {
+    pub(super) const fn new(entries: Vec<SnapshotEntry>) -> Self {
+        Self::V1 { entries }
+    }
+
+    pub(super) fn into_entries(self) -> Vec<SnapshotEntry> {
+        let Self::V1 { entries } = self;
+        entries
+    }
+}
+
+/// The accepted values of a single tag key.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SnapshotEntry {
+    /// The metric the values were accepted for, if they are tracked separately for it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) metric: Option<SnapshotMetric>,
+    pub(super) key: String,
+    pub(super) values: Vec<Vec<TagValue>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SnapshotMetric {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) namespace: Option<String>,
+    pub(super) name: String,
+}
@@ -83,6 +83,14 @@ impl AcceptedTagValueSet {
         }
     }
 
+    /// The accepted values, or `None` if they are only tracked probabilistically.
+    pub fn values(&self) -> Option<impl Iterator<Item = &TagValueSet>> {
+        match &self.storage {
+            TagValueSetStorage::Set(set) => Some(set.iter()),
+            TagValueSetStorage::Bloom(_) => None,
+        }
+    }
+
     pub fn insert(&mut self, value: TagValueSet) {
         match &mut self.storage {
             TagValueSetStorage::Set(set) => {
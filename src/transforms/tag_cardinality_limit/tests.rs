@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use config::PerMetricConfig;
 use vector_lib::config::ComponentKey;
@@ -14,7 +15,7 @@ use crate::event::metric::TagValue;
 use crate::event::{metric, Event, Metric, MetricTags};
 use crate::test_util::components::assert_transform_compliance;
 use crate::transforms::tag_cardinality_limit::config::{
-    default_cache_size, BloomFilterConfig, Mode,
+    default_cache_size, BloomFilterConfig, Mode, TrackingScope,
 };
 use crate::transforms::test::create_topology;
 use tokio::sync::mpsc;
@@ -55,6 +56,8 @@ fn make_transform_hashset(
             mode: Mode::Exact,
         },
         per_metric_limits: HashMap::new(),
+        tracking_scope: TrackingScope::Global,
+        persistence: None,
     }
 }
 
@@ -71,6 +74,8 @@ fn make_transform_bloom(
             }),
         },
         per_metric_limits: HashMap::new(),
+        tracking_scope: TrackingScope::Global,
+        persistence: None,
     }
 }
 
@@ -86,6 +91,8 @@ const fn make_transform_hashset_with_per_metric_limits(
             mode: Mode::Exact,
         },
         per_metric_limits,
+        tracking_scope: TrackingScope::Global,
+        persistence: None,
     }
 }
 
@@ -103,6 +110,8 @@ const fn make_transform_bloom_with_per_metric_limits(
             }),
         },
         per_metric_limits,
+        tracking_scope: TrackingScope::Global,
+        persistence: None,
     }
 }
 
@@ -404,6 +413,99 @@ fn drop_event_checks_all_tags(make_tags: impl Fn(&str, &str) -> MetricTags) {
     assert_eq!(new_event4, Some(event4));
 }
 
+#[test]
+fn per_metric_tracking_scope_limits_each_metric_separately() {
+    let mut config = make_transform_hashset(1, LimitExceededAction::DropTag);
+    config.tracking_scope = TrackingScope::PerMetric;
+    let mut transform = TagCardinalityLimit::new(config);
+
+    let event_a1 = make_metric_with_name(metric_tags!("tag1" => "val1"), "metricA");
+    let event_a2 = make_metric_with_name(metric_tags!("tag1" => "val2"), "metricA");
+    let event_b1 = make_metric_with_name(metric_tags!("tag1" => "val2"), "metricB");
+
+    assert_eq!(transform.transform_one(event_a1.clone()), Some(event_a1));
+    let new_event_a2 = transform.transform_one(event_a2).unwrap();
+    assert!(!new_event_a2
+        .as_metric()
+        .tags()
+        .unwrap()
+        .contains_key("tag1"));
+    // The value limit of `metricA` has been reached, but not the one of `metricB`.
+    assert_eq!(transform.transform_one(event_b1.clone()), Some(event_b1));
+}
+
+async fn load_snapshot(transform: &mut TagCardinalityLimit) {
+    let snapshotter = transform.snapshotter.clone().unwrap();
+    snapshotter.load(transform).await;
+}
+
+async fn write_snapshot(transform: &TagCardinalityLimit) {
+    let snapshotter = transform.snapshotter.as_ref().unwrap();
+    snapshotter.persist(transform.take_snapshot()).await;
+}
+
+#[tokio::test]
+async fn persisted_tag_values_survive_restart() {
+    let data_dir = crate::test_util::temp_dir();
+    std::fs::create_dir_all(&data_dir).unwrap();
+    let data_dir = &data_dir;
+    let make_transform = || async move {
+        let mut transform =
+            TagCardinalityLimit::new(make_transform_hashset(1, LimitExceededAction::DropEvent))
+                .with_persistence(data_dir, Duration::from_secs(60), None);
+        load_snapshot(&mut transform).await;
+        transform
+    };
+    let event1 = make_metric(metric_tags!("tag1" => "val1"));
+    let event2 = make_metric(metric_tags!("tag1" => "val2"));
+
+    let mut transform = make_transform().await;
+    assert_eq!(
+        transform.transform_one(event1.clone()),
+        Some(event1.clone())
+    );
+    write_snapshot(&transform).await;
+
+    // Without the snapshot, the first value seen after the restart would be accepted instead.
+    let mut transform = make_transform().await;
+    assert_eq!(transform.transform_one(event2), None);
+    assert_eq!(transform.transform_one(event1.clone()), Some(event1));
+}
+
+#[tokio::test]
+async fn seed_file_is_loaded_without_snapshot() {
+    let seed_dir = crate::test_util::temp_dir();
+    let data_dir = crate::test_util::temp_dir();
+    std::fs::create_dir_all(&seed_dir).unwrap();
+    std::fs::create_dir_all(&data_dir).unwrap();
+    let mut config = make_transform_hashset(1, LimitExceededAction::DropEvent);
+    config.tracking_scope = TrackingScope::PerMetric;
+    let event1 = make_metric(metric_tags!("tag1" => "val1"));
+    let event2 = make_metric(metric_tags!("tag1" => "val2"));
+
+    let mut transform = TagCardinalityLimit::new(config.clone()).with_persistence(
+        &seed_dir,
+        Duration::from_secs(60),
+        None,
+    );
+    load_snapshot(&mut transform).await;
+    assert_eq!(
+        transform.transform_one(event1.clone()),
+        Some(event1.clone())
+    );
+    write_snapshot(&transform).await;
+
+    let seed_file = seed_dir.join("tag_cardinality_limit.json");
+    let mut transform = TagCardinalityLimit::new(config).with_persistence(
+        &data_dir,
+        Duration::from_secs(60),
+        Some(&seed_file),
+    );
+    load_snapshot(&mut transform).await;
+    assert_eq!(transform.transform_one(event2), None);
+    assert_eq!(transform.transform_one(event1.clone()), Some(event1));
+}
+
 #[tokio::test]
 async fn tag_cardinality_limit_separate_value_limit_per_metric_name_hashset() {
     separate_value_limit_per_metric_name(make_transform_hashset_with_per_metric_limits(
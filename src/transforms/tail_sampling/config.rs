@@ -0,0 +1,192 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use serde_with::serde_as;
+use snafu::Snafu;
+use vector_lib::config::{clone_input_definitions, LogNamespace};
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::{ConfigTargetPath, OptionalValuePath};
+use vector_lib::lookup::owned_value_path;
+
+use crate::{
+    conditions::AnyCondition,
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    schema,
+    transforms::Transform,
+};
+
+use super::transform::TailSampling;
+
+#[derive(Debug, Snafu)]
+pub enum TailSamplingError {
+    #[snafu(display(
+        "Only numbers between 0 and 1 are allowed values for `fallback_ratio`, value: {ratio}"
+    ))]
+    InvalidFallbackRatio { ratio: f64 },
+
+    #[snafu(display("Only non-zero durations are allowed values for `decision_wait_secs`"))]
+    InvalidDecisionWait,
+}
+
+/// Configuration for the `tail_sampling` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "tail_sampling",
+    "Sample whole traces after all of their spans have been seen."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TailSamplingConfig {
+    /// The field containing the ID of the trace a span belongs to.
+    ///
+    /// Spans are buffered by the value of this field. Spans without this field are forwarded
+    /// without being sampled.
+    #[serde(default = "default_trace_id_field")]
+    #[configurable(metadata(docs::examples = "trace_id"))]
+    pub trace_id_field: ConfigTargetPath,
+
+    /// The amount of time to wait after the first span of a trace is seen before deciding whether
+    /// to keep the trace, in seconds.
+    ///
+    /// Spans that arrive after the decision has been made follow the same decision, as long as the
+    /// trace ID is still in the decision cache.
+    #[serde(default = "default_decision_wait_secs")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Decision Wait"))]
+    pub decision_wait_secs: Duration,
+
+    /// The maximum number of traces to buffer while waiting for a decision.
+    ///
+    /// When this limit is reached, a decision is made for the oldest buffered trace early to make
+    /// room for the new one. The same number of past decisions is remembered for spans that arrive
+    /// late.
+    #[serde(default = "default_max_traces")]
+    #[configurable(metadata(docs::type_unit = "traces"))]
+    pub max_traces: NonZeroUsize,
+
+    /// A list of logical conditions used to keep traces.
+    ///
+    /// Each condition is checked against every span of a trace. If any span matches any condition,
+    /// the whole trace is kept.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::examples = ".status.code == 2",
+        docs::examples = "to_unix_timestamp!(.end_time_unix_nano, unit: \"milliseconds\") - to_unix_timestamp!(.start_time_unix_nano, unit: \"milliseconds\") > 500"
+    ))]
+    pub conditions: Vec<AnyCondition>,
+
+    /// The ratio of traces that do not match any of the `conditions` which are kept anyway.
+    ///
+    /// The decision is made by hashing the trace ID, so all instances of Vector sampling the same
+    /// trace make the same decision.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = 0.1))]
+    #[configurable(validation(range(min = 0.0, max = 1.0)))]
+    pub fallback_ratio: f64,
+
+    /// The event key in which the sample rate is stored. If set to an empty string, the sample rate will not be added to the event.
+    ///
+    /// Traces kept because they match one of the `conditions` have a sample rate of `1`. Traces
+    /// kept by the `fallback_ratio` have a sample rate equal to that ratio.
+    #[configurable(metadata(docs::examples = "sample_rate"))]
+    #[serde(default = "default_sample_rate_key")]
+    pub sample_rate_key: OptionalValuePath,
+}
+
+fn default_trace_id_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("trace_id".to_string()).expect("valid static path")
+}
+
+const fn default_decision_wait_secs() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_traces() -> NonZeroUsize {
+    NonZeroUsize::new(50_000).expect("static non-zero number")
+}
+
+fn default_sample_rate_key() -> OptionalValuePath {
+    OptionalValuePath::from(owned_value_path!("sample_rate"))
+}
+
+impl TailSamplingConfig {
+    fn validate_options(&self) -> Result<(), TailSamplingError> {
+        if !(0.0..=1.0).contains(&self.fallback_ratio) {
+            return Err(TailSamplingError::InvalidFallbackRatio {
+                ratio: self.fallback_ratio,
+            });
+        }
+        if self.decision_wait_secs.is_zero() {
+            return Err(TailSamplingError::InvalidDecisionWait);
+        }
+        Ok(())
+    }
+}
+
+impl GenerateConfig for TailSamplingConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            trace_id_field: default_trace_id_field(),
+            decision_wait_secs: default_decision_wait_secs(),
+            max_traces: default_max_traces(),
+            conditions: vec![AnyCondition::String(".status.code == 2".to_string())],
+            fallback_ratio: 0.1,
+            sample_rate_key: default_sample_rate_key(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "tail_sampling")]
+impl TransformConfig for TailSamplingConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        self.validate_options()?;
+        let conditions = self
+            .conditions
+            .iter()
+            .map(|condition| condition.build(&context.enrichment_tables))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Transform::event_task(TailSampling::new(
+            self.trace_id_field.0.clone(),
+            self.decision_wait_secs,
+            self.max_traces,
+            conditions,
+            self.fallback_ratio,
+            self.sample_rate_key.clone(),
+        )))
+    }
+
+    fn input(&self) -> Input {
+        Input::trace()
+    }
+
+    fn validate(&self, _: &schema::Definition) -> Result<(), Vec<String>> {
+        self.validate_options().map_err(|e| vec![e.to_string()])
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(
+            DataType::Trace,
+            clone_input_definitions(input_definitions),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TailSamplingConfig;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<TailSamplingConfig>();
+    }
+}
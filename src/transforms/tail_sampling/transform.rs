@@ -0,0 +1,327 @@
+use std::{
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use futures::Stream;
+use indexmap::IndexMap;
+use lru::LruCache;
+use vector_lib::lookup::lookup_v2::OptionalValuePath;
+use vector_lib::lookup::OwnedTargetPath;
+use vector_lib::stream::expiration_map::{map_with_expiration, Emitter};
+
+use crate::{
+    conditions::Condition,
+    event::{Event, Value},
+    internal_events::TailSamplingTraceDiscarded,
+    transforms::TaskTransform,
+};
+
+/// The longest interval between checks for traces whose decision wait has elapsed.
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The spans of a trace that is waiting for a sampling decision.
+struct PendingTrace {
+    spans: Vec<Event>,
+    /// Whether any span seen so far matched one of the conditions.
+    matched: bool,
+    first_seen: Instant,
+}
+
+/// The sampling decision made for a trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Decision {
+    Matched,
+    Fallback,
+    Dropped,
+}
+
+pub struct TailSampling {
+    trace_id_field: OwnedTargetPath,
+    decision_wait: Duration,
+    max_traces: NonZeroUsize,
+    conditions: Vec<Condition>,
+    fallback_ratio: f64,
+    hash_ratio_threshold: u64,
+    sample_rate_path: Option<OwnedTargetPath>,
+    /// Traces waiting for a decision, in the order their first span was seen.
+    pending: IndexMap<String, PendingTrace>,
+    /// Decisions made for recent traces, applied to spans that arrive after the decision.
+    decisions: LruCache<String, Decision>,
+}
+
+impl TailSampling {
+    pub fn new(
+        trace_id_field: OwnedTargetPath,
+        decision_wait: Duration,
+        max_traces: NonZeroUsize,
+        conditions: Vec<Condition>,
+        fallback_ratio: f64,
+        sample_rate_key: OptionalValuePath,
+    ) -> Self {
+        Self {
+            trace_id_field,
+            decision_wait,
+            max_traces,
+            conditions,
+            fallback_ratio,
+            // Scale the ratio to the width of the hash, as the `sample` transform does, to avoid
+            // precision issues when comparing against it.
+            hash_ratio_threshold: (fallback_ratio * (u64::MAX as u128) as f64) as u64,
+            sample_rate_path: sample_rate_key.path.map(OwnedTargetPath::event),
+            pending: IndexMap::new(),
+            decisions: LruCache::new(max_traces),
+        }
+    }
+
+    fn transform_one(&mut self, event: Event, emitter: &mut Emitter<Event>) {
+        let Some(trace_id) = event
+            .as_trace()
+            .get(&self.trace_id_field)
+            .map(Value::to_string_lossy)
+            .map(|id| id.into_owned())
+        else {
+            emitter.emit(event);
+            return;
+        };
+
+        if let Some(decision) = self.decisions.get(&trace_id).copied() {
+            self.emit_spans(decision, vec![event], emitter);
+            return;
+        }
+
+        let (matched, event) = self.check_conditions(event);
+        if let Some(trace) = self.pending.get_mut(&trace_id) {
+            trace.matched |= matched;
+            trace.spans.push(event);
+            return;
+        }
+
+        if self.pending.len() >= self.max_traces.get() {
+            self.decide_oldest(emitter);
+        }
+        self.pending.insert(
+            trace_id,
+            PendingTrace {
+                spans: vec![event],
+                matched,
+                first_seen: Instant::now(),
+            },
+        );
+    }
+
+    /// Checks the span against the conditions, stopping at the first one that matches.
+    fn check_conditions(&self, mut event: Event) -> (bool, Event) {
+        for condition in &self.conditions {
+            let (matched, checked) = condition.check(event);
+            event = checked;
+            if matched {
+                return (true, event);
+            }
+        }
+        (false, event)
+    }
+
+    fn decide_oldest(&mut self, emitter: &mut Emitter<Event>) {
+        if let Some((trace_id, trace)) = self.pending.shift_remove_index(0) {
+            self.decide(trace_id, trace, emitter);
+        }
+    }
+
+    fn decide(&mut self, trace_id: String, trace: PendingTrace, emitter: &mut Emitter<Event>) {
+        let decision = if trace.matched {
+            Decision::Matched
+        } else if seahash::hash(trace_id.as_bytes()) <= self.hash_ratio_threshold
+            && self.fallback_ratio > 0.0
+        {
+            Decision::Fallback
+        } else {
+            Decision::Dropped
+        };
+        self.decisions.put(trace_id, decision);
+        self.emit_spans(decision, trace.spans, emitter);
+    }
+
+    fn emit_spans(&self, decision: Decision, spans: Vec<Event>, emitter: &mut Emitter<Event>) {
+        let sample_rate = match decision {
+            Decision::Matched => "1".to_string(),
+            Decision::Fallback => self.fallback_ratio.to_string(),
+            Decision::Dropped => {
+                emit!(TailSamplingTraceDiscarded { count: spans.len() });
+                return;
+            }
+        };
+        for mut event in spans {
+            if let Some(path) = &self.sample_rate_path {
+                event.as_mut_trace().insert(path, sample_rate.clone());
+            }
+            emitter.emit(event);
+        }
+    }
+
+    /// Makes a decision for every trace whose decision wait has elapsed.
+    fn flush_expired(&mut self, emitter: &mut Emitter<Event>) {
+        while let Some((_, trace)) = self.pending.first() {
+            if trace.first_seen.elapsed() < self.decision_wait {
+                break;
+            }
+            self.decide_oldest(emitter);
+        }
+    }
+
+    fn flush_all(&mut self, emitter: &mut Emitter<Event>) {
+        while !self.pending.is_empty() {
+            self.decide_oldest(emitter);
+        }
+    }
+}
+
+impl TaskTransform<Event> for TailSampling {
+    fn transform(
+        self: Box<Self>,
+        input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let check_interval = self.decision_wait.min(MAX_CHECK_INTERVAL);
+        Box::pin(map_with_expiration(
+            self,
+            input_rx,
+            check_interval,
+            |me: &mut Box<TailSampling>, event, emitter: &mut Emitter<Event>| {
+                me.transform_one(event, emitter);
+            },
+            |me: &mut Box<TailSampling>, emitter: &mut Emitter<Event>| me.flush_expired(emitter),
+            |me: &mut Box<TailSampling>, emitter: &mut Emitter<Event>| me.flush_all(emitter),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::lookup::owned_value_path;
+
+    use super::*;
+    use crate::{
+        conditions::{ConditionalConfig, VrlConfig},
+        event::{LogEvent, TraceEvent},
+    };
+
+    fn span(trace_id: &str, name: &str, status: i64) -> Event {
+        let mut log = LogEvent::default();
+        log.insert("trace_id", trace_id);
+        log.insert("name", name);
+        log.insert("status", status);
+        Event::Trace(TraceEvent::from(log))
+    }
+
+    fn sampler(fallback_ratio: f64, max_traces: usize) -> TailSampling {
+        let condition = VrlConfig {
+            source: ".status == 2".to_string(),
+            runtime: Default::default(),
+        }
+        .build(&Default::default())
+        .expect("should not fail to build VRL condition");
+
+        TailSampling::new(
+            OwnedTargetPath::event(owned_value_path!("trace_id")),
+            Duration::from_secs(30),
+            NonZeroUsize::new(max_traces).unwrap(),
+            vec![condition],
+            fallback_ratio,
+            OptionalValuePath::from(owned_value_path!("sample_rate")),
+        )
+    }
+
+    fn names(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| {
+                event
+                    .as_trace()
+                    .get("name")
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keeps_whole_trace_when_any_span_matches() {
+        let mut sampler = sampler(0.0, 10);
+        let mut emitter = Emitter::new();
+
+        sampler.transform_one(span("a", "a1", 0), &mut emitter);
+        sampler.transform_one(span("b", "b1", 0), &mut emitter);
+        sampler.transform_one(span("a", "a2", 2), &mut emitter);
+        sampler.transform_one(span("b", "b2", 0), &mut emitter);
+        sampler.flush_all(&mut emitter);
+
+        let events = emitter.into_values();
+        assert_eq!(names(&events), vec!["a1", "a2"]);
+        assert_eq!(
+            events[0].as_trace().get("sample_rate"),
+            Some(&Value::from("1"))
+        );
+    }
+
+    #[test]
+    fn late_spans_follow_decision() {
+        let mut sampler = sampler(0.0, 10);
+        let mut emitter = Emitter::new();
+
+        sampler.transform_one(span("a", "a1", 2), &mut emitter);
+        sampler.transform_one(span("b", "b1", 0), &mut emitter);
+        sampler.flush_all(&mut emitter);
+        sampler.transform_one(span("a", "a2", 0), &mut emitter);
+        sampler.transform_one(span("b", "b2", 2), &mut emitter);
+
+        assert_eq!(names(&emitter.into_values()), vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn decides_oldest_trace_when_full() {
+        let mut sampler = sampler(0.0, 1);
+        let mut emitter = Emitter::new();
+
+        sampler.transform_one(span("a", "a1", 2), &mut emitter);
+        sampler.transform_one(span("b", "b1", 0), &mut emitter);
+
+        assert_eq!(names(&emitter.into_values()), vec!["a1"]);
+        assert_eq!(sampler.pending.len(), 1);
+    }
+
+    #[test]
+    fn fallback_ratio_keeps_roughly_the_configured_ratio() {
+        let mut sampler = sampler(0.25, 10_000);
+        let mut emitter = Emitter::new();
+
+        for i in 0..10_000 {
+            sampler.transform_one(span(&format!("trace-{i}"), "span", 0), &mut emitter);
+        }
+        sampler.flush_all(&mut emitter);
+
+        let events = emitter.into_values();
+        let ratio = events.len() as f64 / 10_000.0;
+        assert!((ratio - 0.25).abs() < 0.03, "ratio was {ratio}");
+        assert_eq!(
+            events[0].as_trace().get("sample_rate"),
+            Some(&Value::from("0.25"))
+        );
+    }
+
+    #[test]
+    fn spans_without_trace_id_pass_through() {
+        let mut sampler = sampler(0.0, 10);
+        let mut emitter = Emitter::new();
+
+        let mut log = LogEvent::default();
+        log.insert("name", "orphan");
+        sampler.transform_one(Event::Trace(TraceEvent::from(log)), &mut emitter);
+
+        assert_eq!(names(&emitter.into_values()), vec!["orphan"]);
+    }
+}
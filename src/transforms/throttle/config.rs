@@ -3,8 +3,9 @@ use serde_with::serde_as;
 use std::time::Duration;
 use vector_lib::config::{clone_input_definitions, LogNamespace};
 use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
 
-use super::transform::Throttle;
+use super::transform::{Throttle, DROPPED};
 use crate::{
     conditions::AnyCondition,
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
@@ -31,10 +32,35 @@ pub struct ThrottleInternalMetricsConfig {
     pub emit_events_discarded_per_key: bool,
 }
 
+/// The algorithm used to decide whether an event is within the rate limit.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThrottleAlgorithm {
+    /// Each bucket holds up to `burst` tokens, refilled at a rate of `threshold` tokens per
+    /// `window_secs`. Each event takes one token, and events are rate limited while the bucket is
+    /// empty.
+    #[default]
+    TokenBucket,
+
+    /// Each bucket records the time of every event allowed within the last `window_secs`, and
+    /// events are rate limited while `threshold` events have been allowed.
+    ///
+    /// This is exact, but uses memory proportional to `threshold` for every bucket.
+    SlidingLog,
+
+    /// Each bucket counts the events allowed in the current and previous fixed windows of
+    /// `window_secs`. The count of the previous window is weighted by how much of it overlaps the
+    /// sliding window ending now.
+    ///
+    /// This approximates `sliding_log` using a constant amount of memory for every bucket.
+    SlidingWindowCounter,
+}
+
 /// Configuration for the `throttle` transform.
 #[serde_as]
 #[configurable_component(transform("throttle", "Rate limit logs passing through a topology."))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct ThrottleConfig {
     /// The number of events allowed for a given bucket per configured `window_secs`.
@@ -42,11 +68,39 @@ pub struct ThrottleConfig {
     /// Each unique key has its own `threshold`.
     pub threshold: u32,
 
+    /// The field containing the number of events allowed for the event's bucket per configured
+    /// `window_secs`.
+    ///
+    /// This allows applying quotas defined in the events themselves, such as per-tenant limits. If
+    /// the event doesn't have the field, or its value is not a positive integer, `threshold` is
+    /// used instead. Values above `threshold_field_max` are lowered to it.
+    #[configurable(metadata(docs::examples = "tenant_quota"))]
+    pub threshold_field: Option<ConfigTargetPath>,
+
+    /// The maximum threshold that can be taken from `threshold_field`.
+    ///
+    /// This bounds the state kept for each bucket, since the `sliding_log` algorithm records the
+    /// time of every event allowed within the window.
+    #[serde(default = "default_threshold_field_max")]
+    #[configurable(metadata(docs::type_unit = "events"))]
+    pub threshold_field_max: u32,
+
     /// The time window in which the configured `threshold` is applied, in seconds.
     #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
     #[configurable(metadata(docs::human_name = "Time Window"))]
     pub window_secs: Duration,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub algorithm: ThrottleAlgorithm,
+
+    /// The maximum number of events allowed at once for a given bucket when using the
+    /// `token_bucket` algorithm.
+    ///
+    /// If left unspecified, this is the same as the bucket's threshold.
+    #[configurable(metadata(docs::type_unit = "events"))]
+    pub burst: Option<u32>,
+
     /// The value to group events into separate buckets to be rate limited independently.
     ///
     /// If left unspecified, or if the event doesn't have `key_field`, then the event is not rate
@@ -57,18 +111,46 @@ pub struct ThrottleConfig {
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
 
+    /// Whether or not to send events over the rate limit to a named `dropped` output instead of
+    /// discarding them.
+    ///
+    /// The rate limited events are not modified.
+    #[serde(default)]
+    pub reroute_dropped: bool,
+
     #[configurable(derived)]
     #[serde(default)]
     pub internal_metrics: ThrottleInternalMetricsConfig,
 }
 
+const fn default_threshold_field_max() -> u32 {
+    10_000
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0,
+            threshold_field: None,
+            threshold_field_max: default_threshold_field_max(),
+            window_secs: Duration::default(),
+            algorithm: ThrottleAlgorithm::default(),
+            burst: None,
+            key_field: None,
+            exclude: None,
+            reroute_dropped: false,
+            internal_metrics: ThrottleInternalMetricsConfig::default(),
+        }
+    }
+}
+
 impl_generate_config_from_default!(ThrottleConfig);
 
 #[async_trait::async_trait]
 #[typetag::serde(name = "throttle")]
 impl TransformConfig for ThrottleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Throttle::new(self, context, clock::MonotonicClock).map(Transform::event_task)
+        Throttle::new(self, context, clock::MonotonicClock).map(Transform::synchronous)
     }
 
     fn input(&self) -> Input {
@@ -82,10 +164,17 @@ impl TransformConfig for ThrottleConfig {
         _: LogNamespace,
     ) -> Vec<TransformOutput> {
         // The event is not modified, so the definition is passed through as-is
-        vec![TransformOutput::new(
+        let mut outputs = vec![TransformOutput::new(
             DataType::Log,
             clone_input_definitions(input_definitions),
-        )]
+        )];
+        if self.reroute_dropped {
+            outputs.push(
+                TransformOutput::new(DataType::Log, clone_input_definitions(input_definitions))
+                    .with_port(DROPPED),
+            );
+        }
+        outputs
     }
 }
 
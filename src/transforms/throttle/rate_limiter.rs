@@ -1,57 +1,293 @@
-use governor::clock;
-use governor::middleware::NoOpMiddleware;
-use governor::state::keyed::DashMapStateStore;
-use governor::{Quota, RateLimiter};
+use std::collections::{HashMap, VecDeque};
 use std::hash::Hash;
-use std::sync::Arc;
+use std::num::NonZeroU32;
 use std::time::Duration;
-use tokio;
 
-/// Re-usable wrapper around the structs/type from the governor crate.
-/// Spawns a background task that periodically flushes keys that haven't been accessed recently.
-pub struct RateLimiterRunner<K, C>
+use governor::clock::{self, Reference};
+use governor::nanos::Nanos;
+
+use super::config::ThrottleAlgorithm;
+
+/// The rate limiting state of a single key.
+#[derive(Clone, Debug)]
+enum KeyState<I> {
+    /// The token bucket is tracked with its equivalent "generic cell rate" formulation: the
+    /// theoretical arrival time is the point at which the bucket would be full again.
+    TokenBucket { theoretical_arrival: I },
+    /// The times at which the events allowed within the current window were seen.
+    SlidingLog { allowed: VecDeque<I> },
+    /// The number of events allowed in the current and previous fixed windows.
+    SlidingWindowCounter {
+        window_start: I,
+        current: u32,
+        previous: u32,
+    },
+}
+
+impl<I: Reference> KeyState<I> {
+    const fn new(algorithm: ThrottleAlgorithm, now: I) -> Self {
+        match algorithm {
+            ThrottleAlgorithm::TokenBucket => Self::TokenBucket {
+                theoretical_arrival: now,
+            },
+            ThrottleAlgorithm::SlidingLog => Self::SlidingLog {
+                allowed: VecDeque::new(),
+            },
+            ThrottleAlgorithm::SlidingWindowCounter => Self::SlidingWindowCounter {
+                window_start: now,
+                current: 0,
+                previous: 0,
+            },
+        }
+    }
+
+    fn check(
+        &mut self,
+        now: I,
+        window: Duration,
+        threshold: NonZeroU32,
+        burst: NonZeroU32,
+    ) -> bool {
+        match self {
+            Self::TokenBucket {
+                theoretical_arrival,
+            } => {
+                let emission_interval = window / threshold.get();
+                let tolerance = emission_interval * (burst.get() - 1);
+                let arrival = (*theoretical_arrival).max(now);
+                if elapsed(now, arrival) > tolerance {
+                    return false;
+                }
+                *theoretical_arrival = arrival + Nanos::from(emission_interval);
+                true
+            }
+            Self::SlidingLog { allowed } => {
+                while allowed
+                    .front()
+                    .is_some_and(|seen| elapsed(*seen, now) >= window)
+                {
+                    allowed.pop_front();
+                }
+                if allowed.len() >= threshold.get() as usize {
+                    return false;
+                }
+                allowed.push_back(now);
+                true
+            }
+            Self::SlidingWindowCounter {
+                window_start,
+                current,
+                previous,
+            } => {
+                let since_start = elapsed(*window_start, now);
+                if since_start >= window {
+                    let windows = since_start.as_nanos() / window.as_nanos();
+                    *previous = if windows == 1 { *current } else { 0 };
+                    *current = 0;
+                    *window_start = *window_start
+                        + Nanos::from(Duration::from_nanos((windows * window.as_nanos()) as u64));
+                }
+
+                // Weight the previous window by how much of it still overlaps the sliding window
+                // ending now.
+                let into_window = elapsed(*window_start, now).as_secs_f64() / window.as_secs_f64();
+                let estimate = f64::from(*previous) * (1.0 - into_window) + f64::from(*current);
+                if estimate >= f64::from(threshold.get()) {
+                    return false;
+                }
+                *current += 1;
+                true
+            }
+        }
+    }
+
+    /// Whether the state is equivalent to that of a key that has not been seen at all, meaning it
+    /// can be dropped without affecting future decisions.
+    fn is_idle(&self, now: I, window: Duration) -> bool {
+        match self {
+            Self::TokenBucket {
+                theoretical_arrival,
+            } => *theoretical_arrival <= now,
+            Self::SlidingLog { allowed } => allowed
+                .back()
+                .is_none_or(|seen| elapsed(*seen, now) >= window),
+            Self::SlidingWindowCounter { window_start, .. } => {
+                elapsed(*window_start, now) >= window * 2
+            }
+        }
+    }
+}
+
+fn elapsed<I: Reference>(earlier: I, later: I) -> Duration {
+    later.duration_since(earlier).into()
+}
+
+/// Rate limits events independently for each key, using the configured algorithm.
+///
+/// Keys whose state has fully recovered are periodically flushed so that the number of tracked
+/// keys doesn't grow without bound.
+#[derive(Clone)]
+pub struct KeyedRateLimiter<K, C>
 where
-    K: Hash + Eq + Clone,
     C: clock::Clock,
 {
-    pub rate_limiter: Arc<RateLimiter<K, DashMapStateStore<K>, C, NoOpMiddleware<C::Instant>>>,
-    flush_handle: tokio::task::JoinHandle<()>,
+    algorithm: ThrottleAlgorithm,
+    window: Duration,
+    burst: Option<NonZeroU32>,
+    clock: C,
+    keys: HashMap<K, KeyState<C::Instant>>,
+    last_flush: C::Instant,
 }
 
-impl<K, C> RateLimiterRunner<K, C>
+impl<K, C> KeyedRateLimiter<K, C>
 where
-    K: Hash + Eq + Clone + Send + Sync + 'static,
-    C: clock::Clock + Clone + Send + Sync + 'static,
+    K: Hash + Eq + Clone,
+    C: clock::Clock,
 {
-    pub fn start(quota: Quota, clock: C, flush_keys_interval: Duration) -> Self {
-        let rate_limiter = Arc::new(RateLimiter::dashmap_with_clock(quota, clock));
-
-        let rate_limiter_clone = Arc::clone(&rate_limiter);
-        let flush_handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(flush_keys_interval);
-            loop {
-                interval.tick().await;
-                rate_limiter_clone.retain_recent();
+    pub fn new(
+        algorithm: ThrottleAlgorithm,
+        window: Duration,
+        burst: Option<NonZeroU32>,
+        clock: C,
+    ) -> Self {
+        let last_flush = clock.now();
+        Self {
+            algorithm,
+            window,
+            burst,
+            clock,
+            keys: HashMap::new(),
+            last_flush,
+        }
+    }
+
+    /// Checks whether an event for the given key is allowed under `threshold` events per window,
+    /// counting it against the key's limit if so.
+    pub fn check_key(&mut self, key: &K, threshold: NonZeroU32) -> bool {
+        let now = self.clock.now();
+        self.flush_idle_keys(now);
+
+        let burst = self.burst.unwrap_or(threshold);
+        match self.keys.get_mut(key) {
+            Some(state) => state.check(now, self.window, threshold, burst),
+            None => {
+                let mut state = KeyState::new(self.algorithm, now);
+                let allowed = state.check(now, self.window, threshold, burst);
+                self.keys.insert(key.clone(), state);
+                allowed
             }
-        });
+        }
+    }
 
-        Self {
-            rate_limiter,
-            flush_handle,
+    fn flush_idle_keys(&mut self, now: C::Instant) {
+        if elapsed(self.last_flush, now) < self.window {
+            return;
         }
+        let window = self.window;
+        self.keys.retain(|_, state| !state.is_idle(now, window));
+        self.last_flush = now;
     }
 
-    pub fn check_key(&self, key: &K) -> bool {
-        self.rate_limiter.check_key(key).is_ok()
+    #[cfg(test)]
+    pub fn tracked_keys(&self) -> usize {
+        self.keys.len()
     }
 }
 
-impl<K, C> Drop for RateLimiterRunner<K, C>
-where
-    K: Hash + Eq + Clone,
-    C: clock::Clock,
-{
-    fn drop(&mut self) {
-        self.flush_handle.abort();
+#[cfg(test)]
+mod tests {
+    use governor::clock::FakeRelativeClock;
+
+    use super::*;
+
+    fn allowed(
+        limiter: &mut KeyedRateLimiter<&'static str, FakeRelativeClock>,
+        threshold: u32,
+        count: usize,
+    ) -> usize {
+        let threshold = NonZeroU32::new(threshold).unwrap();
+        (0..count)
+            .filter(|_| limiter.check_key(&"key", threshold))
+            .count()
+    }
+
+    #[test]
+    fn token_bucket_allows_burst() {
+        let clock = FakeRelativeClock::default();
+        let mut limiter = KeyedRateLimiter::new(
+            ThrottleAlgorithm::TokenBucket,
+            Duration::from_secs(10),
+            NonZeroU32::new(5),
+            clock.clone(),
+        );
+
+        assert_eq!(allowed(&mut limiter, 2, 10), 5);
+
+        // One token is refilled every 5 seconds.
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(allowed(&mut limiter, 2, 10), 1);
+
+        clock.advance(Duration::from_secs(25));
+        assert_eq!(allowed(&mut limiter, 2, 10), 5);
+    }
+
+    #[test]
+    fn sliding_log_forgets_events_outside_window() {
+        let clock = FakeRelativeClock::default();
+        let mut limiter = KeyedRateLimiter::new(
+            ThrottleAlgorithm::SlidingLog,
+            Duration::from_secs(10),
+            None,
+            clock.clone(),
+        );
+
+        assert_eq!(allowed(&mut limiter, 2, 1), 1);
+        clock.advance(Duration::from_secs(6));
+        assert_eq!(allowed(&mut limiter, 2, 5), 1);
+
+        // Only the first event has left the window.
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(allowed(&mut limiter, 2, 5), 1);
+    }
+
+    #[test]
+    fn sliding_window_counter_weights_previous_window() {
+        let clock = FakeRelativeClock::default();
+        let mut limiter = KeyedRateLimiter::new(
+            ThrottleAlgorithm::SlidingWindowCounter,
+            Duration::from_secs(10),
+            None,
+            clock.clone(),
+        );
+
+        assert_eq!(allowed(&mut limiter, 4, 10), 4);
+
+        // Halfway through the next window, half of the previous window still counts.
+        clock.advance(Duration::from_secs(15));
+        assert_eq!(allowed(&mut limiter, 4, 10), 2);
+
+        // Two full windows later, nothing counts anymore.
+        clock.advance(Duration::from_secs(20));
+        assert_eq!(allowed(&mut limiter, 4, 10), 4);
+    }
+
+    #[test]
+    fn flushes_idle_keys() {
+        let clock = FakeRelativeClock::default();
+        let mut limiter = KeyedRateLimiter::new(
+            ThrottleAlgorithm::SlidingLog,
+            Duration::from_secs(10),
+            None,
+            clock.clone(),
+        );
+        let threshold = NonZeroU32::new(1).unwrap();
+
+        assert!(limiter.check_key(&"a", threshold));
+        assert!(limiter.check_key(&"b", threshold));
+        assert_eq!(limiter.tracked_keys(), 2);
+
+        clock.advance(Duration::from_secs(10));
+        assert!(limiter.check_key(&"a", threshold));
+        assert_eq!(limiter.tracked_keys(), 1);
     }
 }
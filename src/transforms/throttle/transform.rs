@@ -1,13 +1,11 @@
-use async_stream::stream;
-use futures::{Stream, StreamExt};
-use governor::{clock, Quota};
+use governor::clock;
 use snafu::Snafu;
-use std::hash::Hash;
-use std::{num::NonZeroU32, pin::Pin, time::Duration};
+use std::num::NonZeroU32;
+use vector_lib::lookup::OwnedTargetPath;
 
 use super::{
-    config::{ThrottleConfig, ThrottleInternalMetricsConfig},
-    rate_limiter::RateLimiterRunner,
+    config::{ThrottleAlgorithm, ThrottleConfig, ThrottleInternalMetricsConfig},
+    rate_limiter::KeyedRateLimiter,
 };
 use crate::{
     conditions::Condition,
@@ -15,16 +13,21 @@ use crate::{
     event::Event,
     internal_events::{TemplateRenderingError, ThrottleEventDiscarded},
     template::Template,
-    transforms::TaskTransform,
+    transforms::{SyncTransform, TransformOutputsBuf},
 };
 
+/// The name of the output that rate limited events are sent to when `reroute_dropped` is set.
+pub(super) const DROPPED: &str = "dropped";
+
 #[derive(Clone)]
 pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
-    pub quota: Quota,
-    pub flush_keys_interval: Duration,
+    threshold: NonZeroU32,
+    /// The field to take the threshold from, along with the maximum threshold it can hold.
+    threshold_field: Option<(OwnedTargetPath, NonZeroU32)>,
     key_field: Option<Template>,
     exclude: Option<Condition>,
-    pub clock: C,
+    limiter: KeyedRateLimiter<Option<String>, C>,
+    reroute_dropped: bool,
     internal_metrics: ThrottleInternalMetricsConfig,
 }
 
@@ -38,19 +41,30 @@ where
         context: &TransformContext,
         clock: C,
     ) -> crate::Result<Self> {
-        let flush_keys_interval = config.window_secs;
-
         let threshold = match NonZeroU32::new(config.threshold) {
-            Some(threshold) => threshold,
-            None => return Err(Box::new(ConfigError::NonZero)),
+            Some(threshold) if !config.window_secs.is_zero() => threshold,
+            _ => return Err(Box::new(ConfigError::NonZero)),
         };
 
-        let quota = match Quota::with_period(Duration::from_secs_f64(
-            flush_keys_interval.as_secs_f64() / f64::from(threshold.get()),
-        )) {
-            Some(quota) => quota.allow_burst(threshold),
-            None => return Err(Box::new(ConfigError::NonZero)),
+        let burst = match config.burst {
+            None => None,
+            Some(_) if config.algorithm != ThrottleAlgorithm::TokenBucket => {
+                return Err(Box::new(ConfigError::BurstRequiresTokenBucket))
+            }
+            Some(burst) => match NonZeroU32::new(burst) {
+                Some(burst) => Some(burst),
+                None => return Err(Box::new(ConfigError::NonZero)),
+            },
         };
+
+        let threshold_field = match &config.threshold_field {
+            None => None,
+            Some(field) => match NonZeroU32::new(config.threshold_field_max) {
+                Some(max) => Some((field.0.clone(), max)),
+                None => return Err(Box::new(ConfigError::NonZero)),
+            },
+        };
+
         let exclude = config
             .exclude
             .as_ref()
@@ -58,21 +72,27 @@ where
             .transpose()?;
 
         Ok(Self {
-            quota,
-            clock,
-            flush_keys_interval,
+            threshold,
+            threshold_field,
             key_field: config.key_field.clone(),
             exclude,
+            limiter: KeyedRateLimiter::new(config.algorithm, config.window_secs, burst, clock),
+            reroute_dropped: config.reroute_dropped,
             internal_metrics: config.internal_metrics.clone(),
         })
     }
 
-    #[must_use]
-    pub fn start_rate_limiter<K>(&self) -> RateLimiterRunner<K, C>
-    where
-        K: Hash + Eq + Clone + Send + Sync + 'static,
-    {
-        RateLimiterRunner::start(self.quota, self.clock.clone(), self.flush_keys_interval)
+    /// The threshold for the event's bucket, taken from `threshold_field` when it holds a positive
+    /// integer, and capped to `threshold_field_max`.
+    fn event_threshold(&self, event: &Event) -> NonZeroU32 {
+        self.threshold_field
+            .as_ref()
+            .and_then(|(field, max)| {
+                let threshold = event.as_log().get(field)?.as_integer()?;
+                let threshold = NonZeroU32::new(u32::try_from(threshold).ok()?)?;
+                Some(threshold.min(*max))
+            })
+            .unwrap_or(self.threshold)
     }
 
     pub fn emit_event_discarded(&self, key: String) {
@@ -83,147 +103,131 @@ where
     }
 }
 
-impl<C, I> TaskTransform<Event> for Throttle<C, I>
+impl<C, I> SyncTransform for Throttle<C, I>
 where
     C: clock::Clock<Instant = I> + Clone + Send + Sync + 'static,
-    I: clock::Reference + Send + 'static,
+    I: clock::Reference + Send + Sync + 'static,
 {
-    fn transform(
-        self: Box<Self>,
-        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
-    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
-    where
-        Self: 'static,
-    {
-        let limiter = self.start_rate_limiter();
-
-        Box::pin(stream! {
-            while let Some(event) = input_rx.next().await {
-                let (throttle, event) = match self.exclude.as_ref() {
-                    Some(condition) => {
-                        let (result, event) = condition.check(event);
-                        (!result, event)
-                    },
-                    _ => (true, event)
-                };
-                let output = if throttle {
-                    let key = self.key_field.as_ref().and_then(|t| {
-                        t.render_string(&event)
-                            .map_err(|error| {
-                                emit!(TemplateRenderingError {
-                                    error,
-                                    field: Some("key_field"),
-                                    drop_event: false,
-                                })
-                            })
-                            .ok()
-                    });
-
-                    if limiter.check_key(&key) {
-                        Some(event)
-                    } else {
-                        self.emit_event_discarded(key.unwrap_or_else(|| "None".to_string()));
-                        None
-                    }
-                } else {
-                    Some(event)
-                };
-                if let Some(event) = output {
-                    yield event;
-                }
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        let (throttle, event) = match self.exclude.as_ref() {
+            Some(condition) => {
+                let (result, event) = condition.check(event);
+                (!result, event)
             }
-        })
+            _ => (true, event),
+        };
+        if !throttle {
+            output.push(None, event);
+            return;
+        }
+
+        let key = self.key_field.as_ref().and_then(|t| {
+            t.render_string(&event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("key_field"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        });
+
+        let threshold = self.event_threshold(&event);
+        if self.limiter.check_key(&key, threshold) {
+            output.push(None, event);
+        } else if self.reroute_dropped {
+            output.push(Some(DROPPED), event);
+        } else {
+            self.emit_event_discarded(key.unwrap_or_else(|| "None".to_string()));
+        }
     }
 }
 
 #[derive(Debug, Snafu)]
 pub enum ConfigError {
-    #[snafu(display("`threshold`, and `window_secs` must be non-zero"))]
+    #[snafu(display(
+        "`threshold`, `window_secs`, `burst`, and `threshold_field_max` must be non-zero"
+    ))]
     NonZero,
+
+    #[snafu(display("`burst` can only be set when `algorithm` is `token_bucket`"))]
+    BurstRequiresTokenBucket,
 }
 
 #[cfg(test)]
 mod tests {
-    use std::task::Poll;
-
-    use futures::SinkExt;
+    use std::time::Duration;
 
     use super::*;
-    use crate::transforms::Transform;
     use crate::{
-        event::LogEvent, test_util::components::assert_transform_compliance,
+        config::{DataType, TransformOutput},
+        event::LogEvent,
+        test_util::components::assert_transform_compliance,
         transforms::test::create_topology,
     };
     use tokio::sync::mpsc;
     use tokio_stream::wrappers::ReceiverStream;
 
-    #[tokio::test]
-    async fn throttle_events() {
+    fn new_throttle(
+        config: &str,
+    ) -> (
+        Throttle<clock::FakeRelativeClock, governor::nanos::Nanos>,
+        clock::FakeRelativeClock,
+    ) {
         let clock = clock::FakeRelativeClock::default();
-        let config = toml::from_str::<ThrottleConfig>(
+        let config = toml::from_str::<ThrottleConfig>(config).unwrap();
+        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone()).unwrap();
+        (throttle, clock)
+    }
+
+    /// Sends the event through the transform, returning whether it was sent to the primary output,
+    /// the `dropped` output, or neither.
+    fn transform_one(throttle: &mut dyn SyncTransform, event: Event) -> (bool, bool) {
+        let mut outputs = TransformOutputsBuf::new_with_capacity(
+            vec![
+                TransformOutput::new(DataType::all_bits(), Default::default()),
+                TransformOutput::new(DataType::all_bits(), Default::default()).with_port(DROPPED),
+            ],
+            1,
+        );
+        throttle.transform(event, &mut outputs);
+        (
+            outputs.drain().count() == 1,
+            outputs.drain_named(DROPPED).count() == 1,
+        )
+    }
+
+    fn allowed(throttle: &mut dyn SyncTransform, event: impl Into<Event>) -> bool {
+        transform_one(throttle, event.into()).0
+    }
+
+    #[test]
+    fn throttle_events() {
+        let (mut throttle, clock) = new_throttle(
             r"
 threshold = 2
 window_secs = 5
 ",
-        )
-        .unwrap();
+        );
 
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
-
-        let throttle = throttle.into_task();
-
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
-
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
-
-        tx.send(LogEvent::default().into()).await.unwrap();
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+        assert!(allowed(&mut throttle, LogEvent::default()));
+        assert!(allowed(&mut throttle, LogEvent::default()));
 
         clock.advance(Duration::from_secs(2));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        // We should be back to pending, having the second event dropped
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        // The third event should be dropped
+        assert!(!allowed(&mut throttle, LogEvent::default()));
 
         clock.advance(Duration::from_secs(3));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
         // The rate limiter should now be refreshed and allow an additional event through
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
-
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
-
-        tx.disconnect();
-
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+        assert!(allowed(&mut throttle, LogEvent::default()));
     }
 
-    #[tokio::test]
-    async fn throttle_exclude() {
-        let clock = clock::FakeRelativeClock::default();
-        let config = toml::from_str::<ThrottleConfig>(
+    #[test]
+    fn throttle_exclude() {
+        let (mut throttle, clock) = new_throttle(
             r#"
 threshold = 2
 window_secs = 5
@@ -231,119 +235,158 @@ exclude = """
 exists(.special)
 """
 "#,
-        )
-        .unwrap();
-
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
-
-        let throttle = throttle.into_task();
+        );
 
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
-
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
-
-        tx.send(LogEvent::default().into()).await.unwrap();
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+        assert!(allowed(&mut throttle, LogEvent::default()));
+        assert!(allowed(&mut throttle, LogEvent::default()));
 
         clock.advance(Duration::from_secs(2));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        // We should be back to pending, having the second event dropped
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        // The third event should be dropped
+        assert!(!allowed(&mut throttle, LogEvent::default()));
 
         let mut special_log = LogEvent::default();
         special_log.insert("special", "true");
-        tx.send(special_log.into()).await.unwrap();
         // The rate limiter should allow this log through regardless of current limit
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
+        assert!(allowed(&mut throttle, special_log));
 
         clock.advance(Duration::from_secs(3));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
         // The rate limiter should now be refreshed and allow an additional event through
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
+        assert!(allowed(&mut throttle, LogEvent::default()));
+    }
+
+    #[test]
+    fn throttle_buckets() {
+        let (mut throttle, _clock) = new_throttle(
+            r#"
+threshold = 1
+window_secs = 5
+key_field = "{{ bucket }}"
+"#,
+        );
 
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        let mut log_a = LogEvent::default();
+        log_a.insert("bucket", "a");
+        let mut log_b = LogEvent::default();
+        log_b.insert("bucket", "b");
 
-        tx.disconnect();
+        assert!(allowed(&mut throttle, log_a.clone()));
+        assert!(allowed(&mut throttle, log_b.clone()));
 
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+        assert!(!allowed(&mut throttle, log_a));
+        assert!(!allowed(&mut throttle, log_b));
     }
 
-    #[tokio::test]
-    async fn throttle_buckets() {
-        let clock = clock::FakeRelativeClock::default();
-        let config = toml::from_str::<ThrottleConfig>(
+    #[test]
+    fn throttle_threshold_field() {
+        let (mut throttle, _clock) = new_throttle(
             r#"
 threshold = 1
+threshold_field = "quota"
 window_secs = 5
-key_field = "{{ bucket }}"
+key_field = "{{ tenant }}"
 "#,
-        )
-        .unwrap();
+        );
+
+        let mut large = LogEvent::default();
+        large.insert("tenant", "large");
+        large.insert("quota", 3);
+        let mut small = LogEvent::default();
+        small.insert("tenant", "small");
+        small.insert("quota", "not a number");
+
+        let large_allowed = (0..5)
+            .filter(|_| allowed(&mut throttle, large.clone()))
+            .count();
+        let small_allowed = (0..5)
+            .filter(|_| allowed(&mut throttle, small.clone()))
+            .count();
+
+        assert_eq!(large_allowed, 3);
+        assert_eq!(small_allowed, 1);
+    }
 
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
+    #[test]
+    fn throttle_threshold_field_max() {
+        let (mut throttle, _clock) = new_throttle(
+            r#"
+threshold = 1
+threshold_field = "quota"
+threshold_field_max = 2
+window_secs = 5
+algorithm = "sliding_log"
+"#,
+        );
 
-        let throttle = throttle.into_task();
+        let mut log = LogEvent::default();
+        log.insert("quota", 1_000_000);
 
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
+        let allowed_count = (0..5)
+            .filter(|_| allowed(&mut throttle, log.clone()))
+            .count();
+        assert_eq!(allowed_count, 2);
+    }
 
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+    #[test]
+    fn throttle_reroute_dropped() {
+        let (mut throttle, _clock) = new_throttle(
+            r"
+threshold = 1
+window_secs = 5
+reroute_dropped = true
+",
+        );
+
+        assert_eq!(
+            transform_one(&mut throttle, LogEvent::default().into()),
+            (true, false)
+        );
+        assert_eq!(
+            transform_one(&mut throttle, LogEvent::default().into()),
+            (false, true)
+        );
+    }
 
-        let mut log_a = LogEvent::default();
-        log_a.insert("bucket", "a");
-        let mut log_b = LogEvent::default();
-        log_b.insert("bucket", "b");
-        tx.send(log_a.into()).await.unwrap();
-        tx.send(log_b.into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+    #[test]
+    fn throttle_sliding_log() {
+        let (mut throttle, clock) = new_throttle(
+            r#"
+threshold = 2
+window_secs = 5
+algorithm = "sliding_log"
+"#,
+        );
 
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        assert!(allowed(&mut throttle, LogEvent::default()));
+        clock.advance(Duration::from_secs(3));
+        assert!(allowed(&mut throttle, LogEvent::default()));
+        assert!(!allowed(&mut throttle, LogEvent::default()));
 
-        tx.disconnect();
+        // Only the first event has left the window
+        clock.advance(Duration::from_secs(2));
+        assert!(allowed(&mut throttle, LogEvent::default()));
+        assert!(!allowed(&mut throttle, LogEvent::default()));
+    }
 
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+    #[test]
+    fn burst_requires_token_bucket() {
+        let config = toml::from_str::<ThrottleConfig>(
+            r#"
+threshold = 2
+window_secs = 5
+algorithm = "sliding_window_counter"
+burst = 10
+"#,
+        )
+        .unwrap();
+
+        assert!(Throttle::new(
+            &config,
+            &TransformContext::default(),
+            clock::FakeRelativeClock::default()
+        )
+        .is_err());
     }
 
     #[tokio::test]
@@ -352,9 +395,7 @@ key_field = "{{ bucket }}"
             let config = ThrottleConfig {
                 threshold: 1,
                 window_secs: Duration::from_secs_f64(1.0),
-                key_field: None,
-                exclude: None,
-                internal_metrics: Default::default(),
+                ..Default::default()
             };
             let (tx, rx) = mpsc::channel(1);
             let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
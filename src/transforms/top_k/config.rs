@@ -0,0 +1,122 @@
+use std::{collections::HashMap, num::NonZeroUsize, time::Duration};
+
+use serde_with::serde_as;
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+
+use crate::config::{
+    DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext, TransformOutput,
+};
+use crate::schema;
+use crate::transforms::top_k::TopK;
+use crate::transforms::Transform;
+
+/// How the weight of a tag value is measured.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RankBy {
+    /// Each metric event adds one to the weight of its tag value.
+    #[default]
+    Count,
+
+    /// Each metric event adds its value to the weight of its tag value.
+    ///
+    /// The value of counters and gauges is used as is. For distributions, aggregated histograms,
+    /// aggregated summaries, and sketches, the sum of the samples is used. For sets, the number of
+    /// values is used. Negative values count as positive.
+    Value,
+}
+
+/// Configuration for the `top_k` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "top_k",
+    "Keep only the most frequent values of high cardinality tags, rolling up the rest."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TopKConfig {
+    /// The tags whose values are limited.
+    ///
+    /// Each tag is limited independently for each metric name.
+    #[configurable(metadata(docs::examples = "path", docs::examples = "user_id"))]
+    pub tags: Vec<String>,
+
+    /// The number of values to keep for each tag of each metric name.
+    #[serde(default = "default_k")]
+    #[configurable(metadata(docs::type_unit = "values"))]
+    pub k: NonZeroUsize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub rank_by: RankBy,
+
+    /// The length of the window over which the top values are measured, in seconds.
+    ///
+    /// The values that ranked among the top values in the previous window are also kept, so that
+    /// the kept values don't change abruptly when a new window starts.
+    #[serde(default = "default_window_secs")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Window"))]
+    pub window_secs: Duration,
+
+    /// The value that replaces tag values that don't rank among the top values.
+    ///
+    /// The value of absolute counters and gauges whose tag values are replaced is the sum of the
+    /// latest values of all of the series replaced into the same series, so that they don't
+    /// overwrite each other. Series that aren't seen for a whole window are removed from the sum.
+    /// The tag values of other absolute metrics are never replaced.
+    #[serde(default = "default_other_value")]
+    #[configurable(metadata(docs::examples = "other"))]
+    pub other_value: String,
+}
+
+const fn default_k() -> NonZeroUsize {
+    NonZeroUsize::new(10).expect("static non-zero number")
+}
+
+const fn default_window_secs() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_other_value() -> String {
+    "other".to_string()
+}
+
+impl GenerateConfig for TopKConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            tags: vec!["path".to_string()],
+            k: default_k(),
+            rank_by: RankBy::default(),
+            window_secs: default_window_secs(),
+            other_value: default_other_value(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "top_k")]
+impl TransformConfig for TopKConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        if self.window_secs.is_zero() {
+            return Err("Only non-zero durations are allowed values for `window_secs`".into());
+        }
+        Ok(Transform::event_task(TopK::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::metric()
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        _: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(DataType::Metric, HashMap::new())]
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use futures::{Stream, StreamExt};
+
+use crate::{
+    event::{
+        metric::{Metric, MetricKind, MetricSeries, MetricSketch, MetricValue},
+        Event,
+    },
+    internal_events::TopKTagValueReplaced,
+    transforms::TaskTransform,
+};
+
+mod config;
+mod space_saving;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{RankBy, TopKConfig};
+use space_saving::SpaceSaving;
+
+type MetricId = (Option<String>, String);
+
+/// The top values of a tag of a metric.
+#[derive(Debug)]
+struct TagTopK {
+    /// The top values of the current window.
+    current: SpaceSaving,
+    /// The values that ranked among the top values at the end of the previous window.
+    previous: HashSet<String>,
+}
+
+/// The absolute metrics whose tag values were replaced into the same series.
+///
+/// Absolute values are combined by summing the latest value of each of the original series, rather
+/// than letting them overwrite each other downstream.
+#[derive(Debug, Default)]
+struct RolledUpSeries {
+    total: f64,
+    /// The latest value of each original series, and whether it was seen in the current window.
+    members: HashMap<MetricSeries, (f64, bool)>,
+}
+
+#[derive(Debug)]
+pub struct TopK {
+    tags: Vec<String>,
+    k: usize,
+    rank_by: RankBy,
+    window: Duration,
+    other_value: String,
+    window_start: Instant,
+    top_values: HashMap<MetricId, HashMap<String, TagTopK>>,
+    rolled_up: HashMap<MetricSeries, RolledUpSeries>,
+}
+
+impl TopK {
+    fn new(config: &TopKConfig) -> Self {
+        Self {
+            tags: config.tags.clone(),
+            k: config.k.get(),
+            rank_by: config.rank_by,
+            window: config.window_secs,
+            other_value: config.other_value.clone(),
+            window_start: Instant::now(),
+            top_values: HashMap::new(),
+            rolled_up: HashMap::new(),
+        }
+    }
+
+    /// Starts a new window, remembering the top values of the one that ended. Tags, and rolled up
+    /// absolute series, that were not seen at all during the window that ended are forgotten.
+    fn roll_window(&mut self, now: Instant) {
+        self.window_start = now;
+        let k = self.k;
+        self.top_values.retain(|_, tags| {
+            tags.retain(|_, top_k| {
+                if top_k.current.is_empty() {
+                    return false;
+                }
+                let current = std::mem::replace(&mut top_k.current, SpaceSaving::new(k));
+                top_k.previous = current.top().map(str::to_string).collect();
+                true
+            });
+            !tags.is_empty()
+        });
+        self.rolled_up.retain(|_, rolled_up| {
+            rolled_up
+                .members
+                .retain(|_, (_, seen)| std::mem::take(seen));
+            rolled_up.total = rolled_up.members.values().map(|(value, _)| value).sum();
+            !rolled_up.members.is_empty()
+        });
+    }
+
+    fn weight(&self, metric: &Metric) -> f64 {
+        let weight = match self.rank_by {
+            RankBy::Count => return 1.0,
+            RankBy::Value => match metric.value() {
+                MetricValue::Counter { value } | MetricValue::Gauge { value } => *value,
+                MetricValue::Set { values } => values.len() as f64,
+                MetricValue::Distribution { samples, .. } => samples
+                    .iter()
+                    .map(|sample| sample.value * f64::from(sample.rate))
+                    .sum(),
+                MetricValue::AggregatedHistogram { sum, .. }
+                | MetricValue::AggregatedSummary { sum, .. } => *sum,
+                MetricValue::Sketch { sketch } => match sketch {
+                    MetricSketch::AgentDDSketch(ddsketch) => ddsketch.sum().unwrap_or(0.0),
+                },
+            },
+        };
+        if weight.is_finite() {
+            weight.abs()
+        } else {
+            0.0
+        }
+    }
+
+    fn transform_one(&mut self, mut event: Event) -> Event {
+        let now = Instant::now();
+        if now.duration_since(self.window_start) >= self.window {
+            self.roll_window(now);
+        }
+
+        let metric = event.as_mut_metric();
+        let weight = self.weight(metric);
+        let metric_id = (
+            metric.namespace().map(str::to_string),
+            metric.name().to_string(),
+        );
+        let Some(tags) = metric.tags() else {
+            return event;
+        };
+
+        let k = self.k;
+        let metric_top_values = self.top_values.entry(metric_id).or_default();
+        let mut replaced = Vec::new();
+        for key in &self.tags {
+            let Some(value) = tags.get(key) else {
+                continue;
+            };
+            let top_k = metric_top_values
+                .entry(key.clone())
+                .or_insert_with(|| TagTopK {
+                    current: SpaceSaving::new(k),
+                    previous: HashSet::new(),
+                });
+            // The value is always recorded, even if it ranked among the top values previously.
+            let is_top = top_k.current.insert(value, weight);
+            if !is_top && !top_k.previous.contains(value) {
+                replaced.push(key.clone());
+            }
+        }
+        if replaced.is_empty() {
+            return event;
+        }
+
+        let original_series = match (metric.kind(), metric.value()) {
+            (MetricKind::Incremental, _) => None,
+            (MetricKind::Absolute, MetricValue::Counter { .. } | MetricValue::Gauge { .. }) => {
+                Some(metric.series().clone())
+            }
+            // Other absolute values can't be summed, so replacing their tag values would make the
+            // original series overwrite each other.
+            (MetricKind::Absolute, _) => return event,
+        };
+
+        let tags = metric.tags_mut().expect("tags are present");
+        for key in replaced {
+            emit!(TopKTagValueReplaced { tag_key: &key });
+            tags.replace(key, self.other_value.clone());
+        }
+        if let Some(original_series) = original_series {
+            self.roll_up_absolute(original_series, metric);
+        }
+        event
+    }
+
+    /// Replaces the value of an absolute metric whose tag values were replaced with the sum of the
+    /// latest values of all of the series rolled up with it.
+    fn roll_up_absolute(&mut self, original_series: MetricSeries, metric: &mut Metric) {
+        let series = metric.series().clone();
+        let (MetricValue::Counter { value } | MetricValue::Gauge { value }) = metric.value_mut()
+        else {
+            return;
+        };
+        let rolled_up = self.rolled_up.entry(series).or_default();
+        let previous = rolled_up.members.insert(original_series, (*value, true));
+        rolled_up.total += *value - previous.map_or(0.0, |(previous, _)| previous);
+        *value = rolled_up.total;
+    }
+}
+
+impl TaskTransform<Event> for TopK {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut inner = self;
+        Box::pin(task.map(move |v| inner.transform_one(v)))
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashMap};
+
+use ordered_float::NotNan;
+
+/// The number of items tracked by the sketch for every item it reports as part of the top K.
+///
+/// Tracking more items than reported makes it much less likely that an item that just replaced
+/// the lightest tracked item ranks among the top K, which would otherwise happen to nearly every
+/// item of a long tail of rare items.
+const CAPACITY_FACTOR: usize = 10;
+
+type Entry = (NotNan<f64>, String);
+
+/// A "space-saving" sketch of the heaviest items of a stream.
+///
+/// At most `k * CAPACITY_FACTOR` items are tracked. When a new item is seen while the sketch is
+/// full, it replaces the lightest tracked item and inherits its weight, so the weight of every
+/// tracked item is an upper bound of its true weight. Any item heavier than the total weight
+/// divided by the number of tracked items is guaranteed to be tracked.
+#[derive(Debug)]
+pub(super) struct SpaceSaving {
+    k: usize,
+    capacity: usize,
+    weights: HashMap<String, NotNan<f64>>,
+    /// The `k` heaviest tracked items, ordered by weight.
+    top: BTreeSet<Entry>,
+    /// The rest of the tracked items, ordered by weight.
+    rest: BTreeSet<Entry>,
+}
+
+impl SpaceSaving {
+    pub(super) fn new(k: usize) -> Self {
+        Self {
+            k,
+            capacity: k.saturating_mul(CAPACITY_FACTOR),
+            weights: HashMap::new(),
+            top: BTreeSet::new(),
+            rest: BTreeSet::new(),
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Adds `weight` to the weight of `item`, and returns whether it now ranks among the top K.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` is NaN.
+    pub(super) fn insert(&mut self, item: &str, weight: f64) -> bool {
+        let weight = NotNan::new(weight).expect("weight must not be NaN");
+        let weight = match self.weights.get(item) {
+            Some(&previous) => {
+                let entry = (previous, item.to_string());
+                if !self.top.remove(&entry) {
+                    self.rest.remove(&entry);
+                }
+                previous + weight
+            }
+            None if self.weights.len() >= self.capacity => {
+                // There are always more tracked items than the top K, so there is an item to
+                // replace outside of it.
+                let (lightest, evicted) = self.rest.pop_first().expect("sketch is full");
+                self.weights.remove(&evicted);
+                lightest + weight
+            }
+            None => weight,
+        };
+        self.weights.insert(item.to_string(), weight);
+
+        let entry = (weight, item.to_string());
+        if self.top.len() < self.k {
+            self.top.insert(entry);
+            return true;
+        }
+        match self.top.first() {
+            // Ties are broken in favor of the items already ranking among the top K.
+            Some((lightest_top, _)) if weight > *lightest_top => {
+                let demoted = self.top.pop_first().expect("top is not empty");
+                self.rest.insert(demoted);
+                self.top.insert(entry);
+                true
+            }
+            _ => {
+                self.rest.insert(entry);
+                false
+            }
+        }
+    }
+
+    /// The items currently ranking among the top K.
+    pub(super) fn top(&self) -> impl Iterator<Item = &str> {
+        self.top.iter().map(|(_, item)| item.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_heaviest_items() {
+        let mut sketch = SpaceSaving::new(2);
+        for i in 0..100 {
+            sketch.insert("a", 1.0);
+            sketch.insert("b", 1.0);
+            sketch.insert(&format!("rare-{i}"), 1.0);
+        }
+
+        let mut top = sketch.top().collect::<Vec<_>>();
+        top.sort_unstable();
+        assert_eq!(top, vec!["a", "b"]);
+        assert_eq!(sketch.weights.len(), 20);
+    }
+
+    #[test]
+    fn promotes_items_that_become_heavier() {
+        let mut sketch = SpaceSaving::new(1);
+
+        assert!(sketch.insert("a", 2.0));
+        assert!(!sketch.insert("b", 1.0));
+        assert!(!sketch.insert("b", 1.0));
+        assert!(sketch.insert("b", 1.0));
+        assert_eq!(sketch.top().collect::<Vec<_>>(), vec!["b"]);
+    }
+}
@@ -0,0 +1,170 @@
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use vector_lib::metric_tags;
+
+use super::*;
+use crate::event::{metric, Metric, MetricTags};
+use crate::test_util::components::assert_transform_compliance;
+use crate::transforms::test::create_topology;
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<TopKConfig>();
+}
+
+fn make_config(k: usize, rank_by: RankBy) -> TopKConfig {
+    toml::from_str(&format!(
+        r#"
+tags = ["path"]
+k = {k}
+rank_by = "{}"
+"#,
+        match rank_by {
+            RankBy::Count => "count",
+            RankBy::Value => "value",
+        }
+    ))
+    .unwrap()
+}
+
+fn make_metric(tags: MetricTags, value: f64) -> Event {
+    Event::Metric(
+        Metric::new(
+            "requests",
+            metric::MetricKind::Incremental,
+            metric::MetricValue::Counter { value },
+        )
+        .with_tags(Some(tags)),
+    )
+}
+
+fn path(event: &Event) -> &str {
+    event.as_metric().tags().unwrap().get("path").unwrap()
+}
+
+#[tokio::test]
+async fn replaces_values_outside_of_top_k() {
+    assert_transform_compliance(async move {
+        let (tx, rx) = mpsc::channel(1);
+        let (topology, mut out) =
+            create_topology(ReceiverStream::new(rx), make_config(1, RankBy::Count)).await;
+
+        let paths = ["/a", "/a", "/b", "/a", "/c"];
+        let mut output = Vec::new();
+        for path in paths {
+            tx.send(make_metric(
+                metric_tags!("path" => path, "host" => "a"),
+                1.0,
+            ))
+            .await
+            .unwrap();
+            output.push(out.recv().await.unwrap());
+        }
+
+        drop(tx);
+        topology.stop().await;
+        assert_eq!(out.recv().await, None);
+
+        assert_eq!(
+            output.iter().map(path).collect::<Vec<_>>(),
+            vec!["/a", "/a", "other", "/a", "other"]
+        );
+        // Other tags are left untouched.
+        assert!(output
+            .iter()
+            .all(|event| event.as_metric().tags().unwrap().get("host") == Some("a")));
+    })
+    .await;
+}
+
+#[test]
+fn ranks_by_value() {
+    let mut transform = TopK::new(&make_config(1, RankBy::Value));
+
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/a"), 1.0));
+    assert_eq!(path(&event), "/a");
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/b"), 10.0));
+    assert_eq!(path(&event), "/b");
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/a"), 5.0));
+    assert_eq!(path(&event), "other");
+}
+
+#[test]
+fn limits_each_metric_independently() {
+    let mut transform = TopK::new(&make_config(1, RankBy::Count));
+
+    transform.transform_one(make_metric(metric_tags!("path" => "/a"), 1.0));
+    let event = transform.transform_one(
+        make_metric(metric_tags!("path" => "/b"), 1.0)
+            .into_metric()
+            .with_name("errors")
+            .into(),
+    );
+    assert_eq!(path(&event), "/b");
+}
+
+#[test]
+fn sums_rolled_up_absolute_gauges() {
+    let mut transform = TopK::new(&make_config(1, RankBy::Count));
+    let mut gauge = |tag: &str, value: f64| {
+        let event = transform.transform_one(Event::Metric(
+            Metric::new(
+                "connections",
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value },
+            )
+            .with_tags(Some(metric_tags!("path" => tag))),
+        ));
+        (
+            path(&event).to_string(),
+            event.into_metric().value().clone(),
+        )
+    };
+
+    assert_eq!(gauge("/a", 1.0).0, "/a");
+    assert_eq!(gauge("/a", 1.0).0, "/a");
+    assert_eq!(
+        gauge("/b", 2.0),
+        (
+            "other".to_string(),
+            metric::MetricValue::Gauge { value: 2.0 }
+        )
+    );
+    assert_eq!(
+        gauge("/c", 3.0),
+        (
+            "other".to_string(),
+            metric::MetricValue::Gauge { value: 5.0 }
+        )
+    );
+    // The latest value of each series is used.
+    assert_eq!(
+        gauge("/b", 4.0),
+        (
+            "other".to_string(),
+            metric::MetricValue::Gauge { value: 7.0 }
+        )
+    );
+}
+
+#[test]
+fn keeps_top_values_of_previous_window() {
+    let mut transform = TopK::new(&make_config(1, RankBy::Count));
+
+    transform.transform_one(make_metric(metric_tags!("path" => "/a"), 1.0));
+    transform.roll_window(Instant::now());
+
+    // `/b` is the only value seen in the new window, but `/a` was the top value of the previous
+    // one.
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/b"), 1.0));
+    assert_eq!(path(&event), "/b");
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/a"), 1.0));
+    assert_eq!(path(&event), "/a");
+    let event = transform.transform_one(make_metric(metric_tags!("path" => "/c"), 1.0));
+    assert_eq!(path(&event), "other");
+
+    // Values not seen during a whole window are forgotten.
+    transform.roll_window(Instant::now());
+    transform.roll_window(Instant::now());
+    assert!(transform.top_values.is_empty());
+}
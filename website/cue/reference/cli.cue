@@ -204,6 +204,67 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"buffer": {
+			description: """
+				Inspect, dump, or replay the contents of a sink's disk buffer, then exit. The subcommand
+				is one of `inspect`, which reports the number of records and events in the buffer, their
+				size on disk, and the oldest and newest event timestamps; `dump`, which writes every event
+				in the buffer to stdout as newline-delimited JSON; or `replay`, which sends every event in
+				the buffer to the sink defined in the file given with `--to`.
+
+				The buffer is opened read-only, so this command can be used while Vector is running, and
+				events are never removed from the buffer.
+				"""
+
+			example: "vector buffer replay my_sink --config /etc/vector/vector.yaml --to /tmp/recovery_sink.yaml"
+
+			options: {
+				"config": {
+					_short:      "c"
+					description: env_vars.VECTOR_CONFIG.description
+					type:        "string"
+					default:     env_vars.VECTOR_CONFIG.type.string.default
+					env_var:     "VECTOR_CONFIG"
+				}
+				"config-dir": {
+					description: env_vars.VECTOR_CONFIG_DIR.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_DIR"
+				}
+				"config-toml": {
+					description: env_vars.VECTOR_CONFIG_TOML.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_TOML"
+				}
+				"config-json": {
+					description: env_vars.VECTOR_CONFIG_JSON.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_JSON"
+				}
+				"config-yaml": {
+					description: env_vars.VECTOR_CONFIG_YAML.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_YAML"
+				}
+				"data-dir": {
+					description: "The data directory of the Vector instance that owns the buffer. Defaults to the `data_dir` set in the configuration."
+					type:        "string"
+				}
+				"to": {
+					description: "Only for `replay`: a configuration file containing a single sink definition to send the events to. Secrets in this file are retrieved from the secret backends of the configuration."
+					type:        "string"
+					example:     "/tmp/recovery_sink.yaml"
+				}
+			}
+
+			args: {
+				sink_id: {
+					description: "The ID of the sink whose buffer to open"
+					type:        "string"
+				}
+			}
+		}
+
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
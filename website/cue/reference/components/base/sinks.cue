@@ -12,6 +12,96 @@ base: components: sinks: configuration: {
 			"""
 		required: false
 		type: object: options: {
+			compression: {
+				description: """
+					Compresses data files.
+
+					Records are compressed individually before being written, and encrypted afterwards if
+					encryption is enabled. As `max_size` limits the size of the buffer on disk, compressing
+					records allows more events to be buffered within the same amount of space.
+
+					Changing this setting only affects new records: existing records are always read back
+					correctly, regardless of how they were compressed.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: string: {
+					default: "none"
+					enum: {
+						lz4: """
+								Records are compressed with [LZ4][lz4].
+
+								This is very fast, at the cost of a lower compression ratio than Zstandard.
+
+								[lz4]: https://lz4.org/
+								"""
+						none: "Records are not compressed."
+						zstd: """
+								Records are compressed with [Zstandard][zstd].
+
+								This typically achieves a better compression ratio than LZ4, at the cost of more CPU.
+
+								[zstd]: https://facebook.github.io/zstd/
+								"""
+					}
+				}
+			}
+			encryption: {
+				description: """
+					Encrypts data files at rest.
+
+					When not set, records are written to data files unencrypted. Records written before
+					encryption was enabled can still be read. Encrypted records whose key is no longer
+					provided, for example because encryption was disabled, don't prevent the buffer from
+					starting, but can't be read and are dropped.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: object: options: {
+					key: {
+						description: """
+							The active key, as 32 bytes of base64-encoded key material.
+
+							The key should be kept in a secret backend, and referenced with `SECRET[<backend>.<name>]`.
+							"""
+						required: true
+						type: string: examples: ["SECRET[vault.disk_buffer_key_2024_06]"]
+					}
+					key_id: {
+						description: """
+							The identifier of the active key.
+
+							All new records are encrypted with the active key. The identifier is stored alongside every
+							record, and is used to find the correct key when reading records back. Identifiers must be
+							unique, and should never be reused for different key material.
+							"""
+						required: true
+						type: string: examples: ["2024-06"]
+					}
+					previous_keys: {
+						description: """
+							Keys that were previously active.
+
+							Previous keys are never used to encrypt new records, and are only used to read records that
+							were written before the active key was rotated. Once all such records have been read, the
+							previous key can be removed.
+							"""
+						required: false
+						type: array: items: type: object: options: {
+							key: {
+								description: "The key, as 32 bytes of base64-encoded key material."
+								required:    true
+								type: string: examples: ["SECRET[vault.disk_buffer_key_2024_01]"]
+							}
+							key_id: {
+								description: "The identifier this key was used with when it was the active key."
+								required:    true
+								type: string: examples: ["2024-01"]
+							}
+						}
+					}
+				}
+			}
 			max_events: {
 				description:   "The maximum number of events allowed in the buffer."
 				relevant_when: "type = \"memory\""
@@ -71,11 +161,56 @@ base: components: sinks: configuration: {
 														highest priority, and it is preferable to temporarily lose events rather than cause a
 														slowdown in the acceptance/consumption of events.
 														"""
+						drop_oldest: """
+														Drops the oldest buffered events to make room for the event.
+
+														The evicted events will be intentionally dropped. This mode is typically used when the
+														freshness of the data is the highest priority, such as for real-time monitoring, and it is
+														preferable to lose the stalest events rather than the most recent ones.
+
+														For disk buffers, space is reclaimed one data file at a time, and only data files that the
+														reader has not yet started reading can be evicted. When no such data file exists, the event
+														is dropped instead.
+														"""
+						overflow: """
+														Overflows to the next stage in the buffer topology.
+
+														If the current buffer stage is full, attempt to send this event to the next buffer stage.
+														That stage may also be configured overflow, and so on, but ultimately the last stage in a
+														buffer topology must use one of the other handling behaviors. This means that next stage may
+														potentially be able to buffer the event, but it may also block or drop the event.
+
+														This mode can only be used when two or more buffer stages are configured, and is typically
+														used to overflow from an in-memory buffer to a disk buffer.
+														"""
 					}
 				}
 			}
 		}
 	}
+	dead_letter: {
+		description: """
+			A fallback sink that receives the events this sink permanently failed to deliver.
+
+			Events rejected by the downstream service with a non-retriable error, such as an HTTP 400
+			response, are sent to this sink instead of being dropped. Each event is annotated with the
+			status and the reason for the rejection, such as the error returned by the service and
+			truncated to 1024 bytes, under the `dead_letter` metadata field. Acknowledgements for these
+			events are only sent once the fallback sink has handled them.
+
+			The fallback sink reports its internal metrics under the component ID of this sink suffixed
+			with `.dead_letter`.
+			"""
+		required: false
+		type: object: {
+			examples: [{
+				type:     "file"
+				path:     "/var/lib/vector/dead_letter-%Y-%m-%d.log"
+				encoding: codec: "json"
+			}]
+			options: {}
+		}
+	}
 	graph: {
 		description: """
 			Extra graph configuration
@@ -6,8 +6,9 @@ components: sinks: [Name=string]: {
 	features: _
 
 	configuration: {
-		inputs: base.components.sinks.configuration.inputs
-		buffer: base.components.sinks.configuration.buffer
+		inputs:      base.components.sinks.configuration.inputs
+		buffer:      base.components.sinks.configuration.buffer
+		dead_letter: base.components.sinks.configuration.dead_letter
 		healthcheck: {
 			description: base.components.sinks.configuration.healthcheck.description
 			required:    base.components.sinks.configuration.healthcheck.required
@@ -279,10 +280,11 @@ components: sinks: [Name=string]: {
 										type: string: {
 											default: "A suitable default is chosen depending on the sink type and the selected codec."
 											enum: {
-												bytes:               "Byte frames are concatenated."
-												character_delimited: "Byte frames are delimited by a chosen character."
-												length_delimited:    "Byte frames are prefixed by an unsigned big-endian 32-bit integer indicating the length."
-												newline_delimited:   "Byte frames are delimited by a newline character."
+												bytes:                   "Byte frames are concatenated."
+												character_delimited:     "Byte frames are delimited by a chosen character."
+												length_delimited:        "Byte frames are prefixed by an unsigned big-endian 32-bit integer indicating the length."
+												newline_delimited:       "Byte frames are delimited by a newline character."
+												varint_length_delimited: "Byte frames are prefixed by an unsigned varint indicating the length, as in streams of delimited protobuf messages."
 											}
 										}
 									}
@@ -664,6 +666,7 @@ components: sinks: [Name=string]: {
 		buffer_byte_size:                     components.sources.internal_metrics.output.metrics.buffer_byte_size
 		buffer_discarded_events_total:        components.sources.internal_metrics.output.metrics.buffer_discarded_events_total
 		buffer_events:                        components.sources.internal_metrics.output.metrics.buffer_events
+		buffer_evicted_events_total:          components.sources.internal_metrics.output.metrics.buffer_evicted_events_total
 		buffer_received_events_total:         components.sources.internal_metrics.output.metrics.buffer_received_events_total
 		buffer_received_event_bytes_total:    components.sources.internal_metrics.output.metrics.buffer_received_event_bytes_total
 		buffer_sent_events_total:             components.sources.internal_metrics.output.metrics.buffer_sent_events_total
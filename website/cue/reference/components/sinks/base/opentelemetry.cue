@@ -151,10 +151,212 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 					description:   "Apache Avro-specific encoder options."
 					relevant_when: "codec = \"avro\""
 					required:      true
-					type: object: options: schema: {
-						description: "The Avro schema."
-						required:    true
-						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					type: object: options: {
+						schema: {
+							description: "The Avro schema."
+							required:    true
+							type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+						}
+						schema_registry: {
+							description: """
+								Prefixes each Avro datum with the ID of `schema` in a Confluent Schema Registry, following
+								the Confluent wire format.
+
+								The ID is looked up, or registered if `auto_register_schemas` is enabled, under the subject
+								named by `subject_name_strategy` when the first event is encoded, which waits for the Schema
+								Registry to respond. Failed lookups are attempted again after 30 seconds.
+								"""
+							required: false
+							type: object: options: {
+								auth: {
+									description: "Authentication for the Schema Registry."
+									required:    false
+									type: object: options: {
+										password: {
+											description:   "The basic authentication password."
+											relevant_when: "strategy = \"basic\""
+											required:      true
+											type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}", "password"]
+										}
+										strategy: {
+											description: "The authentication strategy to use."
+											required:    true
+											type: string: enum: {
+												basic: """
+													Basic authentication.
+
+													The username and password are concatenated and encoded via [base64][base64].
+
+													[base64]: https://en.wikipedia.org/wiki/Base64
+													"""
+												bearer: """
+													Bearer authentication.
+
+													The bearer token value (OAuth2, JWT, etc.) is passed as-is.
+													"""
+											}
+										}
+										token: {
+											description:   "The bearer authentication token."
+											relevant_when: "strategy = \"bearer\""
+											required:      true
+											type: string: {}
+										}
+										user: {
+											description:   "The basic authentication username."
+											relevant_when: "strategy = \"basic\""
+											required:      true
+											type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}", "username"]
+										}
+									}
+								}
+								auto_register_schemas: {
+									description: """
+										Registers the schema under its subject when encoding, if it is not registered yet.
+
+										When disabled, the schema must already be registered under its subject. Only used when
+										encoding.
+										"""
+									required: false
+									type: bool: default: false
+								}
+								subject_name_strategy: {
+									description: """
+										How the subject a schema is registered under is named when encoding.
+
+										See the [Confluent documentation][subject_name_strategy] for more details.
+
+										[subject_name_strategy]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#subject-name-strategy
+										"""
+									required: false
+									type: string: {
+										default: "topic_name"
+										enum: {
+											record_name:       "The subject is named after the fully-qualified name of the record or message type."
+											topic_name:        "The subject is named `<topic>-value`."
+											topic_record_name: "The subject is named `<topic>-<fully-qualified record or message type name>`."
+										}
+									}
+								}
+								timeout_secs: {
+									description: "The timeout for requests to the Schema Registry, in seconds."
+									required:    false
+									type: uint: {
+										default: 10
+										unit:    "seconds"
+									}
+								}
+								tls: {
+									description: "TLS configuration."
+									required:    false
+									type: object: options: {
+										alpn_protocols: {
+											description: """
+												Sets the list of supported ALPN protocols.
+
+												Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+												that they are defined.
+												"""
+											required: false
+											type: array: items: type: string: examples: ["h2"]
+										}
+										ca_file: {
+											description: """
+												Absolute path to an additional CA certificate file.
+
+												The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+												"""
+											required: false
+											type: string: examples: ["/path/to/certificate_authority.crt"]
+										}
+										crt_file: {
+											description: """
+												Absolute path to a certificate file used to identify this server.
+
+												The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+												an inline string in PEM format.
+
+												If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+												"""
+											required: false
+											type: string: examples: ["/path/to/host_certificate.crt"]
+										}
+										key_file: {
+											description: """
+												Absolute path to a private key file used to identify this server.
+
+												The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+												"""
+											required: false
+											type: string: examples: ["/path/to/host_certificate.key"]
+										}
+										key_pass: {
+											description: """
+												Passphrase used to unlock the encrypted key file.
+
+												This has no effect unless `key_file` is set.
+												"""
+											required: false
+											type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+										}
+										server_name: {
+											description: """
+												Server name to use when using Server Name Indication (SNI).
+
+												Only relevant for outgoing connections.
+												"""
+											required: false
+											type: string: examples: ["www.example.com"]
+										}
+										verify_certificate: {
+											description: """
+												Enables certificate verification. For components that create a server, this requires that the
+												client connections have a valid client certificate. For components that initiate requests,
+												this validates that the upstream has a valid certificate.
+
+												If enabled, certificates must not be expired and must be issued by a trusted
+												issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+												certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+												so on, until the verification process reaches a root certificate.
+
+												Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+												"""
+											required: false
+											type: bool: {}
+										}
+										verify_hostname: {
+											description: """
+												Enables hostname verification.
+
+												If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+												the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+												Only relevant for outgoing connections.
+
+												Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+												"""
+											required: false
+											type: bool: {}
+										}
+									}
+								}
+								topic: {
+									description: """
+										The topic used to name the subject of the schema when encoding.
+
+										Required when `subject_name_strategy` is `topic_name` or `topic_record_name`. Only used
+										when encoding.
+										"""
+									required: false
+									type: string: examples: ["logs"]
+								}
+								url: {
+									description: "The URL of the Schema Registry."
+									required:    true
+									type: string: examples: ["http://localhost:8081"]
+								}
+							}
+						}
 					}
 				}
 				cef: {
@@ -251,6 +453,14 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 																			[apache_avro]: https://avro.apache.org/
 																			"""
+						cbor: """
+																			Encodes an event as a [CBOR][cbor] data item.
+
+																			Bytes that are valid UTF-8 are encoded as text strings, and all other bytes as byte strings.
+																			Timestamps are encoded as RFC 3339 strings tagged as standard date/time.
+
+																			[cbor]: https://www.rfc-editor.org/rfc/rfc8949
+																			"""
 						cef: "Encodes an event as a CEF (Common Event Format) formatted message."
 						csv: """
 																			Encodes an event as a CSV message.
@@ -285,6 +495,14 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 																			[logfmt]: https://brandur.org/logfmt
 																			"""
+						msgpack: """
+																			Encodes an event as a [MessagePack][msgpack] value.
+
+																			Bytes that are valid UTF-8 are encoded as strings, and all other bytes as binary data.
+																			Timestamps are encoded with the timestamp extension type.
+
+																			[msgpack]: https://msgpack.org/
+																			"""
 						native: """
 																			Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -301,6 +519,16 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 																			[vector_native_json]: https://github.com/vectordotdev/vector/blob/master/lib/codecs/tests/data/native_encoding/schema.cue
 																			[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
 																			"""
+						otlp: """
+																			Encodes an event as an [OpenTelemetry protocol (OTLP)][otlp] export request.
+
+																			Each event is encoded as its own request, except for batches sent as a single message
+																			without framing, whose events are all encoded into one request and so must be of the same
+																			type. Logs and traces are rebuilt from the fields added by the `opentelemetry` source, and
+																			the `resource.` and `scope.` prefixed tags of metrics become resource and scope attributes.
+
+																			[otlp]: https://opentelemetry.io/docs/specs/otlp/
+																			"""
 						protobuf: """
 																			Encodes an event as a [Protobuf][protobuf] message.
 
@@ -450,6 +678,26 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 					required:    false
 					type: array: items: type: string: {}
 				}
+				otlp: {
+					description:   "OTLP-specific encoding options."
+					relevant_when: "codec = \"otlp\""
+					required:      false
+					type: object: options: format: {
+						description: "The format the export requests are encoded in."
+						required:    false
+						type: string: {
+							default: "protobuf"
+							enum: {
+								json: """
+									Encodes the export requests as [OTLP/JSON][otlp_json].
+
+									[otlp_json]: https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding
+									"""
+								protobuf: "Encodes the export requests as binary Protobuf messages."
+							}
+						}
+					}
+				}
 				protobuf: {
 					description:   "Options for the Protobuf serializer."
 					relevant_when: "codec = \"protobuf\""
@@ -471,6 +719,207 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 							required:    true
 							type: string: examples: ["package.Message"]
 						}
+						schema_registry: {
+							description: """
+								Encodes messages in the Confluent wire format, prefixed with the ID of their schema in a
+								Confluent Schema Registry.
+
+								The ID of the latest schema registered under the subject named by `subject_name_strategy` is
+								looked up when the first event is encoded, which waits for the Schema Registry to respond.
+								Failed lookups are attempted again after 30 seconds. Registering the schema requires its
+								`.proto` source, so `auto_register_schemas` is not supported.
+								"""
+							required: false
+							type: object: options: {
+								auth: {
+									description: "Authentication for the Schema Registry."
+									required:    false
+									type: object: options: {
+										password: {
+											description:   "The basic authentication password."
+											relevant_when: "strategy = \"basic\""
+											required:      true
+											type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}", "password"]
+										}
+										strategy: {
+											description: "The authentication strategy to use."
+											required:    true
+											type: string: enum: {
+												basic: """
+													Basic authentication.
+
+													The username and password are concatenated and encoded via [base64][base64].
+
+													[base64]: https://en.wikipedia.org/wiki/Base64
+													"""
+												bearer: """
+													Bearer authentication.
+
+													The bearer token value (OAuth2, JWT, etc.) is passed as-is.
+													"""
+											}
+										}
+										token: {
+											description:   "The bearer authentication token."
+											relevant_when: "strategy = \"bearer\""
+											required:      true
+											type: string: {}
+										}
+										user: {
+											description:   "The basic authentication username."
+											relevant_when: "strategy = \"basic\""
+											required:      true
+											type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}", "username"]
+										}
+									}
+								}
+								auto_register_schemas: {
+									description: """
+										Registers the schema under its subject when encoding, if it is not registered yet.
+
+										When disabled, the schema must already be registered under its subject. Only used when
+										encoding.
+										"""
+									required: false
+									type: bool: default: false
+								}
+								subject_name_strategy: {
+									description: """
+										How the subject a schema is registered under is named when encoding.
+
+										See the [Confluent documentation][subject_name_strategy] for more details.
+
+										[subject_name_strategy]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#subject-name-strategy
+										"""
+									required: false
+									type: string: {
+										default: "topic_name"
+										enum: {
+											record_name:       "The subject is named after the fully-qualified name of the record or message type."
+											topic_name:        "The subject is named `<topic>-value`."
+											topic_record_name: "The subject is named `<topic>-<fully-qualified record or message type name>`."
+										}
+									}
+								}
+								timeout_secs: {
+									description: "The timeout for requests to the Schema Registry, in seconds."
+									required:    false
+									type: uint: {
+										default: 10
+										unit:    "seconds"
+									}
+								}
+								tls: {
+									description: "TLS configuration."
+									required:    false
+									type: object: options: {
+										alpn_protocols: {
+											description: """
+												Sets the list of supported ALPN protocols.
+
+												Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+												that they are defined.
+												"""
+											required: false
+											type: array: items: type: string: examples: ["h2"]
+										}
+										ca_file: {
+											description: """
+												Absolute path to an additional CA certificate file.
+
+												The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+												"""
+											required: false
+											type: string: examples: ["/path/to/certificate_authority.crt"]
+										}
+										crt_file: {
+											description: """
+												Absolute path to a certificate file used to identify this server.
+
+												The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+												an inline string in PEM format.
+
+												If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+												"""
+											required: false
+											type: string: examples: ["/path/to/host_certificate.crt"]
+										}
+										key_file: {
+											description: """
+												Absolute path to a private key file used to identify this server.
+
+												The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+												"""
+											required: false
+											type: string: examples: ["/path/to/host_certificate.key"]
+										}
+										key_pass: {
+											description: """
+												Passphrase used to unlock the encrypted key file.
+
+												This has no effect unless `key_file` is set.
+												"""
+											required: false
+											type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+										}
+										server_name: {
+											description: """
+												Server name to use when using Server Name Indication (SNI).
+
+												Only relevant for outgoing connections.
+												"""
+											required: false
+											type: string: examples: ["www.example.com"]
+										}
+										verify_certificate: {
+											description: """
+												Enables certificate verification. For components that create a server, this requires that the
+												client connections have a valid client certificate. For components that initiate requests,
+												this validates that the upstream has a valid certificate.
+
+												If enabled, certificates must not be expired and must be issued by a trusted
+												issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+												certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+												so on, until the verification process reaches a root certificate.
+
+												Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+												"""
+											required: false
+											type: bool: {}
+										}
+										verify_hostname: {
+											description: """
+												Enables hostname verification.
+
+												If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+												the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+												Only relevant for outgoing connections.
+
+												Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+												"""
+											required: false
+											type: bool: {}
+										}
+									}
+								}
+								topic: {
+									description: """
+										The topic used to name the subject of the schema when encoding.
+
+										Required when `subject_name_strategy` is `topic_name` or `topic_record_name`. Only used
+										when encoding.
+										"""
+									required: false
+									type: string: examples: ["logs"]
+								}
+								url: {
+									description: "The URL of the Schema Registry."
+									required:    true
+									type: string: examples: ["http://localhost:8081"]
+								}
+							}
+						}
 					}
 				}
 				timestamp_format: {
@@ -540,6 +989,23 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 																			The prefix is a 32-bit unsigned integer, little endian.
 																			"""
 						newline_delimited: "Event data is delimited by a newline (LF) character."
+						varint_length_delimited: """
+							Event data is prefixed with its length in bytes, encoded as an unsigned [varint][varint].
+
+							This is the framing used by streams of delimited protobuf messages.
+
+							[varint]: https://protobuf.dev/programming-guides/encoding/#varints
+							"""
+					}
+				}
+				varint_length_delimited: {
+					description:   "Options for the varint length delimited encoder."
+					relevant_when: "method = \"varint_length_delimited\""
+					required:      false
+					type: object: options: max_frame_length: {
+						description: "Maximum frame length"
+						required:    false
+						type: uint: default: 8388608
 					}
 				}
 			}
@@ -83,6 +83,12 @@ components: sources: datadog_agent: {
 				If [multiple_outputs](#multiple_outputs) is enabled, received trace events will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "errors"
+			description: """
+				If [reroute_decoding_errors](#reroute_decoding_errors) is enabled, log messages that fail to decode will go to this output stream, holding the raw message and the decoding error. Use `<component_id>.errors` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -235,6 +235,17 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		dedupe_events_suppressed_total: {
+			description:       "The total number of duplicate events suppressed by the `dedupe` transform, per key."
+			type:              "counter"
+			default_namespace: "vector"
+			tags: _component_tags & {
+				key: {
+					description: "The values of the fields used to match the suppressed event."
+					required:    true
+				}
+			}
+		}
 		events_discarded_total: {
 			description:       "The total number of events discarded by this component."
 			type:              "counter"
@@ -261,6 +272,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		buffer_evicted_events_total: {
+			description:       "The number of events evicted from this buffer to make room for newer events."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		buffer_received_event_bytes_total: {
 			description:       "The number of bytes received by this buffer."
 			type:              "counter"
@@ -706,6 +723,21 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		reduce_groups_evicted_total: {
+			description:       "The total number of groups flushed early by the `reduce` transform to stay within its limits."
+			type:              "counter"
+			default_namespace: "vector"
+			tags: _component_tags & {
+				reason: {
+					description: "The limit that caused the group to be flushed."
+					required:    true
+					enum: {
+						max_groups:       "The `max_groups` limit was reached."
+						max_memory_bytes: "The `max_memory_bytes` limit was reached."
+					}
+				}
+			}
+		}
 		send_errors_total: {
 			description:       "The total number of errors sending messages."
 			type:              "counter"
@@ -796,6 +828,17 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		top_k_tag_values_replaced_total: {
+			description:       "The total number of tag values replaced by the `top_k` transform because they didn't rank among the top values."
+			type:              "counter"
+			default_namespace: "vector"
+			tags: _component_tags & {
+				tag_key: {
+					description: "The key of the tag whose value was replaced."
+					required:    true
+				}
+			}
+		}
 		uptime_seconds: {
 			description:       "The total number of seconds the Vector instance has been up."
 			type:              "gauge"
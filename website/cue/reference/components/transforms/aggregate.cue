@@ -184,6 +184,18 @@ components: transforms: aggregate: {
 				"""
 		}
 
+		distribution_modes: {
+			title: "Summarizing Distributions"
+			body: """
+				The `Distribution`, `Histogram`, and `Summary` modes reduce high-frequency streams of `absolute`
+				`gauge` or `incremental` `counter` metrics to a single metric per series that describes the
+				distribution of the values seen during the interval. `Distribution` keeps every value as a sample,
+				`Histogram` counts the values into the configured `buckets`, and `Summary` estimates the configured
+				`quantiles` using a sketch, so its memory use does not grow with the number of values. The
+				`Summary` mode emits `absolute` metrics, while the other two emit `incremental` metrics.
+				"""
+		}
+
 	}
 
 	telemetry: metrics: {
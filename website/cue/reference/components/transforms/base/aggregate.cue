@@ -1,6 +1,18 @@
 package metadata
 
 base: components: transforms: aggregate: configuration: {
+	buckets: {
+		description: """
+			The upper limits of the buckets to count values into when `mode` is `Histogram`.
+
+			The limits must be finite and in strictly increasing order.
+			"""
+		required: false
+		type: array: {
+			default: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+			items: type: float: {}
+		}
+	}
 	interval_ms: {
 		description: """
 			The interval between flushes, in milliseconds.
@@ -20,16 +32,40 @@ base: components: transforms: aggregate: configuration: {
 		type: string: {
 			default: "Auto"
 			enum: {
-				Auto:   "Default mode. Sums incremental metrics and uses the latest value for absolute metrics."
-				Count:  "Counts metrics for incremental and absolute metrics"
-				Diff:   "Returns difference between latest value for absolute, ignores incremental"
+				Auto:  "Default mode. Sums incremental metrics and uses the latest value for absolute metrics."
+				Count: "Counts metrics for incremental and absolute metrics"
+				Diff:  "Returns difference between latest value for absolute, ignores incremental"
+				Distribution: """
+					Collects the values of absolute gauges and incremental counters into a distribution, ignores
+					other metrics
+					"""
+				Histogram: """
+					Counts the values of absolute gauges and incremental counters into a histogram with the
+					configured `buckets`, ignores other metrics
+					"""
 				Latest: "Returns the latest value for absolute metrics, ignores incremental"
 				Max:    "Max value of absolute metric, ignores incremental"
 				Mean:   "Mean value of absolute metric, ignores incremental"
 				Min:    "Min value of absolute metric, ignores incremental"
 				Stdev:  "Stdev value of absolute metric, ignores incremental"
 				Sum:    "Sums incremental metrics, ignores absolute"
+				Summary: """
+					Summarizes the values of absolute gauges and incremental counters into the configured
+					`quantiles`, ignores other metrics
+					"""
 			}
 		}
 	}
+	quantiles: {
+		description: """
+			The quantiles to calculate when `mode` is `Summary`.
+
+			Quantiles are estimated using a sketch, so they have a bounded relative error of about 1%.
+			"""
+		required: false
+		type: array: {
+			default: [0.5, 0.75, 0.9, 0.95, 0.99]
+			items: type: float: {}
+		}
+	}
 }
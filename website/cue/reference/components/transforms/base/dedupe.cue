@@ -4,10 +4,81 @@ base: components: transforms: dedupe: configuration: {
 	cache: {
 		description: "Caching configuration for deduplication."
 		required:    false
-		type: object: options: num_events: {
-			description: "Number of events to cache and use for comparing incoming events to previously seen events."
-			required:    false
-			type: uint: default: 5000
+		type: object: options: {
+			num_events: {
+				description: "Number of events to cache and use for comparing incoming events to previously seen events."
+				required:    false
+				type: uint: default: 5000
+			}
+			persistence: {
+				description: """
+					Configuration for persisting the deduplication cache to disk.
+
+					When enabled, the cache is periodically snapshotted to the data directory and reloaded when
+					the transform starts, so events that were seen before a restart or configuration reload are
+					still recognized as duplicates afterwards.
+					"""
+				required: false
+				type: object: options: {
+					data_dir: {
+						description: """
+							The directory used to persist the cache snapshot.
+
+							By default, the [global `data_dir` option][global_data_dir] is used.
+							Make sure the running user has write permissions to this directory.
+
+							[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+							"""
+						required: false
+						type: string: examples: ["/var/lib/vector"]
+					}
+					snapshot_interval_secs: {
+						description: """
+							The interval between snapshots of the cache, in seconds.
+
+							A snapshot is always written when the transform shuts down. This interval bounds how
+							many entries are lost if Vector exits uncleanly.
+							"""
+						required: false
+						type: uint: {
+							default: 60
+							unit:    "seconds"
+						}
+					}
+				}
+			}
+			ttl_secs: {
+				description: """
+					The maximum amount of time, in seconds, that an entry is kept in the cache.
+
+					How the age of an entry is measured depends on `ttl_mode`. Once an entry is older than
+					this, the next matching event is forwarded as if it had never been seen before. When
+					unset, entries are only evicted when the cache is full.
+					"""
+				required: false
+				type: uint: examples: [600]
+			}
+			ttl_mode: {
+				description: "How the age of a cache entry is measured when `ttl_secs` is set."
+				required:    false
+				type: string: {
+					default: "sliding"
+					enum: {
+						sliding: """
+							The age of an entry is measured from the last time a matching event was seen.
+
+							A steady stream of duplicates is suppressed indefinitely.
+							"""
+						window: """
+							The age of an entry is measured from the time the first matching event was forwarded.
+
+							Duplicates are suppressed for `ttl_secs` after an event is forwarded, after which the
+							next matching event is forwarded and starts a new window. This is useful for
+							suppressing repeated alerts for a fixed amount of time.
+							"""
+					}
+				}
+			}
 		}
 	}
 	fields: {
@@ -34,4 +105,22 @@ base: components: transforms: dedupe: configuration: {
 			}
 		}
 	}
+	internal_metrics: {
+		description: "Configuration of internal metrics for the `dedupe` transform."
+		required:    false
+		type: object: options: emit_events_suppressed_per_key: {
+			description: """
+				Whether or not to emit the `dedupe_events_suppressed_total` internal metric with the `key` tag.
+
+				If true, the counter is incremented for each suppressed duplicate, tagged with the
+				values of the fields used to match it. If false, the number of suppressed events can
+				still be seen through the `component_discarded_events_total` internal metric.
+
+				Note that this defaults to false because the `key` tag has potentially unbounded cardinality.
+				Only set this to true if you know that the number of unique keys is bounded.
+				"""
+			required: false
+			type: bool: default: false
+		}
+	}
 }
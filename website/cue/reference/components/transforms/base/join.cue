@@ -0,0 +1,104 @@
+package metadata
+
+base: components: transforms: join: configuration: {
+	join_type: {
+		description: "The kind of join to perform."
+		required:    false
+		type: string: {
+			default: "inner"
+			enum: {
+				inner: """
+					Only merged events are sent to the default output.
+
+					Events from either side that don't match any event from the other side are sent to the
+					`unmatched` output.
+					"""
+				left: """
+					Merged events, and events from the left side that don't match any event from the right side,
+					are sent to the default output.
+
+					Events from the right side that don't match any event from the left side are sent to the
+					`unmatched` output.
+					"""
+				outer: """
+					Merged events, and events from either side that don't match any event from the other side,
+					are sent to the default output.
+					"""
+			}
+		}
+	}
+	key: {
+		description: """
+			The field containing the key that events are joined on.
+
+			Events from the left side are merged with events from the right side that have the same
+			value for this field. Events without this field can't be joined, and are handled like
+			events that didn't match any event.
+			"""
+		required: true
+		type: string: examples: ["request_id"]
+	}
+	left: {
+		description: """
+			The IDs of the upstream components whose events are on the left side of the join.
+
+			Each of these components must also be listed in `inputs`. A specific output of a component
+			can be selected with `<component_id>.<output>`.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["requests"]
+	}
+	max_keys: {
+		description: """
+			The maximum number of keys to buffer events for.
+
+			When this limit is reached, the window of the oldest key is ended early to make room for the
+			new one.
+			"""
+		required: false
+		type: uint: {
+			default: 10000
+			unit:    "keys"
+		}
+	}
+	right: {
+		description: """
+			The IDs of the upstream components whose events are on the right side of the join.
+
+			Each of these components must also be listed in `inputs`. A specific output of a component
+			can be selected with `<component_id>.<output>`.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["responses"]
+	}
+	right_field: {
+		description: """
+			The field of merged events in which the fields of the right event are stored.
+
+			By default, the fields of the right event are added to the top level of the left event, with
+			the values of the left event taking precedence when both events have the same field.
+			"""
+		required: false
+		type: string: examples: ["response"]
+	}
+	right_key: {
+		description: "The field containing the key of events from the right side, if it differs from `key`."
+		required:    false
+		type: string: examples: ["trace_id"]
+	}
+	window_secs: {
+		description: """
+			The amount of time to buffer events for a key after the first event with that key is seen,
+			in seconds.
+
+			Events are only joined with events of the same key that are seen within this window. Once
+			it has elapsed, the events that didn't match any event are sent to the output determined by
+			`join_type`.
+			"""
+		required: false
+		type: float: {
+			default: 30.0
+			unit:    "seconds"
+		}
+	}
+}
@@ -60,6 +60,27 @@ base: components: transforms: reduce: configuration: {
 		required:    false
 		type: uint: {}
 	}
+	max_groups: {
+		description: """
+			The maximum number of groups to reduce at once.
+
+			When a new group would exceed this limit, the group that was least recently updated is
+			flushed early to make room for it.
+			"""
+		required: false
+		type: uint: unit: "groups"
+	}
+	max_memory_bytes: {
+		description: """
+			The maximum amount of memory to use for the groups being reduced, in bytes.
+
+			The memory used by a group is estimated from the size of the events added to it. When this
+			limit is exceeded, groups are flushed early, starting with the one that was least recently
+			updated, until the estimate is back under the limit.
+			"""
+		required: false
+		type: uint: unit: "bytes"
+	}
 	merge_strategies: {
 		description: """
 			A map of field names to custom merge strategies.
@@ -99,6 +120,32 @@ base: components: transforms: reduce: configuration: {
 			}
 		}
 	}
+	persistence: {
+		description: """
+			Configuration for persisting the groups being reduced across restarts.
+
+			When enabled, the groups that are still open when Vector shuts down are written to disk instead
+			of being flushed, and are loaded again when the transform starts. Time spent while Vector is
+			stopped does not count towards `expire_after_ms` or `end_every_period_ms`. When the transform is
+			removed or changed by a configuration reload, its open groups are flushed as usual.
+
+			Events in persisted groups are acknowledged to their sources once the groups have been written
+			to disk, rather than when the reduced event is delivered.
+			"""
+		required: false
+		type: object: options: data_dir: {
+			description: """
+				The directory used to persist the groups being reduced.
+
+				By default, the [global `data_dir` option][global_data_dir] is used.
+				Make sure the running user has write permissions to this directory.
+
+				[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+				"""
+			required: false
+			type: string: examples: ["/var/lib/vector"]
+		}
+	}
 	starts_when: {
 		description: """
 			A condition used to distinguish the first event of a transaction.
@@ -1,6 +1,42 @@
 package metadata
 
 base: components: transforms: sample: configuration: {
+	adaptive: {
+		description: """
+			Configuration for adaptive sampling.
+
+			Instead of applying the same rate to every bucket of events, the throughput of each bucket is
+			measured over `window_secs` and its ratio is adjusted so that it forwards roughly
+			`target_events_per_sec`. Buckets with a throughput below the target are not sampled at all, so
+			rare buckets are kept in full while busy ones are sampled down.
+
+			Buckets are determined by `group_by`. If `key_field` is also set, its value is hashed to decide
+			which events of a bucket are forwarded.
+			"""
+		required: false
+		type: object: options: {
+			target_events_per_sec: {
+				description: "The number of events per second to forward for each bucket."
+				required:    true
+				type: float: examples: [100.0]
+			}
+			window_secs: {
+				description: """
+					The window over which the throughput of each bucket is measured, in seconds.
+
+					The ratio applied to a bucket is recomputed from the throughput of its previous window. All
+					events of a new bucket are forwarded until its first window has elapsed. Buckets that
+					receive no events for two windows are forgotten, and start over as new buckets if events
+					arrive for them again.
+					"""
+				required: false
+				type: float: {
+					default: 10.0
+					unit:    "seconds"
+				}
+			}
+		}
+	}
 	exclude: {
 		description: "A logical condition used to exclude events from sampling."
 		required:    false
@@ -111,6 +111,80 @@ base: components: transforms: tag_cardinality_limit: configuration: {
 			}
 		}
 	}
+	persistence: {
+		description: """
+			Configuration for persisting the accepted tag values to disk.
+
+			When enabled, the accepted values are periodically snapshotted to the data directory and
+			reloaded when the transform starts, so the same values keep being accepted after a restart or
+			configuration reload instead of a fresh set of values being accepted.
+
+			Only the `exact` mode supports persistence.
+			"""
+		required: false
+		type: object: options: {
+			data_dir: {
+				description: """
+					The directory used to persist the snapshot of accepted tag values.
+
+					By default, the [global `data_dir` option][global_data_dir] is used.
+					Make sure the running user has write permissions to this directory.
+
+					[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+					"""
+				required: false
+				type: string: examples: ["/var/lib/vector"]
+			}
+			seed_file: {
+				description: """
+					A snapshot to load the accepted tag values from when none has been persisted to the data
+					directory yet.
+
+					The file is only read once, when the transform starts without a snapshot of its own, for
+					example to start a new instance from a copy of the snapshot of another instance. It is
+					never written to, and instances don't share the values they accept while running, so
+					instances seeded from the same file still accept different values afterwards.
+					"""
+				required: false
+				type: string: examples: ["/etc/vector/tag_cardinality_limit.json"]
+			}
+			snapshot_interval_secs: {
+				description: """
+					The interval between snapshots of the accepted tag values, in seconds.
+
+					A snapshot is always written when the transform shuts down. This interval bounds how
+					many accepted values are lost if Vector exits uncleanly.
+					"""
+				required: false
+				type: uint: {
+					default: 60
+					unit:    "seconds"
+				}
+			}
+		}
+	}
+	tracking_scope: {
+		description: "Controls whether the accepted values of metrics without per-metric limits are shared."
+		required:    false
+		type: string: {
+			default: "global"
+			enum: {
+				global: """
+					The accepted values of each tag key are shared by all metrics without per-metric limits.
+
+					The `value_limit` then caps the number of distinct values of a tag key across all of these
+					metrics.
+					"""
+				per_metric: """
+					The accepted values of each tag key are tracked separately for each metric name and
+					namespace.
+
+					The `value_limit` then caps the number of distinct values of a tag key for each metric,
+					at the cost of keeping a set of accepted values for every metric.
+					"""
+			}
+		}
+	}
 	value_limit: {
 		description: "How many distinct values to accept for any given key."
 		required:    false
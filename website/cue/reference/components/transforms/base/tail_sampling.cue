@@ -0,0 +1,84 @@
+package metadata
+
+base: components: transforms: tail_sampling: configuration: {
+	conditions: {
+		description: """
+			A list of logical conditions used to keep traces.
+
+			Each condition is checked against every span of a trace. If any span matches any condition,
+			the whole trace is kept.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: condition: examples: [".status.code == 2", "to_unix_timestamp!(.end_time_unix_nano, unit: \"milliseconds\") - to_unix_timestamp!(.start_time_unix_nano, unit: \"milliseconds\") > 500"]
+		}
+	}
+	decision_wait_secs: {
+		description: """
+			The amount of time to wait after the first span of a trace is seen before deciding whether
+			to keep the trace, in seconds.
+
+			Spans that arrive after the decision has been made follow the same decision, as long as the
+			trace ID is still in the decision cache.
+			"""
+		required: false
+		type: float: {
+			default: 30.0
+			unit:    "seconds"
+		}
+	}
+	fallback_ratio: {
+		description: """
+			The ratio of traces that do not match any of the `conditions` which are kept anyway.
+
+			The decision is made by hashing the trace ID, so all instances of Vector sampling the same
+			trace make the same decision.
+			"""
+		required: false
+		type: float: {
+			default: 0.0
+			examples: [0.1]
+		}
+	}
+	max_traces: {
+		description: """
+			The maximum number of traces to buffer while waiting for a decision.
+
+			When this limit is reached, a decision is made for the oldest buffered trace early to make
+			room for the new one. The same number of past decisions is remembered for spans that arrive
+			late.
+			"""
+		required: false
+		type: uint: {
+			default: 50000
+			unit:    "traces"
+		}
+	}
+	sample_rate_key: {
+		description: """
+			The event key in which the sample rate is stored. If set to an empty string, the sample rate will not be added to the event.
+
+			Traces kept because they match one of the `conditions` have a sample rate of `1`. Traces
+			kept by the `fallback_ratio` have a sample rate equal to that ratio.
+			"""
+		required: false
+		type: string: {
+			default: "sample_rate"
+			examples: ["sample_rate"]
+		}
+	}
+	trace_id_field: {
+		description: """
+			The field containing the ID of the trace a span belongs to.
+
+			Spans are buffered by the value of this field. Spans without this field are forwarded
+			without being sampled.
+			"""
+		required: false
+		type: string: {
+			default: "trace_id"
+			examples: ["trace_id"]
+		}
+	}
+}
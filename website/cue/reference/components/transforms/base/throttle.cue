@@ -1,6 +1,43 @@
 package metadata
 
 base: components: transforms: throttle: configuration: {
+	algorithm: {
+		description: "The algorithm used to decide whether an event is within the rate limit."
+		required:    false
+		type: string: {
+			default: "token_bucket"
+			enum: {
+				sliding_log: """
+					Each bucket records the time of every event allowed within the last `window_secs`, and
+					events are rate limited while `threshold` events have been allowed.
+
+					This is exact, but uses memory proportional to `threshold` for every bucket.
+					"""
+				sliding_window_counter: """
+					Each bucket counts the events allowed in the current and previous fixed windows of
+					`window_secs`. The count of the previous window is weighted by how much of it overlaps the
+					sliding window ending now.
+
+					This approximates `sliding_log` using a constant amount of memory for every bucket.
+					"""
+				token_bucket: """
+					Each bucket holds up to `burst` tokens, refilled at a rate of `threshold` tokens per
+					`window_secs`. Each event takes one token, and events are rate limited while the bucket is
+					empty.
+					"""
+			}
+		}
+	}
+	burst: {
+		description: """
+			The maximum number of events allowed at once for a given bucket when using the
+			`token_bucket` algorithm.
+
+			If left unspecified, this is the same as the bucket's threshold.
+			"""
+		required: false
+		type: uint: unit: "events"
+	}
 	exclude: {
 		description: "A logical condition used to exclude events from sampling."
 		required:    false
@@ -38,6 +75,16 @@ base: components: transforms: throttle: configuration: {
 			syntax: "template"
 		}
 	}
+	reroute_dropped: {
+		description: """
+			Whether or not to send events over the rate limit to a named `dropped` output instead of
+			discarding them.
+
+			The rate limited events are not modified.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	threshold: {
 		description: """
 			The number of events allowed for a given bucket per configured `window_secs`.
@@ -47,6 +94,31 @@ base: components: transforms: throttle: configuration: {
 		required: true
 		type: uint: {}
 	}
+	threshold_field: {
+		description: """
+			The field containing the number of events allowed for the event's bucket per configured
+			`window_secs`.
+
+			This allows applying quotas defined in the events themselves, such as per-tenant limits. If
+			the event doesn't have the field, or its value is not a positive integer, `threshold` is
+			used instead. Values above `threshold_field_max` are lowered to it.
+			"""
+		required: false
+		type: string: examples: ["tenant_quota"]
+	}
+	threshold_field_max: {
+		description: """
+			The maximum threshold that can be taken from `threshold_field`.
+
+			This bounds the state kept for each bucket, since the `sliding_log` algorithm records the
+			time of every event allowed within the window.
+			"""
+		required: false
+		type: uint: {
+			default: 10000
+			unit:    "events"
+		}
+	}
 	window_secs: {
 		description: "The time window in which the configured `threshold` is applied, in seconds."
 		required:    true
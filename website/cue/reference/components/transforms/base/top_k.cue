@@ -0,0 +1,66 @@
+package metadata
+
+base: components: transforms: top_k: configuration: {
+	k: {
+		description: "The number of values to keep for each tag of each metric name."
+		required:    false
+		type: uint: {
+			default: 10
+			unit:    "values"
+		}
+	}
+	other_value: {
+		description: """
+			The value that replaces tag values that don't rank among the top values.
+
+			The value of absolute counters and gauges whose tag values are replaced is the sum of the
+			latest values of all of the series replaced into the same series, so that they don't
+			overwrite each other. Series that aren't seen for a whole window are removed from the sum.
+			The tag values of other absolute metrics are never replaced.
+			"""
+		required: false
+		type: string: {
+			default: "other"
+			examples: ["other"]
+		}
+	}
+	rank_by: {
+		description: "How the weight of a tag value is measured."
+		required:    false
+		type: string: {
+			default: "count"
+			enum: {
+				count: "Each metric event adds one to the weight of its tag value."
+				value: """
+					Each metric event adds its value to the weight of its tag value.
+
+					The value of counters and gauges is used as is. For distributions, aggregated histograms,
+					aggregated summaries, and sketches, the sum of the samples is used. For sets, the number of
+					values is used. Negative values count as positive.
+					"""
+			}
+		}
+	}
+	tags: {
+		description: """
+			The tags whose values are limited.
+
+			Each tag is limited independently for each metric name.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["path", "user_id"]
+	}
+	window_secs: {
+		description: """
+			The length of the window over which the top values are measured, in seconds.
+
+			The values that ranked among the top values in the previous window are also kept, so that
+			the kept values don't change abruptly when a new window starts.
+			"""
+		required: false
+		type: float: {
+			default: 300.0
+			unit:    "seconds"
+		}
+	}
+}
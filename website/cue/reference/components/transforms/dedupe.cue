@@ -83,6 +83,44 @@ components: transforms: dedupe: {
 				"""
 		}
 
+		expiration: {
+			title: "Expiration"
+			body: """
+				When `cache.ttl_secs` is set, entries also expire once they are older
+				than the configured duration, so an event that matches an expired
+				entry is forwarded as if it had never been seen. How the age of an
+				entry is measured depends on `cache.ttl_mode`:
+
+				- `sliding` (the default) measures age from the last time a matching
+				  event was seen, so a steady stream of duplicates is suppressed
+				  indefinitely.
+				- `window` measures age from the time the first matching event was
+				  forwarded. Duplicates are suppressed for `cache.ttl_secs`, after
+				  which the next matching event is forwarded and opens a new window.
+				  For example, `ttl_secs = 600` with `ttl_mode = "window"` forwards
+				  at most one copy of an identical alert every ten minutes.
+
+				Entries are still bounded by `cache.num_events` in both modes.
+				"""
+		}
+
+		persistence: {
+			title: "Persistence"
+			body: """
+				By default the cache only lives in memory, so after Vector restarts
+				or reloads its configuration, the first copy of every event is
+				forwarded again. When `cache.persistence` is set, the cache is
+				written to a snapshot file in the data directory every
+				`cache.persistence.snapshot_interval_secs` seconds and when the
+				transform shuts down, and the snapshot is loaded again when the
+				transform starts. This is useful with sources that redeliver events
+				after a restart, such as `kafka` or `aws_sqs`.
+
+				Entries that have outlived `cache.ttl_secs` are discarded when the
+				snapshot is loaded.
+				"""
+		}
+
 		missing_fields: {
 			title: "Missing Fields"
 			body: """
@@ -93,4 +131,8 @@ components: transforms: dedupe: {
 				"""
 		}
 	}
+
+	telemetry: metrics: {
+		dedupe_events_suppressed_total: components.sources.internal_metrics.output.metrics.dedupe_events_suppressed_total
+	}
 }
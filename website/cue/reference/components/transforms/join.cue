@@ -0,0 +1,106 @@
+package metadata
+
+components: transforms: join: {
+	title: "Join"
+
+	description: """
+		Correlates events from two sets of inputs that share a key, such as request and
+		response logs, and merges them into single events.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.join.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  true
+	}
+
+	examples: [
+		{
+			title: "Join requests with their responses"
+
+			configuration: {
+				left:  ["requests"]
+				right: ["responses"]
+				key:   "request_id"
+			}
+
+			input: [
+				{log: {request_id: "abcd1234", method: "GET", path: "/path"}},
+				{log: {request_id: "abcd1234", status: 200, duration_ms: 15.2}},
+			]
+			output: log: {
+				request_id:  "abcd1234"
+				method:      "GET"
+				path:        "/path"
+				status:      200
+				duration_ms: 15.2
+			}
+		},
+	]
+
+	how_it_works: {
+		sides: {
+			title: "Left and Right Sides"
+			body: """
+				Every component in `left` and `right` must also be listed in the transform's
+				`inputs`. Events are assigned to a side by the component they were received
+				from. Events received from any other input are sent to the `unmatched` output
+				as they are.
+				"""
+		}
+
+		windows: {
+			title: "Join Windows"
+			body: """
+				Events are buffered by the value of their key for `window_secs` after the
+				first event with that key is seen. Whenever an event arrives, it is merged with
+				every buffered event with the same key from the other side, and each merged
+				event is sent to the default output right away.
+
+				Once the window of a key has elapsed, the buffered events that were not merged
+				with any event are sent to the default output or to the `unmatched` output,
+				depending on `join_type`. Events that were merged are discarded, as they have
+				already been sent as part of merged events.
+				"""
+		}
+
+		memory_usage: {
+			title: "Memory Usage"
+			body: """
+				All events of a key are held in memory until its window has elapsed. At most
+				`max_keys` keys are buffered at once. When that limit is reached, the window of
+				the oldest key is ended early.
+				"""
+		}
+	}
+
+	outputs: [
+		components._default_output,
+		{
+			name: "unmatched"
+			description: """
+				Events that were not merged with any event from the other side, and that are not
+				sent to the default output according to `join_type`. For a transform component
+				named `foo`, this output can be accessed by specifying `foo.unmatched` as the input
+				to another component.
+				"""
+		},
+	]
+}
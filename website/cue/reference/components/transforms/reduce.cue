@@ -144,7 +144,32 @@ components: transforms: reduce: {
 		},
 	]
 
+	how_it_works: {
+		group_limits: {
+			title: "Group Limits"
+			body: """
+				By default, the number of groups being reduced at once is unbounded. The `max_groups` and
+				`max_memory_bytes` options cap the number of groups and their estimated memory usage. When
+				either limit is exceeded, the group that was least recently updated is flushed early, as if
+				it had expired, until the transform is back under its limits. Each early flush increments
+				the `reduce_groups_evicted_total` metric.
+				"""
+		}
+
+		persistence: {
+			title: "Persistence"
+			body: """
+				By default, all open groups are flushed when Vector shuts down, which splits transactions
+				that span a restart. When `persistence` is configured, open groups are written to the
+				data directory on shutdown instead, and are loaded again when the transform starts so that
+				they can keep being reduced. The persisted state is removed once loaded, so groups are
+				never restored twice. If Vector exits uncleanly, the groups that were open are lost.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
-		stale_events_flushed_total: components.sources.internal_metrics.output.metrics.stale_events_flushed_total
+		reduce_groups_evicted_total: components.sources.internal_metrics.output.metrics.reduce_groups_evicted_total
+		stale_events_flushed_total:  components.sources.internal_metrics.output.metrics.stale_events_flushed_total
 	}
 }
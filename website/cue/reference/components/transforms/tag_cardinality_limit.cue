@@ -176,6 +176,24 @@ components: transforms: tag_cardinality_limit: {
 				will reset the cache. This means that new values will be passed through until
 				the cardinality limit is reached again. See [intended usage](#intended-usage)
 				for more info.
+
+				In mode `exact`, the accepted values can be persisted to disk with the
+				`persistence` option. They are then reloaded when Vector restarts, so the same
+				values keep being accepted. When no snapshot has been persisted yet, for example
+				for a new replica, the accepted values are loaded from `persistence.seed_file`
+				instead, which lets replicas of a deployment start out accepting the same values.
+				"""
+		}
+
+		tracking_scope: {
+			title: "Tracking Scope"
+			body: """
+				By default, the accepted values of each tag key are shared by all metrics that
+				don't have `per_metric_limits`, so the `value_limit` caps the values of a tag key
+				across all of them. With `tracking_scope` set to `per_metric`, each metric name
+				and namespace gets its own set of accepted values instead, so a single metric
+				with a high cardinality tag can't use up the values for all other metrics. This
+				increases the memory usage in proportion to the number of distinct metrics.
 				"""
 		}
 	}
@@ -0,0 +1,62 @@
+package metadata
+
+components: transforms: tail_sampling: {
+	title: "Tail Sampling"
+
+	description: """
+		Samples whole traces after all of their spans have been seen, keeping traces that match
+		any of the configured conditions and a configurable ratio of the rest.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.tail_sampling.configuration
+
+	input: {
+		logs:    false
+		metrics: null
+		traces:  true
+	}
+
+	how_it_works: {
+		decision_wait: {
+			title: "Decision Wait"
+			body: """
+				Spans are buffered by their trace ID for `decision_wait_secs` after the
+				first span of a trace is seen. Once that time has elapsed, the whole
+				trace is kept if any of its spans matched any of the `conditions`.
+				Otherwise, it is kept with a probability of `fallback_ratio`, decided
+				by hashing the trace ID so that every Vector instance makes the same
+				decision for the same trace.
+
+				Spans that arrive after the decision has been made follow the same
+				decision, as long as the trace ID is still among the last
+				`max_traces` decisions.
+				"""
+		}
+
+		memory_usage: {
+			title: "Memory Usage"
+			body: """
+				All spans of a trace are held in memory until its decision is made.
+				At most `max_traces` traces are buffered at once. When that limit is
+				reached, a decision is made early for the oldest buffered trace.
+				"""
+		}
+	}
+}
@@ -78,8 +78,10 @@ components: transforms: throttle: {
 			title: "Rate Limiting"
 			body:  """
 				The `throttle` transform will spread load across the configured `window_secs`, ensuring that each bucket's
-				throughput averages out to the `threshold` per `window_secs`. It utilizes a [Generic Cell Rate Algorithm](\(urls.gcra)) to
-				rate limit the event stream.
+				throughput averages out to the `threshold` per `window_secs`. The `algorithm` option selects how
+				this is enforced. The default `token_bucket` algorithm is implemented as a
+				[Generic Cell Rate Algorithm](\(urls.gcra)), while `sliding_log` and `sliding_window_counter` never
+				allow more than `threshold` events within any `window_secs`, exactly or approximately.
 				"""
 			sub_sections: [
 				{
@@ -96,9 +98,13 @@ components: transforms: throttle: {
 						pass through a rate limiter. Each event passing through the transform consumes an available cell,
 						if there is no available cell the event will be rate limited.
 
-						A rate limiter is created with a maximum number of cells equal to the `threshold`, and cells replenish
-						at a rate of `window_secs` divided by `threshold`. For example, a `window_secs` of 60 with a `threshold` of 10
-						replenishes a cell every 6 seconds and allows a burst of up to 10 events.
+						A rate limiter is created with a maximum number of cells equal to the `burst`, which defaults to the
+						`threshold`, and cells replenish at a rate of `window_secs` divided by `threshold`. For example, a
+						`window_secs` of 60 with a `threshold` of 10 replenishes a cell every 6 seconds and allows a burst of
+						up to 10 events.
+
+						When `threshold_field` is set, the threshold of each event's bucket is taken from that field, so
+						quotas such as per-tenant limits can be defined in the data itself.
 						"""
 				},
 				{
@@ -108,9 +114,24 @@ components: transforms: throttle: {
 						for that particular bucket when the rate limiter is at capacity. Any event passed when the rate
 						limiter is at capacity will be discarded and tracked by an `events_discarded_total` metric tagged
 						by the bucket's `key`.
+
+						When `reroute_dropped` is set, rate limited events are instead sent to the `dropped` output.
 						"""
 				},
 			]
 		}
 	}
+
+	outputs: [
+		components._default_output,
+		{
+			name: "dropped"
+			description: """
+				This transform also implements an additional `dropped` output. When `reroute_dropped` is set to
+				`true`, events over the rate limit are sent to the `dropped` output instead of being discarded.
+				For a transform component named `foo`, this output can be accessed by specifying `foo.dropped` as
+				the input to another component.
+				"""
+		},
+	]
 }
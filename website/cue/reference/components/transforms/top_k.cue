@@ -0,0 +1,138 @@
+package metadata
+
+components: transforms: top_k: {
+	title: "Top K"
+
+	description: """
+		Bounds the cardinality of tags on metric events by keeping only the most frequent,
+		or heaviest, values of each tag and replacing the rest with a single `other` value.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.top_k.configuration
+
+	input: {
+		logs: false
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: false
+	}
+
+	examples: [
+		{
+			title: "Keep the most requested paths"
+			notes: """
+				In this example only the most requested value of the `path` tag is kept. The
+				third metric's `path` tag is replaced with `other`, because `/checkout` has been
+				seen less often than `/home`.
+				"""
+			configuration: {
+				tags: ["path"]
+				k: 1
+			}
+			input: [
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "/home"
+				}},
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "/home"
+				}},
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "/checkout"
+				}},
+			]
+			output: [
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "/home"
+				}},
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "/home"
+				}},
+				{metric: {
+					kind: "incremental"
+					name: "requests"
+					counter: value: 1.0
+					tags: path: "other"
+				}},
+			]
+		},
+	]
+
+	how_it_works: {
+		space_saving: {
+			title: "Finding the top values"
+			body: """
+				The values of each tag of each metric name are tracked with a "space-saving"
+				sketch, which uses a bounded amount of memory regardless of the number of distinct
+				values seen. The sketch tracks `10 * k` values; when a new value is seen while it is
+				full, the value with the lowest weight is forgotten and the new value inherits its
+				weight. Values that are heavy enough are therefore always kept, while rarely seen
+				values are replaced even though they might briefly be tracked.
+
+				With `rank_by` set to `count`, every metric event adds one to the weight of its tag
+				value. With `rank_by` set to `value`, the value of the metric is added instead.
+				"""
+		}
+
+		windows: {
+			title: "Windows"
+			body: """
+				The top values are measured over windows of `window_secs`. When a window ends, the
+				values that ranked among the top values during it keep being passed through for the
+				whole next window, in addition to the top values of the new window. This avoids
+				popular values being replaced at the start of every window, before they rank among the
+				top values again. Tags that are not seen during a whole window are forgotten.
+				"""
+		}
+
+		other_rollup: {
+			title: "Rolling up other values"
+			body: """
+				Tag values that don't rank among the top values are replaced with `other_value`, so
+				that all of them are reported as a single series. This works well for incremental
+				metrics, which downstream components and sinks add together. Absolute metrics, such
+				as gauges, rolled up into the same series overwrite each other instead, so only the
+				last value received is reported for that series.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		top_k_tag_values_replaced_total: components.sources.internal_metrics.output.metrics.top_k_tag_values_replaced_total
+	}
+}